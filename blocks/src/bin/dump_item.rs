@@ -0,0 +1,18 @@
+use std::env;
+use steven_blocks::item::VanillaIDMap;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        println!("usage: {} protocol_version id [damage]", args[0]);
+        return;
+    }
+    let protocol_version = str::parse::<i32>(&args[1]).unwrap();
+    let id = str::parse::<isize>(&args[2]).unwrap();
+    let damage = args.get(3).map(|d| str::parse::<isize>(d).unwrap());
+
+    let item_map = VanillaIDMap::new(protocol_version);
+    let item = item_map.by_vanilla_id(id, damage);
+
+    println!("{:?}", item);
+}
@@ -0,0 +1,3179 @@
+//! Maps the numeric item ids sent by servers to vanilla items.
+//!
+//! Before 1.13 an item is picked by its id and damage value, e.g. wool's
+//! damage is its colour. The flattening gave every variant its own name and
+//! since then an item's id is its index in the registry, which changes
+//! whenever a version adds items.
+
+use crate::Block;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// A vanilla item, which may not exist in every version
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Item {
+    /// The name without the `minecraft:` namespace, as used since 1.13
+    pub name: &'static str,
+    /// The id and damage value before 1.13, if the item existed then
+    pub legacy: Option<(u16, u16)>,
+}
+
+impl Item {
+    /// Returns the block placed by the item. Only known for blocks that
+    /// existed before 1.13.
+    pub fn block(&self, blocks: &crate::VanillaIDMap) -> Option<Block> {
+        match self.legacy {
+            Some((id, data)) if id < 256 && data < 16 => {
+                blocks.by_hierarchical_id(((id as usize) << 4) | data as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns how much damage the item takes before breaking, for tools,
+    /// weapons and armor.
+    pub fn max_damage(&self) -> Option<u16> {
+        let fixed = match self.name {
+            "bow" => 384,
+            "crossbow" => 465,
+            "trident" => 250,
+            "shield" => 336,
+            "elytra" => 432,
+            "turtle_helmet" => 275,
+            "fishing_rod" | "flint_and_steel" => 64,
+            "shears" => 238,
+            "carrot_on_a_stick" => 25,
+            "warped_fungus_on_a_stick" => 100,
+            _ => 0,
+        };
+        if fixed != 0 {
+            return Some(fixed);
+        }
+        let (material, kind) = self.name.split_once('_')?;
+        match kind {
+            "sword" | "pickaxe" | "axe" | "shovel" | "hoe" => match material {
+                "wooden" => Some(59),
+                "stone" => Some(131),
+                "iron" => Some(250),
+                "golden" => Some(32),
+                "diamond" => Some(1561),
+                "netherite" => Some(2031),
+                _ => None,
+            },
+            "helmet" | "chestplate" | "leggings" | "boots" => {
+                let base = match kind {
+                    "helmet" => 11,
+                    "chestplate" => 16,
+                    "leggings" => 15,
+                    _ => 13,
+                };
+                let multiplier = match material {
+                    "leather" => 5,
+                    "chainmail" | "iron" => 15,
+                    "golden" => 7,
+                    "diamond" => 33,
+                    "netherite" => 37,
+                    _ => return None,
+                };
+                Some(base * multiplier)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the kind and material of tools
+    pub fn tool(&self) -> Option<Tool> {
+        if self.name == "shears" {
+            return Some(Tool {
+                kind: ToolKind::Shears,
+                level: 0,
+                speed: 1.0,
+            });
+        }
+        let (material, kind) = self.name.split_once('_')?;
+        let kind = match kind {
+            "pickaxe" => ToolKind::Pickaxe,
+            "axe" => ToolKind::Axe,
+            "shovel" => ToolKind::Shovel,
+            "hoe" => ToolKind::Hoe,
+            "sword" => ToolKind::Sword,
+            _ => return None,
+        };
+        let (level, speed) = match material {
+            "wooden" => (0, 2.0),
+            "stone" => (1, 4.0),
+            "iron" => (2, 6.0),
+            "golden" => (0, 12.0),
+            "diamond" => (3, 8.0),
+            "netherite" => (4, 9.0),
+            _ => return None,
+        };
+        Some(Tool { kind, level, speed })
+    }
+
+    /// Returns how many of the item fit in a single slot
+    pub fn max_stack_size(&self) -> u8 {
+        if self.max_damage().is_some() {
+            return 1;
+        }
+        let name = self.name;
+        match name {
+            "ender_pearl" | "snowball" | "egg" | "bucket" | "sign" | "armor_stand"
+            | "written_book" | "honey_bottle" => 16,
+            _ if name.ends_with("_sign") || name.ends_with("_banner") => 16,
+            "saddle" | "cake" | "writable_book" | "enchanted_book" | "knowledge_book"
+            | "totem_of_undying" | "beetroot_soup" | "debug_stick" | "spyglass" | "bundle" => 1,
+            _ if name.ends_with("_bucket")
+                || name.ends_with("_bed")
+                || name.ends_with("_boat")
+                || name.ends_with("minecart")
+                || name.ends_with("shulker_box")
+                || name.ends_with("_horse_armor")
+                || name.ends_with("_banner_pattern")
+                || name.ends_with("_stew")
+                || name.ends_with("potion")
+                || name.starts_with("music_disc_") =>
+            {
+                1
+            }
+            _ => 64,
+        }
+    }
+}
+
+/// The kinds of tools that break some blocks faster than by hand
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolKind {
+    Pickaxe,
+    Axe,
+    Shovel,
+    Hoe,
+    Sword,
+    Shears,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tool {
+    pub kind: ToolKind,
+    /// Which blocks the tool can harvest, from 0 for wood and gold up to
+    /// 4 for netherite
+    pub level: u8,
+    /// How many times faster than by hand the tool breaks the blocks it
+    /// is made for. Swords and shears depend on the block instead.
+    pub speed: f64,
+}
+
+#[derive(Default)]
+pub struct VanillaIDMap {
+    flat: Vec<Item>,
+    legacy: HashMap<(u16, u16), Item>,
+
+    protocol_version: i32,
+}
+
+impl VanillaIDMap {
+    pub fn new(protocol_version: i32) -> VanillaIDMap {
+        let mut legacy = HashMap::new();
+        let mut legacy_ids = HashMap::new();
+        for &(id, damage, name) in LEGACY_ITEMS {
+            let item = Item {
+                name,
+                legacy: Some((id, damage)),
+            };
+            legacy.insert((id, damage), item);
+            legacy_ids.entry(name).or_insert((id, damage));
+        }
+
+        let names: Vec<&'static str> = if protocol_version >= 755 {
+            FLAT_ITEMS_1_17.to_vec()
+        } else if protocol_version >= 404 {
+            FLAT_ITEMS
+                .iter()
+                .filter(|item| item.since <= protocol_version && protocol_version < item.until)
+                .map(|item| item.name)
+                .collect()
+        } else {
+            vec![]
+        };
+        let flat = names
+            .into_iter()
+            .map(|name| {
+                let old_name = RENAMED
+                    .iter()
+                    .find(|(new, _)| *new == name)
+                    .map_or(name, |(_, old)| *old);
+                Item {
+                    name,
+                    legacy: legacy_ids.get(old_name).copied(),
+                }
+            })
+            .collect();
+
+        VanillaIDMap {
+            flat,
+            legacy,
+            protocol_version,
+        }
+    }
+
+    /// Looks up the item in a stack. Before 1.13 a damage value that isn't
+    /// a variant, such as a tool's durability, falls back to the base item.
+    pub fn by_vanilla_id(&self, id: isize, damage: Option<isize>) -> Option<Item> {
+        if self.protocol_version >= 404 {
+            return usize::try_from(id)
+                .ok()
+                .and_then(|id| self.flat.get(id))
+                .copied();
+        }
+        let id = u16::try_from(id).ok()?;
+        let damage = damage.and_then(|d| u16::try_from(d).ok()).unwrap_or(0);
+        self.legacy
+            .get(&(id, damage))
+            .or_else(|| self.legacy.get(&(id, 0)))
+            .copied()
+    }
+}
+
+struct FlatItem {
+    name: &'static str,
+    /// The first protocol version with the item
+    since: i32,
+    /// The first protocol version without the item
+    until: i32,
+}
+
+macro_rules! flat_items {
+    ($($name:literal $(since $since:literal)? $(until $until:literal)?,)*) => (
+        &[$(
+            FlatItem {
+                name: $name,
+                since: flat_items!(@or $($since)?, 0),
+                until: flat_items!(@or $($until)?, i32::MAX),
+            },
+        )*]
+    );
+    (@or $val:literal, $default:expr) => ($val);
+    (@or , $default:expr) => ($default);
+}
+
+/// Items renamed since the flattening, new name first
+const RENAMED: &[(&str, &str)] = &[
+    ("oak_sign", "sign"),
+    ("red_dye", "rose_red"),
+    ("green_dye", "cactus_green"),
+    ("yellow_dye", "dandelion_yellow"),
+    ("smooth_stone_slab", "stone_slab"),
+    ("zombified_piglin_spawn_egg", "zombie_pigman_spawn_egg"),
+    ("dirt_path", "grass_path"),
+];
+
+/// Pre-1.13 items by id and damage value, named as they were after the
+/// flattening
+const LEGACY_ITEMS: &[(u16, u16, &str)] = &[
+    (1, 0, "stone"),
+    (1, 1, "granite"),
+    (1, 2, "polished_granite"),
+    (1, 3, "diorite"),
+    (1, 4, "polished_diorite"),
+    (1, 5, "andesite"),
+    (1, 6, "polished_andesite"),
+    (2, 0, "grass_block"),
+    (3, 0, "dirt"),
+    (3, 1, "coarse_dirt"),
+    (3, 2, "podzol"),
+    (4, 0, "cobblestone"),
+    (5, 0, "oak_planks"),
+    (5, 1, "spruce_planks"),
+    (5, 2, "birch_planks"),
+    (5, 3, "jungle_planks"),
+    (5, 4, "acacia_planks"),
+    (5, 5, "dark_oak_planks"),
+    (6, 0, "oak_sapling"),
+    (6, 1, "spruce_sapling"),
+    (6, 2, "birch_sapling"),
+    (6, 3, "jungle_sapling"),
+    (6, 4, "acacia_sapling"),
+    (6, 5, "dark_oak_sapling"),
+    (7, 0, "bedrock"),
+    (12, 0, "sand"),
+    (12, 1, "red_sand"),
+    (13, 0, "gravel"),
+    (14, 0, "gold_ore"),
+    (15, 0, "iron_ore"),
+    (16, 0, "coal_ore"),
+    (17, 0, "oak_log"),
+    (17, 1, "spruce_log"),
+    (17, 2, "birch_log"),
+    (17, 3, "jungle_log"),
+    (18, 0, "oak_leaves"),
+    (18, 1, "spruce_leaves"),
+    (18, 2, "birch_leaves"),
+    (18, 3, "jungle_leaves"),
+    (19, 0, "sponge"),
+    (19, 1, "wet_sponge"),
+    (20, 0, "glass"),
+    (21, 0, "lapis_ore"),
+    (22, 0, "lapis_block"),
+    (23, 0, "dispenser"),
+    (24, 0, "sandstone"),
+    (24, 1, "chiseled_sandstone"),
+    (24, 2, "cut_sandstone"),
+    (25, 0, "note_block"),
+    (27, 0, "powered_rail"),
+    (28, 0, "detector_rail"),
+    (29, 0, "sticky_piston"),
+    (30, 0, "cobweb"),
+    (31, 0, "dead_bush"),
+    (31, 1, "grass"),
+    (31, 2, "fern"),
+    (32, 0, "dead_bush"),
+    (33, 0, "piston"),
+    (35, 0, "white_wool"),
+    (35, 1, "orange_wool"),
+    (35, 2, "magenta_wool"),
+    (35, 3, "light_blue_wool"),
+    (35, 4, "yellow_wool"),
+    (35, 5, "lime_wool"),
+    (35, 6, "pink_wool"),
+    (35, 7, "gray_wool"),
+    (35, 8, "light_gray_wool"),
+    (35, 9, "cyan_wool"),
+    (35, 10, "purple_wool"),
+    (35, 11, "blue_wool"),
+    (35, 12, "brown_wool"),
+    (35, 13, "green_wool"),
+    (35, 14, "red_wool"),
+    (35, 15, "black_wool"),
+    (37, 0, "dandelion"),
+    (38, 0, "poppy"),
+    (38, 1, "blue_orchid"),
+    (38, 2, "allium"),
+    (38, 3, "azure_bluet"),
+    (38, 4, "red_tulip"),
+    (38, 5, "orange_tulip"),
+    (38, 6, "white_tulip"),
+    (38, 7, "pink_tulip"),
+    (38, 8, "oxeye_daisy"),
+    (39, 0, "brown_mushroom"),
+    (40, 0, "red_mushroom"),
+    (41, 0, "gold_block"),
+    (42, 0, "iron_block"),
+    (44, 0, "stone_slab"),
+    (44, 1, "sandstone_slab"),
+    (44, 2, "petrified_oak_slab"),
+    (44, 3, "cobblestone_slab"),
+    (44, 4, "brick_slab"),
+    (44, 5, "stone_brick_slab"),
+    (44, 6, "nether_brick_slab"),
+    (44, 7, "quartz_slab"),
+    (45, 0, "bricks"),
+    (46, 0, "tnt"),
+    (47, 0, "bookshelf"),
+    (48, 0, "mossy_cobblestone"),
+    (49, 0, "obsidian"),
+    (50, 0, "torch"),
+    (52, 0, "spawner"),
+    (53, 0, "oak_stairs"),
+    (54, 0, "chest"),
+    (56, 0, "diamond_ore"),
+    (57, 0, "diamond_block"),
+    (58, 0, "crafting_table"),
+    (60, 0, "farmland"),
+    (61, 0, "furnace"),
+    (65, 0, "ladder"),
+    (66, 0, "rail"),
+    (67, 0, "cobblestone_stairs"),
+    (69, 0, "lever"),
+    (70, 0, "stone_pressure_plate"),
+    (72, 0, "oak_pressure_plate"),
+    (73, 0, "redstone_ore"),
+    (76, 0, "redstone_torch"),
+    (77, 0, "stone_button"),
+    (78, 0, "snow"),
+    (79, 0, "ice"),
+    (80, 0, "snow_block"),
+    (81, 0, "cactus"),
+    (82, 0, "clay"),
+    (84, 0, "jukebox"),
+    (85, 0, "oak_fence"),
+    (86, 0, "carved_pumpkin"),
+    (87, 0, "netherrack"),
+    (88, 0, "soul_sand"),
+    (89, 0, "glowstone"),
+    (91, 0, "jack_o_lantern"),
+    (95, 0, "white_stained_glass"),
+    (95, 1, "orange_stained_glass"),
+    (95, 2, "magenta_stained_glass"),
+    (95, 3, "light_blue_stained_glass"),
+    (95, 4, "yellow_stained_glass"),
+    (95, 5, "lime_stained_glass"),
+    (95, 6, "pink_stained_glass"),
+    (95, 7, "gray_stained_glass"),
+    (95, 8, "light_gray_stained_glass"),
+    (95, 9, "cyan_stained_glass"),
+    (95, 10, "purple_stained_glass"),
+    (95, 11, "blue_stained_glass"),
+    (95, 12, "brown_stained_glass"),
+    (95, 13, "green_stained_glass"),
+    (95, 14, "red_stained_glass"),
+    (95, 15, "black_stained_glass"),
+    (96, 0, "oak_trapdoor"),
+    (97, 0, "infested_stone"),
+    (97, 1, "infested_cobblestone"),
+    (97, 2, "infested_stone_bricks"),
+    (97, 3, "infested_mossy_stone_bricks"),
+    (97, 4, "infested_cracked_stone_bricks"),
+    (97, 5, "infested_chiseled_stone_bricks"),
+    (98, 0, "stone_bricks"),
+    (98, 1, "mossy_stone_bricks"),
+    (98, 2, "cracked_stone_bricks"),
+    (98, 3, "chiseled_stone_bricks"),
+    (99, 0, "brown_mushroom_block"),
+    (100, 0, "red_mushroom_block"),
+    (101, 0, "iron_bars"),
+    (102, 0, "glass_pane"),
+    (103, 0, "melon"),
+    (106, 0, "vine"),
+    (107, 0, "oak_fence_gate"),
+    (108, 0, "brick_stairs"),
+    (109, 0, "stone_brick_stairs"),
+    (110, 0, "mycelium"),
+    (111, 0, "lily_pad"),
+    (112, 0, "nether_bricks"),
+    (113, 0, "nether_brick_fence"),
+    (114, 0, "nether_brick_stairs"),
+    (116, 0, "enchanting_table"),
+    (120, 0, "end_portal_frame"),
+    (121, 0, "end_stone"),
+    (122, 0, "dragon_egg"),
+    (123, 0, "redstone_lamp"),
+    (126, 0, "oak_slab"),
+    (126, 1, "spruce_slab"),
+    (126, 2, "birch_slab"),
+    (126, 3, "jungle_slab"),
+    (126, 4, "acacia_slab"),
+    (126, 5, "dark_oak_slab"),
+    (128, 0, "sandstone_stairs"),
+    (129, 0, "emerald_ore"),
+    (130, 0, "ender_chest"),
+    (131, 0, "tripwire_hook"),
+    (133, 0, "emerald_block"),
+    (134, 0, "spruce_stairs"),
+    (135, 0, "birch_stairs"),
+    (136, 0, "jungle_stairs"),
+    (137, 0, "command_block"),
+    (138, 0, "beacon"),
+    (139, 0, "cobblestone_wall"),
+    (139, 1, "mossy_cobblestone_wall"),
+    (143, 0, "oak_button"),
+    (145, 0, "anvil"),
+    (145, 1, "chipped_anvil"),
+    (145, 2, "damaged_anvil"),
+    (146, 0, "trapped_chest"),
+    (147, 0, "light_weighted_pressure_plate"),
+    (148, 0, "heavy_weighted_pressure_plate"),
+    (151, 0, "daylight_detector"),
+    (152, 0, "redstone_block"),
+    (153, 0, "nether_quartz_ore"),
+    (154, 0, "hopper"),
+    (155, 0, "quartz_block"),
+    (155, 1, "chiseled_quartz_block"),
+    (155, 2, "quartz_pillar"),
+    (156, 0, "quartz_stairs"),
+    (157, 0, "activator_rail"),
+    (158, 0, "dropper"),
+    (159, 0, "white_terracotta"),
+    (159, 1, "orange_terracotta"),
+    (159, 2, "magenta_terracotta"),
+    (159, 3, "light_blue_terracotta"),
+    (159, 4, "yellow_terracotta"),
+    (159, 5, "lime_terracotta"),
+    (159, 6, "pink_terracotta"),
+    (159, 7, "gray_terracotta"),
+    (159, 8, "light_gray_terracotta"),
+    (159, 9, "cyan_terracotta"),
+    (159, 10, "purple_terracotta"),
+    (159, 11, "blue_terracotta"),
+    (159, 12, "brown_terracotta"),
+    (159, 13, "green_terracotta"),
+    (159, 14, "red_terracotta"),
+    (159, 15, "black_terracotta"),
+    (160, 0, "white_stained_glass_pane"),
+    (160, 1, "orange_stained_glass_pane"),
+    (160, 2, "magenta_stained_glass_pane"),
+    (160, 3, "light_blue_stained_glass_pane"),
+    (160, 4, "yellow_stained_glass_pane"),
+    (160, 5, "lime_stained_glass_pane"),
+    (160, 6, "pink_stained_glass_pane"),
+    (160, 7, "gray_stained_glass_pane"),
+    (160, 8, "light_gray_stained_glass_pane"),
+    (160, 9, "cyan_stained_glass_pane"),
+    (160, 10, "purple_stained_glass_pane"),
+    (160, 11, "blue_stained_glass_pane"),
+    (160, 12, "brown_stained_glass_pane"),
+    (160, 13, "green_stained_glass_pane"),
+    (160, 14, "red_stained_glass_pane"),
+    (160, 15, "black_stained_glass_pane"),
+    (161, 0, "acacia_leaves"),
+    (161, 1, "dark_oak_leaves"),
+    (162, 0, "acacia_log"),
+    (162, 1, "dark_oak_log"),
+    (163, 0, "acacia_stairs"),
+    (164, 0, "dark_oak_stairs"),
+    (165, 0, "slime_block"),
+    (166, 0, "barrier"),
+    (167, 0, "iron_trapdoor"),
+    (168, 0, "prismarine"),
+    (168, 1, "prismarine_bricks"),
+    (168, 2, "dark_prismarine"),
+    (169, 0, "sea_lantern"),
+    (170, 0, "hay_block"),
+    (171, 0, "white_carpet"),
+    (171, 1, "orange_carpet"),
+    (171, 2, "magenta_carpet"),
+    (171, 3, "light_blue_carpet"),
+    (171, 4, "yellow_carpet"),
+    (171, 5, "lime_carpet"),
+    (171, 6, "pink_carpet"),
+    (171, 7, "gray_carpet"),
+    (171, 8, "light_gray_carpet"),
+    (171, 9, "cyan_carpet"),
+    (171, 10, "purple_carpet"),
+    (171, 11, "blue_carpet"),
+    (171, 12, "brown_carpet"),
+    (171, 13, "green_carpet"),
+    (171, 14, "red_carpet"),
+    (171, 15, "black_carpet"),
+    (172, 0, "terracotta"),
+    (173, 0, "coal_block"),
+    (174, 0, "packed_ice"),
+    (175, 0, "sunflower"),
+    (175, 1, "lilac"),
+    (175, 2, "tall_grass"),
+    (175, 3, "large_fern"),
+    (175, 4, "rose_bush"),
+    (175, 5, "peony"),
+    (179, 0, "red_sandstone"),
+    (179, 1, "chiseled_red_sandstone"),
+    (179, 2, "cut_red_sandstone"),
+    (180, 0, "red_sandstone_stairs"),
+    (182, 0, "red_sandstone_slab"),
+    (183, 0, "spruce_fence_gate"),
+    (188, 0, "spruce_fence"),
+    (184, 0, "birch_fence_gate"),
+    (189, 0, "birch_fence"),
+    (185, 0, "jungle_fence_gate"),
+    (190, 0, "jungle_fence"),
+    (186, 0, "dark_oak_fence_gate"),
+    (191, 0, "dark_oak_fence"),
+    (187, 0, "acacia_fence_gate"),
+    (192, 0, "acacia_fence"),
+    (198, 0, "end_rod"),
+    (199, 0, "chorus_plant"),
+    (200, 0, "chorus_flower"),
+    (201, 0, "purpur_block"),
+    (202, 0, "purpur_pillar"),
+    (203, 0, "purpur_stairs"),
+    (205, 0, "purpur_slab"),
+    (206, 0, "end_stone_bricks"),
+    (208, 0, "grass_path"),
+    (210, 0, "repeating_command_block"),
+    (211, 0, "chain_command_block"),
+    (213, 0, "magma_block"),
+    (214, 0, "nether_wart_block"),
+    (215, 0, "red_nether_bricks"),
+    (216, 0, "bone_block"),
+    (217, 0, "structure_void"),
+    (218, 0, "observer"),
+    (219, 0, "white_shulker_box"),
+    (235, 0, "white_glazed_terracotta"),
+    (220, 0, "orange_shulker_box"),
+    (236, 0, "orange_glazed_terracotta"),
+    (221, 0, "magenta_shulker_box"),
+    (237, 0, "magenta_glazed_terracotta"),
+    (222, 0, "light_blue_shulker_box"),
+    (238, 0, "light_blue_glazed_terracotta"),
+    (223, 0, "yellow_shulker_box"),
+    (239, 0, "yellow_glazed_terracotta"),
+    (224, 0, "lime_shulker_box"),
+    (240, 0, "lime_glazed_terracotta"),
+    (225, 0, "pink_shulker_box"),
+    (241, 0, "pink_glazed_terracotta"),
+    (226, 0, "gray_shulker_box"),
+    (242, 0, "gray_glazed_terracotta"),
+    (227, 0, "light_gray_shulker_box"),
+    (243, 0, "light_gray_glazed_terracotta"),
+    (228, 0, "cyan_shulker_box"),
+    (244, 0, "cyan_glazed_terracotta"),
+    (229, 0, "purple_shulker_box"),
+    (245, 0, "purple_glazed_terracotta"),
+    (230, 0, "blue_shulker_box"),
+    (246, 0, "blue_glazed_terracotta"),
+    (231, 0, "brown_shulker_box"),
+    (247, 0, "brown_glazed_terracotta"),
+    (232, 0, "green_shulker_box"),
+    (248, 0, "green_glazed_terracotta"),
+    (233, 0, "red_shulker_box"),
+    (249, 0, "red_glazed_terracotta"),
+    (234, 0, "black_shulker_box"),
+    (250, 0, "black_glazed_terracotta"),
+    (251, 0, "white_concrete"),
+    (251, 1, "orange_concrete"),
+    (251, 2, "magenta_concrete"),
+    (251, 3, "light_blue_concrete"),
+    (251, 4, "yellow_concrete"),
+    (251, 5, "lime_concrete"),
+    (251, 6, "pink_concrete"),
+    (251, 7, "gray_concrete"),
+    (251, 8, "light_gray_concrete"),
+    (251, 9, "cyan_concrete"),
+    (251, 10, "purple_concrete"),
+    (251, 11, "blue_concrete"),
+    (251, 12, "brown_concrete"),
+    (251, 13, "green_concrete"),
+    (251, 14, "red_concrete"),
+    (251, 15, "black_concrete"),
+    (252, 0, "white_concrete_powder"),
+    (252, 1, "orange_concrete_powder"),
+    (252, 2, "magenta_concrete_powder"),
+    (252, 3, "light_blue_concrete_powder"),
+    (252, 4, "yellow_concrete_powder"),
+    (252, 5, "lime_concrete_powder"),
+    (252, 6, "pink_concrete_powder"),
+    (252, 7, "gray_concrete_powder"),
+    (252, 8, "light_gray_concrete_powder"),
+    (252, 9, "cyan_concrete_powder"),
+    (252, 10, "purple_concrete_powder"),
+    (252, 11, "blue_concrete_powder"),
+    (252, 12, "brown_concrete_powder"),
+    (252, 13, "green_concrete_powder"),
+    (252, 14, "red_concrete_powder"),
+    (252, 15, "black_concrete_powder"),
+    (255, 0, "structure_block"),
+    (256, 0, "iron_shovel"),
+    (257, 0, "iron_pickaxe"),
+    (258, 0, "iron_axe"),
+    (259, 0, "flint_and_steel"),
+    (260, 0, "apple"),
+    (261, 0, "bow"),
+    (262, 0, "arrow"),
+    (263, 0, "coal"),
+    (263, 1, "charcoal"),
+    (264, 0, "diamond"),
+    (265, 0, "iron_ingot"),
+    (266, 0, "gold_ingot"),
+    (267, 0, "iron_sword"),
+    (268, 0, "wooden_sword"),
+    (269, 0, "wooden_shovel"),
+    (270, 0, "wooden_pickaxe"),
+    (271, 0, "wooden_axe"),
+    (272, 0, "stone_sword"),
+    (273, 0, "stone_shovel"),
+    (274, 0, "stone_pickaxe"),
+    (275, 0, "stone_axe"),
+    (276, 0, "diamond_sword"),
+    (277, 0, "diamond_shovel"),
+    (278, 0, "diamond_pickaxe"),
+    (279, 0, "diamond_axe"),
+    (280, 0, "stick"),
+    (281, 0, "bowl"),
+    (282, 0, "mushroom_stew"),
+    (283, 0, "golden_sword"),
+    (284, 0, "golden_shovel"),
+    (285, 0, "golden_pickaxe"),
+    (286, 0, "golden_axe"),
+    (287, 0, "string"),
+    (288, 0, "feather"),
+    (289, 0, "gunpowder"),
+    (290, 0, "wooden_hoe"),
+    (291, 0, "stone_hoe"),
+    (292, 0, "iron_hoe"),
+    (293, 0, "diamond_hoe"),
+    (294, 0, "golden_hoe"),
+    (295, 0, "wheat_seeds"),
+    (296, 0, "wheat"),
+    (297, 0, "bread"),
+    (298, 0, "leather_helmet"),
+    (299, 0, "leather_chestplate"),
+    (300, 0, "leather_leggings"),
+    (301, 0, "leather_boots"),
+    (302, 0, "chainmail_helmet"),
+    (303, 0, "chainmail_chestplate"),
+    (304, 0, "chainmail_leggings"),
+    (305, 0, "chainmail_boots"),
+    (306, 0, "iron_helmet"),
+    (307, 0, "iron_chestplate"),
+    (308, 0, "iron_leggings"),
+    (309, 0, "iron_boots"),
+    (310, 0, "diamond_helmet"),
+    (311, 0, "diamond_chestplate"),
+    (312, 0, "diamond_leggings"),
+    (313, 0, "diamond_boots"),
+    (314, 0, "golden_helmet"),
+    (315, 0, "golden_chestplate"),
+    (316, 0, "golden_leggings"),
+    (317, 0, "golden_boots"),
+    (318, 0, "flint"),
+    (319, 0, "porkchop"),
+    (320, 0, "cooked_porkchop"),
+    (321, 0, "painting"),
+    (322, 0, "golden_apple"),
+    (322, 1, "enchanted_golden_apple"),
+    (323, 0, "sign"),
+    (324, 0, "oak_door"),
+    (325, 0, "bucket"),
+    (326, 0, "water_bucket"),
+    (327, 0, "lava_bucket"),
+    (328, 0, "minecart"),
+    (329, 0, "saddle"),
+    (330, 0, "iron_door"),
+    (331, 0, "redstone"),
+    (332, 0, "snowball"),
+    (333, 0, "oak_boat"),
+    (334, 0, "leather"),
+    (335, 0, "milk_bucket"),
+    (336, 0, "brick"),
+    (337, 0, "clay_ball"),
+    (338, 0, "sugar_cane"),
+    (339, 0, "paper"),
+    (340, 0, "book"),
+    (341, 0, "slime_ball"),
+    (342, 0, "chest_minecart"),
+    (343, 0, "furnace_minecart"),
+    (344, 0, "egg"),
+    (345, 0, "compass"),
+    (346, 0, "fishing_rod"),
+    (347, 0, "clock"),
+    (348, 0, "glowstone_dust"),
+    (349, 0, "cod"),
+    (349, 1, "salmon"),
+    (349, 2, "tropical_fish"),
+    (349, 3, "pufferfish"),
+    (350, 0, "cooked_cod"),
+    (350, 1, "cooked_salmon"),
+    (351, 0, "ink_sac"),
+    (351, 1, "rose_red"),
+    (351, 2, "cactus_green"),
+    (351, 3, "cocoa_beans"),
+    (351, 4, "lapis_lazuli"),
+    (351, 5, "purple_dye"),
+    (351, 6, "cyan_dye"),
+    (351, 7, "light_gray_dye"),
+    (351, 8, "gray_dye"),
+    (351, 9, "pink_dye"),
+    (351, 10, "lime_dye"),
+    (351, 11, "dandelion_yellow"),
+    (351, 12, "light_blue_dye"),
+    (351, 13, "magenta_dye"),
+    (351, 14, "orange_dye"),
+    (351, 15, "bone_meal"),
+    (352, 0, "bone"),
+    (353, 0, "sugar"),
+    (354, 0, "cake"),
+    (355, 0, "white_bed"),
+    (355, 1, "orange_bed"),
+    (355, 2, "magenta_bed"),
+    (355, 3, "light_blue_bed"),
+    (355, 4, "yellow_bed"),
+    (355, 5, "lime_bed"),
+    (355, 6, "pink_bed"),
+    (355, 7, "gray_bed"),
+    (355, 8, "light_gray_bed"),
+    (355, 9, "cyan_bed"),
+    (355, 10, "purple_bed"),
+    (355, 11, "blue_bed"),
+    (355, 12, "brown_bed"),
+    (355, 13, "green_bed"),
+    (355, 14, "red_bed"),
+    (355, 15, "black_bed"),
+    (356, 0, "repeater"),
+    (357, 0, "cookie"),
+    (358, 0, "filled_map"),
+    (359, 0, "shears"),
+    (360, 0, "melon_slice"),
+    (361, 0, "pumpkin_seeds"),
+    (362, 0, "melon_seeds"),
+    (363, 0, "beef"),
+    (364, 0, "cooked_beef"),
+    (365, 0, "chicken"),
+    (366, 0, "cooked_chicken"),
+    (367, 0, "rotten_flesh"),
+    (368, 0, "ender_pearl"),
+    (369, 0, "blaze_rod"),
+    (370, 0, "ghast_tear"),
+    (371, 0, "gold_nugget"),
+    (372, 0, "nether_wart"),
+    (373, 0, "potion"),
+    (374, 0, "glass_bottle"),
+    (375, 0, "spider_eye"),
+    (376, 0, "fermented_spider_eye"),
+    (377, 0, "blaze_powder"),
+    (378, 0, "magma_cream"),
+    (379, 0, "brewing_stand"),
+    (380, 0, "cauldron"),
+    (381, 0, "ender_eye"),
+    (382, 0, "glistering_melon_slice"),
+    (383, 50, "creeper_spawn_egg"),
+    (383, 51, "skeleton_spawn_egg"),
+    (383, 52, "spider_spawn_egg"),
+    (383, 54, "zombie_spawn_egg"),
+    (383, 55, "slime_spawn_egg"),
+    (383, 56, "ghast_spawn_egg"),
+    (383, 57, "zombie_pigman_spawn_egg"),
+    (383, 58, "enderman_spawn_egg"),
+    (383, 59, "cave_spider_spawn_egg"),
+    (383, 60, "silverfish_spawn_egg"),
+    (383, 61, "blaze_spawn_egg"),
+    (383, 62, "magma_cube_spawn_egg"),
+    (383, 65, "bat_spawn_egg"),
+    (383, 66, "witch_spawn_egg"),
+    (383, 67, "endermite_spawn_egg"),
+    (383, 68, "guardian_spawn_egg"),
+    (383, 90, "pig_spawn_egg"),
+    (383, 91, "sheep_spawn_egg"),
+    (383, 92, "cow_spawn_egg"),
+    (383, 93, "chicken_spawn_egg"),
+    (383, 94, "squid_spawn_egg"),
+    (383, 95, "wolf_spawn_egg"),
+    (383, 96, "mooshroom_spawn_egg"),
+    (383, 98, "ocelot_spawn_egg"),
+    (383, 100, "horse_spawn_egg"),
+    (383, 101, "rabbit_spawn_egg"),
+    (383, 120, "villager_spawn_egg"),
+    (384, 0, "experience_bottle"),
+    (385, 0, "fire_charge"),
+    (386, 0, "writable_book"),
+    (387, 0, "written_book"),
+    (388, 0, "emerald"),
+    (389, 0, "item_frame"),
+    (390, 0, "flower_pot"),
+    (391, 0, "carrot"),
+    (392, 0, "potato"),
+    (393, 0, "baked_potato"),
+    (394, 0, "poisonous_potato"),
+    (395, 0, "map"),
+    (396, 0, "golden_carrot"),
+    (397, 0, "skeleton_skull"),
+    (397, 1, "wither_skeleton_skull"),
+    (397, 2, "zombie_head"),
+    (397, 3, "player_head"),
+    (397, 4, "creeper_head"),
+    (397, 5, "dragon_head"),
+    (398, 0, "carrot_on_a_stick"),
+    (399, 0, "nether_star"),
+    (400, 0, "pumpkin_pie"),
+    (401, 0, "firework_rocket"),
+    (402, 0, "firework_star"),
+    (403, 0, "enchanted_book"),
+    (404, 0, "comparator"),
+    (405, 0, "nether_brick"),
+    (406, 0, "quartz"),
+    (407, 0, "tnt_minecart"),
+    (408, 0, "hopper_minecart"),
+    (409, 0, "prismarine_shard"),
+    (410, 0, "prismarine_crystals"),
+    (411, 0, "rabbit"),
+    (412, 0, "cooked_rabbit"),
+    (413, 0, "rabbit_stew"),
+    (414, 0, "rabbit_foot"),
+    (415, 0, "rabbit_hide"),
+    (416, 0, "armor_stand"),
+    (417, 0, "iron_horse_armor"),
+    (418, 0, "golden_horse_armor"),
+    (419, 0, "diamond_horse_armor"),
+    (420, 0, "lead"),
+    (421, 0, "name_tag"),
+    (422, 0, "command_block_minecart"),
+    (423, 0, "mutton"),
+    (424, 0, "cooked_mutton"),
+    (425, 0, "black_banner"),
+    (425, 1, "red_banner"),
+    (425, 2, "green_banner"),
+    (425, 3, "brown_banner"),
+    (425, 4, "blue_banner"),
+    (425, 5, "purple_banner"),
+    (425, 6, "cyan_banner"),
+    (425, 7, "light_gray_banner"),
+    (425, 8, "gray_banner"),
+    (425, 9, "pink_banner"),
+    (425, 10, "lime_banner"),
+    (425, 11, "yellow_banner"),
+    (425, 12, "light_blue_banner"),
+    (425, 13, "magenta_banner"),
+    (425, 14, "orange_banner"),
+    (425, 15, "white_banner"),
+    (426, 0, "end_crystal"),
+    (427, 0, "spruce_door"),
+    (428, 0, "birch_door"),
+    (429, 0, "jungle_door"),
+    (430, 0, "acacia_door"),
+    (431, 0, "dark_oak_door"),
+    (432, 0, "chorus_fruit"),
+    (433, 0, "popped_chorus_fruit"),
+    (434, 0, "beetroot"),
+    (435, 0, "beetroot_seeds"),
+    (436, 0, "beetroot_soup"),
+    (437, 0, "dragon_breath"),
+    (438, 0, "splash_potion"),
+    (439, 0, "spectral_arrow"),
+    (440, 0, "tipped_arrow"),
+    (441, 0, "lingering_potion"),
+    (442, 0, "shield"),
+    (443, 0, "elytra"),
+    (444, 0, "spruce_boat"),
+    (445, 0, "birch_boat"),
+    (446, 0, "jungle_boat"),
+    (447, 0, "acacia_boat"),
+    (448, 0, "dark_oak_boat"),
+    (449, 0, "totem_of_undying"),
+    (450, 0, "shulker_shell"),
+    (452, 0, "iron_nugget"),
+    (453, 0, "knowledge_book"),
+    (2256, 0, "music_disc_13"),
+    (2257, 0, "music_disc_cat"),
+    (2258, 0, "music_disc_blocks"),
+    (2259, 0, "music_disc_chirp"),
+    (2260, 0, "music_disc_far"),
+    (2261, 0, "music_disc_mall"),
+    (2262, 0, "music_disc_mellohi"),
+    (2263, 0, "music_disc_stal"),
+    (2264, 0, "music_disc_strad"),
+    (2265, 0, "music_disc_ward"),
+    (2266, 0, "music_disc_11"),
+    (2267, 0, "music_disc_wait"),
+];
+
+/// 1.13.2 to 1.16.5 items in registry order
+const FLAT_ITEMS: &[FlatItem] = flat_items! {
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "crimson_nylium" since 735,
+    "warped_nylium" since 735,
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "crimson_planks" since 735,
+    "warped_planks" since 735,
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "gold_ore",
+    "iron_ore",
+    "coal_ore",
+    "nether_gold_ore" since 735,
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "crimson_stem" since 735,
+    "warped_stem" since 735,
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_crimson_stem" since 735,
+    "stripped_warped_stem" since 735,
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "stripped_crimson_hyphae" since 735,
+    "stripped_warped_hyphae" since 735,
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "crimson_hyphae" since 735,
+    "warped_hyphae" since 735,
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "lapis_ore",
+    "lapis_block",
+    "dispenser",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "note_block",
+    "powered_rail",
+    "detector_rail",
+    "sticky_piston",
+    "cobweb",
+    "grass",
+    "fern",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "piston",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower" since 477,
+    "lily_of_the_valley" since 477,
+    "wither_rose" since 477,
+    "brown_mushroom",
+    "red_mushroom",
+    "crimson_fungus" since 735,
+    "warped_fungus" since 735,
+    "crimson_roots" since 735,
+    "warped_roots" since 735,
+    "nether_sprouts" since 735,
+    "weeping_vines" since 735,
+    "twisting_vines" since 735,
+    "sugar_cane" since 735,
+    "kelp" since 735,
+    "bamboo" since 735,
+    "gold_block",
+    "iron_block",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "crimson_slab" since 735,
+    "warped_slab" since 735,
+    "stone_slab",
+    "smooth_stone_slab" since 477,
+    "sandstone_slab",
+    "cut_sandstone_slab" since 477,
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab" since 477,
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "tnt",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "diamond_ore",
+    "diamond_block",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "rail",
+    "cobblestone_stairs",
+    "lever",
+    "stone_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "crimson_pressure_plate" since 735,
+    "warped_pressure_plate" since 735,
+    "polished_blackstone_pressure_plate" since 735,
+    "redstone_ore",
+    "redstone_torch",
+    "stone_button" until 735,
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "crimson_fence" since 735,
+    "warped_fence" since 735,
+    "pumpkin",
+    "carved_pumpkin",
+    "netherrack",
+    "soul_sand",
+    "soul_soil" since 735,
+    "basalt" since 735,
+    "polished_basalt" since 735,
+    "soul_torch" since 735,
+    "glowstone",
+    "jack_o_lantern",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "crimson_trapdoor" since 735,
+    "warped_trapdoor" since 735,
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "chain" since 735,
+    "glass_pane",
+    "melon",
+    "vine",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "crimson_fence_gate" since 735,
+    "warped_fence_gate" since 735,
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "cracked_nether_bricks" since 735,
+    "chiseled_nether_bricks" since 735,
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "redstone_lamp",
+    "sandstone_stairs",
+    "emerald_ore",
+    "ender_chest",
+    "tripwire_hook",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "crimson_stairs" since 735,
+    "warped_stairs" since 735,
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall" since 477,
+    "prismarine_wall" since 477,
+    "red_sandstone_wall" since 477,
+    "mossy_stone_brick_wall" since 477,
+    "granite_wall" since 477,
+    "stone_brick_wall" since 477,
+    "nether_brick_wall" since 477,
+    "andesite_wall" since 477,
+    "red_nether_brick_wall" since 477,
+    "sandstone_wall" since 477,
+    "end_stone_brick_wall" since 477,
+    "diorite_wall" since 477,
+    "blackstone_wall" since 735,
+    "polished_blackstone_wall" since 735,
+    "polished_blackstone_brick_wall" since 735,
+    "stone_button" since 735,
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "crimson_button" since 735,
+    "warped_button" since 735,
+    "polished_blackstone_button" since 735,
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "trapped_chest",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "daylight_detector",
+    "redstone_block",
+    "nether_quartz_ore",
+    "hopper",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_bricks" since 735,
+    "quartz_pillar",
+    "quartz_stairs",
+    "activator_rail",
+    "dropper",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "iron_trapdoor",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "coal_block",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "slime_block",
+    "grass_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "warped_wart_block" since 735,
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "observer",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs" since 477,
+    "smooth_red_sandstone_stairs" since 477,
+    "mossy_stone_brick_stairs" since 477,
+    "polished_diorite_stairs" since 477,
+    "mossy_cobblestone_stairs" since 477,
+    "end_stone_brick_stairs" since 477,
+    "stone_stairs" since 477,
+    "smooth_sandstone_stairs" since 477,
+    "smooth_quartz_stairs" since 477,
+    "granite_stairs" since 477,
+    "andesite_stairs" since 477,
+    "red_nether_brick_stairs" since 477,
+    "polished_andesite_stairs" since 477,
+    "diorite_stairs" since 477,
+    "polished_granite_slab" since 477,
+    "smooth_red_sandstone_slab" since 477,
+    "mossy_stone_brick_slab" since 477,
+    "polished_diorite_slab" since 477,
+    "mossy_cobblestone_slab" since 477,
+    "end_stone_brick_slab" since 477,
+    "smooth_sandstone_slab" since 477,
+    "smooth_quartz_slab" since 477,
+    "granite_slab" since 477,
+    "andesite_slab" since 477,
+    "red_nether_brick_slab" since 477,
+    "polished_andesite_slab" since 477,
+    "diorite_slab" since 477,
+    "scaffolding" since 477,
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "crimson_door" since 735,
+    "warped_door" since 735,
+    "repeater",
+    "comparator",
+    "structure_block",
+    "jigsaw" since 477,
+    "turtle_helmet",
+    "scute",
+    "iron_shovel" until 735,
+    "iron_pickaxe" until 735,
+    "iron_axe" until 735,
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "iron_ingot",
+    "gold_ingot",
+    "netherite_ingot" since 735,
+    "netherite_scrap" since 735,
+    "iron_sword" until 735,
+    "wooden_sword" until 735,
+    "wooden_shovel" until 735,
+    "wooden_pickaxe" until 735,
+    "wooden_axe" until 735,
+    "stone_sword" until 735,
+    "stone_shovel" until 735,
+    "stone_pickaxe" until 735,
+    "stone_axe" until 735,
+    "diamond_sword" until 735,
+    "diamond_shovel" until 735,
+    "diamond_pickaxe" until 735,
+    "diamond_axe" until 735,
+    "stick" until 735,
+    "bowl" until 735,
+    "mushroom_stew" until 735,
+    "golden_sword" until 735,
+    "golden_shovel" until 735,
+    "golden_pickaxe" until 735,
+    "golden_axe" until 735,
+    "string" until 735,
+    "feather" until 735,
+    "gunpowder" until 735,
+    "wooden_hoe" until 735,
+    "stone_hoe" until 735,
+    "iron_hoe" until 735,
+    "diamond_hoe" until 735,
+    "golden_hoe" until 735,
+    "wooden_sword" since 735,
+    "wooden_shovel" since 735,
+    "wooden_pickaxe" since 735,
+    "wooden_axe" since 735,
+    "wooden_hoe" since 735,
+    "stone_sword" since 735,
+    "stone_shovel" since 735,
+    "stone_pickaxe" since 735,
+    "stone_axe" since 735,
+    "stone_hoe" since 735,
+    "golden_sword" since 735,
+    "golden_shovel" since 735,
+    "golden_pickaxe" since 735,
+    "golden_axe" since 735,
+    "golden_hoe" since 735,
+    "iron_sword" since 735,
+    "iron_shovel" since 735,
+    "iron_pickaxe" since 735,
+    "iron_axe" since 735,
+    "iron_hoe" since 735,
+    "diamond_sword" since 735,
+    "diamond_shovel" since 735,
+    "diamond_pickaxe" since 735,
+    "diamond_axe" since 735,
+    "diamond_hoe" since 735,
+    "netherite_sword" since 735,
+    "netherite_shovel" since 735,
+    "netherite_pickaxe" since 735,
+    "netherite_axe" since 735,
+    "netherite_hoe" since 735,
+    "stick" since 735,
+    "bowl" since 735,
+    "mushroom_stew" since 735,
+    "string" since 735,
+    "feather" since 735,
+    "gunpowder" since 735,
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "netherite_helmet" since 735,
+    "netherite_chestplate" since 735,
+    "netherite_leggings" since 735,
+    "netherite_boots" since 735,
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "sign" until 477,
+    "oak_sign" since 477,
+    "spruce_sign" since 477,
+    "birch_sign" since 477,
+    "jungle_sign" since 477,
+    "acacia_sign" since 477,
+    "dark_oak_sign" since 477,
+    "crimson_sign" since 735,
+    "warped_sign" since 735,
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "minecart",
+    "saddle",
+    "redstone",
+    "snowball",
+    "oak_boat",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "brick",
+    "clay_ball",
+    "sugar_cane" until 735,
+    "kelp" until 735,
+    "dried_kelp_block",
+    "bamboo" since 477 until 735,
+    "paper",
+    "book",
+    "slime_ball",
+    "chest_minecart",
+    "furnace_minecart",
+    "egg",
+    "compass",
+    "fishing_rod",
+    "clock",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "rose_red" until 477,
+    "cactus_green" until 477,
+    "cocoa_beans",
+    "lapis_lazuli",
+    "purple_dye" until 477,
+    "cyan_dye" until 477,
+    "light_gray_dye" until 477,
+    "gray_dye" until 477,
+    "pink_dye" until 477,
+    "lime_dye" until 477,
+    "dandelion_yellow" until 477,
+    "light_blue_dye" until 477,
+    "magenta_dye" until 477,
+    "orange_dye" until 477,
+    "white_dye" since 477,
+    "orange_dye" since 477,
+    "magenta_dye" since 477,
+    "light_blue_dye" since 477,
+    "yellow_dye" since 477,
+    "lime_dye" since 477,
+    "pink_dye" since 477,
+    "gray_dye" since 477,
+    "light_gray_dye" since 477,
+    "cyan_dye" since 477,
+    "purple_dye" since 477,
+    "blue_dye" since 477,
+    "brown_dye" since 477,
+    "green_dye" since 477,
+    "red_dye" since 477,
+    "black_dye" since 477,
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "bat_spawn_egg",
+    "bee_spawn_egg" since 573,
+    "blaze_spawn_egg",
+    "cat_spawn_egg" since 477,
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg" since 477,
+    "ghast_spawn_egg",
+    "guardian_spawn_egg",
+    "hoglin_spawn_egg" since 735,
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg" since 477,
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "piglin_spawn_egg" since 735,
+    "piglin_brute_spawn_egg" since 751,
+    "pillager_spawn_egg" since 477,
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg" since 477,
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "strider_spawn_egg" since 735,
+    "trader_llama_spawn_egg" since 477,
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg" since 477,
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zoglin_spawn_egg" since 735,
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_pigman_spawn_egg" until 735,
+    "zombie_villager_spawn_egg",
+    "zombified_piglin_spawn_egg" since 735,
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "emerald",
+    "item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "carrot_on_a_stick",
+    "warped_fungus_on_a_stick" since 735,
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "quartz",
+    "tnt_minecart",
+    "hopper_minecart",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor" since 477,
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "elytra",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "music_disc_pigstep" since 735,
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow" since 477,
+    "suspicious_stew" since 477,
+    "loom" since 477,
+    "flower_banner_pattern" since 477,
+    "creeper_banner_pattern" since 477,
+    "skull_banner_pattern" since 477,
+    "mojang_banner_pattern" since 477,
+    "globe_banner_pattern" since 477,
+    "piglin_banner_pattern" since 735,
+    "composter" since 477,
+    "barrel" since 477,
+    "smoker" since 477,
+    "blast_furnace" since 477,
+    "cartography_table" since 477,
+    "fletching_table" since 477,
+    "grindstone" since 477,
+    "lectern" since 477,
+    "smithing_table" since 477,
+    "stonecutter" since 477,
+    "bell" since 477,
+    "lantern" since 477,
+    "soul_lantern" since 735,
+    "sweet_berries" since 477,
+    "campfire" since 477,
+    "soul_campfire" since 735,
+    "shroomlight" since 735,
+    "honeycomb" since 573,
+    "bee_nest" since 573,
+    "beehive" since 573,
+    "honey_bottle" since 573,
+    "honey_block" since 573,
+    "honeycomb_block" since 573,
+    "lodestone" since 735,
+    "netherite_block" since 735,
+    "ancient_debris" since 735,
+    "target" since 735,
+    "crying_obsidian" since 735,
+    "blackstone" since 735,
+    "blackstone_slab" since 735,
+    "blackstone_stairs" since 735,
+    "gilded_blackstone" since 735,
+    "polished_blackstone" since 735,
+    "polished_blackstone_slab" since 735,
+    "polished_blackstone_stairs" since 735,
+    "chiseled_polished_blackstone" since 735,
+    "polished_blackstone_bricks" since 735,
+    "polished_blackstone_brick_slab" since 735,
+    "polished_blackstone_brick_stairs" since 735,
+    "cracked_polished_blackstone_bricks" since 735,
+    "respawn_anchor" since 735,
+};
+
+/// 1.17 reordered the items so they're listed separately
+const FLAT_ITEMS_1_17: &[&str] = &[
+    "air",
+    "stone",
+    "granite",
+    "polished_granite",
+    "diorite",
+    "polished_diorite",
+    "andesite",
+    "polished_andesite",
+    "deepslate",
+    "cobbled_deepslate",
+    "polished_deepslate",
+    "calcite",
+    "tuff",
+    "dripstone_block",
+    "grass_block",
+    "dirt",
+    "coarse_dirt",
+    "podzol",
+    "rooted_dirt",
+    "crimson_nylium",
+    "warped_nylium",
+    "cobblestone",
+    "oak_planks",
+    "spruce_planks",
+    "birch_planks",
+    "jungle_planks",
+    "acacia_planks",
+    "dark_oak_planks",
+    "crimson_planks",
+    "warped_planks",
+    "oak_sapling",
+    "spruce_sapling",
+    "birch_sapling",
+    "jungle_sapling",
+    "acacia_sapling",
+    "dark_oak_sapling",
+    "bedrock",
+    "sand",
+    "red_sand",
+    "gravel",
+    "coal_ore",
+    "deepslate_coal_ore",
+    "iron_ore",
+    "deepslate_iron_ore",
+    "copper_ore",
+    "deepslate_copper_ore",
+    "gold_ore",
+    "deepslate_gold_ore",
+    "redstone_ore",
+    "deepslate_redstone_ore",
+    "emerald_ore",
+    "deepslate_emerald_ore",
+    "lapis_ore",
+    "deepslate_lapis_ore",
+    "diamond_ore",
+    "deepslate_diamond_ore",
+    "nether_gold_ore",
+    "nether_quartz_ore",
+    "ancient_debris",
+    "coal_block",
+    "raw_iron_block",
+    "raw_copper_block",
+    "raw_gold_block",
+    "amethyst_block",
+    "budding_amethyst",
+    "iron_block",
+    "copper_block",
+    "gold_block",
+    "diamond_block",
+    "netherite_block",
+    "exposed_copper",
+    "weathered_copper",
+    "oxidized_copper",
+    "cut_copper",
+    "exposed_cut_copper",
+    "weathered_cut_copper",
+    "oxidized_cut_copper",
+    "cut_copper_stairs",
+    "exposed_cut_copper_stairs",
+    "weathered_cut_copper_stairs",
+    "oxidized_cut_copper_stairs",
+    "cut_copper_slab",
+    "exposed_cut_copper_slab",
+    "weathered_cut_copper_slab",
+    "oxidized_cut_copper_slab",
+    "waxed_copper_block",
+    "waxed_exposed_copper",
+    "waxed_weathered_copper",
+    "waxed_oxidized_copper",
+    "waxed_cut_copper",
+    "waxed_exposed_cut_copper",
+    "waxed_weathered_cut_copper",
+    "waxed_oxidized_cut_copper",
+    "waxed_cut_copper_stairs",
+    "waxed_exposed_cut_copper_stairs",
+    "waxed_weathered_cut_copper_stairs",
+    "waxed_oxidized_cut_copper_stairs",
+    "waxed_cut_copper_slab",
+    "waxed_exposed_cut_copper_slab",
+    "waxed_weathered_cut_copper_slab",
+    "waxed_oxidized_cut_copper_slab",
+    "oak_log",
+    "spruce_log",
+    "birch_log",
+    "jungle_log",
+    "acacia_log",
+    "dark_oak_log",
+    "crimson_stem",
+    "warped_stem",
+    "stripped_oak_log",
+    "stripped_spruce_log",
+    "stripped_birch_log",
+    "stripped_jungle_log",
+    "stripped_acacia_log",
+    "stripped_dark_oak_log",
+    "stripped_crimson_stem",
+    "stripped_warped_stem",
+    "stripped_oak_wood",
+    "stripped_spruce_wood",
+    "stripped_birch_wood",
+    "stripped_jungle_wood",
+    "stripped_acacia_wood",
+    "stripped_dark_oak_wood",
+    "stripped_crimson_hyphae",
+    "stripped_warped_hyphae",
+    "oak_wood",
+    "spruce_wood",
+    "birch_wood",
+    "jungle_wood",
+    "acacia_wood",
+    "dark_oak_wood",
+    "crimson_hyphae",
+    "warped_hyphae",
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "azalea_leaves",
+    "flowering_azalea_leaves",
+    "sponge",
+    "wet_sponge",
+    "glass",
+    "tinted_glass",
+    "lapis_block",
+    "sandstone",
+    "chiseled_sandstone",
+    "cut_sandstone",
+    "cobweb",
+    "grass",
+    "fern",
+    "azalea",
+    "flowering_azalea",
+    "dead_bush",
+    "seagrass",
+    "sea_pickle",
+    "white_wool",
+    "orange_wool",
+    "magenta_wool",
+    "light_blue_wool",
+    "yellow_wool",
+    "lime_wool",
+    "pink_wool",
+    "gray_wool",
+    "light_gray_wool",
+    "cyan_wool",
+    "purple_wool",
+    "blue_wool",
+    "brown_wool",
+    "green_wool",
+    "red_wool",
+    "black_wool",
+    "dandelion",
+    "poppy",
+    "blue_orchid",
+    "allium",
+    "azure_bluet",
+    "red_tulip",
+    "orange_tulip",
+    "white_tulip",
+    "pink_tulip",
+    "oxeye_daisy",
+    "cornflower",
+    "lily_of_the_valley",
+    "wither_rose",
+    "spore_blossom",
+    "brown_mushroom",
+    "red_mushroom",
+    "crimson_fungus",
+    "warped_fungus",
+    "crimson_roots",
+    "warped_roots",
+    "nether_sprouts",
+    "weeping_vines",
+    "twisting_vines",
+    "sugar_cane",
+    "kelp",
+    "moss_carpet",
+    "moss_block",
+    "hanging_roots",
+    "big_dripleaf",
+    "small_dripleaf",
+    "bamboo",
+    "oak_slab",
+    "spruce_slab",
+    "birch_slab",
+    "jungle_slab",
+    "acacia_slab",
+    "dark_oak_slab",
+    "crimson_slab",
+    "warped_slab",
+    "stone_slab",
+    "smooth_stone_slab",
+    "sandstone_slab",
+    "cut_sandstone_slab",
+    "petrified_oak_slab",
+    "cobblestone_slab",
+    "brick_slab",
+    "stone_brick_slab",
+    "nether_brick_slab",
+    "quartz_slab",
+    "red_sandstone_slab",
+    "cut_red_sandstone_slab",
+    "purpur_slab",
+    "prismarine_slab",
+    "prismarine_brick_slab",
+    "dark_prismarine_slab",
+    "smooth_quartz",
+    "smooth_red_sandstone",
+    "smooth_sandstone",
+    "smooth_stone",
+    "bricks",
+    "bookshelf",
+    "mossy_cobblestone",
+    "obsidian",
+    "torch",
+    "end_rod",
+    "chorus_plant",
+    "chorus_flower",
+    "purpur_block",
+    "purpur_pillar",
+    "purpur_stairs",
+    "spawner",
+    "oak_stairs",
+    "chest",
+    "crafting_table",
+    "farmland",
+    "furnace",
+    "ladder",
+    "cobblestone_stairs",
+    "snow",
+    "ice",
+    "snow_block",
+    "cactus",
+    "clay",
+    "jukebox",
+    "oak_fence",
+    "spruce_fence",
+    "birch_fence",
+    "jungle_fence",
+    "acacia_fence",
+    "dark_oak_fence",
+    "crimson_fence",
+    "warped_fence",
+    "pumpkin",
+    "carved_pumpkin",
+    "jack_o_lantern",
+    "netherrack",
+    "soul_sand",
+    "soul_soil",
+    "basalt",
+    "polished_basalt",
+    "smooth_basalt",
+    "soul_torch",
+    "glowstone",
+    "infested_stone",
+    "infested_cobblestone",
+    "infested_stone_bricks",
+    "infested_mossy_stone_bricks",
+    "infested_cracked_stone_bricks",
+    "infested_chiseled_stone_bricks",
+    "infested_deepslate",
+    "stone_bricks",
+    "mossy_stone_bricks",
+    "cracked_stone_bricks",
+    "chiseled_stone_bricks",
+    "deepslate_bricks",
+    "cracked_deepslate_bricks",
+    "deepslate_tiles",
+    "cracked_deepslate_tiles",
+    "chiseled_deepslate",
+    "brown_mushroom_block",
+    "red_mushroom_block",
+    "mushroom_stem",
+    "iron_bars",
+    "chain",
+    "glass_pane",
+    "melon",
+    "vine",
+    "glow_lichen",
+    "brick_stairs",
+    "stone_brick_stairs",
+    "mycelium",
+    "lily_pad",
+    "nether_bricks",
+    "cracked_nether_bricks",
+    "chiseled_nether_bricks",
+    "nether_brick_fence",
+    "nether_brick_stairs",
+    "enchanting_table",
+    "end_portal_frame",
+    "end_stone",
+    "end_stone_bricks",
+    "dragon_egg",
+    "sandstone_stairs",
+    "ender_chest",
+    "emerald_block",
+    "spruce_stairs",
+    "birch_stairs",
+    "jungle_stairs",
+    "crimson_stairs",
+    "warped_stairs",
+    "command_block",
+    "beacon",
+    "cobblestone_wall",
+    "mossy_cobblestone_wall",
+    "brick_wall",
+    "prismarine_wall",
+    "red_sandstone_wall",
+    "mossy_stone_brick_wall",
+    "granite_wall",
+    "stone_brick_wall",
+    "nether_brick_wall",
+    "andesite_wall",
+    "red_nether_brick_wall",
+    "sandstone_wall",
+    "end_stone_brick_wall",
+    "diorite_wall",
+    "blackstone_wall",
+    "polished_blackstone_wall",
+    "polished_blackstone_brick_wall",
+    "cobbled_deepslate_wall",
+    "polished_deepslate_wall",
+    "deepslate_brick_wall",
+    "deepslate_tile_wall",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "chiseled_quartz_block",
+    "quartz_block",
+    "quartz_bricks",
+    "quartz_pillar",
+    "quartz_stairs",
+    "white_terracotta",
+    "orange_terracotta",
+    "magenta_terracotta",
+    "light_blue_terracotta",
+    "yellow_terracotta",
+    "lime_terracotta",
+    "pink_terracotta",
+    "gray_terracotta",
+    "light_gray_terracotta",
+    "cyan_terracotta",
+    "purple_terracotta",
+    "blue_terracotta",
+    "brown_terracotta",
+    "green_terracotta",
+    "red_terracotta",
+    "black_terracotta",
+    "barrier",
+    "light",
+    "hay_block",
+    "white_carpet",
+    "orange_carpet",
+    "magenta_carpet",
+    "light_blue_carpet",
+    "yellow_carpet",
+    "lime_carpet",
+    "pink_carpet",
+    "gray_carpet",
+    "light_gray_carpet",
+    "cyan_carpet",
+    "purple_carpet",
+    "blue_carpet",
+    "brown_carpet",
+    "green_carpet",
+    "red_carpet",
+    "black_carpet",
+    "terracotta",
+    "packed_ice",
+    "acacia_stairs",
+    "dark_oak_stairs",
+    "dirt_path",
+    "sunflower",
+    "lilac",
+    "rose_bush",
+    "peony",
+    "tall_grass",
+    "large_fern",
+    "white_stained_glass",
+    "orange_stained_glass",
+    "magenta_stained_glass",
+    "light_blue_stained_glass",
+    "yellow_stained_glass",
+    "lime_stained_glass",
+    "pink_stained_glass",
+    "gray_stained_glass",
+    "light_gray_stained_glass",
+    "cyan_stained_glass",
+    "purple_stained_glass",
+    "blue_stained_glass",
+    "brown_stained_glass",
+    "green_stained_glass",
+    "red_stained_glass",
+    "black_stained_glass",
+    "white_stained_glass_pane",
+    "orange_stained_glass_pane",
+    "magenta_stained_glass_pane",
+    "light_blue_stained_glass_pane",
+    "yellow_stained_glass_pane",
+    "lime_stained_glass_pane",
+    "pink_stained_glass_pane",
+    "gray_stained_glass_pane",
+    "light_gray_stained_glass_pane",
+    "cyan_stained_glass_pane",
+    "purple_stained_glass_pane",
+    "blue_stained_glass_pane",
+    "brown_stained_glass_pane",
+    "green_stained_glass_pane",
+    "red_stained_glass_pane",
+    "black_stained_glass_pane",
+    "prismarine",
+    "prismarine_bricks",
+    "dark_prismarine",
+    "prismarine_stairs",
+    "prismarine_brick_stairs",
+    "dark_prismarine_stairs",
+    "sea_lantern",
+    "red_sandstone",
+    "chiseled_red_sandstone",
+    "cut_red_sandstone",
+    "red_sandstone_stairs",
+    "repeating_command_block",
+    "chain_command_block",
+    "magma_block",
+    "nether_wart_block",
+    "warped_wart_block",
+    "red_nether_bricks",
+    "bone_block",
+    "structure_void",
+    "shulker_box",
+    "white_shulker_box",
+    "orange_shulker_box",
+    "magenta_shulker_box",
+    "light_blue_shulker_box",
+    "yellow_shulker_box",
+    "lime_shulker_box",
+    "pink_shulker_box",
+    "gray_shulker_box",
+    "light_gray_shulker_box",
+    "cyan_shulker_box",
+    "purple_shulker_box",
+    "blue_shulker_box",
+    "brown_shulker_box",
+    "green_shulker_box",
+    "red_shulker_box",
+    "black_shulker_box",
+    "white_glazed_terracotta",
+    "orange_glazed_terracotta",
+    "magenta_glazed_terracotta",
+    "light_blue_glazed_terracotta",
+    "yellow_glazed_terracotta",
+    "lime_glazed_terracotta",
+    "pink_glazed_terracotta",
+    "gray_glazed_terracotta",
+    "light_gray_glazed_terracotta",
+    "cyan_glazed_terracotta",
+    "purple_glazed_terracotta",
+    "blue_glazed_terracotta",
+    "brown_glazed_terracotta",
+    "green_glazed_terracotta",
+    "red_glazed_terracotta",
+    "black_glazed_terracotta",
+    "white_concrete",
+    "orange_concrete",
+    "magenta_concrete",
+    "light_blue_concrete",
+    "yellow_concrete",
+    "lime_concrete",
+    "pink_concrete",
+    "gray_concrete",
+    "light_gray_concrete",
+    "cyan_concrete",
+    "purple_concrete",
+    "blue_concrete",
+    "brown_concrete",
+    "green_concrete",
+    "red_concrete",
+    "black_concrete",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+    "turtle_egg",
+    "dead_tube_coral_block",
+    "dead_brain_coral_block",
+    "dead_bubble_coral_block",
+    "dead_fire_coral_block",
+    "dead_horn_coral_block",
+    "tube_coral_block",
+    "brain_coral_block",
+    "bubble_coral_block",
+    "fire_coral_block",
+    "horn_coral_block",
+    "tube_coral",
+    "brain_coral",
+    "bubble_coral",
+    "fire_coral",
+    "horn_coral",
+    "dead_brain_coral",
+    "dead_bubble_coral",
+    "dead_fire_coral",
+    "dead_horn_coral",
+    "dead_tube_coral",
+    "tube_coral_fan",
+    "brain_coral_fan",
+    "bubble_coral_fan",
+    "fire_coral_fan",
+    "horn_coral_fan",
+    "dead_tube_coral_fan",
+    "dead_brain_coral_fan",
+    "dead_bubble_coral_fan",
+    "dead_fire_coral_fan",
+    "dead_horn_coral_fan",
+    "blue_ice",
+    "conduit",
+    "polished_granite_stairs",
+    "smooth_red_sandstone_stairs",
+    "mossy_stone_brick_stairs",
+    "polished_diorite_stairs",
+    "mossy_cobblestone_stairs",
+    "end_stone_brick_stairs",
+    "stone_stairs",
+    "smooth_sandstone_stairs",
+    "smooth_quartz_stairs",
+    "granite_stairs",
+    "andesite_stairs",
+    "red_nether_brick_stairs",
+    "polished_andesite_stairs",
+    "diorite_stairs",
+    "cobbled_deepslate_stairs",
+    "polished_deepslate_stairs",
+    "deepslate_brick_stairs",
+    "deepslate_tile_stairs",
+    "polished_granite_slab",
+    "smooth_red_sandstone_slab",
+    "mossy_stone_brick_slab",
+    "polished_diorite_slab",
+    "mossy_cobblestone_slab",
+    "end_stone_brick_slab",
+    "smooth_sandstone_slab",
+    "smooth_quartz_slab",
+    "granite_slab",
+    "andesite_slab",
+    "red_nether_brick_slab",
+    "polished_andesite_slab",
+    "diorite_slab",
+    "cobbled_deepslate_slab",
+    "polished_deepslate_slab",
+    "deepslate_brick_slab",
+    "deepslate_tile_slab",
+    "scaffolding",
+    "redstone",
+    "redstone_torch",
+    "redstone_block",
+    "repeater",
+    "comparator",
+    "piston",
+    "sticky_piston",
+    "slime_block",
+    "honey_block",
+    "observer",
+    "hopper",
+    "dispenser",
+    "dropper",
+    "lectern",
+    "target",
+    "lever",
+    "lightning_rod",
+    "daylight_detector",
+    "sculk_sensor",
+    "tripwire_hook",
+    "trapped_chest",
+    "tnt",
+    "redstone_lamp",
+    "note_block",
+    "stone_button",
+    "polished_blackstone_button",
+    "oak_button",
+    "spruce_button",
+    "birch_button",
+    "jungle_button",
+    "acacia_button",
+    "dark_oak_button",
+    "crimson_button",
+    "warped_button",
+    "stone_pressure_plate",
+    "polished_blackstone_pressure_plate",
+    "light_weighted_pressure_plate",
+    "heavy_weighted_pressure_plate",
+    "oak_pressure_plate",
+    "spruce_pressure_plate",
+    "birch_pressure_plate",
+    "jungle_pressure_plate",
+    "acacia_pressure_plate",
+    "dark_oak_pressure_plate",
+    "crimson_pressure_plate",
+    "warped_pressure_plate",
+    "iron_door",
+    "oak_door",
+    "spruce_door",
+    "birch_door",
+    "jungle_door",
+    "acacia_door",
+    "dark_oak_door",
+    "crimson_door",
+    "warped_door",
+    "iron_trapdoor",
+    "oak_trapdoor",
+    "spruce_trapdoor",
+    "birch_trapdoor",
+    "jungle_trapdoor",
+    "acacia_trapdoor",
+    "dark_oak_trapdoor",
+    "crimson_trapdoor",
+    "warped_trapdoor",
+    "oak_fence_gate",
+    "spruce_fence_gate",
+    "birch_fence_gate",
+    "jungle_fence_gate",
+    "acacia_fence_gate",
+    "dark_oak_fence_gate",
+    "crimson_fence_gate",
+    "warped_fence_gate",
+    "powered_rail",
+    "detector_rail",
+    "rail",
+    "activator_rail",
+    "saddle",
+    "minecart",
+    "chest_minecart",
+    "furnace_minecart",
+    "tnt_minecart",
+    "hopper_minecart",
+    "carrot_on_a_stick",
+    "warped_fungus_on_a_stick",
+    "elytra",
+    "oak_boat",
+    "spruce_boat",
+    "birch_boat",
+    "jungle_boat",
+    "acacia_boat",
+    "dark_oak_boat",
+    "structure_block",
+    "jigsaw",
+    "turtle_helmet",
+    "scute",
+    "flint_and_steel",
+    "apple",
+    "bow",
+    "arrow",
+    "coal",
+    "charcoal",
+    "diamond",
+    "emerald",
+    "lapis_lazuli",
+    "quartz",
+    "amethyst_shard",
+    "raw_iron",
+    "iron_ingot",
+    "raw_copper",
+    "copper_ingot",
+    "raw_gold",
+    "gold_ingot",
+    "netherite_ingot",
+    "netherite_scrap",
+    "wooden_sword",
+    "wooden_shovel",
+    "wooden_pickaxe",
+    "wooden_axe",
+    "wooden_hoe",
+    "stone_sword",
+    "stone_shovel",
+    "stone_pickaxe",
+    "stone_axe",
+    "stone_hoe",
+    "golden_sword",
+    "golden_shovel",
+    "golden_pickaxe",
+    "golden_axe",
+    "golden_hoe",
+    "iron_sword",
+    "iron_shovel",
+    "iron_pickaxe",
+    "iron_axe",
+    "iron_hoe",
+    "diamond_sword",
+    "diamond_shovel",
+    "diamond_pickaxe",
+    "diamond_axe",
+    "diamond_hoe",
+    "netherite_sword",
+    "netherite_shovel",
+    "netherite_pickaxe",
+    "netherite_axe",
+    "netherite_hoe",
+    "stick",
+    "bowl",
+    "mushroom_stew",
+    "string",
+    "feather",
+    "gunpowder",
+    "wheat_seeds",
+    "wheat",
+    "bread",
+    "leather_helmet",
+    "leather_chestplate",
+    "leather_leggings",
+    "leather_boots",
+    "chainmail_helmet",
+    "chainmail_chestplate",
+    "chainmail_leggings",
+    "chainmail_boots",
+    "iron_helmet",
+    "iron_chestplate",
+    "iron_leggings",
+    "iron_boots",
+    "diamond_helmet",
+    "diamond_chestplate",
+    "diamond_leggings",
+    "diamond_boots",
+    "golden_helmet",
+    "golden_chestplate",
+    "golden_leggings",
+    "golden_boots",
+    "netherite_helmet",
+    "netherite_chestplate",
+    "netherite_leggings",
+    "netherite_boots",
+    "flint",
+    "porkchop",
+    "cooked_porkchop",
+    "painting",
+    "golden_apple",
+    "enchanted_golden_apple",
+    "oak_sign",
+    "spruce_sign",
+    "birch_sign",
+    "jungle_sign",
+    "acacia_sign",
+    "dark_oak_sign",
+    "crimson_sign",
+    "warped_sign",
+    "bucket",
+    "water_bucket",
+    "lava_bucket",
+    "powder_snow_bucket",
+    "snowball",
+    "leather",
+    "milk_bucket",
+    "pufferfish_bucket",
+    "salmon_bucket",
+    "cod_bucket",
+    "tropical_fish_bucket",
+    "axolotl_bucket",
+    "brick",
+    "clay_ball",
+    "dried_kelp_block",
+    "paper",
+    "book",
+    "slime_ball",
+    "egg",
+    "compass",
+    "bundle",
+    "fishing_rod",
+    "clock",
+    "spyglass",
+    "glowstone_dust",
+    "cod",
+    "salmon",
+    "tropical_fish",
+    "pufferfish",
+    "cooked_cod",
+    "cooked_salmon",
+    "ink_sac",
+    "glow_ink_sac",
+    "cocoa_beans",
+    "white_dye",
+    "orange_dye",
+    "magenta_dye",
+    "light_blue_dye",
+    "yellow_dye",
+    "lime_dye",
+    "pink_dye",
+    "gray_dye",
+    "light_gray_dye",
+    "cyan_dye",
+    "purple_dye",
+    "blue_dye",
+    "brown_dye",
+    "green_dye",
+    "red_dye",
+    "black_dye",
+    "bone_meal",
+    "bone",
+    "sugar",
+    "cake",
+    "white_bed",
+    "orange_bed",
+    "magenta_bed",
+    "light_blue_bed",
+    "yellow_bed",
+    "lime_bed",
+    "pink_bed",
+    "gray_bed",
+    "light_gray_bed",
+    "cyan_bed",
+    "purple_bed",
+    "blue_bed",
+    "brown_bed",
+    "green_bed",
+    "red_bed",
+    "black_bed",
+    "cookie",
+    "filled_map",
+    "shears",
+    "melon_slice",
+    "dried_kelp",
+    "pumpkin_seeds",
+    "melon_seeds",
+    "beef",
+    "cooked_beef",
+    "chicken",
+    "cooked_chicken",
+    "rotten_flesh",
+    "ender_pearl",
+    "blaze_rod",
+    "ghast_tear",
+    "gold_nugget",
+    "nether_wart",
+    "potion",
+    "glass_bottle",
+    "spider_eye",
+    "fermented_spider_eye",
+    "blaze_powder",
+    "magma_cream",
+    "brewing_stand",
+    "cauldron",
+    "ender_eye",
+    "glistering_melon_slice",
+    "axolotl_spawn_egg",
+    "bat_spawn_egg",
+    "bee_spawn_egg",
+    "blaze_spawn_egg",
+    "cat_spawn_egg",
+    "cave_spider_spawn_egg",
+    "chicken_spawn_egg",
+    "cod_spawn_egg",
+    "cow_spawn_egg",
+    "creeper_spawn_egg",
+    "dolphin_spawn_egg",
+    "donkey_spawn_egg",
+    "drowned_spawn_egg",
+    "elder_guardian_spawn_egg",
+    "enderman_spawn_egg",
+    "endermite_spawn_egg",
+    "evoker_spawn_egg",
+    "fox_spawn_egg",
+    "ghast_spawn_egg",
+    "glow_squid_spawn_egg",
+    "goat_spawn_egg",
+    "guardian_spawn_egg",
+    "hoglin_spawn_egg",
+    "horse_spawn_egg",
+    "husk_spawn_egg",
+    "llama_spawn_egg",
+    "magma_cube_spawn_egg",
+    "mooshroom_spawn_egg",
+    "mule_spawn_egg",
+    "ocelot_spawn_egg",
+    "panda_spawn_egg",
+    "parrot_spawn_egg",
+    "phantom_spawn_egg",
+    "pig_spawn_egg",
+    "piglin_spawn_egg",
+    "piglin_brute_spawn_egg",
+    "pillager_spawn_egg",
+    "polar_bear_spawn_egg",
+    "pufferfish_spawn_egg",
+    "rabbit_spawn_egg",
+    "ravager_spawn_egg",
+    "salmon_spawn_egg",
+    "sheep_spawn_egg",
+    "shulker_spawn_egg",
+    "silverfish_spawn_egg",
+    "skeleton_spawn_egg",
+    "skeleton_horse_spawn_egg",
+    "slime_spawn_egg",
+    "spider_spawn_egg",
+    "squid_spawn_egg",
+    "stray_spawn_egg",
+    "strider_spawn_egg",
+    "trader_llama_spawn_egg",
+    "tropical_fish_spawn_egg",
+    "turtle_spawn_egg",
+    "vex_spawn_egg",
+    "villager_spawn_egg",
+    "vindicator_spawn_egg",
+    "wandering_trader_spawn_egg",
+    "witch_spawn_egg",
+    "wither_skeleton_spawn_egg",
+    "wolf_spawn_egg",
+    "zoglin_spawn_egg",
+    "zombie_spawn_egg",
+    "zombie_horse_spawn_egg",
+    "zombie_villager_spawn_egg",
+    "zombified_piglin_spawn_egg",
+    "experience_bottle",
+    "fire_charge",
+    "writable_book",
+    "written_book",
+    "item_frame",
+    "glow_item_frame",
+    "flower_pot",
+    "carrot",
+    "potato",
+    "baked_potato",
+    "poisonous_potato",
+    "map",
+    "golden_carrot",
+    "skeleton_skull",
+    "wither_skeleton_skull",
+    "player_head",
+    "zombie_head",
+    "creeper_head",
+    "dragon_head",
+    "nether_star",
+    "pumpkin_pie",
+    "firework_rocket",
+    "firework_star",
+    "enchanted_book",
+    "nether_brick",
+    "prismarine_shard",
+    "prismarine_crystals",
+    "rabbit",
+    "cooked_rabbit",
+    "rabbit_stew",
+    "rabbit_foot",
+    "rabbit_hide",
+    "armor_stand",
+    "iron_horse_armor",
+    "golden_horse_armor",
+    "diamond_horse_armor",
+    "leather_horse_armor",
+    "lead",
+    "name_tag",
+    "command_block_minecart",
+    "mutton",
+    "cooked_mutton",
+    "white_banner",
+    "orange_banner",
+    "magenta_banner",
+    "light_blue_banner",
+    "yellow_banner",
+    "lime_banner",
+    "pink_banner",
+    "gray_banner",
+    "light_gray_banner",
+    "cyan_banner",
+    "purple_banner",
+    "blue_banner",
+    "brown_banner",
+    "green_banner",
+    "red_banner",
+    "black_banner",
+    "end_crystal",
+    "chorus_fruit",
+    "popped_chorus_fruit",
+    "beetroot",
+    "beetroot_seeds",
+    "beetroot_soup",
+    "dragon_breath",
+    "splash_potion",
+    "spectral_arrow",
+    "tipped_arrow",
+    "lingering_potion",
+    "shield",
+    "totem_of_undying",
+    "shulker_shell",
+    "iron_nugget",
+    "knowledge_book",
+    "debug_stick",
+    "music_disc_13",
+    "music_disc_cat",
+    "music_disc_blocks",
+    "music_disc_chirp",
+    "music_disc_far",
+    "music_disc_mall",
+    "music_disc_mellohi",
+    "music_disc_stal",
+    "music_disc_strad",
+    "music_disc_ward",
+    "music_disc_11",
+    "music_disc_wait",
+    "music_disc_pigstep",
+    "trident",
+    "phantom_membrane",
+    "nautilus_shell",
+    "heart_of_the_sea",
+    "crossbow",
+    "suspicious_stew",
+    "loom",
+    "flower_banner_pattern",
+    "creeper_banner_pattern",
+    "skull_banner_pattern",
+    "mojang_banner_pattern",
+    "globe_banner_pattern",
+    "piglin_banner_pattern",
+    "composter",
+    "barrel",
+    "smoker",
+    "blast_furnace",
+    "cartography_table",
+    "fletching_table",
+    "grindstone",
+    "smithing_table",
+    "stonecutter",
+    "bell",
+    "lantern",
+    "soul_lantern",
+    "sweet_berries",
+    "glow_berries",
+    "campfire",
+    "soul_campfire",
+    "shroomlight",
+    "honeycomb",
+    "bee_nest",
+    "beehive",
+    "honey_bottle",
+    "honeycomb_block",
+    "lodestone",
+    "crying_obsidian",
+    "blackstone",
+    "blackstone_slab",
+    "blackstone_stairs",
+    "gilded_blackstone",
+    "polished_blackstone",
+    "polished_blackstone_slab",
+    "polished_blackstone_stairs",
+    "chiseled_polished_blackstone",
+    "polished_blackstone_bricks",
+    "polished_blackstone_brick_slab",
+    "polished_blackstone_brick_stairs",
+    "cracked_polished_blackstone_bricks",
+    "respawn_anchor",
+    "candle",
+    "white_candle",
+    "orange_candle",
+    "magenta_candle",
+    "light_blue_candle",
+    "yellow_candle",
+    "lime_candle",
+    "pink_candle",
+    "gray_candle",
+    "light_gray_candle",
+    "cyan_candle",
+    "purple_candle",
+    "blue_candle",
+    "brown_candle",
+    "green_candle",
+    "red_candle",
+    "black_candle",
+    "small_amethyst_bud",
+    "medium_amethyst_bud",
+    "large_amethyst_bud",
+    "amethyst_cluster",
+    "pointed_dripstone",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColoredVariant;
+
+    #[test]
+    fn legacy_variants() {
+        let items = VanillaIDMap::new(340);
+        let wool = items.by_vanilla_id(35, Some(14)).unwrap();
+        assert_eq!(wool.name, "red_wool");
+        assert_eq!(
+            wool.block(&crate::VanillaIDMap::new(340)),
+            Some(Block::Wool {
+                color: ColoredVariant::Red
+            })
+        );
+        assert_eq!(
+            items.by_vanilla_id(351, Some(4)).map(|i| i.name),
+            Some("lapis_lazuli")
+        );
+        // Durability isn't a variant
+        assert_eq!(
+            items.by_vanilla_id(256, Some(30)).map(|i| i.name),
+            Some("iron_shovel")
+        );
+        assert_eq!(items.by_vanilla_id(-1, None), None);
+    }
+
+    #[test]
+    fn flat_keeps_legacy_ids() {
+        let items = VanillaIDMap::new(404);
+        assert_eq!(items.by_vanilla_id(0, None).map(|i| i.name), Some("air"));
+        assert_eq!(
+            items.by_vanilla_id(1, None),
+            Some(Item {
+                name: "stone",
+                legacy: Some((1, 0)),
+            })
+        );
+        let items = VanillaIDMap::new(477);
+        let sign = items.flat.iter().find(|i| i.name == "oak_sign").unwrap();
+        assert_eq!(sign.legacy, Some((323, 0)));
+    }
+
+    #[test]
+    fn max_damage() {
+        let items = VanillaIDMap::new(340);
+        let max_damage = |id| items.by_vanilla_id(id, None).unwrap().max_damage();
+        assert_eq!(max_damage(256), Some(250));
+        assert_eq!(max_damage(310), Some(363));
+        assert_eq!(max_damage(261), Some(384));
+        assert_eq!(max_damage(1), None);
+    }
+
+    #[test]
+    fn tools() {
+        let items = VanillaIDMap::new(340);
+        let tool = |id| items.by_vanilla_id(id, None).unwrap().tool();
+        assert_eq!(
+            tool(278),
+            Some(Tool {
+                kind: ToolKind::Pickaxe,
+                level: 3,
+                speed: 8.0,
+            })
+        );
+        assert_eq!(tool(271).map(|t| t.kind), Some(ToolKind::Axe));
+        assert_eq!(tool(359).map(|t| t.kind), Some(ToolKind::Shears));
+        assert_eq!(tool(1), None);
+    }
+
+    #[test]
+    fn max_stack_size() {
+        let items = VanillaIDMap::new(340);
+        let max_stack_size = |id| items.by_vanilla_id(id, None).unwrap().max_stack_size();
+        assert_eq!(max_stack_size(1), 64);
+        assert_eq!(max_stack_size(368), 16);
+        assert_eq!(max_stack_size(323), 16);
+        assert_eq!(max_stack_size(325), 16);
+        assert_eq!(max_stack_size(326), 1);
+        assert_eq!(max_stack_size(278), 1);
+        let items = VanillaIDMap::new(755);
+        let by_name = |name| items.flat.iter().find(|i| i.name == name).unwrap();
+        assert_eq!(by_name("birch_sign").max_stack_size(), 16);
+        assert_eq!(by_name("red_banner").max_stack_size(), 16);
+        assert_eq!(by_name("axolotl_bucket").max_stack_size(), 1);
+        assert_eq!(by_name("music_disc_11").max_stack_size(), 1);
+        assert_eq!(by_name("red_shulker_box").max_stack_size(), 1);
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for &version in &[404, 477, 575, 735, 751, 755] {
+            let items = VanillaIDMap::new(version);
+            let mut names = items.flat.iter().map(|i| i.name).collect::<Vec<_>>();
+            names.sort_unstable();
+            names.dedup();
+            assert_eq!(names.len(), items.flat.len(), "protocol {}", version);
+        }
+    }
+}
@@ -9,6 +9,7 @@ use cgmath::Point3;
 use collision::Aabb3;
 use std::collections::HashMap;
 
+pub mod item;
 pub mod material;
 pub use self::material::Material;
 
@@ -86,6 +87,12 @@ impl VanillaIDMap {
             }
         }
     }
+
+    /// Looks up a block by its pre-1.13 id and data value, `id << 4 | data`,
+    /// whichever version the map is for.
+    pub fn by_hierarchical_id(&self, id: usize) -> Option<Block> {
+        self.hier.get(id).and_then(|v| *v)
+    }
 }
 
 macro_rules! define_blocks {
@@ -102,6 +109,7 @@ macro_rules! define_blocks {
                 $(offset $offsetfunc:expr,)?
                 $(offsets $offsetsfunc:expr,)?
                 $(material $mat:expr,)?
+                $(hardness $hardness:expr,)?
                 model $model:expr,
                 $(variant $variant:expr,)?
                 $(tint $tint:expr,)?
@@ -212,6 +220,22 @@ macro_rules! define_blocks {
                 }
             }
 
+            /// Returns how long the block takes to break, as in vanilla. A
+            /// negative hardness means the block can't be broken.
+            #[allow(unused_variables, unreachable_code)]
+            pub fn get_hardness(&self) -> f64 {
+                match *self {
+                    $(
+                        Block::$name {
+                            $($fname,)?
+                        } => {
+                            $(return $hardness;)?
+                            1.0
+                        }
+                    )+
+                }
+            }
+
             #[allow(unused_variables)]
             pub fn get_model(&self) -> (String, String) {
                 match *self {
@@ -534,6 +558,7 @@ define_blocks! {
             collidable: false,
             .. material::INVISIBLE
         },
+        hardness 0.0,
         model { ("minecraft", "air") },
         collision vec![],
     }
@@ -550,6 +575,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 1.5,
         model { ("minecraft", variant.as_string() ) },
     }
     Grass {
@@ -558,6 +584,7 @@ define_blocks! {
         },
         data { if snowy { None } else { Some(0) } },
         offset { if snowy { Some(0) } else { Some(1) } },
+        hardness 0.6,
         model { ("minecraft", "grass") },
         variant format!("snowy={}", snowy),
         tint TintType::Grass,
@@ -584,6 +611,7 @@ define_blocks! {
                 }
             }
         },
+        hardness 0.5,
         model { ("minecraft", variant.as_string()) },
         variant {
             if variant == DirtVariant::Podzol {
@@ -600,6 +628,7 @@ define_blocks! {
     }
     Cobblestone {
         props {},
+        hardness 2.0,
         model { ("minecraft", "cobblestone") },
     }
     Planks {
@@ -614,6 +643,7 @@ define_blocks! {
             ],
         },
         data Some(variant.plank_data()),
+        hardness 2.0,
         model { ("minecraft", format!("{}_planks", variant.as_string()) ) },
     }
     Sapling {
@@ -631,12 +661,14 @@ define_blocks! {
         data Some(variant.plank_data() | ((stage as usize) << 3)),
         offset Some((variant.plank_data() << 1) | (stage as usize)),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", format!("{}_sapling", variant.as_string()) ) },
         variant format!("stage={}", stage),
         collision vec![],
     }
     Bedrock {
         props {},
+        hardness -1.0,
         model { ("minecraft", "bedrock") },
     }
     FlowingWater {
@@ -649,6 +681,7 @@ define_blocks! {
             absorbed_light: 2,
             ..material::TRANSPARENT
         },
+        hardness 100.0,
         model { ("minecraft", "flowing_water") },
         collision vec![],
     }
@@ -661,6 +694,7 @@ define_blocks! {
             absorbed_light: 2,
             ..material::TRANSPARENT
         },
+        hardness 100.0,
         model { ("minecraft", "water") },
         collision vec![],
     }
@@ -675,6 +709,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::NON_SOLID
         },
+        hardness 100.0,
         model { ("minecraft", "flowing_lava") },
         collision vec![],
     }
@@ -688,6 +723,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::NON_SOLID
         },
+        hardness 100.0,
         model { ("minecraft", "lava") },
         collision vec![],
     }
@@ -696,28 +732,34 @@ define_blocks! {
             red: bool = [false, true],
         },
         data Some(if red { 1 } else { 0 }),
+        hardness 0.5,
         model { ("minecraft", if red { "red_sand" } else { "sand" } ) },
     }
     Gravel {
         props {},
+        hardness 0.6,
         model { ("minecraft", "gravel") },
     }
     GoldOre {
         props {},
+        hardness 3.0,
         model { ("minecraft", "gold_ore") },
     }
     IronOre {
         props {},
+        hardness 3.0,
         model { ("minecraft", "iron_ore") },
     }
     CoalOre {
         props {},
+        hardness 3.0,
         model { ("minecraft", "coal_ore") },
     }
     NetherGoldOre {
         props {},
         data None,
         offsets |protocol_version| { if protocol_version >= 735 { Some(0) } else { None } },
+        hardness 3.0,
         model { ("minecraft", "nether_gold_ore") },
     }
     Log {
@@ -749,6 +791,7 @@ define_blocks! {
             Axis::Y => Some(variant.offset() * 3 + 1),
             Axis::Z => Some(variant.offset() * 3 + 2),
         },
+        hardness 2.0,
         model { ("minecraft", format!("{}_log", variant.as_string()) ) },
         variant format!("axis={}", axis.as_string()),
     }
@@ -772,6 +815,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some(variant.offset() * 3 + axis.index()),
+        hardness 2.0,
         model { ("minecraft", format!("{}_wood", variant.as_string()) ) },
         variant format!("axis={}", axis.as_string()),
     }
@@ -806,6 +850,7 @@ define_blocks! {
             Some(variant.offset() * (7 * 2) + ((distance as usize - 1) << 1) + (if decayable { 0 } else { 1 }))
         },
         material material::LEAVES,
+        hardness 0.2,
         model { ("minecraft", format!("{}_leaves", variant.as_string()) ) },
         tint TintType::Foliage,
     }
@@ -814,20 +859,24 @@ define_blocks! {
             wet: bool = [false, true],
         },
         data Some(if wet { 1 } else { 0 }),
+        hardness 0.6,
         model { ("minecraft", "sponge") },
         variant format!("wet={}", wet),
     }
     Glass {
         props {},
         material material::NON_SOLID,
+        hardness 0.3,
         model { ("minecraft", "glass") },
     }
     LapisOre {
         props {},
+        hardness 3.0,
         model { ("minecraft", "lapis_ore") },
     }
     LapisBlock {
         props {},
+        hardness 3.0,
         model { ("minecraft", "lapis_block") },
     }
     Dispenser {
@@ -844,6 +893,7 @@ define_blocks! {
         },
         data Some(facing.index() | (if triggered { 0x8 } else { 0x0 })),
         offset Some((facing.offset() << 1) | (if triggered { 0 } else { 1 })),
+        hardness 3.5,
         model { ("minecraft", "dispenser") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -856,6 +906,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 0.8,
         model { ("minecraft", variant.as_string() ) },
     }
     NoteBlock {
@@ -884,6 +935,7 @@ define_blocks! {
         data if instrument == NoteBlockInstrument::Harp && note == 0 && powered { Some(0) } else { None },
         offsets |protocol_version| (instrument.offsets(protocol_version)
             .map(|offset| offset * (25 * 2) + ((note as usize) << 1) + if powered { 0 } else { 1 })),
+        hardness 0.8,
         model { ("minecraft", "noteblock") },
     }
     Bed {
@@ -923,6 +975,7 @@ define_blocks! {
                   + (if occupied { 0 } else { 2 })
                   + (if part == BedPart::Head { 0 } else { 1 })),
         material material::NON_SOLID,
+        hardness 0.2,
         model { ("minecraft", "bed") },
         variant format!("facing={},part={}", facing.as_string(), part.as_string()),
         collision vec![Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 9.0/16.0, 1.0))],
@@ -942,6 +995,7 @@ define_blocks! {
         data Some(shape.data() | (if powered { 0x8 } else { 0x0 })),
         offset Some(shape.data() + (if powered { 0 } else { 6 })),
         material material::NON_SOLID,
+        hardness 0.7,
         model { ("minecraft", "golden_rail") },
         variant format!("powered={},shape={}", powered, shape.as_string()),
         collision vec![],
@@ -961,6 +1015,7 @@ define_blocks! {
         data Some(shape.data() | (if powered { 0x8 } else { 0x0 })),
         offset Some(shape.data() + (if powered { 0 } else { 6 })),
         material material::NON_SOLID,
+        hardness 0.7,
         model { ("minecraft", "detector_rail") },
         variant format!("powered={},shape={}", powered, shape.as_string()),
         collision vec![],
@@ -983,6 +1038,7 @@ define_blocks! {
             should_cull_against: !extended,
             ..material::NON_SOLID
         },
+        hardness 0.5,
         model { ("minecraft", "sticky_piston") },
         variant format!("extended={},facing={}", extended, facing.as_string()),
         collision piston_collision(extended, facing),
@@ -990,6 +1046,7 @@ define_blocks! {
     Web {
         props {},
         material material::NON_SOLID,
+        hardness 4.0,
         model { ("minecraft", "web") },
         collision vec![],
     }
@@ -1004,6 +1061,7 @@ define_blocks! {
         data Some(variant.data()),
         offset Some(variant.offset()),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", variant.as_string() ) },
         tint TintType::Grass,
         collision vec![],
@@ -1013,6 +1071,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(0),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "seagrass") },
         collision vec![],
     }
@@ -1026,6 +1085,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(half.offset()),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "tall_seagrass") },
         collision vec![],
     }
@@ -1033,6 +1093,7 @@ define_blocks! {
         props {},
         offset None,
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "dead_bush") },
         collision vec![],
     }
@@ -1054,6 +1115,7 @@ define_blocks! {
             should_cull_against: !extended,
             ..material::NON_SOLID
         },
+        hardness 0.5,
         model { ("minecraft", "piston") },
         variant format!("extended={},facing={}", extended, facing.as_string()),
         collision piston_collision(extended, facing),
@@ -1076,6 +1138,7 @@ define_blocks! {
                     (if short { 0 } else { 2 }) +
                     (if variant == PistonType::Normal { 0 } else { 1 })),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "piston_head") },
         variant format!("facing={},short={},type={}", facing.as_string(), short, variant.as_string()),
         collision {
@@ -1117,6 +1180,7 @@ define_blocks! {
             ],
         },
         data Some(color.data()),
+        hardness 0.8,
         model { ("minecraft", format!("{}_wool", color.as_string()) ) },
     }
     ThermalExpansionRockwool {
@@ -1142,6 +1206,7 @@ define_blocks! {
             ],
         },
         data Some(color.data()),
+        hardness 0.8,
         model { ("minecraft", format!("{}_wool", color.as_string()) ) },
     }
     ThermalFoundationRockwool {
@@ -1167,6 +1232,7 @@ define_blocks! {
             ],
         },
         data Some(color.data()),
+        hardness 0.8,
         model { ("minecraft", format!("{}_wool", color.as_string()) ) },
     }
     PistonExtension {
@@ -1184,11 +1250,13 @@ define_blocks! {
         data if facing == Direction::Up && variant == PistonType::Normal { Some(0) } else { None },
         offset Some(facing.offset() * 2 + (if variant == PistonType::Normal { 0 } else { 1 })),
         material material::INVISIBLE,
+        hardness -1.0,
         model { ("minecraft", "piston_extension") },
     }
     YellowFlower {
         props {},
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "dandelion") },
         collision vec![],
     }
@@ -1212,6 +1280,7 @@ define_blocks! {
         data Some(variant.data()),
         offsets |protocol_version| (variant.offsets(protocol_version)),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", variant.as_string()) },
         collision vec![],
     }
@@ -1221,21 +1290,25 @@ define_blocks! {
             emitted_light: 1,
             ..material::NON_SOLID
         },
+        hardness 0.0,
         model { ("minecraft", "brown_mushroom") },
         collision vec![],
     }
     RedMushroom {
         props {},
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "red_mushroom") },
         collision vec![],
     }
     GoldBlock {
         props {},
+        hardness 3.0,
         model { ("minecraft", "gold_block") },
     }
     IronBlock {
         props {},
+        hardness 5.0,
         model { ("minecraft", "iron_block") },
     }
     DoubleStoneSlab {
@@ -1267,6 +1340,7 @@ define_blocks! {
             Some(data)
         },
         offset None,
+        hardness 2.0,
         model { ("minecraft", format!("{}_double_slab", variant.as_string()) ) },
         variant if seamless { "all" } else { "normal" },
     }
@@ -1287,12 +1361,14 @@ define_blocks! {
         data Some(variant.data() | (if half == BlockHalf::Top { 0x8 } else { 0x0 })),
         offset None,
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", format!("{}_slab", variant.as_string()) ) },
         variant format!("half={}", half.as_string()),
         collision slab_collision(half),
     }
     BrickBlock {
         props {},
+        hardness 2.0,
         model { ("minecraft", "brick_block") },
     }
     TNT {
@@ -1301,18 +1377,22 @@ define_blocks! {
         },
         data Some(if explode { 1 } else { 0 }),
         offset Some(if explode { 0 } else { 1 }),
+        hardness 0.0,
         model { ("minecraft", "tnt") },
     }
     BookShelf {
         props {},
+        hardness 1.5,
         model { ("minecraft", "bookshelf") },
     }
     MossyCobblestone {
         props {},
+        hardness 2.0,
         model { ("minecraft", "mossy_cobblestone") },
     }
     Obsidian {
         props {},
+        hardness 50.0,
         model { ("minecraft", "obsidian") },
     }
     Torch {
@@ -1349,6 +1429,7 @@ define_blocks! {
             emitted_light: 14,
             ..material::NON_SOLID
         },
+        hardness 0.0,
         model { ("minecraft", "torch") },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -1374,6 +1455,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::NON_SOLID
         },
+        hardness 0.0,
         model { ("minecraft", "fire") },
         collision vec![],
         update_state (world, pos) => {
@@ -1399,12 +1481,14 @@ define_blocks! {
         props {},
         data None,
         offsets |protocol_version| { if protocol_version >= 735 { Some(0) } else { None } },
+        hardness 0.0,
         model { ("minecraft", "soul_fire") },
         collision vec![],
     }
     MobSpawner {
         props {},
         material material::NON_SOLID,
+        hardness 5.0,
         model { ("minecraft", "mob_spawner") },
     }
     OakStairs {
@@ -1428,6 +1512,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "oak_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -1453,6 +1538,7 @@ define_blocks! {
             type_.offset() * 2 +
             facing.horizontal_offset() * (2 * 3)),
         material material::NON_SOLID,
+        hardness 2.5,
         model { ("minecraft", "chest") },
     }
     RedstoneWire {
@@ -1478,6 +1564,7 @@ define_blocks! {
             north.offset() * (3 * 3 * 16) +
             east.offset() * (3 * 3 * 16 * 3)),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "redstone_wire") },
         tint TintType::Color{r: ((255.0 / 30.0) * (f64::from(power)) + 14.0) as u8, g: 0, b: 0},
         collision vec![],
@@ -1498,14 +1585,17 @@ define_blocks! {
     }
     DiamondOre {
         props {},
+        hardness 3.0,
         model { ("minecraft", "diamond_ore") },
     }
     DiamondBlock {
         props {},
+        hardness 5.0,
         model { ("minecraft", "diamond_block") },
     }
     CraftingTable {
         props {},
+        hardness 2.5,
         model { ("minecraft", "crafting_table") },
     }
     Wheat {
@@ -1514,6 +1604,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "wheat") },
         variant format!("age={}", age),
         collision vec![],
@@ -1524,6 +1615,7 @@ define_blocks! {
         },
         data Some(moisture as usize),
         material material::NON_SOLID,
+        hardness 0.6,
         model { ("minecraft", "farmland") },
         variant format!("moisture={}", moisture),
         collision vec![Aabb3::new(
@@ -1543,6 +1635,7 @@ define_blocks! {
         },
         data if !lit { Some(facing.index()) } else { None },
         offset Some(if lit { 0 } else { 1 } + facing.horizontal_offset() * 2),
+        hardness 3.5,
         model { ("minecraft", "furnace") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -1561,6 +1654,7 @@ define_blocks! {
             emitted_light: 13,
             ..material::SOLID
         },
+        hardness 3.5,
         model { ("minecraft", "lit_furnace") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -1608,6 +1702,7 @@ define_blocks! {
             }
         },
         material material::INVISIBLE,
+        hardness 1.0,
         model { ("minecraft", "standing_sign") },
         collision vec![],
     }
@@ -1627,6 +1722,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "wooden_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -1648,6 +1744,7 @@ define_blocks! {
         data if !waterlogged { Some(facing.index()) } else { None },
         offset Some(if waterlogged { 0 } else { 1 } + facing.horizontal_offset() * 2),
         material material::NON_SOLID,
+        hardness 0.4,
         model { ("minecraft", "ladder") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -1668,6 +1765,7 @@ define_blocks! {
         },
         data Some(shape.data()),
         material material::NON_SOLID,
+        hardness 0.7,
         model { ("minecraft", "rail") },
         variant format!("shape={}", shape.as_string()),
         collision vec![],
@@ -1693,6 +1791,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "stone_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -1730,6 +1829,7 @@ define_blocks! {
             }
         },
         material material::INVISIBLE,
+        hardness 1.0,
         model { ("minecraft", "wall_sign") },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -1752,6 +1852,7 @@ define_blocks! {
         data face.data_with_facing_and_powered(facing, powered),
         offset Some(face.offset() * (4 * 2) + facing.horizontal_offset() * 2 + if powered { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "lever") },
         variant format!("facing={},powered={}", face.variant_with_facing(facing), powered),
         collision vec![],
@@ -1763,6 +1864,7 @@ define_blocks! {
         data Some(if powered { 1 } else { 0 }),
         offset Some(if powered { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "stone_pressure_plate") },
         variant format!("powered={}", powered),
         collision vec![],
@@ -1783,6 +1885,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 5.0,
         model { ("minecraft", "iron_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -1806,6 +1909,7 @@ define_blocks! {
         data if wood == TreeVariant::Oak { Some(if powered { 1 } else { 0 }) } else { None },
         offset Some(wood.offset() * 2 + if powered { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "wooden_pressure_plate") },
         variant format!("powered={}", powered),
         collision vec![],
@@ -1816,6 +1920,7 @@ define_blocks! {
         },
         data if !lit { Some(0) } else { None },
         offset Some(if lit { 0 } else { 1 }),
+        hardness 3.0,
         model { ("minecraft", if lit { "lit_redstone_ore" } else { "redstone_ore" }) },
     }
     RedstoneOreLit {
@@ -1825,6 +1930,7 @@ define_blocks! {
             emitted_light: 9,
             ..material::SOLID
         },
+        hardness 3.0,
         model { ("minecraft", "lit_redstone_ore") },
     }
     RedstoneTorchUnlit {
@@ -1849,6 +1955,7 @@ define_blocks! {
         },
         offset None,
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "unlit_redstone_torch") },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -1878,6 +1985,7 @@ define_blocks! {
             emitted_light: 7,
             ..material::NON_SOLID
         },
+        hardness 0.0,
         model { ("minecraft", "redstone_torch") },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -1889,6 +1997,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(if lit { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", if lit { "redstone_torch" } else { "unlit_redstone_torch" }) },
         variant "facing=up",
         collision vec![],
@@ -1909,6 +2018,7 @@ define_blocks! {
             emitted_light: 7,
             ..material::NON_SOLID
         },
+        hardness 0.0,
         model { ("minecraft", if lit { "redstone_torch" } else { "unlit_redstone_torch" }) },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -1931,6 +2041,7 @@ define_blocks! {
         data face.data_with_facing_and_powered(facing, powered),
         offset Some(face.offset() * (4 * 2) + facing.horizontal_offset() * 2 + if powered { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "stone_button") },
         variant format!("facing={},powered={}", face.variant_with_facing(facing), powered),
     }
@@ -1940,6 +2051,7 @@ define_blocks! {
         },
         data Some(layers as usize - 1),
         material material::NON_SOLID,
+        hardness 0.1,
         model { ("minecraft", "snow_layer") },
         variant format!("layers={}", layers),
         collision vec![Aabb3::new(
@@ -1953,10 +2065,12 @@ define_blocks! {
             absorbed_light: 2,
             ..material::TRANSPARENT
         },
+        hardness 0.5,
         model { ("minecraft", "ice") },
     }
     Snow {
         props {},
+        hardness 0.2,
         model { ("minecraft", "snow") },
     }
     Cactus {
@@ -1965,6 +2079,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.4,
         model { ("minecraft", "cactus") },
         collision vec![Aabb3::new(
             Point3::new(1.0/16.0, 0.0, 1.0/16.0),
@@ -1973,6 +2088,7 @@ define_blocks! {
     }
     Clay {
         props {},
+        hardness 0.6,
         model { ("minecraft", "clay") },
     }
     Reeds {
@@ -1981,6 +2097,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "reeds") },
         tint TintType::Foliage,
         collision vec![],
@@ -1991,6 +2108,7 @@ define_blocks! {
         },
         data Some(if has_record { 1 } else { 0 }),
         offset Some(if has_record { 0 } else { 1 }),
+        hardness 2.0,
         model { ("minecraft", "jukebox") },
     }
     Fence {
@@ -2008,6 +2126,7 @@ define_blocks! {
             if north { 0 } else { 1<<3 } +
             if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -2034,6 +2153,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index() | (if without_face { 0x4 } else { 0x0 })),
         offset None,
+        hardness 1.0,
         model { ("minecraft", "pumpkin") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -2041,15 +2161,18 @@ define_blocks! {
         props {},
         data None::<usize>,
         offset Some(0),
+        hardness 1.0,
         model { ("minecraft", "pumpkin") },
     }
     Netherrack {
         props {},
+        hardness 0.4,
         model { ("minecraft", "netherrack") },
     }
     SoulSand {
         props {},
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "soul_sand") },
         collision vec![Aabb3::new(
             Point3::new(0.0, 0.0, 0.0),
@@ -2060,6 +2183,7 @@ define_blocks! {
         props {},
         data None,
         offsets |protocol_version| { if protocol_version >= 735 { Some(0) } else { None } },
+        hardness 0.5,
         model { ("minecraft", "soul_soil") },
     }
     Basalt {
@@ -2074,6 +2198,7 @@ define_blocks! {
                 Axis::Z => 2,
                 _ => unreachable!()
             }) } else { None } },
+        hardness 1.25,
         model { ("minecraft", "basalt") },
     }
     PolishedBasalt {
@@ -2088,12 +2213,14 @@ define_blocks! {
                 Axis::Z => 2,
                 _ => unreachable!()
             }) } else { None } },
+        hardness 1.25,
         model { ("minecraft", "polished_basalt") },
     }
     SoulTorch {
         props {},
         data None,
         offsets |protocol_version| { if protocol_version >= 735 { Some(0) } else { None } },
+        hardness 0.0,
         model { ("minecraft", "soul_torch") },
     }
     SoulWallTorch {
@@ -2107,6 +2234,7 @@ define_blocks! {
         },
         data None,
         offsets |protocol_version| { if protocol_version >= 735 { Some(facing.offset()) } else { None } },
+        hardness 0.0,
         model { ("minecraft", "soul_wall_torch") },
     }
     Glowstone {
@@ -2115,6 +2243,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::SOLID
         },
+        hardness 0.3,
         model { ("minecraft", "glowstone") },
     }
     Portal {
@@ -2127,6 +2256,7 @@ define_blocks! {
             emitted_light: 11,
             ..material::TRANSPARENT
         },
+        hardness -1.0,
         model { ("minecraft", "portal") },
         variant format!("axis={}", axis.as_string()),
         collision vec![],
@@ -2146,6 +2276,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::SOLID
         },
+        hardness 1.0,
         model { ("minecraft", "carved_pumpkin") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -2165,6 +2296,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::SOLID
         },
+        hardness 1.0,
         model { ("minecraft", "lit_pumpkin") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -2174,6 +2306,7 @@ define_blocks! {
         },
         data Some(bites as usize),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "cake") },
         variant format!("bites={}", bites),
         collision vec![Aabb3::new(
@@ -2199,6 +2332,7 @@ define_blocks! {
             facing.horizontal_offset() * (2 * 2) +
             ((delay - 1) as usize) * (2 * 2 * 4)),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", if powered { "powered_repeater" } else { "unpowered_repeater" }) },
         variant format!("delay={},facing={},locked={}", delay, facing.as_string(), locked),
         collision vec![Aabb3::new(
@@ -2221,6 +2355,7 @@ define_blocks! {
         data if !locked { Some(facing.horizontal_index() | (delay as usize - 1) << 2) } else { None },
         offset None,
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "powered_repeater") },
         variant format!("delay={},facing={},locked={}", delay, facing.as_string(), locked),
         collision vec![Aabb3::new(
@@ -2252,6 +2387,7 @@ define_blocks! {
         },
         data Some(color.data()),
         material material::TRANSPARENT,
+        hardness 0.3,
         model { ("minecraft", format!("{}_stained_glass", color.as_string()) ) },
     }
     TrapDoor {
@@ -2289,6 +2425,7 @@ define_blocks! {
             facing.horizontal_offset() * (2 * 2 * 2 * 2) +
             wood.offset() * (2 * 2 * 2 * 2 * 4)),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "trapdoor") },
         variant format!("facing={},half={},open={}", facing.as_string(), half.as_string(), open),
         collision trapdoor_collision(facing, half, open),
@@ -2305,6 +2442,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 0.75,
         model { ("minecraft", format!("{}_monster_egg", variant.as_string())) },
     }
     StoneBrick {
@@ -2317,6 +2455,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 1.5,
         model { ("minecraft", variant.as_string() ) },
     }
     BrownMushroomBlock {
@@ -2331,6 +2470,7 @@ define_blocks! {
         },
         data mushroom_block_data(is_stem, west, up, south, north, east, down),
         offset mushroom_block_offset(is_stem, west, up, south, north, east, down),
+        hardness 0.2,
         model { ("minecraft", "brown_mushroom_block") },
         variant format!("variant={}", mushroom_block_variant(is_stem, west, up, south, north, east, down)),
     }
@@ -2346,6 +2486,7 @@ define_blocks! {
         },
         data mushroom_block_data(is_stem, west, up, south, north, east, down),
         offset mushroom_block_offset(is_stem, west, up, south, north, east, down),
+        hardness 0.2,
         model { ("minecraft", "red_mushroom_block") },
         variant format!("variant={}", mushroom_block_variant(is_stem, west, up, south, north, east, down)),
     }
@@ -2360,6 +2501,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset mushroom_block_offset(false, west, up, south, north, east, down),
+        hardness 0.2,
         model { ("minecraft", "mushroom_stem") },
         variant "variant=all_stem".to_string(),
     }
@@ -2378,6 +2520,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 5.0,
         model { ("minecraft", "iron_bars") },
         collision pane_collision(north, south, east, west),
         update_state (world, pos) => {
@@ -2420,6 +2563,7 @@ define_blocks! {
                 None
             }
         },
+        hardness 5.0,
         model { ("minecraft", "chain") },
     }
     GlassPane {
@@ -2437,6 +2581,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 0.3,
         model { ("minecraft", "glass_pane") },
         collision pane_collision(north, south, east, west),
         update_state (world, pos) => {
@@ -2453,6 +2598,7 @@ define_blocks! {
     }
     MelonBlock {
         props {},
+        hardness 1.0,
         model { ("minecraft", "melon_block") },
     }
     AttachedPumpkinStem {
@@ -2467,6 +2613,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "pumpkin_stem") },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -2495,6 +2642,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "melon_stem") },
         variant format!("facing={}", facing.as_string()),
         collision vec![],
@@ -2524,6 +2672,7 @@ define_blocks! {
         },
         data if facing == Direction::Up { Some(age as usize) } else { None },
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "pumpkin_stem") },
         variant {
             if facing == Direction::Up {
@@ -2560,6 +2709,7 @@ define_blocks! {
         },
         data if facing == Direction::North { Some(age as usize) } else { None },
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "melon_stem") },
         variant {
             if facing == Direction::Up {
@@ -2605,6 +2755,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 0.2,
         model { ("minecraft", "vine") },
         variant format!("east={},north={},south={},up={},west={}", east, north, south, up, west),
         tint TintType::Foliage,
@@ -2630,6 +2781,7 @@ define_blocks! {
         data fence_gate_data(facing, in_wall, open, powered),
         offset fence_gate_offset(facing, in_wall, open, powered),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "fence_gate") },
         variant format!("facing={},in_wall={},open={}", facing.as_string(), in_wall, open),
         collision fence_gate_collision(facing, in_wall, open),
@@ -2661,6 +2813,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "brick_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -2687,6 +2840,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 1.5,
         model { ("minecraft", "stone_brick_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -2699,6 +2853,7 @@ define_blocks! {
         data if snowy { None } else { Some(0) },
         offset Some(if snowy { 0 } else { 1 }),
         material material::SOLID,
+        hardness 0.6,
         model { ("minecraft", "mycelium") },
         variant format!("snowy={}", snowy),
         update_state (world, pos) => Block::Mycelium{snowy: is_snowy(world, pos)},
@@ -2706,6 +2861,7 @@ define_blocks! {
     Waterlily {
         props {},
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "waterlily") },
         tint TintType::Foliage,
         collision vec![Aabb3::new(
@@ -2715,6 +2871,7 @@ define_blocks! {
     }
     NetherBrick {
         props {},
+        hardness 2.0,
         model { ("minecraft", "nether_brick") },
     }
     NetherBrickFence {
@@ -2732,6 +2889,7 @@ define_blocks! {
             if north { 0 } else { 1<<3 } +
             if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "nether_brick_fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -2775,6 +2933,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "nether_brick_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -2786,6 +2945,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "nether_wart") },
         variant format!("age={}", age),
         collision vec![],
@@ -2793,6 +2953,7 @@ define_blocks! {
     EnchantingTable {
         props {},
         material material::NON_SOLID,
+        hardness 5.0,
         model { ("minecraft", "enchanting_table") },
         collision vec![Aabb3::new(
             Point3::new(0.0, 0.0, 0.0),
@@ -2815,6 +2976,7 @@ define_blocks! {
             emitted_light: 1,
             ..material::NON_SOLID
         },
+        hardness 0.5,
         model { ("minecraft", "brewing_stand") },
         multipart (key, val) => match key {
             "has_bottle_0" => (val == "true") == has_bottle_0,
@@ -2829,6 +2991,7 @@ define_blocks! {
         },
         data Some(level as usize),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "cauldron") },
         variant format!("level={}", level),
     }
@@ -2838,6 +3001,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::NON_SOLID
         },
+        hardness -1.0,
         model { ("minecraft", "end_portal") },
         collision vec![],
     }
@@ -2857,6 +3021,7 @@ define_blocks! {
             emitted_light: 1,
             ..material::NON_SOLID
         },
+        hardness -1.0,
         model { ("minecraft", "end_portal_frame") },
         variant format!("eye={},facing={}", eye, facing.as_string()),
         collision {
@@ -2877,6 +3042,7 @@ define_blocks! {
     }
     EndStone {
         props {},
+        hardness 3.0,
         model { ("minecraft", "end_stone") },
     }
     DragonEgg {
@@ -2885,6 +3051,7 @@ define_blocks! {
             emitted_light: 1,
             ..material::NON_SOLID
         },
+        hardness 3.0,
         model { ("minecraft", "dragon_egg") },
         collision vec![Aabb3::new(
             Point3::new(1.0/16.0, 0.0, 1.0/16.0),
@@ -2893,6 +3060,7 @@ define_blocks! {
     }
     RedstoneLamp {
         props {},
+        hardness 0.3,
         model { ("minecraft", "redstone_lamp") },
     }
     RedstoneLampLit {
@@ -2901,6 +3069,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::NON_SOLID
         },
+        hardness 0.3,
         model { ("minecraft", "lit_redstone_lamp") },
     }
     DoubleWoodenSlab {
@@ -2916,6 +3085,7 @@ define_blocks! {
         },
         data Some(variant.data()),
         offset None,
+        hardness 2.0,
         model { ("minecraft", format!("{}_double_slab", variant.as_string()) ) },
     }
     WoodenSlab {
@@ -2933,6 +3103,7 @@ define_blocks! {
         data Some(variant.data() | (if half == BlockHalf::Top { 0x8 } else { 0x0 })),
         offset None,
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", format!("{}_slab", variant.as_string()) ) },
         variant format!("half={}", half.as_string()),
         collision slab_collision(half),
@@ -2950,6 +3121,7 @@ define_blocks! {
         data Some(facing.horizontal_index() | ((age as usize) << 2)),
         offset Some(facing.horizontal_offset() + ((age as usize) * 4)),
         material material::NON_SOLID,
+        hardness 0.2,
         model { ("minecraft", "cocoa") },
         variant format!("age={},facing={}", age, facing.as_string()),
         collision {
@@ -2992,6 +3164,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 0.8,
         model { ("minecraft", "sandstone_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3000,6 +3173,7 @@ define_blocks! {
     EmeraldOre {
         props {},
         material material::SOLID,
+        hardness 3.0,
         model { ("minecraft", "emerald_ore") },
     }
     EnderChest {
@@ -3018,6 +3192,7 @@ define_blocks! {
             emitted_light: 7,
             ..material::NON_SOLID
         },
+        hardness 22.5,
         model { ("minecraft", "ender_chest") },
         variant format!("facing={}", facing.as_string()),
         collision vec![Aabb3::new(
@@ -3043,6 +3218,7 @@ define_blocks! {
                     facing.horizontal_offset() * 2 +
                     if attached { 0 } else { 2 * 4 }),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "tripwire_hook") },
         variant format!("attached={},facing={},powered={}", attached, facing.as_string(), powered),
         collision vec![],
@@ -3078,6 +3254,7 @@ define_blocks! {
                  if attached { 0 } else { 1<<6 })
         },
         material material::TRANSPARENT,
+        hardness 0.0,
         model { ("minecraft", "tripwire") },
         variant format!("attached={},east={},north={},south={},west={}", attached, east, north, south, west),
         collision vec![],
@@ -3104,6 +3281,7 @@ define_blocks! {
     }
     EmeraldBlock {
         props {},
+        hardness 5.0,
         model { ("minecraft", "emerald_block") },
     }
     SpruceStairs {
@@ -3127,6 +3305,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "spruce_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3153,6 +3332,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "birch_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3179,6 +3359,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "jungle_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3198,6 +3379,7 @@ define_blocks! {
         },
         data Some(facing.index() | (if conditional { 0x8 } else { 0x0 })),
         offset Some(facing.offset() + (if conditional { 0 } else { 6 })),
+        hardness -1.0,
         model { ("minecraft", "command_block") },
         variant format!("conditional={},facing={}", conditional, facing.as_string()),
     }
@@ -3207,6 +3389,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::NON_SOLID
         },
+        hardness 3.0,
         model { ("minecraft", "beacon") },
     }
     CobblestoneWall {
@@ -3231,6 +3414,7 @@ define_blocks! {
                     if east { 0 } else { 1<<5 } +
                     if variant == CobblestoneWallVariant::Normal { 0 } else { 1<<6 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", format!("{}_wall", variant.as_string())) },
         update_state (world, pos) => {
             let f = |block| matches!(block, Block::CobblestoneWall{..} |
@@ -3291,6 +3475,7 @@ define_blocks! {
             if legacy_data != 0 { None } else { contents.offsets(protocol_version) }
         },
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "flower_pot") },
     }
     Carrots {
@@ -3299,6 +3484,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "carrots") },
         variant format!("age={}", age),
         collision vec![],
@@ -3309,6 +3495,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "potatoes") },
         variant format!("age={}", age),
         collision vec![],
@@ -3339,6 +3526,7 @@ define_blocks! {
         data if variant == TreeVariant::Oak { face.data_with_facing_and_powered(facing, powered) } else { None },
         offset Some(variant.offset() * (3 * 4 * 2) + face.offset() * (4 * 2) + facing.horizontal_offset() * 2 + if powered { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "wooden_button") },
         variant format!("facing={},powered={}", face.variant_with_facing(facing), powered),
     }
@@ -3356,6 +3544,7 @@ define_blocks! {
         data if !nodrop { Some(facing.index()) } else { None },
         offset if !nodrop && facing != Direction::Up { Some(facing.horizontal_offset()) } else { None },
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "skull") },
         variant format!("facing={},nodrop={}", facing.as_string(), nodrop),
         collision {
@@ -3404,6 +3593,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "skull") },
         collision {
             let (min_x, min_y, min_z, max_x, max_y, max_z) = match facing {
@@ -3427,6 +3617,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(rotation as usize),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "skull") },
         collision {
             let (min_x, min_y, min_z, max_x, max_y, max_z) = (0.25, 0.0, 0.25, 0.75, 0.5, 0.75);
@@ -3449,6 +3640,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "zombie_wall_head") },
     }
     ZombieHead {
@@ -3458,6 +3650,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(rotation as usize),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "zombie_head") },
     }
     PlayerWallHead {
@@ -3472,6 +3665,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "player_wall_head") },
     }
     PlayerHead {
@@ -3481,6 +3675,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(rotation as usize),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "player_head") },
     }
     CreeperWallHead {
@@ -3495,6 +3690,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "creeper_wall_head") },
     }
     CreeperHead {
@@ -3504,6 +3700,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(rotation as usize),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "creeper_head") },
     }
     DragonWallHead {
@@ -3518,6 +3715,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(facing.horizontal_offset()),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "dragon_wall_head") },
     }
     DragonHead {
@@ -3527,6 +3725,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(rotation as usize),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "dragon_head") },
     }
     Anvil {
@@ -3542,6 +3741,7 @@ define_blocks! {
         data Some(facing.horizontal_index() | (match damage { 0 => 0x0, 1 => 0x4, 2 => 0x8, _ => unreachable!() })),
         offset Some(facing.horizontal_offset() + (damage as usize) * 4),
         material material::NON_SOLID,
+        hardness 5.0,
         model { ("minecraft", "anvil") },
         variant format!("damage={},facing={}", damage, facing.as_string()),
         collision match facing.axis() {
@@ -3576,6 +3776,7 @@ define_blocks! {
             type_.offset() * 2 +
             facing.horizontal_offset() * (2 * 3)),
         material material::NON_SOLID,
+        hardness 2.5,
         model { ("minecraft", "trapped_chest") },
         variant format!("facing={}", facing.as_string()),
         collision vec![Aabb3::new(
@@ -3589,6 +3790,7 @@ define_blocks! {
         },
         data Some(power as usize),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "light_weighted_pressure_plate") },
         variant format!("power={}", power),
         collision vec![],
@@ -3599,6 +3801,7 @@ define_blocks! {
         },
         data Some(power as usize),
         material material::NON_SOLID,
+        hardness 0.5,
         model { ("minecraft", "heavy_weighted_pressure_plate") },
         variant format!("power={}", power),
         collision vec![],
@@ -3621,6 +3824,7 @@ define_blocks! {
                     if mode == ComparatorMode::Compare { 0 } else { 1<<1 } +
                     facing.horizontal_offset() * (1<<2)),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "unpowered_comparator") },
         variant format!("facing={},mode={},powered={}", facing.as_string(), mode.as_string(), powered),
         collision vec![Aabb3::new(
@@ -3644,6 +3848,7 @@ define_blocks! {
                   | (if powered { 0x8 } else { 0x0 })),
         offset None,
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "powered_comparator") },
         variant format!("facing={},mode={},powered={}", facing.as_string(), mode.as_string(), powered),
         collision vec![Aabb3::new(
@@ -3659,6 +3864,7 @@ define_blocks! {
         data if inverted { None } else { Some(power as usize) },
         offset Some((power as usize) + if inverted { 0 } else { 16 }),
         material material::NON_SOLID,
+        hardness 0.2,
         model { ("minecraft", "daylight_detector") },
         variant format!("power={}", power),
         collision vec![Aabb3::new(
@@ -3668,10 +3874,12 @@ define_blocks! {
     }
     RedstoneBlock {
         props {},
+        hardness 5.0,
         model { ("minecraft", "redstone_block") },
     }
     QuartzOre {
         props {},
+        hardness 3.0,
         model { ("minecraft", "quartz_ore") },
     }
     Hopper {
@@ -3695,6 +3903,7 @@ define_blocks! {
             _ => unreachable!(),
         } + if enabled { 0 } else { 5 }),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "hopper") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -3709,6 +3918,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 0.8,
         model { ("minecraft", match variant {
             QuartzVariant::Normal => "quartz_block",
             QuartzVariant::Chiseled => "chiseled_quartz_block",
@@ -3739,6 +3949,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 0.8,
         model { ("minecraft", "quartz_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3759,6 +3970,7 @@ define_blocks! {
         data Some(shape.data() | (if powered { 0x8 } else { 0x0 })),
         offset Some(shape.data() + (if powered { 0 } else { 6 })),
         material material::NON_SOLID,
+        hardness 0.7,
         model { ("minecraft", "activator_rail") },
         variant format!("powered={},shape={}", powered, shape.as_string()),
         collision vec![],
@@ -3777,6 +3989,7 @@ define_blocks! {
         },
         data Some(facing.index() | (if triggered { 0x8 } else { 0x0 })),
         offset Some(if triggered { 0 } else { 1 } + facing.offset() * 2),
+        hardness 3.5,
         model { ("minecraft", "dropper") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -3802,6 +4015,7 @@ define_blocks! {
             ],
         },
         data Some(color.data()),
+        hardness 1.25,
         model { ("minecraft", format!("{}_stained_hardened_clay", color.as_string()) ) },
     }
     StainedGlassPane {
@@ -3838,6 +4052,7 @@ define_blocks! {
                     if east { 0 } else { 1<<4 } +
                     color.data() * (1<<5)),
         material material::TRANSPARENT,
+        hardness 0.3,
         model { ("minecraft", format!("{}_stained_glass_pane", color.as_string()) ) },
         collision pane_collision(north, south, east, west),
         update_state (world, pos) => {
@@ -3903,6 +4118,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "acacia_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3929,6 +4145,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "dark_oak_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -3937,11 +4154,13 @@ define_blocks! {
     Slime {
         props {},
         material material::TRANSPARENT,
+        hardness 0.0,
         model { ("minecraft", "slime") },
     }
     Barrier {
         props {},
         material material::INVISIBLE,
+        hardness -1.0,
         model { ("minecraft", "barrier") },
     }
     IronTrapDoor {
@@ -3970,6 +4189,7 @@ define_blocks! {
             if half == BlockHalf::Top { 0 } else { 1<<3 } +
             facing.horizontal_offset() * (1<<4)),
         material material::NON_SOLID,
+        hardness 5.0,
         model { ("minecraft", "iron_trapdoor") },
         variant format!("facing={},half={},open={}", facing.as_string(), half.as_string(), open),
         collision trapdoor_collision(facing, half, open),
@@ -3983,6 +4203,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 1.5,
         model { ("minecraft", variant.as_string() ) },
     }
     PrismarineStairs {
@@ -4011,6 +4232,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(stair_offset(facing, half, shape, waterlogged).unwrap() + (2 * 5 * 2 * 4) * variant.data()),
         material material::NON_SOLID,
+        hardness 1.5,
         model { ("minecraft", match variant {
             PrismarineVariant::Normal => "prismarine_stairs",
             PrismarineVariant::Brick => "prismarine_brick_stairs",
@@ -4037,6 +4259,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(if waterlogged { 0 } else { 1 } + type_.offset() * 2 + variant.data() * (2 * 3)),
         material material::NON_SOLID,
+        hardness 1.5,
         model { ("minecraft", match variant {
             PrismarineVariant::Normal => "prismarine_slab",
             PrismarineVariant::Brick => "prismarine_brick_slab",
@@ -4051,6 +4274,7 @@ define_blocks! {
             emitted_light: 15,
             ..material::SOLID
         },
+        hardness 0.3,
         model { ("minecraft", "sea_lantern") },
     }
     HayBlock {
@@ -4059,6 +4283,7 @@ define_blocks! {
         },
         data Some(match axis { Axis::X => 0x4, Axis::Y => 0x0, Axis::Z => 0x8, _ => unreachable!() }),
         offset Some(match axis { Axis::X => 0, Axis::Y => 1, Axis::Z => 2, _ => unreachable!() }),
+        hardness 0.5,
         model { ("minecraft", "hay_block") },
         variant format!("axis={}", axis.as_string()),
     }
@@ -4085,6 +4310,7 @@ define_blocks! {
         },
         data Some(color.data()),
         material material::NON_SOLID,
+        hardness 0.1,
         model { ("minecraft", format!("{}_carpet", color.as_string()) ) },
         collision vec![Aabb3::new(
             Point3::new(0.0, 0.0, 0.0),
@@ -4093,14 +4319,17 @@ define_blocks! {
     }
     HardenedClay {
         props {},
+        hardness 1.25,
         model { ("minecraft", "hardened_clay") },
     }
     CoalBlock {
         props {},
+        hardness 5.0,
         model { ("minecraft", "coal_block") },
     }
     PackedIce {
         props {},
+        hardness 0.5,
         model { ("minecraft", "packed_ice") },
     }
     DoublePlant {
@@ -4118,6 +4347,7 @@ define_blocks! {
         data Some(variant.data() | (if half == BlockHalf::Upper { 0x8 } else { 0x0 })),
         offset Some(half.offset() + variant.offset() * 2),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", variant.as_string()) },
         variant format!("half={}", half.as_string()),
         tint TintType::Foliage,
@@ -4169,6 +4399,7 @@ define_blocks! {
         data if color != ColoredVariant::White { None } else { Some(rotation.data()) },
         offset Some(rotation.data() + color.data() * 16),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "standing_banner") },
         variant format!("rotation={}", rotation.as_string()),
     }
@@ -4202,6 +4433,7 @@ define_blocks! {
         data if color != ColoredVariant::White { None } else { Some(facing.index()) },
         offset Some(facing.horizontal_offset() + color.data() * 4),
         material material::NON_SOLID,
+        hardness 1.0,
         model { ("minecraft", "wall_banner") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -4212,6 +4444,7 @@ define_blocks! {
         data Some(power as usize),
         offset None,
         material material::NON_SOLID,
+        hardness 0.2,
         model { ("minecraft", "daylight_detector_inverted") },
         variant format!("power={}", power),
         collision vec![Aabb3::new(
@@ -4228,6 +4461,7 @@ define_blocks! {
             ],
         },
         data Some(variant.data()),
+        hardness 0.8,
         model { ("minecraft", variant.as_string()) },
     }
     RedSandstoneStairs {
@@ -4251,6 +4485,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 0.8,
         model { ("minecraft", "red_sandstone_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -4276,6 +4511,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(if waterlogged { 0 } else { 1 } + type_.offset() * 2 + variant.data() * (2 * 3)),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", format!("{}_slab", variant.as_string()) ) },
         variant format!("type={}", type_.as_string()),
         collision slab_collision(type_),
@@ -4305,6 +4541,7 @@ define_blocks! {
             variant.offsets(protocol_version).map(|o| if waterlogged { 0 } else { 1 } + type_.offset() * 2 + o * (2 * 3))
         },
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", format!("{}_slab", variant.as_string()) ) },
         variant format!("type={}", type_.as_string()),
         collision slab_collision(type_),
@@ -4351,6 +4588,7 @@ define_blocks! {
             StoneSlabVariant::RedSandstone => 3,
             _ => unreachable!(),
         }),
+        hardness 2.0,
         model { ("minecraft", format!("smooth_{}", variant.as_string()) ) },
     }
     SpruceFenceGate {
@@ -4368,6 +4606,7 @@ define_blocks! {
         data fence_gate_data(facing, in_wall, open, powered),
         offset fence_gate_offset(facing, in_wall, open, powered),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "spruce_fence_gate") },
         variant format!("facing={},in_wall={},open={}", facing.as_string(), in_wall, open),
         collision fence_gate_collision(facing, in_wall, open),
@@ -4393,6 +4632,7 @@ define_blocks! {
         data fence_gate_data(facing, in_wall, open, powered),
         offset fence_gate_offset(facing, in_wall, open, powered),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "birch_fence_gate") },
         variant format!("facing={},in_wall={},open={}", facing.as_string(), in_wall, open),
         collision fence_gate_collision(facing, in_wall, open),
@@ -4418,6 +4658,7 @@ define_blocks! {
         data fence_gate_data(facing, in_wall, open, powered),
         offset fence_gate_offset(facing, in_wall, open, powered),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "jungle_fence_gate") },
         variant format!("facing={},in_wall={},open={}", facing.as_string(), in_wall, open),
         collision fence_gate_collision(facing, in_wall, open),
@@ -4443,6 +4684,7 @@ define_blocks! {
         data fence_gate_data(facing, in_wall, open, powered),
         offset fence_gate_offset(facing, in_wall, open, powered),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "dark_oak_fence_gate") },
         variant format!("facing={},in_wall={},open={}", facing.as_string(), in_wall, open),
         collision fence_gate_collision(facing, in_wall, open),
@@ -4468,6 +4710,7 @@ define_blocks! {
         data fence_gate_data(facing, in_wall, open, powered),
         offset fence_gate_offset(facing, in_wall, open, powered),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "acacia_fence_gate") },
         variant format!("facing={},in_wall={},open={}", facing.as_string(), in_wall, open),
         collision fence_gate_collision(facing, in_wall, open),
@@ -4493,6 +4736,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "spruce_fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -4522,6 +4766,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "birch_fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -4551,6 +4796,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "jungle_fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -4580,6 +4826,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "dark_oak_fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -4609,6 +4856,7 @@ define_blocks! {
                     if north { 0 } else { 1<<3 } +
                     if east { 0 } else { 1<<4 }),
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", "acacia_fence") },
         collision fence_collision(north, south, west, east),
         update_state (world, pos) => {
@@ -4639,6 +4887,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "spruce_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -4663,6 +4912,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "birch_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -4687,6 +4937,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "jungle_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -4711,6 +4962,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "acacia_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -4735,6 +4987,7 @@ define_blocks! {
         data door_data(facing, half, hinge, open, powered),
         offset door_offset(facing, half, hinge, open, powered),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "dark_oak_door") },
         variant format!("facing={},half={},hinge={},open={}", facing.as_string(), half.as_string(), hinge.as_string(), open),
         collision door_collision(facing, hinge, open),
@@ -4760,6 +5013,7 @@ define_blocks! {
             emitted_light: 14,
             ..material::NON_SOLID
         },
+        hardness 0.0,
         model { ("minecraft", "end_rod") },
         variant format!("facing={}", facing.as_string()),
         collision {
@@ -4797,6 +5051,7 @@ define_blocks! {
                     if east { 0 } else { 1<<4 } +
                     if down { 0 } else { 1<<5 }),
         material material::NON_SOLID,
+        hardness 0.4,
         model { ("minecraft", "chorus_plant") },
         collision {
             let mut collision = vec![Aabb3::new(
@@ -4872,11 +5127,13 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.4,
         model { ("minecraft", "chorus_flower") },
         variant format!("age={}", age),
     }
     PurpurBlock {
         props {},
+        hardness 1.5,
         model { ("minecraft", "purpur_block") },
     }
     PurpurPillar {
@@ -4885,6 +5142,7 @@ define_blocks! {
         },
         data Some(match axis { Axis::X => 0x4, Axis::Y => 0x0, Axis::Z => 0x8, _ => unreachable!() }),
         offset Some(match axis { Axis::X => 0, Axis::Y => 1, Axis::Z => 2, _ => unreachable!() }),
+        hardness 1.5,
         model { ("minecraft", "purpur_pillar") },
         variant format!("axis={}", axis.as_string()),
     }
@@ -4909,6 +5167,7 @@ define_blocks! {
         data stair_data(facing, half, shape, waterlogged),
         offset stair_offset(facing, half, shape, waterlogged),
         material material::NON_SOLID,
+        hardness 1.5,
         model { ("minecraft", "purpur_stairs") },
         variant format!("facing={},half={},shape={}", facing.as_string(), half.as_string(), shape.as_string()),
         collision stair_collision(facing, shape, half),
@@ -4919,6 +5178,7 @@ define_blocks! {
             variant: StoneSlabVariant = [StoneSlabVariant::Purpur],
         },
         offset None,
+        hardness 2.0,
         model { ("minecraft", format!("{}_double_slab", variant.as_string()) ) },
     }
     PurpurSlab {
@@ -4929,12 +5189,14 @@ define_blocks! {
         data if half == BlockHalf::Top { Some(0x8) } else { Some(0) },
         offset None,
         material material::NON_SOLID,
+        hardness 2.0,
         model { ("minecraft", format!("{}_slab", variant.as_string()) ) },
         variant format!("half={},variant=default", half.as_string()),
         collision slab_collision(half),
     }
     EndBricks {
         props {},
+        hardness 3.0,
         model { ("minecraft", "end_bricks") },
     }
     Beetroots {
@@ -4943,6 +5205,7 @@ define_blocks! {
         },
         data Some(age as usize),
         material material::NON_SOLID,
+        hardness 0.0,
         model { ("minecraft", "beetroots") },
         variant format!("age={}", age),
         collision vec![],
@@ -4950,6 +5213,7 @@ define_blocks! {
     GrassPath {
         props {},
         material material::NON_SOLID,
+        hardness 0.65,
         model { ("minecraft", "grass_path") },
         collision vec![Aabb3::new(
             Point3::new(0.0, 0.0, 0.0),
@@ -4959,6 +5223,7 @@ define_blocks! {
     EndGateway {
         props {},
         material material::NON_SOLID,
+        hardness -1.0,
         model { ("minecraft", "end_gateway") },
         collision vec![],
     }
@@ -4976,6 +5241,7 @@ define_blocks! {
         },
         data Some(facing.index() | (if conditional { 0x8 } else { 0x0 })),
         offset Some(facing.offset() + (if conditional { 0 } else { 6 })),
+        hardness -1.0,
         model { ("minecraft", "repeating_command_block") },
         variant format!("conditional={},facing={}", conditional, facing.as_string()),
     }
@@ -4993,6 +5259,7 @@ define_blocks! {
         },
         data Some(facing.index() | (if conditional { 0x8 } else { 0x0 })),
         offset Some(facing.offset() + (if conditional { 0 } else { 6 })),
+        hardness -1.0,
         model { ("minecraft", "chain_command_block") },
         variant format!("conditional={},facing={}", conditional, facing.as_string()),
     }
@@ -5002,18 +5269,22 @@ define_blocks! {
         },
         data if age == 0 { Some(0) } else { None },
         offset Some(age as usize),
+        hardness 0.5,
         model { ("minecraft", "frosted_ice") },
     }
     MagmaBlock {
         props {},
+        hardness 0.5,
         model { ("minecraft", "magma") },
     }
     NetherWartBlock {
         props {},
+        hardness 1.0,
         model { ("minecraft", "nether_wart_block") },
     }
     RedNetherBrick {
         props {},
+        hardness 2.0,
         model { ("minecraft", "red_nether_brick") },
     }
     BoneBlock {
@@ -5022,6 +5293,7 @@ define_blocks! {
         },
         data Some(axis.index() << 2),
         offset Some(match axis { Axis::X => 0, Axis::Y => 1, Axis::Z => 2, _ => unreachable!() }),
+        hardness 2.0,
         model { ("minecraft", "bone_block") },
         variant format!("axis={}", axis.as_string()),
     }
@@ -5031,6 +5303,7 @@ define_blocks! {
             collidable: false,
             .. material::INVISIBLE
         },
+        hardness 0.0,
         model { ("minecraft", "structure_void") },
         // TODO: a small hit box but no collision
         collision vec![],
@@ -5049,6 +5322,7 @@ define_blocks! {
         },
         data Some(facing.index() | (if powered { 0x8 } else { 0x0 })),
         offset Some(if powered { 0 } else { 1 } + facing.offset() * 2),
+        hardness 3.0,
         model { ("minecraft", "observer") },
         variant format!("facing={},powered={}", facing.as_string(), powered),
     }
@@ -5068,6 +5342,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "sponge") },
     }
     WhiteShulkerBox {
@@ -5083,6 +5358,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "white_wool") },
     }
     OrangeShulkerBox {
@@ -5098,6 +5374,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "orange_wool") },
     }
     MagentaShulkerBox {
@@ -5113,6 +5390,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "magenta_wool") },
     }
     LightBlueShulkerBox {
@@ -5128,6 +5406,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "light_blue_wool") },
     }
     YellowShulkerBox {
@@ -5143,6 +5422,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "yellow_wool") },
     }
     LimeShulkerBox {
@@ -5158,6 +5438,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "lime_wool") },
     }
     PinkShulkerBox {
@@ -5173,6 +5454,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "pink_wool") },
     }
     GrayShulkerBox {
@@ -5188,6 +5470,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "gray_wool") },
     }
     LightGrayShulkerBox {
@@ -5203,6 +5486,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "light_gray_wool") },
     }
     CyanShulkerBox {
@@ -5218,6 +5502,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "cyan_wool") },
     }
     PurpleShulkerBox {
@@ -5233,6 +5518,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "purple_wool") },
     }
     BlueShulkerBox {
@@ -5248,6 +5534,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "blue_wool") },
     }
     BrownShulkerBox {
@@ -5263,6 +5550,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "brown_wool") },
     }
     GreenShulkerBox {
@@ -5278,6 +5566,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "green_wool") },
     }
     RedShulkerBox {
@@ -5293,6 +5582,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "red_wool") },
     }
     BlackShulkerBox {
@@ -5308,6 +5598,7 @@ define_blocks! {
         },
         data Some(facing.index()),
         offset Some(facing.offset()),
+        hardness 2.0,
         model { ("minecraft", "black_wool") },
     }
     WhiteGlazedTerracotta {
@@ -5321,6 +5612,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "white_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5335,6 +5627,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "orange_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5349,6 +5642,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "magenta_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5363,6 +5657,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "light_blue_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5377,6 +5672,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "yellow_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5391,6 +5687,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "lime_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5405,6 +5702,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "pink_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5419,6 +5717,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "gray_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5433,6 +5732,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "silver_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5447,6 +5747,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "cyan_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5461,6 +5762,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "purple_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5475,6 +5777,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "blue_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5489,6 +5792,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "brown_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5503,6 +5807,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "green_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5517,6 +5822,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "red_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5531,6 +5837,7 @@ define_blocks! {
         },
         data Some(facing.horizontal_index()),
         offset Some(facing.horizontal_offset()),
+        hardness 1.4,
         model { ("minecraft", "black_glazed_terracotta") },
         variant format!("facing={}", facing.as_string()),
     }
@@ -5556,6 +5863,7 @@ define_blocks! {
             ],
         },
         data Some(color.data()),
+        hardness 1.8,
         model { ("minecraft", format!("{}_concrete", color.as_string()) ) },
     }
     ConcretePowder {
@@ -5580,6 +5888,7 @@ define_blocks! {
             ],
         },
         data Some(color.data()),
+        hardness 0.5,
         model { ("minecraft", format!("{}_concrete_powder", color.as_string()) ) },
     }
     Kelp {
@@ -5588,18 +5897,21 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some(age as usize),
+        hardness 0.0,
         model { ("minecraft", "kelp") },
     }
     KelpPlant {
         props {},
         data None::<usize>,
         offset Some(0),
+        hardness 0.0,
         model { ("minecraft", "kelp_plant") },
     }
     DriedKelpBlock {
         props {},
         data None::<usize>,
         offset Some(0),
+        hardness 0.5,
         model { ("minecraft", "dried_kelp_block") },
     }
     TurtleEgg {
@@ -5609,6 +5921,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some((hatch as usize) + ((age - 1) as usize) * 3),
+        hardness 0.5,
         model { ("minecraft", "turtle_egg") },
     }
     CoralBlock {
@@ -5628,6 +5941,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some(variant.offset()),
+        hardness 1.5,
         model { ("minecraft", format!("{}_block", variant.as_string())) },
     }
     Coral {
@@ -5648,6 +5962,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some(if waterlogged { 0 } else { 1 } + variant.offset() * 2),
+        hardness 0.0,
         model { ("minecraft", variant.as_string()) },
     }
     CoralWallFan {
@@ -5676,6 +5991,7 @@ define_blocks! {
         offset Some(if waterlogged { 0 } else { 1 } +
                     facing.horizontal_offset() * 2 +
                     variant.offset() * (2 * 4)),
+        hardness 0.0,
         model { ("minecraft", format!("{}_wall_fan", variant.as_string())) },
     }
     CoralFan {
@@ -5697,6 +6013,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(if waterlogged { 0 } else { 1 } +
                     variant.offset() * 2),
+        hardness 0.0,
         model { ("minecraft", format!("{}_fan", variant.as_string())) },
     }
     SeaPickle {
@@ -5707,6 +6024,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(if waterlogged { 0 } else { 1 } +
                     ((age - 1) as usize) * 2),
+        hardness 0.0,
         model { ("minecraft", "sea_pickle") },
         variant format!("age={}", age),
     }
@@ -5714,6 +6032,7 @@ define_blocks! {
         props {},
         data None::<usize>,
         offset Some(0),
+        hardness 2.8,
         model { ("minecraft", "blue_ice") },
     }
     Conduit {
@@ -5723,6 +6042,7 @@ define_blocks! {
         data None::<usize>,
         offset Some(if waterlogged { 0 } else { 1 }),
         material material::NON_SOLID,
+        hardness 3.0,
         model { ("minecraft", "conduit") },
     }
     VoidAir {
@@ -5733,6 +6053,7 @@ define_blocks! {
             collidable: false,
             .. material::INVISIBLE
         },
+        hardness 0.0,
         model { ("minecraft", "air") },
         collision vec![],
     }
@@ -5744,6 +6065,7 @@ define_blocks! {
             collidable: false,
             .. material::INVISIBLE
         },
+        hardness 0.0,
         model { ("minecraft", "air") },
         collision vec![],
     }
@@ -5753,6 +6075,7 @@ define_blocks! {
         },
         data None::<usize>,
         offset Some(if drag { 0 } else { 1 }),
+        hardness 100.0,
         model { ("minecraft", "bubble_column") },
     }
     Missing253 {
@@ -5777,6 +6100,7 @@ define_blocks! {
             ],
         },
         data Some(mode.data()),
+        hardness -1.0,
         model { ("minecraft", "structure_block") },
         variant format!("mode={}", mode.as_string()),
     }
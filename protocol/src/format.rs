@@ -12,12 +12,48 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use lazy_static::lazy_static;
+use std::collections::HashMap;
 use std::fmt;
 use std::mem;
+use std::sync::RwLock;
+
+lazy_static! {
+    /// The translations of the current language, used to display
+    /// translated components.
+    static ref TRANSLATIONS: RwLock<Option<HashMap<String, String>>> = RwLock::new(None);
+}
+
+/// Replaces the translations used to display `Translate` and `Keybind`
+/// components.
+pub fn set_translations(translations: HashMap<String, String>) {
+    *TRANSLATIONS.write().unwrap() = Some(translations);
+}
+
+/// Looks up the text for a translation key in the current language
+pub fn translate(key: &str) -> Option<String> {
+    if let Some(translations) = TRANSLATIONS.read().unwrap().as_ref() {
+        if let Some(val) = translations.get(key) {
+            return Some(val.clone());
+        }
+    }
+    // Keep chat readable before the language files are loaded
+    match key {
+        "chat.type.text" => Some("<%s> %s"),
+        "chat.type.announcement" => Some("[%s] %s"),
+        "chat.type.emote" => Some("* %s %s"),
+        _ => None,
+    }
+    .map(str::to_owned)
+}
 
 #[derive(Debug, Clone)]
 pub enum Component {
     Text(TextComponent),
+    Translate(TranslateComponent),
+    Score(ScoreComponent),
+    Selector(SelectorComponent),
+    Keybind(KeybindComponent),
 }
 
 impl Component {
@@ -35,55 +71,63 @@ impl Component {
     }
 
     pub fn from_value(v: &serde_json::Value) -> Self {
-        let mut modifier = Modifier::from_value(v);
         if let Some(val) = v.as_str() {
-            Component::Text(TextComponent {
-                text: val.to_owned(),
+            return Component::Text(TextComponent::new(val));
+        }
+        if let Some(list) = v.as_array() {
+            // The first element is the parent of the rest
+            let mut list = list.iter().map(Component::from_value);
+            let mut component = list.next().unwrap_or_default();
+            let rest: Vec<Component> = list.collect();
+            if !rest.is_empty() {
+                component
+                    .modifier_mut()
+                    .extra
+                    .get_or_insert_with(Vec::new)
+                    .extend(rest);
+            }
+            return component;
+        }
+        if v.is_number() || v.is_boolean() {
+            return Component::Text(TextComponent::new(&v.to_string()));
+        }
+
+        let mut modifier = Modifier::from_value(v);
+        if v.get("text").is_some() {
+            Component::Text(TextComponent::from_value(v, modifier))
+        } else if let Some(translate) = v.get("translate").and_then(|v| v.as_str()) {
+            Component::Translate(TranslateComponent {
+                translate: translate.to_owned(),
+                with: v
+                    .get("with")
+                    .and_then(|v| v.as_array())
+                    .map(|args| args.iter().map(Component::from_value).collect())
+                    .unwrap_or_default(),
+                modifier,
+            })
+        } else if let Some(score) = v.get("score") {
+            let field = |name| {
+                score
+                    .get(name)
+                    .and_then(|v: &serde_json::Value| v.as_str())
+                    .map(str::to_owned)
+            };
+            Component::Score(ScoreComponent {
+                name: field("name").unwrap_or_default(),
+                objective: field("objective").unwrap_or_default(),
+                value: field("value"),
+                modifier,
+            })
+        } else if let Some(selector) = v.get("selector").and_then(|v| v.as_str()) {
+            Component::Selector(SelectorComponent {
+                selector: selector.to_owned(),
+                modifier,
+            })
+        } else if let Some(keybind) = v.get("keybind").and_then(|v| v.as_str()) {
+            Component::Keybind(KeybindComponent {
+                keybind: keybind.to_owned(),
                 modifier,
             })
-        } else if v.get("text").is_some() {
-            Component::Text(TextComponent::from_value(v, modifier))
-        } else if let Some(translate) = v.get("translate") {
-            let translate_key = translate.as_str().unwrap_or_default();
-            if let Some(serde_json::Value::Array(args)) = v.get("with") {
-                // TODO: recursively build components, avoid throwing away all but "text"
-                let text_args: Vec<&str> = args
-                    .iter()
-                    .map(|v| {
-                        if let serde_json::Value::Object(obj) = v {
-                            // Usernames might be in "extra":["text":"foo"] and "text":"" empty for
-                            // some reason; use extra instead if present TODO: use both
-                            if let Some(serde_json::Value::Array(extra)) = obj.get("extra") {
-                                if let Some(item) = extra.get(0) {
-                                    if let Some(text) = item.get("text") {
-                                        return text.as_str().unwrap_or_default();
-                                    }
-                                }
-                            }
-
-                            if let Some(text) = obj.get("text") {
-                                text.as_str().unwrap_or_default()
-                            } else {
-                                Default::default()
-                            }
-                        } else {
-                            v.as_str().unwrap_or_default()
-                        }
-                    })
-                    .collect();
-                // TODO: translations, https://wiki.vg/Chat#Translation_component
-                Component::Text(TextComponent::new(
-                    match translate_key {
-                        "chat.type.text" => format!("<{}> {}", text_args[0], text_args[1]),
-                        "chat.type.announcement" => format!("[{}] {}", text_args[0], text_args[1]),
-                        _ => format!("unhandled: {}", translate_key),
-                    }
-                    .as_str(),
-                ))
-            } else {
-                // TODO
-                Component::Text(TextComponent::new(translate_key))
-            }
         } else {
             modifier.color = Some(Color::RGB(255, 0, 0));
             Component::Text(TextComponent {
@@ -95,40 +139,52 @@ impl Component {
 
     pub fn to_value(&self) -> serde_json::Value {
         match self {
-            Component::Text(text) => {
-                let mut map = serde_json::Map::new();
-                map.insert("text".to_string(), serde_json::Value::String(text.text.clone()));
-                if let Some(bold) = text.modifier.bold{
-                    map.insert("bold".to_string(), serde_json::Value::String(bold.to_string()));
-                }
-                if let Some(color) = text.modifier.color{
-                    map.insert("color".to_string(), serde_json::Value::String(color.to_string()));
-                }
-                if let Some(italic) = text.modifier.italic{
-                    map.insert("italic".to_string(), serde_json::Value::String(italic.to_string()));
-                }
-                if let Some(obfuscated) = text.modifier.obfuscated{
-                    map.insert("obfuscated".to_string(), serde_json::Value::String(obfuscated.to_string()));
-                }
-                if let Some(strikethrough) = text.modifier.strikethrough{
-                    map.insert("strikethrough".to_string(), serde_json::Value::String(strikethrough.to_string()));
-                }
-                if let Some(underlined) = text.modifier.underlined{
-                    map.insert("underlined".to_string(), serde_json::Value::String(underlined.to_string()));
-                }
-                if let Some(extras) = &text.modifier.extra{
-                    let mut extra: Vec<serde_json::Map<String, serde_json::Value>> = Vec::new();
-                    for elem in extras{
-                        if let serde_json::Value::Object(map) = elem.to_value(){
-                            extra.push(map);
-                        }else{
-                            unreachable!("Expected object at this stage");
-                        }
-                    }
-                    map.insert("extra".to_string(), serde_json::Value::String(text.text.clone()));
-                }
-                serde_json::Value::Object(map)
-            }
+            Component::Text(text) => text.to_value(),
+            Component::Translate(translate) => translate.to_value(),
+            Component::Score(score) => score.to_value(),
+            Component::Selector(selector) => selector.to_value(),
+            Component::Keybind(keybind) => keybind.to_value(),
+        }
+    }
+
+    pub fn modifier(&self) -> &Modifier {
+        match self {
+            Component::Text(text) => &text.modifier,
+            Component::Translate(translate) => &translate.modifier,
+            Component::Score(score) => &score.modifier,
+            Component::Selector(selector) => &selector.modifier,
+            Component::Keybind(keybind) => &keybind.modifier,
+        }
+    }
+
+    pub fn modifier_mut(&mut self) -> &mut Modifier {
+        match self {
+            Component::Text(text) => &mut text.modifier,
+            Component::Translate(translate) => &mut translate.modifier,
+            Component::Score(score) => &mut score.modifier,
+            Component::Selector(selector) => &mut selector.modifier,
+            Component::Keybind(keybind) => &mut keybind.modifier,
+        }
+    }
+
+    /// Converts the component into a text component with the same
+    /// formatting, looking up translations in the current language.
+    pub fn resolve(&self) -> TextComponent {
+        match self {
+            Component::Text(text) => text.clone(),
+            Component::Translate(translate) => translate.resolve(),
+            Component::Score(score) => TextComponent {
+                text: score.value.clone().unwrap_or_default(),
+                modifier: score.modifier.clone(),
+            },
+            Component::Selector(selector) => TextComponent {
+                text: selector.selector.clone(),
+                modifier: selector.modifier.clone(),
+            },
+            Component::Keybind(keybind) => TextComponent {
+                text: translate(&keybind.keybind).unwrap_or_else(|| keybind.keybind.clone()),
+                modifier: keybind.modifier.clone(),
+            },
         }
     }
 }
@@ -137,6 +193,7 @@ impl fmt::Display for Component {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Component::Text(ref txt) => write!(f, "{}", txt),
+            ref other => write!(f, "{}", other.resolve()),
         }
     }
 }
@@ -159,9 +216,11 @@ pub struct Modifier {
     pub strikethrough: Option<bool>,
     pub obfuscated: Option<bool>,
     pub color: Option<Color>,
+    pub click_event: Option<Box<ClickEvent>>,
+    pub hover_event: Option<Box<HoverEvent>>,
 }
 
-// TODO: Missing events click/hover/insert
+// TODO: Missing insertion
 
 impl Modifier {
     pub fn from_value(v: &serde_json::Value) -> Self {
@@ -176,6 +235,14 @@ impl Modifier {
                 .and_then(|v| v.as_str())
                 .map(|v| Color::from_string(&v.to_owned())),
             extra: Option::None,
+            click_event: v
+                .get("clickEvent")
+                .and_then(ClickEvent::from_value)
+                .map(Box::new),
+            hover_event: v
+                .get("hoverEvent")
+                .and_then(HoverEvent::from_value)
+                .map(Box::new),
         };
         if let Some(extra) = v.get("extra") {
             if let Some(data) = extra.as_array() {
@@ -189,9 +256,454 @@ impl Modifier {
         m
     }
 
+    /// Returns an object with the modifier's fields, the component's own
+    /// fields are added to it.
     pub fn to_value(&self) -> serde_json::Value {
-        unimplemented!()
+        use serde_json::Value;
+        let mut map = serde_json::Map::new();
+        let flags = [
+            ("bold", self.bold),
+            ("italic", self.italic),
+            ("underlined", self.underlined),
+            ("strikethrough", self.strikethrough),
+            ("obfuscated", self.obfuscated),
+        ];
+        for (name, flag) in flags.iter() {
+            if let Some(flag) = flag {
+                map.insert(name.to_string(), Value::Bool(*flag));
+            }
+        }
+        if let Some(color) = self.color {
+            map.insert("color".to_string(), Value::String(color.to_string()));
+        }
+        if let Some(click_event) = &self.click_event {
+            map.insert("clickEvent".to_string(), click_event.to_value());
+        }
+        if let Some(hover_event) = &self.hover_event {
+            map.insert("hoverEvent".to_string(), hover_event.to_value());
+        }
+        if let Some(extra) = &self.extra {
+            map.insert(
+                "extra".to_string(),
+                Value::Array(extra.iter().map(Component::to_value).collect()),
+            );
+        }
+        Value::Object(map)
+    }
+
+    /// Like `to_value` but with an extra field
+    fn to_value_with(&self, key: &str, value: serde_json::Value) -> serde_json::Value {
+        let mut v = self.to_value();
+        if let serde_json::Value::Object(ref mut map) = v {
+            map.insert(key.to_owned(), value);
+        }
+        v
+    }
+}
+
+/// What happens when a component is clicked in chat
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClickEvent {
+    OpenUrl(String),
+    OpenFile(String),
+    RunCommand(String),
+    SuggestCommand(String),
+    /// Turns to a page of a book
+    ChangePage(String),
+    CopyToClipboard(String),
+}
+
+impl ClickEvent {
+    pub fn from_value(v: &serde_json::Value) -> Option<Self> {
+        let value = match v.get("value")? {
+            serde_json::Value::String(value) => value.clone(),
+            // Page numbers are sometimes sent as numbers
+            other => other.to_string(),
+        };
+        Some(match v.get("action")?.as_str()? {
+            "open_url" => ClickEvent::OpenUrl(value),
+            "open_file" => ClickEvent::OpenFile(value),
+            "run_command" => ClickEvent::RunCommand(value),
+            "suggest_command" => ClickEvent::SuggestCommand(value),
+            "change_page" => ClickEvent::ChangePage(value),
+            "copy_to_clipboard" => ClickEvent::CopyToClipboard(value),
+            _ => return None,
+        })
+    }
+
+    pub fn to_value(&self) -> serde_json::Value {
+        let (action, value) = match self {
+            ClickEvent::OpenUrl(value) => ("open_url", value),
+            ClickEvent::OpenFile(value) => ("open_file", value),
+            ClickEvent::RunCommand(value) => ("run_command", value),
+            ClickEvent::SuggestCommand(value) => ("suggest_command", value),
+            ClickEvent::ChangePage(value) => ("change_page", value),
+            ClickEvent::CopyToClipboard(value) => ("copy_to_clipboard", value),
+        };
+        serde_json::json!({
+            "action": action,
+            "value": value,
+        })
+    }
+}
+
+/// What is shown when hovering over a component in chat
+#[derive(Debug, Clone)]
+pub enum HoverEvent {
+    ShowText(Box<Component>),
+    ShowItem(HoverItem),
+    ShowEntity(HoverEntity),
+}
+
+/// An item shown by a hover event
+#[derive(Debug, Clone)]
+pub struct HoverItem {
+    pub id: String,
+    pub count: i32,
+    /// The item's NBT data converted to JSON
+    pub tag: Option<serde_json::Value>,
+}
+
+/// An entity shown by a hover event
+#[derive(Debug, Clone)]
+pub struct HoverEntity {
+    /// The entity's type, e.g. `minecraft:pig`
+    pub kind: String,
+    /// The entity's UUID
+    pub id: String,
+    pub name: Option<Box<Component>>,
+}
+
+impl HoverEvent {
+    /// Parses both the `value` form used before 1.16, where items and
+    /// entities are given as NBT text, and the `contents` form used since.
+    pub fn from_value(v: &serde_json::Value) -> Option<Self> {
+        let action = v.get("action")?.as_str()?;
+        if let Some(contents) = v.get("contents") {
+            return Some(match action {
+                "show_text" => HoverEvent::ShowText(Box::new(Component::from_value(contents))),
+                "show_item" => HoverEvent::ShowItem(match contents.as_str() {
+                    Some(id) => HoverItem {
+                        id: id.to_owned(),
+                        count: 1,
+                        tag: None,
+                    },
+                    None => HoverItem {
+                        id: contents.get("id")?.as_str()?.to_owned(),
+                        count: contents.get("count").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                        tag: contents
+                            .get("tag")
+                            .and_then(|v| v.as_str())
+                            .and_then(parse_snbt),
+                    },
+                }),
+                "show_entity" => HoverEvent::ShowEntity(HoverEntity {
+                    kind: contents.get("type")?.as_str()?.to_owned(),
+                    id: match contents.get("id")? {
+                        serde_json::Value::Array(parts) => uuid_from_ints(parts)?,
+                        id => id.as_str()?.to_owned(),
+                    },
+                    name: contents
+                        .get("name")
+                        .map(|name| Box::new(Component::from_value(name))),
+                }),
+                _ => return None,
+            });
+        }
+
+        let value = Component::from_value(v.get("value")?);
+        Some(match action {
+            "show_text" => HoverEvent::ShowText(Box::new(value)),
+            // Achievements were named by their translation keys
+            "show_achievement" => HoverEvent::ShowText(Box::new(Component::Translate(
+                TranslateComponent::new(&value.to_string(), vec![]),
+            ))),
+            "show_item" => {
+                let item = parse_snbt(&value.to_string())?;
+                let id = match item.get("id")? {
+                    serde_json::Value::String(id) => id.clone(),
+                    // Numeric ids from before 1.8
+                    id => id.to_string(),
+                };
+                HoverEvent::ShowItem(HoverItem {
+                    id,
+                    count: item.get("Count").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                    tag: item.get("tag").cloned(),
+                })
+            }
+            "show_entity" => {
+                let entity = parse_snbt(&value.to_string())?;
+                let field = |name| entity.get(name).and_then(|v: &serde_json::Value| v.as_str());
+                HoverEvent::ShowEntity(HoverEntity {
+                    kind: field("type").unwrap_or("").to_owned(),
+                    id: field("id").unwrap_or("").to_owned(),
+                    name: field("name").map(|name| Box::new(Component::from_string(name))),
+                })
+            }
+            _ => return None,
+        })
+    }
+
+    /// Uses the `contents` form from 1.16
+    pub fn to_value(&self) -> serde_json::Value {
+        match self {
+            HoverEvent::ShowText(text) => serde_json::json!({
+                "action": "show_text",
+                "contents": text.to_value(),
+            }),
+            HoverEvent::ShowItem(item) => {
+                let mut contents = serde_json::json!({
+                    "id": item.id,
+                    "count": item.count,
+                });
+                if let Some(tag) = &item.tag {
+                    contents["tag"] = serde_json::Value::String(to_snbt(tag));
+                }
+                serde_json::json!({
+                    "action": "show_item",
+                    "contents": contents,
+                })
+            }
+            HoverEvent::ShowEntity(entity) => {
+                let mut contents = serde_json::json!({
+                    "type": entity.kind,
+                    "id": entity.id,
+                });
+                if let Some(name) = &entity.name {
+                    contents["name"] = name.to_value();
+                }
+                serde_json::json!({
+                    "action": "show_entity",
+                    "contents": contents,
+                })
+            }
+        }
+    }
+}
+
+/// Formats a UUID sent as four integers, most significant first
+fn uuid_from_ints(parts: &[serde_json::Value]) -> Option<String> {
+    if parts.len() != 4 {
+        return None;
+    }
+    let mut hex = String::with_capacity(32);
+    for part in parts {
+        hex.push_str(&format!("{:08x}", part.as_i64()? as u32));
+    }
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    ))
+}
+
+/// How deeply compounds and lists may nest in NBT text, like vanilla's
+/// limit for binary NBT. Deeper text is rejected instead of overflowing
+/// the stack.
+const MAX_SNBT_DEPTH: usize = 512;
+
+/// Parses NBT in its text form (e.g. `{id:"minecraft:stone",Count:1b}`)
+/// into JSON. Number suffixes are dropped.
+pub fn parse_snbt(text: &str) -> Option<serde_json::Value> {
+    let mut parser = SnbtParser {
+        text: text.as_bytes(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos == parser.text.len() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+struct SnbtParser<'a> {
+    text: &'a [u8],
+    pos: usize,
+    /// How many compounds and lists the parser is inside
+    depth: usize,
+}
+
+impl<'a> SnbtParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.text.get(self.pos).map_or(false, u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.text.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        if self.peek()? == c {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
     }
+
+    fn value(&mut self) -> Option<serde_json::Value> {
+        match self.peek()? {
+            c @ b'{' | c @ b'[' => {
+                if self.depth >= MAX_SNBT_DEPTH {
+                    return None;
+                }
+                self.depth += 1;
+                let value = if c == b'{' {
+                    self.compound()
+                } else {
+                    self.list()
+                };
+                self.depth -= 1;
+                value
+            }
+            b'"' | b'\'' => self.quoted().map(serde_json::Value::String),
+            _ => Some(unquoted_value(self.unquoted(true)?)),
+        }
+    }
+
+    fn compound(&mut self) -> Option<serde_json::Value> {
+        self.expect(b'{')?;
+        let mut map = serde_json::Map::new();
+        if self.peek()? == b'}' {
+            self.pos += 1;
+            return Some(serde_json::Value::Object(map));
+        }
+        loop {
+            let key = match self.peek()? {
+                b'"' | b'\'' => self.quoted()?,
+                _ => self.unquoted(false)?,
+            };
+            self.expect(b':')?;
+            map.insert(key, self.value()?);
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b'}' => {
+                    self.pos += 1;
+                    return Some(serde_json::Value::Object(map));
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn list(&mut self) -> Option<serde_json::Value> {
+        self.expect(b'[')?;
+        // Typed arrays, e.g. [I;1,2,3]
+        if matches!(self.peek(), Some(b'B') | Some(b'I') | Some(b'L'))
+            && self.text.get(self.pos + 1) == Some(&b';')
+        {
+            self.pos += 2;
+        }
+        let mut list = vec![];
+        if self.peek()? == b']' {
+            self.pos += 1;
+            return Some(serde_json::Value::Array(list));
+        }
+        loop {
+            // Lists from before 1.13 may have indices, e.g. [0:"a",1:"b"]
+            let start = self.pos;
+            if self.unquoted(false).is_some() && self.peek() == Some(b':') {
+                self.pos += 1;
+            } else {
+                self.pos = start;
+            }
+            list.push(self.value()?);
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b']' => {
+                    self.pos += 1;
+                    return Some(serde_json::Value::Array(list));
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn quoted(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.pos += 1;
+        let mut bytes = vec![];
+        loop {
+            let c = *self.text.get(self.pos)?;
+            self.pos += 1;
+            match c {
+                b'\\' => {
+                    bytes.push(*self.text.get(self.pos)?);
+                    self.pos += 1;
+                }
+                c if c == quote => break,
+                c => bytes.push(c),
+            }
+        }
+        String::from_utf8(bytes).ok()
+    }
+
+    /// Reads an unquoted string. Values may contain colons, e.g. a
+    /// namespaced id like minecraft:stone, keys end at them.
+    fn unquoted(&mut self, is_value: bool) -> Option<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while let Some(&c) = self.text.get(self.pos) {
+            if !(c.is_ascii_alphanumeric() || b"_-.+".contains(&c) || (is_value && c == b':')) {
+                break;
+            }
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return None;
+        }
+        String::from_utf8(self.text[start..self.pos].to_vec()).ok()
+    }
+}
+
+/// Converts an unquoted NBT value to a JSON number, bool or string
+fn unquoted_value(text: String) -> serde_json::Value {
+    let number = text.trim_end_matches(|c: char| "bBsSlLfFdD".contains(c));
+    if let Ok(val) = number.parse::<i64>() {
+        return serde_json::Value::from(val);
+    }
+    if let Ok(val) = number.parse::<f64>() {
+        if let Some(val) = serde_json::Number::from_f64(val) {
+            return serde_json::Value::Number(val);
+        }
+    }
+    match text.as_str() {
+        "true" => serde_json::Value::Bool(true),
+        "false" => serde_json::Value::Bool(false),
+        _ => serde_json::Value::String(text),
+    }
+}
+
+/// Writes JSON as NBT text, the inverse of `parse_snbt`
+pub fn to_snbt(v: &serde_json::Value) -> String {
+    match v {
+        serde_json::Value::Object(map) => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(key, value)| format!("{}:{}", snbt_string(key), to_snbt(value)))
+                .collect();
+            format!("{{{}}}", entries.join(","))
+        }
+        serde_json::Value::Array(list) => {
+            let entries: Vec<String> = list.iter().map(to_snbt).collect();
+            format!("[{}]", entries.join(","))
+        }
+        serde_json::Value::String(text) => snbt_string(text),
+        other => other.to_string(),
+    }
+}
+
+fn snbt_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 #[derive(Debug, Clone)]
@@ -218,7 +730,8 @@ impl TextComponent {
     }
 
     pub fn to_value(&self) -> serde_json::Value {
-        unimplemented!()
+        self.modifier
+            .to_value_with("text", serde_json::Value::String(self.text.clone()))
     }
 }
 
@@ -234,6 +747,177 @@ impl fmt::Display for TextComponent {
     }
 }
 
+/// Text looked up in the language files, e.g. death messages.
+///
+/// The translation can contain `%s` or `%1$s` placeholders which are
+/// replaced with the components in `with`.
+#[derive(Debug, Clone)]
+pub struct TranslateComponent {
+    pub translate: String,
+    pub with: Vec<Component>,
+    pub modifier: Modifier,
+}
+
+impl TranslateComponent {
+    pub fn new(translate: &str, with: Vec<Component>) -> TranslateComponent {
+        TranslateComponent {
+            translate: translate.to_owned(),
+            with,
+            modifier: Default::default(),
+        }
+    }
+
+    pub fn to_value(&self) -> serde_json::Value {
+        let mut v = self.modifier.to_value_with(
+            "translate",
+            serde_json::Value::String(self.translate.clone()),
+        );
+        if let (serde_json::Value::Object(ref mut map), false) = (&mut v, self.with.is_empty()) {
+            map.insert(
+                "with".to_owned(),
+                serde_json::Value::Array(self.with.iter().map(Component::to_value).collect()),
+            );
+        }
+        v
+    }
+
+    /// Fills in the translation with the arguments. Untranslated keys
+    /// are shown as they are.
+    pub fn resolve(&self) -> TextComponent {
+        let format = translate(&self.translate).unwrap_or_else(|| self.translate.clone());
+        let mut parts: Vec<Component> = split_format(&format)
+            .into_iter()
+            .map(|part| match part {
+                FormatPart::Text(text) => Component::Text(TextComponent::new(&text)),
+                FormatPart::Arg(index) => self.with.get(index).cloned().unwrap_or_default(),
+            })
+            .collect();
+        let mut modifier = self.modifier.clone();
+        if let Some(extra) = modifier.extra.take() {
+            parts.extend(extra);
+        }
+        modifier.extra = Some(parts);
+        TextComponent {
+            text: String::new(),
+            modifier,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum FormatPart {
+    Text(String),
+    /// The index of an argument
+    Arg(usize),
+}
+
+/// Splits a translation into text and placeholders. Like vanilla any
+/// letter is accepted after the `%` and `%%` is a literal `%`.
+fn split_format(format: &str) -> Vec<FormatPart> {
+    let mut parts = vec![];
+    let mut text = String::new();
+    let mut next_arg = 0;
+    let mut rest = format;
+    while let Some(pos) = rest.find('%') {
+        text.push_str(&rest[..pos]);
+        let spec = &rest[pos + 1..];
+        if let Some(after) = spec.strip_prefix('%') {
+            text.push('%');
+            rest = after;
+            continue;
+        }
+        let digits = spec.len() - spec.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (index, conversion) = if digits > 0 && spec[digits..].starts_with('$') {
+            (
+                spec[..digits].parse::<usize>().ok().map(|i| i.saturating_sub(1)),
+                &spec[digits + 1..],
+            )
+        } else {
+            (None, spec)
+        };
+        match conversion.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                let index = index.unwrap_or_else(|| {
+                    next_arg += 1;
+                    next_arg - 1
+                });
+                if !text.is_empty() {
+                    parts.push(FormatPart::Text(mem::take(&mut text)));
+                }
+                parts.push(FormatPart::Arg(index));
+                rest = &conversion[c.len_utf8()..];
+            }
+            _ => {
+                text.push('%');
+                rest = spec;
+            }
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        parts.push(FormatPart::Text(text));
+    }
+    parts
+}
+
+/// A player's score in a scoreboard objective
+#[derive(Debug, Clone)]
+pub struct ScoreComponent {
+    pub name: String,
+    pub objective: String,
+    /// The score, servers fill this in before sending the component
+    pub value: Option<String>,
+    pub modifier: Modifier,
+}
+
+impl ScoreComponent {
+    pub fn to_value(&self) -> serde_json::Value {
+        let mut score = serde_json::Map::new();
+        score.insert(
+            "name".to_owned(),
+            serde_json::Value::String(self.name.clone()),
+        );
+        score.insert(
+            "objective".to_owned(),
+            serde_json::Value::String(self.objective.clone()),
+        );
+        if let Some(value) = &self.value {
+            score.insert("value".to_owned(), serde_json::Value::String(value.clone()));
+        }
+        self.modifier
+            .to_value_with("score", serde_json::Value::Object(score))
+    }
+}
+
+/// An entity selector such as `@p`, servers normally replace these with
+/// the names of the matching entities.
+#[derive(Debug, Clone)]
+pub struct SelectorComponent {
+    pub selector: String,
+    pub modifier: Modifier,
+}
+
+impl SelectorComponent {
+    pub fn to_value(&self) -> serde_json::Value {
+        self.modifier
+            .to_value_with("selector", serde_json::Value::String(self.selector.clone()))
+    }
+}
+
+/// The key bound to a control, e.g. `key.jump`
+#[derive(Debug, Clone)]
+pub struct KeybindComponent {
+    pub keybind: String,
+    pub modifier: Modifier,
+}
+
+impl KeybindComponent {
+    pub fn to_value(&self) -> serde_json::Value {
+        self.modifier
+            .to_value_with("keybind", serde_json::Value::String(self.keybind.clone()))
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Color {
     Black,
@@ -368,6 +1052,97 @@ fn test_color_from() {
     }
 }
 
+#[test]
+fn test_split_format() {
+    assert_eq!(
+        split_format("%s was slain by %s"),
+        vec![
+            FormatPart::Arg(0),
+            FormatPart::Text(" was slain by ".to_owned()),
+            FormatPart::Arg(1),
+        ]
+    );
+    assert_eq!(
+        split_format("%2$s %1$s 100%%"),
+        vec![
+            FormatPart::Arg(1),
+            FormatPart::Text(" ".to_owned()),
+            FormatPart::Arg(0),
+            FormatPart::Text(" 100%".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_snbt() {
+    let item = parse_snbt(r#"{id:"minecraft:stone", Count:3b, tag:{display:{Name:'Rock'}, ench:[0:{id:16s}]}}"#)
+        .unwrap();
+    assert_eq!(item["id"], "minecraft:stone");
+    assert_eq!(item["Count"], 3);
+    assert_eq!(item["tag"]["display"]["Name"], "Rock");
+    assert_eq!(item["tag"]["ench"][0]["id"], 16);
+    assert_eq!(parse_snbt(&to_snbt(&item)).unwrap(), item);
+    assert_eq!(parse_snbt("{id:minecraft:stone}").unwrap()["id"], "minecraft:stone");
+    assert!(parse_snbt("{id:").is_none());
+}
+
+#[test]
+fn test_parse_snbt_depth() {
+    let nested = |depth| "[".repeat(depth) + &"]".repeat(depth);
+    assert!(parse_snbt(&nested(MAX_SNBT_DEPTH)).is_some());
+    assert!(parse_snbt(&nested(MAX_SNBT_DEPTH + 1)).is_none());
+    assert!(parse_snbt(&"[".repeat(100_000)).is_none());
+    assert!(parse_snbt(&"{a:".repeat(100_000)).is_none());
+
+    // Hover values are NBT text inside a JSON string, so serde_json's
+    // own nesting limit doesn't apply to them
+    let json = serde_json::json!({
+        "text": "item",
+        "hoverEvent": {"action": "show_item", "value": "[".repeat(100_000)},
+    });
+    let component = Component::from_string(&json.to_string());
+    assert_eq!(component.to_string(), "item");
+}
+
+#[test]
+fn test_events() {
+    let component = Component::from_string(
+        r#"{"text":"here","clickEvent":{"action":"open_url","value":"https://example.com"},
+            "hoverEvent":{"action":"show_item","value":"{id:\"minecraft:stone\",Count:2b}"}}"#,
+    );
+    let modifier = component.modifier();
+    assert_eq!(
+        modifier.click_event,
+        Some(Box::new(ClickEvent::OpenUrl("https://example.com".to_owned())))
+    );
+    match modifier.hover_event.as_deref() {
+        Some(HoverEvent::ShowItem(item)) => {
+            assert_eq!(item.id, "minecraft:stone");
+            assert_eq!(item.count, 2);
+        }
+        _ => panic!("Wrong hover event"),
+    }
+    // Round trips through the 1.16 form
+    let value = component.to_value();
+    assert_eq!(value["hoverEvent"]["contents"]["count"], 2);
+    let component = Component::from_value(&value);
+    assert!(matches!(
+        component.modifier().hover_event.as_deref(),
+        Some(HoverEvent::ShowItem(_))
+    ));
+}
+
+#[test]
+fn test_translate_round_trip() {
+    let json = r#"{"translate":"chat.type.text","with":[{"text":"Steve","bold":true},"hi"]}"#;
+    let component = Component::from_string(json);
+    assert_eq!(component.to_string(), "<Steve> hi");
+    let value = component.to_value();
+    assert_eq!(value["translate"], "chat.type.text");
+    assert_eq!(value["with"][0]["bold"], true);
+    assert_eq!(Component::from_value(&value).to_string(), "<Steve> hi");
+}
+
 const LEGACY_CHAR: char = '§';
 
 pub fn convert_legacy(c: &mut Component) {
@@ -452,5 +1227,17 @@ pub fn convert_legacy(c: &mut Component) {
                 txt.text = "".to_owned();
             }
         }
+        ref mut other => {
+            if let Component::Translate(ref mut translate) = *other {
+                for arg in &mut translate.with {
+                    convert_legacy(arg);
+                }
+            }
+            if let Some(extra) = other.modifier_mut().extra.as_mut() {
+                for e in extra.iter_mut() {
+                    convert_legacy(e);
+                }
+            }
+        }
     }
 }
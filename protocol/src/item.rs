@@ -17,7 +17,7 @@ use crate::protocol::{self, Serializable};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::io;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Stack {
     pub id: isize,
     pub count: isize,
@@ -36,6 +36,69 @@ impl Default for Stack {
     }
 }
 
+impl Stack {
+    /// Returns how much of the item's durability is used up, stored in
+    /// the NBT since 1.13.
+    pub fn durability_damage(&self) -> isize {
+        self.damage.unwrap_or_else(|| {
+            self.tag
+                .as_ref()
+                .and_then(|tag| tag.1.as_compound()?.get("Damage"))
+                .and_then(|damage| damage.as_int())
+                .map_or(0, |damage| damage as isize)
+        })
+    }
+
+    /// Whether the NBT stops the item from losing durability
+    pub fn is_unbreakable(&self) -> bool {
+        self.tag
+            .as_ref()
+            .and_then(|tag| tag.1.as_compound()?.get("Unbreakable"))
+            .and_then(|unbreakable| unbreakable.as_byte())
+            .map_or(false, |unbreakable| unbreakable != 0)
+    }
+
+    /// Returns the level of an enchantment, given by its numeric id before
+    /// 1.13 and its name since. Zero if the item doesn't have it.
+    pub fn enchantment_level(&self, legacy_id: i16, name: &str) -> i16 {
+        let tag = match self.tag.as_ref().and_then(|tag| tag.1.as_compound()) {
+            Some(tag) => tag,
+            None => return 0,
+        };
+        tag.get("Enchantments")
+            .or_else(|| tag.get("ench"))
+            .and_then(|list| list.as_list())
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|enchantment| enchantment.as_compound())
+            .find(|enchantment| match enchantment.get("id") {
+                Some(nbt::Tag::String(id)) => {
+                    id.strip_prefix("minecraft:").unwrap_or(id) == name
+                }
+                Some(nbt::Tag::Short(id)) => *id == legacy_id,
+                _ => false,
+            })
+            .and_then(|enchantment| enchantment.get("lvl")?.as_short())
+            .unwrap_or(0)
+    }
+
+    /// Returns the colour leather armor was dyed with, if any
+    pub fn dye_colour(&self) -> Option<(u8, u8, u8)> {
+        let colour = self
+            .tag
+            .as_ref()
+            .and_then(|tag| {
+                tag.1
+                    .as_compound()?
+                    .get("display")?
+                    .as_compound()?
+                    .get("color")
+            })?
+            .as_int()?;
+        Some(((colour >> 16) as u8, (colour >> 8) as u8, colour as u8))
+    }
+}
+
 impl Serializable for Option<Stack> {
     fn read_from<R: io::Read>(buf: &mut R) -> Result<Option<Stack>, protocol::Error> {
         let protocol_version = protocol::current_protocol_version();
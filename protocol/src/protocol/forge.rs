@@ -27,7 +27,7 @@ impl Serializable for Phase {
         Ok(match phase {
             2 => Phase::WaitingCAck,
             3 => Phase::Complete,
-            _ => panic!("bad FML|HS server phase: {}", phase),
+            _ => return Err(Error::Err(format!("bad FML|HS server phase: {}", phase))),
         })
     }
 
@@ -141,7 +141,9 @@ impl Serializable for FmlHs {
                     override_dimension,
                 })
             }
-            1 => panic!("Received unexpected FML|HS ClientHello from server"),
+            1 => Err(Error::Err(
+                "Received unexpected FML|HS ClientHello from server".to_owned(),
+            )),
             2 => Ok(FmlHs::ModList {
                 mods: Serializable::read_from(buf)?,
             }),
@@ -167,7 +169,10 @@ impl Serializable for FmlHs {
             255 => Ok(FmlHs::HandshakeAck {
                 phase: Serializable::read_from(buf)?,
             }),
-            _ => panic!("Unhandled FML|HS packet: discriminator={}", discriminator),
+            _ => Err(Error::Err(format!(
+                "Unhandled FML|HS packet: discriminator={}",
+                discriminator
+            ))),
         }
     }
 
@@ -281,7 +286,12 @@ pub mod fml2 {
                     filename: Serializable::read_from(buf)?,
                     contents: Serializable::read_from(buf)?,
                 },
-                _ => unimplemented!(),
+                _ => {
+                    return Err(Error::Err(format!(
+                        "Unhandled FML2 handshake packet: id={}",
+                        id
+                    )))
+                }
             })
         }
     }
@@ -0,0 +1,230 @@
+// Copyright 2016 Matthew Collins
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Logs into a server, taking the connection from the handshake to the
+//! start of play.
+
+use super::forge::fml2::FmlHandshake;
+use super::packet::{self, Packet};
+use super::{mojang, Conn, Error, LenPrefixedBytes, Serializable, State, VarInt, UUID};
+use log::{debug, info, warn};
+use rand::Rng;
+use std::io;
+use std::str::FromStr;
+
+/// A connection that has logged in and is in the play state
+pub struct Login {
+    /// Decrypts packets from the server, usually moved to a thread of
+    /// its own
+    pub read: Conn,
+    /// Encrypts packets to the server
+    pub write: Conn,
+    pub uuid: UUID,
+}
+
+/// Connects and logs in to the server at `address`.
+///
+/// `fml_network_version` is the version of the Forge handshake the server
+/// asked for in its status response, if it is running Forge.
+pub fn login(
+    profile: &mojang::Profile,
+    address: &str,
+    protocol_version: i32,
+    fml_network_version: Option<i64>,
+) -> Result<Login, Error> {
+    let tag = match fml_network_version {
+        Some(1) => "\0FML\0",
+        Some(2) => "\0FML2\0",
+        None => "",
+        Some(version) => {
+            return Err(Error::Err(format!(
+                "Unsupported FML network version {}",
+                version
+            )))
+        }
+    };
+
+    let mut write = Conn::new(address, protocol_version)?;
+    write.write_packet(packet::handshake::serverbound::Handshake {
+        protocol_version: VarInt(protocol_version),
+        host: write.host.clone() + tag,
+        port: write.port,
+        next: VarInt(2),
+    })?;
+    write.state = State::Login;
+    write.write_packet(packet::login::serverbound::LoginStart {
+        username: profile.username.clone(),
+    })?;
+
+    // Once encryption is enabled each direction has a cipher of its own
+    let mut read = write.clone();
+    let mut encrypted = false;
+    let uuid = loop {
+        match read.read_packet()? {
+            Packet::SetInitialCompression(val) => {
+                read.set_compresssion(val.threshold.0);
+                write.set_compresssion(val.threshold.0);
+            }
+            Packet::EncryptionRequest(val) => {
+                encrypt(
+                    profile,
+                    &mut read,
+                    &mut write,
+                    &val.server_id,
+                    &val.public_key.data,
+                    &val.verify_token.data,
+                )?;
+                encrypted = true;
+            }
+            Packet::EncryptionRequest_i16(val) => {
+                encrypt(
+                    profile,
+                    &mut read,
+                    &mut write,
+                    &val.server_id,
+                    &val.public_key.data,
+                    &val.verify_token.data,
+                )?;
+                encrypted = true;
+            }
+            Packet::LoginSuccess_String(val) => {
+                debug!("Login: {} {}", val.username, val.uuid);
+                break parse_uuid(&val.uuid)?;
+            }
+            Packet::LoginSuccess_UUID(val) => {
+                debug!("Login: {} {:?}", val.username, val.uuid);
+                break val.uuid;
+            }
+            Packet::LoginDisconnect(val) => return Err(Error::Disconnect(Box::new(val.reason))),
+            Packet::LoginPluginRequest(req) => {
+                on_login_plugin_request(&mut write, *req, read.compression_threshold)?;
+            }
+            val => return Err(Error::Err(format!("Wrong packet: {:?}", val))),
+        }
+    };
+    if !encrypted {
+        warn!("Server is running in offline mode");
+    }
+
+    read.state = State::Play;
+    write.state = State::Play;
+    Ok(Login { read, write, uuid })
+}
+
+fn parse_uuid(uuid: &str) -> Result<UUID, Error> {
+    UUID::from_str(uuid).map_err(|_| Error::Err(format!("Invalid uuid {:?}", uuid)))
+}
+
+/// Answers the server's encryption request and enables encryption
+fn encrypt(
+    profile: &mojang::Profile,
+    read: &mut Conn,
+    write: &mut Conn,
+    server_id: &str,
+    public_key: &[u8],
+    verify_token: &[u8],
+) -> Result<(), Error> {
+    let mut shared = [0; 16];
+    rand::thread_rng().fill(&mut shared);
+
+    let shared_e = rsa_public_encrypt_pkcs1::encrypt(public_key, &shared).map_err(Error::Err)?;
+    let token_e = rsa_public_encrypt_pkcs1::encrypt(public_key, verify_token).map_err(Error::Err)?;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        profile.join_server(server_id, &shared, public_key)?;
+    }
+
+    if write.protocol_version >= 47 {
+        write.write_packet(packet::login::serverbound::EncryptionResponse {
+            shared_secret: LenPrefixedBytes::new(shared_e),
+            verify_token: LenPrefixedBytes::new(token_e),
+        })?;
+    } else {
+        write.write_packet(packet::login::serverbound::EncryptionResponse_i16 {
+            shared_secret: LenPrefixedBytes::new(shared_e),
+            verify_token: LenPrefixedBytes::new(token_e),
+        })?;
+    }
+
+    read.enable_encyption(&shared, true);
+    write.enable_encyption(&shared, false);
+    Ok(())
+}
+
+/// Answers the Forge handshake, other requests are refused with an
+/// unsuccessful response like the vanilla client does.
+fn on_login_plugin_request(
+    write: &mut Conn,
+    req: packet::login::clientbound::LoginPluginRequest,
+    compression_threshold: i32,
+) -> Result<(), Error> {
+    use FmlHandshake::*;
+    let packet = if req.channel == "fml:loginwrapper" {
+        let mut cursor = io::Cursor::new(req.data);
+        let channel: String = Serializable::read_from(&mut cursor)?;
+        if channel == "fml:handshake" {
+            let (id, mut data) = Conn::read_raw_packet_from(&mut cursor, compression_threshold)?;
+            match FmlHandshake::packet_by_id(id, &mut data) {
+                Ok(packet) => Some(packet),
+                Err(err) => {
+                    warn!("Failed to read FML handshake: {}", err);
+                    None
+                }
+            }
+        } else {
+            warn!("Unsupported fml:loginwrapper channel {:?}", channel);
+            None
+        }
+    } else {
+        debug!("Refusing LoginPluginRequest for channel {:?}", req.channel);
+        None
+    };
+
+    let reply = match packet {
+        Some(ModList {
+            mod_names,
+            channels,
+            registries,
+        }) => {
+            info!(
+                "ModList mod_names={:?} channels={:?} registries={:?}",
+                mod_names, channels, registries
+            );
+            Some(ModListReply {
+                mod_names,
+                channels,
+                registries,
+            })
+        }
+        Some(ServerRegistry { name, .. }) => {
+            info!("ServerRegistry {:?}", name);
+            Some(Acknowledgement)
+        }
+        Some(ConfigurationData { filename, contents }) => {
+            info!(
+                "ConfigurationData filename={:?} contents={}",
+                filename,
+                String::from_utf8_lossy(&contents)
+            );
+            Some(Acknowledgement)
+        }
+        Some(packet) => {
+            warn!("Unexpected FML handshake packet {:?}", packet);
+            None
+        }
+        None => None,
+    };
+    write.write_fml2_handshake_plugin_message(req.message_id, reply.as_ref())
+}
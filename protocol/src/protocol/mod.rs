@@ -21,6 +21,7 @@ use cfb8::Cfb8;
 use std_or_web::fs;
 
 pub mod forge;
+pub mod login;
 pub mod mojang;
 
 use crate::format;
@@ -47,6 +48,18 @@ pub const SUPPORTED_PROTOCOLS: [i32; 26] = [
 static CURRENT_PROTOCOL_VERSION: AtomicI32 = AtomicI32::new(SUPPORTED_PROTOCOLS[0]);
 static NETWORK_DEBUG: AtomicBool = AtomicBool::new(false);
 
+/// Fails for protocol versions without known packet ids
+fn check_protocol_version(version: i32) -> Result<(), Error> {
+    if SUPPORTED_PROTOCOLS.contains(&version) {
+        Ok(())
+    } else {
+        Err(Error::Err(format!(
+            "unsupported protocol version {}",
+            version
+        )))
+    }
+}
+
 pub fn current_protocol_version() -> i32 {
     CURRENT_PROTOCOL_VERSION.load(Ordering::Relaxed)
 }
@@ -87,7 +100,7 @@ macro_rules! state_packets {
         }
 
         impl PacketType for Packet {
-            fn packet_id(&self, version: i32) -> i32 {
+            fn packet_id(&self, version: i32) -> Option<i32> {
                 match self {
                     $(
                         $(
@@ -140,8 +153,8 @@ macro_rules! state_packets {
 
                     impl PacketType for $name {
 
-                        fn packet_id(&self, version: i32) -> i32 {
-                            packet::versions::translate_internal_packet_id_for_version(version, State::$stateName, Direction::$dirName, internal_ids::$name, false).unwrap_or_else(|| panic!("Could not resolve packet ID in protocol {} for {:?}",version, self))
+                        fn packet_id(&self, version: i32) -> Option<i32> {
+                            packet::versions::translate_internal_packet_id_for_version(version, State::$stateName, Direction::$dirName, internal_ids::$name, false)
                         }
 
                         fn write<W: io::Write>(&self, buf: &mut W) -> Result<(), Error> {
@@ -479,11 +492,16 @@ impl std::str::FromStr for UUID {
         if s.len() != 36 {
             return Err(UUIDParseError {});
         }
-        let mut parts = hex::decode(&s[..8]).unwrap();
-        parts.extend_from_slice(&hex::decode(&s[9..13]).unwrap());
-        parts.extend_from_slice(&hex::decode(&s[14..18]).unwrap());
-        parts.extend_from_slice(&hex::decode(&s[19..23]).unwrap());
-        parts.extend_from_slice(&hex::decode(&s[24..36]).unwrap());
+        let decode = |range: std::ops::Range<usize>| {
+            s.get(range)
+                .and_then(|part| hex::decode(part).ok())
+                .ok_or(UUIDParseError {})
+        };
+        let mut parts = decode(0..8)?;
+        parts.extend_from_slice(&decode(9..13)?);
+        parts.extend_from_slice(&decode(14..18)?);
+        parts.extend_from_slice(&decode(19..23)?);
+        parts.extend_from_slice(&decode(24..36)?);
         let mut high = 0u64;
         let mut low = 0u64;
         for i in 0..8 {
@@ -536,18 +554,20 @@ impl Default for Biomes3D {
 
 impl Serializable for Biomes3D {
     fn read_from<R: io::Read>(buf: &mut R) -> Result<Biomes3D, Error> {
-        let data: [i32; 1024] = [0; 1024];
+        let mut data: [i32; 1024] = [0; 1024];
 
         // Non-length-prefixed three-dimensional biome data
-        for item in &mut data.to_vec() {
-            let b: i32 = Serializable::read_from(buf)?;
-            *item = b;
+        for item in data.iter_mut() {
+            *item = Serializable::read_from(buf)?;
         }
 
         Result::Ok(Biomes3D { data })
     }
-    fn write_to<W: io::Write>(&self, _buf: &mut W) -> Result<(), Error> {
-        unimplemented!()
+    fn write_to<W: io::Write>(&self, buf: &mut W) -> Result<(), Error> {
+        for item in self.data.iter() {
+            item.write_to(buf)?;
+        }
+        Ok(())
     }
 }
 
@@ -1028,7 +1048,10 @@ pub enum State {
 #[derive(Debug)]
 pub enum Error {
     Err(String),
-    Disconnect(format::Component),
+    /// A packet that couldn't be parsed, the connection can carry on as
+    /// the rest of the packet was skipped.
+    Malformed(String),
+    Disconnect(Box<format::Component>),
     IOError(io::Error),
     Json(serde_json::Error),
     #[cfg(not(target_arch = "wasm32"))]
@@ -1060,6 +1083,7 @@ impl ::std::fmt::Display for Error {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match *self {
             Error::Err(ref val) => write!(f, "protocol error: {}", val),
+            Error::Malformed(ref val) => write!(f, "malformed packet: {}", val),
             Error::Disconnect(ref val) => write!(f, "{}", val),
             Error::IOError(ref e) => e.fmt(f),
             Error::Json(ref e) => e.fmt(f),
@@ -1109,9 +1133,33 @@ impl Conn {
         })
     }
 
+    /// Wraps a connection accepted from a client, reading serverbound
+    /// packets and writing clientbound ones. Used to act as the server,
+    /// e.g. in tests.
+    pub fn from_client(stream: TcpStream, protocol_version: i32) -> Result<Conn, Error> {
+        let address = stream.local_addr()?;
+        Result::Ok(Conn {
+            stream,
+            host: address.ip().to_string(),
+            port: address.port(),
+            direction: Direction::Clientbound,
+            state: State::Handshaking,
+            protocol_version,
+            cipher: Option::None,
+            compression_threshold: -1,
+        })
+    }
+
     pub fn write_packet<T: PacketType>(&mut self, packet: T) -> Result<(), Error> {
+        check_protocol_version(self.protocol_version)?;
+        let id = packet.packet_id(self.protocol_version).ok_or_else(|| {
+            Error::Err(format!(
+                "packet not supported by protocol version {}",
+                self.protocol_version
+            ))
+        })?;
         let mut buf = Vec::new();
-        VarInt(packet.packet_id(self.protocol_version)).write_to(&mut buf)?;
+        VarInt(id).write_to(&mut buf)?;
         packet.write(&mut buf)?;
 
         let mut extra = if self.compression_threshold >= 0 {
@@ -1177,6 +1225,51 @@ impl Conn {
         self.write_plugin_message("FML|HS", &buf)
     }
 
+    /// Answers a message of the server's side of the FML|HS handshake,
+    /// offering `mods` as the client's mod list.
+    pub fn answer_fmlhs(&mut self, msg: &forge::FmlHs, mods: &[forge::ForgeMod]) -> Result<(), Error> {
+        use forge::FmlHs::*;
+        use forge::Phase::*;
+        match msg {
+            ServerHello {
+                fml_protocol_version,
+                ..
+            } => {
+                self.write_plugin_message("REGISTER", b"FML|HS\0FML\0FML|MP\0FML\0FORGE")?;
+                self.write_fmlhs_plugin_message(&ClientHello {
+                    fml_protocol_version: *fml_protocol_version,
+                })?;
+                // The mods the server listed in its status response, so
+                // the client matches the server
+                self.write_fmlhs_plugin_message(&ModList {
+                    mods: LenPrefixed::new(mods.to_vec()),
+                })
+            }
+            ModList { .. } => self.write_fmlhs_plugin_message(&HandshakeAck {
+                phase: WaitingServerData,
+            }),
+            ModIdData { .. } | RegistryData { has_more: false, .. } => {
+                self.write_fmlhs_plugin_message(&HandshakeAck {
+                    phase: WaitingServerComplete,
+                })
+            }
+            HandshakeAck {
+                phase: WaitingCAck,
+            } => self.write_fmlhs_plugin_message(&HandshakeAck {
+                phase: PendingComplete,
+            }),
+            HandshakeAck { phase: Complete } => {
+                debug!("FML|HS handshake complete!");
+                Ok(())
+            }
+            HandshakeAck { phase } => {
+                warn!("Unexpected FML|HS HandshakeAck phase {:?}", phase);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn write_login_plugin_response(
         &mut self,
         message_id: VarInt,
@@ -1213,7 +1306,7 @@ impl Conn {
 
             self.write_login_plugin_response(message_id, true, &outer_buf)
         } else {
-            unimplemented!() // successful: false, no payload
+            self.write_login_plugin_response(message_id, false, &[])
         }
     }
 
@@ -1254,6 +1347,7 @@ impl Conn {
     }
 
     pub fn read_packet(&mut self) -> Result<packet::Packet, Error> {
+        check_protocol_version(self.protocol_version)?;
         let compression_threshold = self.compression_threshold;
         let (id, mut buf) = Conn::read_raw_packet_from(self, compression_threshold)?;
 
@@ -1270,7 +1364,13 @@ impl Conn {
             fs::File::create("last-packet")?.write_all(buf.get_ref())?;
         }
 
-        let packet = packet::packet_by_id(self.protocol_version, self.state, dir, id, &mut buf)?;
+        let packet = packet::packet_by_id(self.protocol_version, self.state, dir, id, &mut buf)
+            .map_err(|err| {
+                Error::Malformed(format!(
+                    "0x{:X} in state {:?} for protocol {}: {}",
+                    id, self.state, self.protocol_version, err
+                ))
+            })?;
 
         if is_network_debug() {
             debug!("packet = {:?}", packet);
@@ -1283,7 +1383,7 @@ impl Conn {
                 if ibuf.len() != pos {
                     debug!("pos = {:?}", pos);
                     debug!("ibuf = {:?}", ibuf);
-                    return Result::Err(Error::Err(format!(
+                    return Result::Err(Error::Malformed(format!(
                         "Failed to read all of packet 0x{:X}, \
                                                            had {} bytes left",
                         id,
@@ -1292,7 +1392,10 @@ impl Conn {
                 }
                 Result::Ok(val)
             }
-            None => Result::Err(Error::Err("missing packet".to_owned())),
+            None => Result::Err(Error::Malformed(format!(
+                "unknown packet 0x{:X} in state {:?}",
+                id, self.state
+            ))),
         }
     }
 
@@ -1561,7 +1664,8 @@ impl Clone for Conn {
 }
 
 pub trait PacketType {
-    fn packet_id(&self, protocol_version: i32) -> i32;
+    /// The packet's id, `None` if the protocol version doesn't have it
+    fn packet_id(&self, protocol_version: i32) -> Option<i32>;
 
     fn write<W: io::Write>(&self, buf: &mut W) -> Result<(), Error>;
 }
@@ -97,6 +97,15 @@ state_packets!(
                 field displayed_skin_parts: u8 =,
                 field main_hand: VarInt =,
             }
+            packet ClientSettings_Filtering {
+                field locale: String =,
+                field view_distance: u8 =,
+                field chat_mode: VarInt =,
+                field chat_colors: bool =,
+                field displayed_skin_parts: u8 =,
+                field main_hand: VarInt =,
+                field text_filtering_enabled: bool =,
+            }
             packet ClientSettings_u8 {
                 field locale: String =,
                 field view_distance: u8 =,
@@ -153,6 +162,27 @@ state_packets!(
                 field mode: u8 =,
                 field clicked_item: Option<item::Stack> =,
             }
+            /// ClickWindow_Slots is used since 1.17, sending the slots the
+            /// click changed instead of waiting for a confirmation.
+            packet ClickWindow_Slots {
+                field id: u8 =,
+                field slot: i16 =,
+                field button: u8 =,
+                field mode: VarInt =,
+                field changed_slots: LenPrefixed<VarInt, packet::ChangedSlot> =,
+                field carried_item: Option<item::Stack> =,
+            }
+            /// ClickWindow_State is used since 1.17.1, which added the state
+            /// id the server last sent for the window.
+            packet ClickWindow_State {
+                field id: u8 =,
+                field state_id: VarInt =,
+                field slot: i16 =,
+                field button: u8 =,
+                field mode: VarInt =,
+                field changed_slots: LenPrefixed<VarInt, packet::ChangedSlot> =,
+                field carried_item: Option<item::Stack> =,
+            }
             /// CloseWindow is sent when the client closes a window.
             packet CloseWindow {
                 field id: u8 =,
@@ -978,6 +1008,12 @@ state_packets!(
                 field id: u8 =,
                 field items: LenPrefixed<i16, Option<item::Stack>> =,
             }
+            packet WindowItems_State {
+                field id: u8 =,
+                field state_id: VarInt =,
+                field items: LenPrefixed<VarInt, Option<item::Stack>> =,
+                field carried_item: Option<item::Stack> =,
+            }
             /// WindowProperty changes the value of a property of a window. Properties
             /// vary depending on the window type.
             packet WindowProperty {
@@ -991,6 +1027,12 @@ state_packets!(
                 field property: i16 =,
                 field item: Option<item::Stack> =,
             }
+            packet WindowSetSlot_State {
+                field id: u8 =,
+                field state_id: VarInt =,
+                field property: i16 =,
+                field item: Option<item::Stack> =,
+            }
             /// SetCooldown disables a set item (by id) for the set number of ticks
             packet SetCooldown {
                 field item_id: VarInt =,
@@ -2648,6 +2690,26 @@ impl Serializable for CriterionProgress {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct ChangedSlot {
+    pub slot: i16,
+    pub item: Option<item::Stack>,
+}
+
+impl Serializable for ChangedSlot {
+    fn read_from<R: io::Read>(buf: &mut R) -> Result<Self, Error> {
+        Ok(ChangedSlot {
+            slot: Serializable::read_from(buf)?,
+            item: Serializable::read_from(buf)?,
+        })
+    }
+
+    fn write_to<W: io::Write>(&self, buf: &mut W) -> Result<(), Error> {
+        self.slot.write_to(buf)?;
+        self.item.write_to(buf)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct EntityEquipment {
     pub slot: u8,
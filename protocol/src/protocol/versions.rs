@@ -16,6 +16,7 @@ mod v1_15;
 mod v1_16_1;
 mod v1_16_4;
 mod v1_17;
+mod v1_17_1;
 mod v1_7_10;
 mod v1_8_9;
 mod v1_9;
@@ -72,7 +73,7 @@ pub fn translate_internal_packet_id_for_version(
     to_internal: bool,
 ) -> Option<i32> {
     match version {
-        756 => unimplemented!(), //Not compatible with 1.17
+        756 => v1_17_1::translate_internal_packet_id(state, dir, id, to_internal),
         755 => v1_17::translate_internal_packet_id(state, dir, id, to_internal),
         754 | 753 | 751 => v1_16_4::translate_internal_packet_id(state, dir, id, to_internal),
         736 => v1_16_1::translate_internal_packet_id(state, dir, id, to_internal),
@@ -96,6 +97,6 @@ pub fn translate_internal_packet_id_for_version(
         74 => v15w39c::translate_internal_packet_id(state, dir, id, to_internal),
         47 => v1_8_9::translate_internal_packet_id(state, dir, id, to_internal),
         5 => v1_7_10::translate_internal_packet_id(state, dir, id, to_internal),
-        _ => panic!("unsupported protocol version: {}", version),
+        _ => None,
     }
 }
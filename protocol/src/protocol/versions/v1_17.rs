@@ -13,10 +13,10 @@ protocol_packet_ids!(
             0x02 => SetDifficulty
             0x03 => ChatMessage
             0x04 => ClientStatus
-            0x05 => ClientSettings
+            0x05 => ClientSettings_Filtering
             0x06 => TabComplete
             0x07 => ClickWindowButton
-            0x08 => ClickWindow
+            0x08 => ClickWindow_Slots
             0x09 => CloseWindow
             0x0a => PluginMessageServerbound
             0x0b => EditBook
@@ -54,6 +54,11 @@ impl Metadata {
         self.map.get(&key.index).map(T::unwrap)
     }
 
+    /// Returns the raw value at the index, whatever its type
+    pub fn get_value(&self, index: i32) -> Option<&Value> {
+        self.map.get(&index)
+    }
+
     pub fn put<T: MetaValue>(&mut self, key: &MetadataKey<T>, val: T) {
         self.map.insert(key.index, val.wrap());
     }
@@ -679,7 +684,7 @@ impl Serializable for VillagerData {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum PoseData {
     Standing,
     FallFlying,
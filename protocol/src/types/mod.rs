@@ -49,4 +49,12 @@ impl Gamemode {
     pub fn noclip(&self) -> bool {
         matches!(*self, Gamemode::Spectator)
     }
+
+    pub fn can_dig(&self) -> bool {
+        !matches!(*self, Gamemode::Spectator)
+    }
+
+    pub fn instant_break(&self) -> bool {
+        matches!(*self, Gamemode::Creative)
+    }
 }
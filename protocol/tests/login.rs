@@ -0,0 +1,384 @@
+//! Plays back logging in and the start of play against a mock server for
+//! every supported protocol version. How the client plays is tested with
+//! the same scripts in the client's `tests/server.rs`.
+
+mod mock_server;
+
+use mock_server::{
+    expect_login, join_game, keep_alive, login_success, profile, chunk_data, MockServer, Step,
+    KEEP_ALIVE_ID,
+};
+use std::io;
+use std::net::TcpListener;
+use steven_protocol::format;
+use steven_protocol::protocol::forge::{self, FmlHs};
+use steven_protocol::protocol::login::Login;
+use steven_protocol::protocol::packet::{self, Packet};
+use steven_protocol::protocol::{
+    self, Direction, Serializable, State, VarInt, SUPPORTED_PROTOCOLS,
+};
+
+/// Logs in through the client's own login, without Forge
+fn login(address: &str, version: i32) -> Result<Login, protocol::Error> {
+    protocol::login::login(&profile(), address, version, None)
+}
+
+#[test]
+fn login_and_play() {
+    let _lock = mock_server::lock_version();
+    for version in SUPPORTED_PROTOCOLS.iter().copied() {
+        let has_compression = mock_server::has_packet(
+            version,
+            State::Login,
+            Direction::Clientbound,
+            packet::login::clientbound::internal_ids::SetInitialCompression,
+        );
+        let mut steps = expect_login(version, "");
+        if has_compression {
+            // Small enough that the chunk is compressed
+            steps.push(Step::Compress(8));
+        }
+        steps.extend(vec![
+            Step::Send(login_success(version)),
+            Step::SetState(State::Play),
+            Step::Send(join_game(version)),
+            Step::Send(chunk_data(version)),
+        ]);
+        let server = MockServer::start(version, steps);
+
+        let mut conn = login(&server.address, version)
+            .unwrap_or_else(|err| panic!("protocol {}: login failed: {}", version, err))
+            .read;
+        assert_eq!(
+            conn.compression_threshold,
+            if has_compression { 8 } else { -1 },
+            "protocol {}",
+            version
+        );
+        match conn.read_packet() {
+            Ok(Packet::JoinGame_WorldNames_IsHard(_))
+            | Ok(Packet::JoinGame_WorldNames(_))
+            | Ok(Packet::JoinGame_HashedSeed_Respawn(_))
+            | Ok(Packet::JoinGame_i32_ViewDistance(_))
+            | Ok(Packet::JoinGame_i32(_))
+            | Ok(Packet::JoinGame_i8(_))
+            | Ok(Packet::JoinGame_i8_NoDebug(_)) => {}
+            val => panic!("protocol {}: expected JoinGame, got {:?}", version, val),
+        }
+        match conn.read_packet() {
+            Ok(Packet::ChunkData_Biomes3D_BitMask(_))
+            | Ok(Packet::ChunkData_Biomes3D_VarInt(_))
+            | Ok(Packet::ChunkData_Biomes3D_bool(_))
+            | Ok(Packet::ChunkData_Biomes3D(_))
+            | Ok(Packet::ChunkData_HeightMap(_))
+            | Ok(Packet::ChunkData(_))
+            | Ok(Packet::ChunkData_NoEntities(_))
+            | Ok(Packet::ChunkData_NoEntities_u16(_))
+            | Ok(Packet::ChunkData_17(_)) => {}
+            val => panic!("protocol {}: expected ChunkData, got {:?}", version, val),
+        }
+
+        if let Err(err) = server.finish() {
+            panic!("protocol {}: {}", version, err);
+        }
+    }
+}
+
+#[test]
+fn login_disconnect() {
+    let _lock = mock_server::lock_version();
+    for version in SUPPORTED_PROTOCOLS.iter().copied() {
+        let mut steps = expect_login(version, "");
+        steps.push(Step::Send(Packet::LoginDisconnect(Box::new(
+            packet::login::clientbound::LoginDisconnect {
+                reason: format::Component::Text(format::TextComponent::new("Server is full")),
+            },
+        ))));
+        let server = MockServer::start(version, steps);
+
+        match login(&server.address, version) {
+            Err(protocol::Error::Disconnect(reason)) => {
+                assert_eq!(reason.to_string(), "Server is full", "protocol {}", version)
+            }
+            Err(err) => panic!("protocol {}: {}", version, err),
+            Ok(_) => panic!("protocol {}: expected to be disconnected", version),
+        }
+        if let Err(err) = server.finish() {
+            panic!("protocol {}: {}", version, err);
+        }
+    }
+}
+
+#[test]
+fn malformed_packet_is_skipped() {
+    let _lock = mock_server::lock_version();
+    let version = SUPPORTED_PROTOCOLS[1];
+    let mut steps = expect_login(version, "");
+    steps.extend(vec![
+        Step::Send(login_success(version)),
+        Step::SetState(State::Play),
+        // No version has a play packet with this id
+        Step::SendRaw(vec![0x7f, 1, 2, 3]),
+        Step::Send(keep_alive(version)),
+    ]);
+    let server = MockServer::start(version, steps);
+
+    let mut conn = login(&server.address, version).unwrap().read;
+    match conn.read_packet() {
+        Err(protocol::Error::Malformed(_)) => {}
+        val => panic!("expected a malformed packet, got {:?}", val),
+    }
+    match conn.read_packet() {
+        Ok(Packet::KeepAliveClientbound_i64(val)) => {
+            assert_eq!(val.id, i64::from(KEEP_ALIVE_ID))
+        }
+        val => panic!("expected KeepAlive, got {:?}", val),
+    }
+    server.finish().unwrap();
+}
+
+/// Serializes the values one after another
+macro_rules! bytes {
+    ($($value:expr),* $(,)?) => {{
+        let mut buf: Vec<u8> = vec![];
+        $($value.write_to(&mut buf).unwrap();)*
+        buf
+    }};
+}
+
+fn send_fmlhs(data: Vec<u8>) -> Step {
+    Step::Send(Packet::PluginMessageClientbound(Box::new(
+        packet::play::clientbound::PluginMessageClientbound {
+            channel: "FML|HS".to_owned(),
+            data,
+        },
+    )))
+}
+
+fn expect_plugin_message(name: &'static str, channel: &'static str, data: Vec<u8>) -> Step {
+    Step::expect(name, move |packet| match packet {
+        Packet::PluginMessageServerbound(val) if val.channel == channel && val.data == data => {
+            Ok(())
+        }
+        val => Err(format!("{:?}", val)),
+    })
+}
+
+#[test]
+fn forge_fml_handshake() {
+    let _lock = mock_server::lock_version();
+    let mods = vec![forge::ForgeMod {
+        modid: "forge".to_owned(),
+        version: "14.23.5.2854".to_owned(),
+    }];
+    let mod_list = bytes![2u8, VarInt(1), "forge".to_owned(), "14.23.5.2854".to_owned()];
+    // FML|HS is only used between 1.8 and 1.12.2
+    for version in SUPPORTED_PROTOCOLS.iter().copied().filter(|v| (47..=340).contains(v)) {
+        let mut steps = expect_login(version, "\0FML\0");
+        steps.extend(vec![
+            Step::Send(login_success(version)),
+            Step::SetState(State::Play),
+            // ServerHello with FML protocol version 2 and a dimension
+            send_fmlhs(bytes![0u8, 2i8, 0i32]),
+            expect_plugin_message(
+                "REGISTER",
+                "REGISTER",
+                b"FML|HS\0FML\0FML|MP\0FML\0FORGE".to_vec(),
+            ),
+            expect_plugin_message("ClientHello", "FML|HS", vec![1, 2]),
+            expect_plugin_message("ModList", "FML|HS", mod_list.clone()),
+            send_fmlhs(mod_list.clone()),
+            expect_plugin_message("HandshakeAck WaitingServerData", "FML|HS", vec![255, 2]),
+            // RegistryData, the client waits for the last one
+            send_fmlhs(bytes![
+                3u8,
+                true,
+                "minecraft:blocks".to_owned(),
+                VarInt(1),
+                "minecraft:stone".to_owned(),
+                VarInt(1),
+                VarInt(0),
+                VarInt(0),
+            ]),
+            send_fmlhs(bytes![
+                3u8,
+                false,
+                "minecraft:items".to_owned(),
+                VarInt(0),
+                VarInt(0),
+                VarInt(0),
+            ]),
+            expect_plugin_message("HandshakeAck WaitingServerComplete", "FML|HS", vec![255, 3]),
+            // HandshakeAck WaitingCAck
+            send_fmlhs(vec![255, 2]),
+            expect_plugin_message("HandshakeAck PendingComplete", "FML|HS", vec![255, 4]),
+            // HandshakeAck Complete
+            send_fmlhs(vec![255, 3]),
+        ]);
+        let server = MockServer::start(version, steps);
+
+        let Login {
+            mut read,
+            mut write,
+            ..
+        } = protocol::login::login(&profile(), &server.address, version, Some(1))
+            .unwrap_or_else(|err| panic!("protocol {}: login failed: {}", version, err));
+        loop {
+            let msg: FmlHs = match read.read_packet() {
+                Ok(Packet::PluginMessageClientbound(val)) if val.channel == "FML|HS" => {
+                    Serializable::read_from(&mut io::Cursor::new(val.data))
+                        .unwrap_or_else(|err| panic!("protocol {}: {}", version, err))
+                }
+                val => panic!("protocol {}: expected FML|HS, got {:?}", version, val),
+            };
+            let done = matches!(
+                msg,
+                FmlHs::HandshakeAck {
+                    phase: forge::Phase::Complete
+                }
+            );
+            write
+                .answer_fmlhs(&msg, &mods)
+                .unwrap_or_else(|err| panic!("protocol {}: {}", version, err));
+            if done {
+                break;
+            }
+        }
+
+        if let Err(err) = server.finish() {
+            panic!("protocol {}: {}", version, err);
+        }
+    }
+}
+
+/// Wraps an FML2 handshake packet like Forge does for login plugin
+/// messages
+fn fml2_wrap(id: i32, payload: Vec<u8>) -> Vec<u8> {
+    let inner = [bytes![VarInt(id)], payload].concat();
+    [
+        bytes!["fml:handshake".to_owned(), VarInt(inner.len() as i32)],
+        inner,
+    ]
+    .concat()
+}
+
+fn send_login_plugin_request(message_id: i32, channel: &str, data: Vec<u8>) -> Step {
+    Step::Send(Packet::LoginPluginRequest(Box::new(
+        packet::login::clientbound::LoginPluginRequest {
+            message_id: VarInt(message_id),
+            channel: channel.to_owned(),
+            data,
+        },
+    )))
+}
+
+fn expect_login_plugin_response(
+    name: &'static str,
+    message_id: i32,
+    successful: bool,
+    data: Vec<u8>,
+) -> Step {
+    Step::expect(name, move |packet| match packet {
+        Packet::LoginPluginResponse(val)
+            if val.message_id.0 == message_id
+                && val.successful == successful
+                && val.data == data =>
+        {
+            Ok(())
+        }
+        val => Err(format!("{:?}", val)),
+    })
+}
+
+#[test]
+fn forge_fml2_handshake() {
+    let _lock = mock_server::lock_version();
+    let acknowledgement = fml2_wrap(99, vec![]);
+    // FML2 is used since 1.13
+    for version in SUPPORTED_PROTOCOLS.iter().copied().filter(|&v| v >= 393) {
+        let mut steps = expect_login(version, "\0FML2\0");
+        steps.extend(vec![
+            send_login_plugin_request(
+                1,
+                "fml:loginwrapper",
+                fml2_wrap(
+                    1,
+                    bytes![
+                        VarInt(1),
+                        "forge".to_owned(),
+                        VarInt(1),
+                        "fml:handshake".to_owned(),
+                        "FML2".to_owned(),
+                        VarInt(1),
+                        "minecraft:item".to_owned(),
+                    ],
+                ),
+            ),
+            // The client claims to have the same mods
+            expect_login_plugin_response(
+                "ModListReply",
+                1,
+                true,
+                fml2_wrap(
+                    2,
+                    bytes![
+                        VarInt(1),
+                        "forge".to_owned(),
+                        VarInt(1),
+                        "fml:handshake".to_owned(),
+                        "FML2".to_owned(),
+                        VarInt(1),
+                        "minecraft:item".to_owned(),
+                        String::new(),
+                    ],
+                ),
+            ),
+            send_login_plugin_request(
+                2,
+                "fml:loginwrapper",
+                fml2_wrap(3, bytes!["minecraft:item".to_owned(), false]),
+            ),
+            expect_login_plugin_response("Acknowledgement", 2, true, acknowledgement.clone()),
+            send_login_plugin_request(
+                3,
+                "fml:loginwrapper",
+                fml2_wrap(4, bytes!["forge-server.toml".to_owned(), b"a = 1".to_vec()]),
+            ),
+            expect_login_plugin_response("Acknowledgement", 3, true, acknowledgement.clone()),
+            // Other channels are refused
+            send_login_plugin_request(4, "example:hello", vec![1, 2, 3]),
+            expect_login_plugin_response("refusal", 4, false, vec![]),
+            Step::Send(login_success(version)),
+        ]);
+        let server = MockServer::start(version, steps);
+
+        let login = protocol::login::login(&profile(), &server.address, version, Some(2))
+            .unwrap_or_else(|err| panic!("protocol {}: login failed: {}", version, err));
+        assert_eq!(login.read.state, State::Play, "protocol {}", version);
+        assert_eq!(login.write.state, State::Play, "protocol {}", version);
+
+        if let Err(err) = server.finish() {
+            panic!("protocol {}: {}", version, err);
+        }
+    }
+}
+
+#[test]
+fn unsupported_fml_version() {
+    match protocol::login::login(&profile(), "127.0.0.1:1", SUPPORTED_PROTOCOLS[0], Some(3)) {
+        Err(protocol::Error::Err(err)) => assert_eq!(err, "Unsupported FML network version 3"),
+        Err(err) => panic!("{}", err),
+        Ok(_) => panic!("expected an error"),
+    }
+}
+
+#[test]
+fn unsupported_protocol_version() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    match login(&address, 1) {
+        Err(protocol::Error::Err(err)) => assert_eq!(err, "unsupported protocol version 1"),
+        Err(err) => panic!("{}", err),
+        Ok(_) => panic!("expected an error"),
+    }
+}
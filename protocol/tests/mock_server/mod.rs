@@ -0,0 +1,445 @@
+//! A server that plays back a script of packets, for testing the client
+//! side of the protocol without a real Minecraft server.
+
+#![allow(dead_code)]
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use lazy_static::lazy_static;
+use std::io::Write;
+use std::net::TcpListener;
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+use steven_protocol::protocol::packet::{self, Packet};
+use steven_protocol::protocol::{
+    self, mojang, Conn, Direction, LenPrefixed, LenPrefixedBytes, Serializable, State, VarInt,
+    VarLong,
+};
+
+/// How long the server waits for the client before failing the script
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    /// The protocol version is global, so tests using different versions
+    /// can't run at the same time.
+    static ref VERSION_LOCK: Mutex<()> = Mutex::new(());
+}
+
+pub fn lock_version() -> MutexGuard<'static, ()> {
+    VERSION_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+type Check = Box<dyn FnOnce(Packet) -> Result<(), String> + Send>;
+
+pub enum Step {
+    /// Reads a serverbound packet and checks it
+    Expect(&'static str, Check),
+    /// Reads serverbound packets until one matches, skipping the ones the
+    /// client sends by itself like its settings
+    Find(&'static str, Box<dyn Fn(&Packet) -> bool + Send>),
+    /// Writes a clientbound packet
+    Send(Packet),
+    /// Writes a packet's id and data as they are, e.g. to send broken
+    /// packets. Not supported once compression is enabled.
+    SendRaw(Vec<u8>),
+    /// Changes which packets are expected, e.g. after the handshake
+    SetState(State),
+    /// Sends `SetInitialCompression` then compresses packets larger than
+    /// the threshold
+    Compress(i32),
+}
+
+impl Step {
+    pub fn expect<F>(name: &'static str, check: F) -> Step
+    where
+        F: FnOnce(Packet) -> Result<(), String> + Send + 'static,
+    {
+        Step::Expect(name, Box::new(check))
+    }
+
+    pub fn find<F>(name: &'static str, matches: F) -> Step
+    where
+        F: Fn(&Packet) -> bool + Send + 'static,
+    {
+        Step::Find(name, Box::new(matches))
+    }
+}
+
+pub struct MockServer {
+    pub address: String,
+    script: thread::JoinHandle<Result<(), String>>,
+}
+
+impl MockServer {
+    /// Listens on a free local port, running the script for the first
+    /// client that connects.
+    pub fn start(protocol_version: i32, steps: Vec<Step>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let script = thread::spawn(move || {
+            let (stream, _) = listener.accept().map_err(|err| err.to_string())?;
+            stream
+                .set_read_timeout(Some(TIMEOUT))
+                .map_err(|err| err.to_string())?;
+            let mut conn =
+                Conn::from_client(stream, protocol_version).map_err(|err| err.to_string())?;
+            for step in steps {
+                run_step(&mut conn, step)?;
+            }
+            Ok(())
+        });
+        MockServer { address, script }
+    }
+
+    /// Waits for the script to end, returning the first step that failed
+    pub fn finish(self) -> Result<(), String> {
+        self.script
+            .join()
+            .map_err(|_| "script panicked".to_owned())?
+    }
+}
+
+fn run_step(conn: &mut Conn, step: Step) -> Result<(), String> {
+    match step {
+        Step::Expect(name, check) => {
+            let packet = conn
+                .read_packet()
+                .map_err(|err| format!("expected {}: {}", name, err))?;
+            check(packet).map_err(|err| format!("expected {}: {}", name, err))
+        }
+        Step::Find(name, matches) => loop {
+            let packet = conn
+                .read_packet()
+                .map_err(|err| format!("expected {}: {}", name, err))?;
+            if matches(&packet) {
+                return Ok(());
+            }
+        },
+        Step::Send(packet) => conn.write_packet(packet).map_err(|err| err.to_string()),
+        Step::SendRaw(data) => {
+            let mut frame = vec![];
+            protocol::VarInt(data.len() as i32)
+                .write_to(&mut frame)
+                .map_err(|err| err.to_string())?;
+            frame.extend(data);
+            conn.write_all(&frame).map_err(|err| err.to_string())
+        }
+        Step::SetState(state) => {
+            conn.state = state;
+            Ok(())
+        }
+        Step::Compress(threshold) => {
+            conn.write_packet(packet::login::clientbound::SetInitialCompression {
+                threshold: protocol::VarInt(threshold),
+            })
+            .map_err(|err| err.to_string())?;
+            conn.set_compresssion(threshold);
+            Ok(())
+        }
+    }
+}
+
+/// Whether the version has an id for the packet
+pub fn has_packet(version: i32, state: State, dir: Direction, internal_id: i32) -> bool {
+    protocol::versions::translate_internal_packet_id_for_version(
+        version,
+        state,
+        dir,
+        internal_id,
+        false,
+    )
+    .is_some()
+}
+
+/// Picks the first packet the version has an id for, the candidates are
+/// pairs of internal ids and packets.
+pub fn pick_packet(
+    version: i32,
+    state: State,
+    dir: Direction,
+    candidates: Vec<(i32, Packet)>,
+) -> Option<Packet> {
+    candidates
+        .into_iter()
+        .find(|(id, _)| has_packet(version, state, dir, *id))
+        .map(|(_, packet)| packet)
+}
+
+pub const USERNAME: &str = "Steven";
+pub const KEEP_ALIVE_ID: i32 = 42;
+
+pub fn profile() -> mojang::Profile {
+    mojang::Profile {
+        username: USERNAME.to_owned(),
+        id: String::new(),
+        access_token: String::new(),
+    }
+}
+
+/// The handshake and login start, checking the client's fields.
+/// `tag` is what the client should add to the host for Forge servers.
+pub fn expect_login(version: i32, tag: &'static str) -> Vec<Step> {
+    vec![
+        Step::expect("Handshake", move |packet| match packet {
+            Packet::Handshake(val)
+                if val.protocol_version.0 == version
+                    && val.next.0 == 2
+                    && val.host == format!("127.0.0.1{}", tag) =>
+            {
+                Ok(())
+            }
+            val => Err(format!("{:?}", val)),
+        }),
+        Step::SetState(State::Login),
+        Step::expect("LoginStart", |packet| match packet {
+            Packet::LoginStart(val) if val.username == USERNAME => Ok(()),
+            val => Err(format!("{:?}", val)),
+        }),
+    ]
+}
+
+pub fn login_success(version: i32) -> Packet {
+    use packet::login::clientbound::*;
+    pick_packet(
+        version,
+        State::Login,
+        Direction::Clientbound,
+        vec![
+            (
+                internal_ids::LoginSuccess_UUID,
+                Packet::LoginSuccess_UUID(Box::new(LoginSuccess_UUID {
+                    uuid: protocol::UUID::default(),
+                    username: USERNAME.to_owned(),
+                })),
+            ),
+            (
+                internal_ids::LoginSuccess_String,
+                Packet::LoginSuccess_String(Box::new(LoginSuccess_String {
+                    uuid: "00000000-0000-0000-0000-000000000000".to_owned(),
+                    username: USERNAME.to_owned(),
+                })),
+            ),
+        ],
+    )
+    .unwrap()
+}
+
+pub fn join_game(version: i32) -> Packet {
+    use packet::play::clientbound::*;
+    pick_packet(
+        version,
+        State::Play,
+        Direction::Clientbound,
+        vec![
+            (
+                internal_ids::JoinGame_WorldNames_IsHard,
+                Packet::JoinGame_WorldNames_IsHard(Box::default()),
+            ),
+            (
+                internal_ids::JoinGame_WorldNames,
+                Packet::JoinGame_WorldNames(Box::default()),
+            ),
+            (
+                internal_ids::JoinGame_HashedSeed_Respawn,
+                Packet::JoinGame_HashedSeed_Respawn(Box::default()),
+            ),
+            (
+                internal_ids::JoinGame_i32_ViewDistance,
+                Packet::JoinGame_i32_ViewDistance(Box::default()),
+            ),
+            (
+                internal_ids::JoinGame_i32,
+                Packet::JoinGame_i32(Box::default()),
+            ),
+            (
+                internal_ids::JoinGame_i8,
+                Packet::JoinGame_i8(Box::default()),
+            ),
+            (
+                internal_ids::JoinGame_i8_NoDebug,
+                Packet::JoinGame_i8_NoDebug(Box::default()),
+            ),
+        ],
+    )
+    .unwrap()
+}
+
+pub fn keep_alive(version: i32) -> Packet {
+    use packet::play::clientbound::*;
+    let id = KEEP_ALIVE_ID;
+    pick_packet(
+        version,
+        State::Play,
+        Direction::Clientbound,
+        vec![
+            (
+                internal_ids::KeepAliveClientbound_i64,
+                Packet::KeepAliveClientbound_i64(Box::new(KeepAliveClientbound_i64 {
+                    id: i64::from(id),
+                })),
+            ),
+            (
+                internal_ids::KeepAliveClientbound_VarInt,
+                Packet::KeepAliveClientbound_VarInt(Box::new(KeepAliveClientbound_VarInt {
+                    id: VarInt(id),
+                })),
+            ),
+            (
+                internal_ids::KeepAliveClientbound_i32,
+                Packet::KeepAliveClientbound_i32(Box::new(KeepAliveClientbound_i32 { id })),
+            ),
+        ],
+    )
+    .unwrap()
+}
+
+/// Whether the packet answers `keep_alive`
+pub fn is_keep_alive_reply(packet: &Packet) -> bool {
+    let id = match packet {
+        Packet::KeepAliveServerbound_i64(val) => val.id,
+        Packet::KeepAliveServerbound_VarInt(val) => i64::from(val.id.0),
+        Packet::KeepAliveServerbound_i32(val) => i64::from(val.id),
+        _ => return false,
+    };
+    id == i64::from(KEEP_ALIVE_ID)
+}
+
+/// The chunk at 0, 0 with its bottom section filled with stone, in the
+/// version's format
+pub fn chunk_data(version: i32) -> Packet {
+    use packet::play::clientbound::*;
+    let data = stone_chunk(version);
+    pick_packet(
+        version,
+        State::Play,
+        Direction::Clientbound,
+        vec![
+            (
+                internal_ids::ChunkData_Biomes3D_BitMask,
+                Packet::ChunkData_Biomes3D_BitMask(Box::new(ChunkData_Biomes3D_BitMask {
+                    bitmask: LenPrefixed::new(vec![VarLong(1)]),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_Biomes3D_VarInt,
+                Packet::ChunkData_Biomes3D_VarInt(Box::new(ChunkData_Biomes3D_VarInt {
+                    new: true,
+                    bitmask: VarInt(1),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_Biomes3D_bool,
+                Packet::ChunkData_Biomes3D_bool(Box::new(ChunkData_Biomes3D_bool {
+                    new: true,
+                    bitmask: VarInt(1),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_Biomes3D,
+                Packet::ChunkData_Biomes3D(Box::new(ChunkData_Biomes3D {
+                    new: true,
+                    bitmask: VarInt(1),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_HeightMap,
+                Packet::ChunkData_HeightMap(Box::new(ChunkData_HeightMap {
+                    new: true,
+                    bitmask: VarInt(1),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData,
+                Packet::ChunkData(Box::new(ChunkData {
+                    new: true,
+                    bitmask: VarInt(1),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_NoEntities,
+                Packet::ChunkData_NoEntities(Box::new(ChunkData_NoEntities {
+                    new: true,
+                    bitmask: VarInt(1),
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_NoEntities_u16,
+                Packet::ChunkData_NoEntities_u16(Box::new(ChunkData_NoEntities_u16 {
+                    new: true,
+                    bitmask: 1,
+                    data: LenPrefixedBytes::new(data.clone()),
+                    ..Default::default()
+                })),
+            ),
+            (
+                internal_ids::ChunkData_17,
+                Packet::ChunkData_17(Box::new(ChunkData_17 {
+                    new: true,
+                    bitmask: 1,
+                    compressed_data: LenPrefixedBytes::new(data),
+                    ..Default::default()
+                })),
+            ),
+        ],
+    )
+    .unwrap()
+}
+
+/// The data of a chunk column whose only section, the bottom one, is
+/// all stone
+fn stone_chunk(version: i32) -> Vec<u8> {
+    let mut data = vec![];
+    if version > 47 {
+        if version >= 451 {
+            // The number of blocks that aren't air
+            4096i16.write_to(&mut data).unwrap();
+        }
+        // A palette of only stone, 4 bits per block
+        4u8.write_to(&mut data).unwrap();
+        let stone = if version >= 393 { 1 } else { 1 << 4 };
+        LenPrefixed::<VarInt, VarInt>::new(vec![VarInt(stone)])
+            .write_to(&mut data)
+            .unwrap();
+        LenPrefixed::<VarInt, u64>::new(vec![0; 256])
+            .write_to(&mut data)
+            .unwrap();
+    } else if version >= 47 {
+        // The id and metadata of every block
+        for _ in 0..4096 {
+            data.extend_from_slice(&[1 << 4, 0]);
+        }
+    } else {
+        // The ids then the metadata of every block
+        data.extend(vec![1; 4096]);
+        data.extend(vec![0; 2048]);
+    }
+    if version < 451 {
+        // Block light and sky light
+        data.extend(vec![0; 2 * 2048]);
+    }
+    if version < 573 {
+        // Enough for the biomes of every version that sends them here
+        data.extend(vec![0; 1024]);
+    }
+    if version < 47 {
+        let mut zlib = ZlibEncoder::new(vec![], Compression::default());
+        zlib.write_all(&data).unwrap();
+        data = zlib.finish().unwrap();
+    }
+    data
+}
@@ -0,0 +1,1552 @@
+//! The sound event registry, which servers since 1.9 refer to sounds by.
+//!
+//! Vanilla registers sound events in alphabetical order, apart from
+//! `ambient.cave` which has stayed first since it was added, so every
+//! version that adds or renames an event shifts the ids after it.
+
+struct SoundEvent {
+    name: &'static str,
+    /// The first protocol version with the event
+    since: i32,
+    /// The first protocol version without the event
+    until: i32,
+}
+
+macro_rules! sound_events {
+    ($($name:literal $(since $since:literal)? $(until $until:literal)?,)*) => (
+        &[$(
+            SoundEvent {
+                name: $name,
+                since: sound_events!(@or $($since)?, 0),
+                until: sound_events!(@or $($until)?, i32::MAX),
+            },
+        )*]
+    );
+    (@or $val:literal, $default:expr) => ($val);
+    (@or , $default:expr) => ($default);
+}
+
+/// Looks up the name of a sound event by its id in the protocol
+/// version's registry.
+pub fn name_by_id(id: i32, protocol_version: i32) -> Option<&'static str> {
+    if id < 0 {
+        return None;
+    }
+    SOUND_EVENTS
+        .iter()
+        .filter(|event| event.since <= protocol_version && protocol_version < event.until)
+        .nth(id as usize)
+        .map(|event| event.name)
+}
+
+/// Returns the name an event had before 1.13, which is what the
+/// resources the client loads call it.
+pub fn legacy_name(name: &str) -> Option<String> {
+    RENAMED.iter().find_map(|(new, old)| {
+        let rest = name.strip_prefix(new)?;
+        if rest.is_empty() || rest.starts_with('.') {
+            Some(format!("{}{}", old, rest))
+        } else {
+            None
+        }
+    })
+}
+
+/// Events and groups of events renamed by 1.13 or later, new name first
+const RENAMED: &[(&str, &str)] = &[
+    ("block.ender_chest", "block.enderchest"),
+    ("block.lily_pad", "block.waterlily"),
+    ("block.metal_pressure_plate", "block.metal_pressureplate"),
+    ("block.note_block", "block.note"),
+    ("block.slime_block", "block.slime"),
+    ("block.stone_pressure_plate", "block.stone_pressureplate"),
+    ("block.wooden_button", "block.wood_button"),
+    ("block.wooden_pressure_plate", "block.wood_pressureplate"),
+    ("block.wool", "block.cloth"),
+    ("entity.armor_stand", "entity.armorstand"),
+    ("entity.dragon_fireball", "entity.enderdragon_fireball"),
+    ("entity.ender_dragon", "entity.enderdragon"),
+    ("entity.ender_eye", "entity.endereye"),
+    ("entity.ender_pearl", "entity.enderpearl"),
+    ("entity.enderman", "entity.endermen"),
+    ("entity.evoker_fangs", "entity.evocation_fangs"),
+    ("entity.evoker", "entity.evocation_illager"),
+    ("entity.firework_rocket", "entity.firework"),
+    ("entity.fishing_bobber", "entity.bobber"),
+    ("entity.illusioner", "entity.illusion_illager"),
+    ("entity.iron_golem", "entity.irongolem"),
+    ("entity.item_frame", "entity.itemframe"),
+    ("entity.leash_knot", "entity.leashknot"),
+    ("entity.lightning_bolt", "entity.lightning"),
+    ("entity.lingering_potion", "entity.lingeringpotion"),
+    (
+        "entity.magma_cube.death_small",
+        "entity.small_magmacube.death",
+    ),
+    (
+        "entity.magma_cube.hurt_small",
+        "entity.small_magmacube.hurt",
+    ),
+    (
+        "entity.magma_cube.squish_small",
+        "entity.small_magmacube.squish",
+    ),
+    ("entity.magma_cube", "entity.magmacube"),
+    (
+        "entity.parrot.imitate.ender_dragon",
+        "entity.parrot.imitate.enderdragon",
+    ),
+    (
+        "entity.parrot.imitate.evoker",
+        "entity.parrot.imitate.evocation_illager",
+    ),
+    (
+        "entity.parrot.imitate.illusioner",
+        "entity.parrot.imitate.illusion_illager",
+    ),
+    (
+        "entity.parrot.imitate.magma_cube",
+        "entity.parrot.imitate.magmacube",
+    ),
+    (
+        "entity.parrot.imitate.vindicator",
+        "entity.parrot.imitate.vindication_illager",
+    ),
+    (
+        "entity.polar_bear.ambient_baby",
+        "entity.polar_bear.baby_ambient",
+    ),
+    ("entity.slime.death_small", "entity.small_slime.death"),
+    ("entity.slime.hurt_small", "entity.small_slime.hurt"),
+    ("entity.slime.jump_small", "entity.small_slime.jump"),
+    ("entity.slime.squish_small", "entity.small_slime.squish"),
+    ("entity.snow_golem", "entity.snowman"),
+    ("entity.villager.trade", "entity.villager.trading"),
+    ("entity.vindicator", "entity.vindication_illager"),
+    (
+        "entity.zombie.attack_wooden_door",
+        "entity.zombie.attack_door_wood",
+    ),
+    (
+        "entity.zombie.break_wooden_door",
+        "entity.zombie.break_door_wood",
+    ),
+    ("entity.zombie_pigman", "entity.zombie_pig"),
+    ("entity.zombified_piglin", "entity.zombie_pig"),
+    ("music_disc", "record"),
+];
+
+/// Every sound event from 1.9 to 1.17.1 in registry order
+const SOUND_EVENTS: &[SoundEvent] = sound_events! {
+    "ambient.cave",
+    "ambient.basalt_deltas.additions" since 735,
+    "ambient.basalt_deltas.loop" since 735,
+    "ambient.basalt_deltas.mood" since 735,
+    "ambient.crimson_forest.additions" since 735,
+    "ambient.crimson_forest.loop" since 735,
+    "ambient.crimson_forest.mood" since 735,
+    "ambient.nether_wastes.additions" since 735,
+    "ambient.nether_wastes.loop" since 735,
+    "ambient.nether_wastes.mood" since 735,
+    "ambient.soul_sand_valley.additions" since 735,
+    "ambient.soul_sand_valley.loop" since 735,
+    "ambient.soul_sand_valley.mood" since 735,
+    "ambient.underwater.enter" since 393,
+    "ambient.underwater.exit" since 393,
+    "ambient.underwater.loop" since 393,
+    "ambient.underwater.loop.additions" since 393,
+    "ambient.underwater.loop.additions.rare" since 393,
+    "ambient.underwater.loop.additions.ultra_rare" since 393,
+    "ambient.warped_forest.additions" since 735,
+    "ambient.warped_forest.loop" since 735,
+    "ambient.warped_forest.mood" since 735,
+    "block.amethyst_block.break" since 755,
+    "block.amethyst_block.chime" since 755,
+    "block.amethyst_block.fall" since 755,
+    "block.amethyst_block.hit" since 755,
+    "block.amethyst_block.place" since 755,
+    "block.amethyst_block.step" since 755,
+    "block.amethyst_cluster.break" since 755,
+    "block.amethyst_cluster.fall" since 755,
+    "block.amethyst_cluster.hit" since 755,
+    "block.amethyst_cluster.place" since 755,
+    "block.amethyst_cluster.step" since 755,
+    "block.ancient_debris.break" since 735,
+    "block.ancient_debris.fall" since 735,
+    "block.ancient_debris.hit" since 735,
+    "block.ancient_debris.place" since 735,
+    "block.ancient_debris.step" since 735,
+    "block.anvil.break",
+    "block.anvil.destroy",
+    "block.anvil.fall",
+    "block.anvil.hit",
+    "block.anvil.land",
+    "block.anvil.place",
+    "block.anvil.step",
+    "block.anvil.use",
+    "block.azalea.break" since 755,
+    "block.azalea.fall" since 755,
+    "block.azalea.hit" since 755,
+    "block.azalea.place" since 755,
+    "block.azalea.step" since 755,
+    "block.azalea_leaves.break" since 755,
+    "block.azalea_leaves.fall" since 755,
+    "block.azalea_leaves.hit" since 755,
+    "block.azalea_leaves.place" since 755,
+    "block.azalea_leaves.step" since 755,
+    "block.bamboo.break" since 477,
+    "block.bamboo.fall" since 477,
+    "block.bamboo.hit" since 477,
+    "block.bamboo.place" since 477,
+    "block.bamboo.step" since 477,
+    "block.bamboo_sapling.break" since 477,
+    "block.bamboo_sapling.hit" since 477,
+    "block.bamboo_sapling.place" since 477,
+    "block.barrel.close" since 477,
+    "block.barrel.open" since 477,
+    "block.basalt.break" since 735,
+    "block.basalt.fall" since 735,
+    "block.basalt.hit" since 735,
+    "block.basalt.place" since 735,
+    "block.basalt.step" since 735,
+    "block.beacon.activate" since 393,
+    "block.beacon.ambient" since 393,
+    "block.beacon.deactivate" since 393,
+    "block.beacon.power_select" since 393,
+    "block.beehive.drip" since 573,
+    "block.beehive.enter" since 573,
+    "block.beehive.exit" since 573,
+    "block.beehive.shear" since 573,
+    "block.beehive.work" since 573,
+    "block.bell.resonate" since 477,
+    "block.bell.use" since 477,
+    "block.big_dripleaf.break" since 755,
+    "block.big_dripleaf.fall" since 755,
+    "block.big_dripleaf.hit" since 755,
+    "block.big_dripleaf.place" since 755,
+    "block.big_dripleaf.step" since 755,
+    "block.big_dripleaf.tilt_down" since 755,
+    "block.big_dripleaf.tilt_up" since 755,
+    "block.blastfurnace.fire_crackle" since 477,
+    "block.bone_block.break" since 735,
+    "block.bone_block.fall" since 735,
+    "block.bone_block.hit" since 735,
+    "block.bone_block.place" since 735,
+    "block.bone_block.step" since 735,
+    "block.brewing_stand.brew",
+    "block.bubble_column.bubble_pop" since 393,
+    "block.bubble_column.upwards_ambient" since 393,
+    "block.bubble_column.upwards_inside" since 393,
+    "block.bubble_column.whirlpool_ambient" since 393,
+    "block.bubble_column.whirlpool_inside" since 393,
+    "block.cake.add_candle" since 755,
+    "block.calcite.break" since 755,
+    "block.calcite.fall" since 755,
+    "block.calcite.hit" since 755,
+    "block.calcite.place" since 755,
+    "block.calcite.step" since 755,
+    "block.campfire.crackle" since 477,
+    "block.candle.ambient" since 755,
+    "block.candle.break" since 755,
+    "block.candle.extinguish" since 755,
+    "block.candle.fall" since 755,
+    "block.candle.hit" since 755,
+    "block.candle.place" since 755,
+    "block.candle.step" since 755,
+    "block.cave_vines.break" since 755,
+    "block.cave_vines.fall" since 755,
+    "block.cave_vines.hit" since 755,
+    "block.cave_vines.pick_berries" since 755,
+    "block.cave_vines.place" since 755,
+    "block.cave_vines.step" since 755,
+    "block.chain.break" since 735,
+    "block.chain.fall" since 735,
+    "block.chain.hit" since 735,
+    "block.chain.place" since 735,
+    "block.chain.step" since 735,
+    "block.chest.close",
+    "block.chest.locked",
+    "block.chest.open",
+    "block.chorus_flower.death",
+    "block.chorus_flower.grow",
+    "block.cloth.break" until 393,
+    "block.cloth.fall" until 393,
+    "block.cloth.hit" until 393,
+    "block.cloth.place" until 393,
+    "block.cloth.step" until 393,
+    "block.comparator.click",
+    "block.composter.empty" since 477,
+    "block.composter.fill" since 477,
+    "block.composter.fill_success" since 477,
+    "block.composter.ready" since 477,
+    "block.conduit.activate" since 393,
+    "block.conduit.ambient" since 393,
+    "block.conduit.ambient.short" since 393,
+    "block.conduit.attack.target" since 393,
+    "block.conduit.deactivate" since 393,
+    "block.copper.break" since 755,
+    "block.copper.fall" since 755,
+    "block.copper.hit" since 755,
+    "block.copper.place" since 755,
+    "block.copper.step" since 755,
+    "block.coral_block.break" since 393,
+    "block.coral_block.fall" since 393,
+    "block.coral_block.hit" since 393,
+    "block.coral_block.place" since 393,
+    "block.coral_block.step" since 393,
+    "block.crop.break" since 477,
+    "block.deepslate.break" since 755,
+    "block.deepslate.fall" since 755,
+    "block.deepslate.hit" since 755,
+    "block.deepslate.place" since 755,
+    "block.deepslate.step" since 755,
+    "block.deepslate_bricks.break" since 755,
+    "block.deepslate_bricks.fall" since 755,
+    "block.deepslate_bricks.hit" since 755,
+    "block.deepslate_bricks.place" since 755,
+    "block.deepslate_bricks.step" since 755,
+    "block.deepslate_tiles.break" since 755,
+    "block.deepslate_tiles.fall" since 755,
+    "block.deepslate_tiles.hit" since 755,
+    "block.deepslate_tiles.place" since 755,
+    "block.deepslate_tiles.step" since 755,
+    "block.dispenser.dispense",
+    "block.dispenser.fail",
+    "block.dispenser.launch",
+    "block.dripstone_block.break" since 755,
+    "block.dripstone_block.fall" since 755,
+    "block.dripstone_block.hit" since 755,
+    "block.dripstone_block.place" since 755,
+    "block.dripstone_block.step" since 755,
+    "block.enchantment_table.use" since 335,
+    "block.end_gateway.spawn",
+    "block.end_portal.spawn" since 335,
+    "block.end_portal_frame.fill" since 335,
+    "block.ender_chest.close" since 393,
+    "block.ender_chest.open" since 393,
+    "block.enderchest.close" until 393,
+    "block.enderchest.open" until 393,
+    "block.fence_gate.close",
+    "block.fence_gate.open",
+    "block.fire.ambient",
+    "block.fire.extinguish",
+    "block.flowering_azalea.break" since 755,
+    "block.flowering_azalea.fall" since 755,
+    "block.flowering_azalea.hit" since 755,
+    "block.flowering_azalea.place" since 755,
+    "block.flowering_azalea.step" since 755,
+    "block.fungus.break" since 735,
+    "block.fungus.fall" since 735,
+    "block.fungus.hit" since 735,
+    "block.fungus.place" since 735,
+    "block.fungus.step" since 735,
+    "block.furnace.fire_crackle",
+    "block.gilded_blackstone.break" since 735,
+    "block.gilded_blackstone.fall" since 735,
+    "block.gilded_blackstone.hit" since 735,
+    "block.gilded_blackstone.place" since 735,
+    "block.gilded_blackstone.step" since 735,
+    "block.glass.break",
+    "block.glass.fall",
+    "block.glass.hit",
+    "block.glass.place",
+    "block.glass.step",
+    "block.grass.break",
+    "block.grass.fall",
+    "block.grass.hit",
+    "block.grass.place",
+    "block.grass.step",
+    "block.gravel.break",
+    "block.gravel.fall",
+    "block.gravel.hit",
+    "block.gravel.place",
+    "block.gravel.step",
+    "block.grindstone.use" since 477,
+    "block.growing_plant.crop" since 755,
+    "block.hanging_roots.break" since 755,
+    "block.hanging_roots.fall" since 755,
+    "block.hanging_roots.hit" since 755,
+    "block.hanging_roots.place" since 755,
+    "block.hanging_roots.step" since 755,
+    "block.honey_block.break" since 573,
+    "block.honey_block.fall" since 573,
+    "block.honey_block.hit" since 573,
+    "block.honey_block.place" since 573,
+    "block.honey_block.slide" since 573,
+    "block.honey_block.step" since 573,
+    "block.iron_door.close",
+    "block.iron_door.open",
+    "block.iron_trapdoor.close",
+    "block.iron_trapdoor.open",
+    "block.ladder.break",
+    "block.ladder.fall",
+    "block.ladder.hit",
+    "block.ladder.place",
+    "block.ladder.step",
+    "block.lantern.break" since 477,
+    "block.lantern.fall" since 477,
+    "block.lantern.hit" since 477,
+    "block.lantern.place" since 477,
+    "block.lantern.step" since 477,
+    "block.large_amethyst_bud.break" since 755,
+    "block.large_amethyst_bud.place" since 755,
+    "block.lava.ambient",
+    "block.lava.extinguish",
+    "block.lava.pop",
+    "block.lever.click",
+    "block.lily_pad.place" since 393,
+    "block.lodestone.break" since 735,
+    "block.lodestone.fall" since 735,
+    "block.lodestone.hit" since 735,
+    "block.lodestone.place" since 735,
+    "block.lodestone.step" since 735,
+    "block.medium_amethyst_bud.break" since 755,
+    "block.medium_amethyst_bud.place" since 755,
+    "block.metal.break",
+    "block.metal.fall",
+    "block.metal.hit",
+    "block.metal.place",
+    "block.metal.step",
+    "block.metal_pressure_plate.click_off" since 393,
+    "block.metal_pressure_plate.click_on" since 393,
+    "block.metal_pressureplate.click_off" until 393,
+    "block.metal_pressureplate.click_on" until 393,
+    "block.moss.break" since 755,
+    "block.moss.fall" since 755,
+    "block.moss.hit" since 755,
+    "block.moss.place" since 755,
+    "block.moss.step" since 755,
+    "block.moss_carpet.break" since 755,
+    "block.moss_carpet.fall" since 755,
+    "block.moss_carpet.hit" since 755,
+    "block.moss_carpet.place" since 755,
+    "block.moss_carpet.step" since 755,
+    "block.nether_bricks.break" since 735,
+    "block.nether_bricks.fall" since 735,
+    "block.nether_bricks.hit" since 735,
+    "block.nether_bricks.place" since 735,
+    "block.nether_bricks.step" since 735,
+    "block.nether_gold_ore.break" since 735,
+    "block.nether_gold_ore.fall" since 735,
+    "block.nether_gold_ore.hit" since 735,
+    "block.nether_gold_ore.place" since 735,
+    "block.nether_gold_ore.step" since 735,
+    "block.nether_ore.break" since 735,
+    "block.nether_ore.fall" since 735,
+    "block.nether_ore.hit" since 735,
+    "block.nether_ore.place" since 735,
+    "block.nether_ore.step" since 735,
+    "block.nether_sprouts.break" since 735,
+    "block.nether_sprouts.fall" since 735,
+    "block.nether_sprouts.hit" since 735,
+    "block.nether_sprouts.place" since 735,
+    "block.nether_sprouts.step" since 735,
+    "block.nether_wart.break" since 477,
+    "block.netherite_block.break" since 735,
+    "block.netherite_block.fall" since 735,
+    "block.netherite_block.hit" since 735,
+    "block.netherite_block.place" since 735,
+    "block.netherite_block.step" since 735,
+    "block.netherrack.break" since 735,
+    "block.netherrack.fall" since 735,
+    "block.netherrack.hit" since 735,
+    "block.netherrack.place" since 735,
+    "block.netherrack.step" since 735,
+    "block.note.basedrum" until 393,
+    "block.note.bass" until 393,
+    "block.note.bell" since 335 until 393,
+    "block.note.chime" since 335 until 393,
+    "block.note.flute" since 335 until 393,
+    "block.note.guitar" since 335 until 393,
+    "block.note.harp" until 393,
+    "block.note.hat" until 393,
+    "block.note.pling" until 393,
+    "block.note.snare" until 393,
+    "block.note.xylophone" since 335 until 393,
+    "block.note_block.banjo" since 477,
+    "block.note_block.basedrum" since 393,
+    "block.note_block.bass" since 393,
+    "block.note_block.bell" since 393,
+    "block.note_block.bit" since 477,
+    "block.note_block.chime" since 393,
+    "block.note_block.cow_bell" since 477,
+    "block.note_block.didgeridoo" since 477,
+    "block.note_block.flute" since 393,
+    "block.note_block.guitar" since 393,
+    "block.note_block.harp" since 393,
+    "block.note_block.hat" since 393,
+    "block.note_block.iron_xylophone" since 477,
+    "block.note_block.pling" since 393,
+    "block.note_block.snare" since 393,
+    "block.note_block.xylophone" since 393,
+    "block.nylium.break" since 735,
+    "block.nylium.fall" since 735,
+    "block.nylium.hit" since 735,
+    "block.nylium.place" since 735,
+    "block.nylium.step" since 735,
+    "block.piston.contract",
+    "block.piston.extend",
+    "block.pointed_dripstone.break" since 755,
+    "block.pointed_dripstone.drip_lava" since 755,
+    "block.pointed_dripstone.drip_lava_into_cauldron" since 755,
+    "block.pointed_dripstone.drip_water" since 755,
+    "block.pointed_dripstone.drip_water_into_cauldron" since 755,
+    "block.pointed_dripstone.fall" since 755,
+    "block.pointed_dripstone.hit" since 755,
+    "block.pointed_dripstone.land" since 755,
+    "block.pointed_dripstone.place" since 755,
+    "block.pointed_dripstone.step" since 755,
+    "block.polished_deepslate.break" since 755,
+    "block.polished_deepslate.fall" since 755,
+    "block.polished_deepslate.hit" since 755,
+    "block.polished_deepslate.place" since 755,
+    "block.polished_deepslate.step" since 755,
+    "block.portal.ambient",
+    "block.portal.travel",
+    "block.portal.trigger",
+    "block.powder_snow.break" since 755,
+    "block.powder_snow.fall" since 755,
+    "block.powder_snow.hit" since 755,
+    "block.powder_snow.place" since 755,
+    "block.powder_snow.step" since 755,
+    "block.pumpkin.carve" since 393,
+    "block.redstone_torch.burnout",
+    "block.respawn_anchor.ambient" since 735,
+    "block.respawn_anchor.charge" since 735,
+    "block.respawn_anchor.deplete" since 735,
+    "block.respawn_anchor.set_spawn" since 735,
+    "block.rooted_dirt.break" since 755,
+    "block.rooted_dirt.fall" since 755,
+    "block.rooted_dirt.hit" since 755,
+    "block.rooted_dirt.place" since 755,
+    "block.rooted_dirt.step" since 755,
+    "block.roots.break" since 735,
+    "block.roots.fall" since 735,
+    "block.roots.hit" since 735,
+    "block.roots.place" since 735,
+    "block.roots.step" since 735,
+    "block.sand.break",
+    "block.sand.fall",
+    "block.sand.hit",
+    "block.sand.place",
+    "block.sand.step",
+    "block.scaffolding.break" since 477,
+    "block.scaffolding.fall" since 477,
+    "block.scaffolding.hit" since 477,
+    "block.scaffolding.place" since 477,
+    "block.scaffolding.step" since 477,
+    "block.sculk_sensor.break" since 755,
+    "block.sculk_sensor.clicking" since 755,
+    "block.sculk_sensor.clicking_stop" since 755,
+    "block.sculk_sensor.fall" since 755,
+    "block.sculk_sensor.hit" since 755,
+    "block.sculk_sensor.place" since 755,
+    "block.sculk_sensor.step" since 755,
+    "block.shroomlight.break" since 735,
+    "block.shroomlight.fall" since 735,
+    "block.shroomlight.hit" since 735,
+    "block.shroomlight.place" since 735,
+    "block.shroomlight.step" since 735,
+    "block.shulker_box.close" since 315,
+    "block.shulker_box.open" since 315,
+    "block.slime.break" until 393,
+    "block.slime.fall" until 393,
+    "block.slime.hit" until 393,
+    "block.slime.place" until 393,
+    "block.slime.step" until 393,
+    "block.slime_block.break" since 393,
+    "block.slime_block.fall" since 393,
+    "block.slime_block.hit" since 393,
+    "block.slime_block.place" since 393,
+    "block.slime_block.step" since 393,
+    "block.small_amethyst_bud.break" since 755,
+    "block.small_amethyst_bud.place" since 755,
+    "block.small_dripleaf.break" since 755,
+    "block.small_dripleaf.fall" since 755,
+    "block.small_dripleaf.hit" since 755,
+    "block.small_dripleaf.place" since 755,
+    "block.small_dripleaf.step" since 755,
+    "block.smithing_table.use" since 477,
+    "block.smoker.smoke" since 477,
+    "block.snow.break",
+    "block.snow.fall",
+    "block.snow.hit",
+    "block.snow.place",
+    "block.snow.step",
+    "block.soul_sand.break" since 735,
+    "block.soul_sand.fall" since 735,
+    "block.soul_sand.hit" since 735,
+    "block.soul_sand.place" since 735,
+    "block.soul_sand.step" since 735,
+    "block.soul_soil.break" since 735,
+    "block.soul_soil.fall" since 735,
+    "block.soul_soil.hit" since 735,
+    "block.soul_soil.place" since 735,
+    "block.soul_soil.step" since 735,
+    "block.spore_blossom.break" since 755,
+    "block.spore_blossom.fall" since 755,
+    "block.spore_blossom.hit" since 755,
+    "block.spore_blossom.place" since 755,
+    "block.spore_blossom.step" since 755,
+    "block.stem.break" since 735,
+    "block.stem.fall" since 735,
+    "block.stem.hit" since 735,
+    "block.stem.place" since 735,
+    "block.stem.step" since 735,
+    "block.stone.break",
+    "block.stone.fall",
+    "block.stone.hit",
+    "block.stone.place",
+    "block.stone.step",
+    "block.stone_button.click_off",
+    "block.stone_button.click_on",
+    "block.stone_pressure_plate.click_off" since 393,
+    "block.stone_pressure_plate.click_on" since 393,
+    "block.stone_pressureplate.click_off" until 393,
+    "block.stone_pressureplate.click_on" until 393,
+    "block.sweet_berry_bush.break" since 477,
+    "block.sweet_berry_bush.place" since 477,
+    "block.tripwire.attach",
+    "block.tripwire.click_off",
+    "block.tripwire.click_on",
+    "block.tripwire.detach",
+    "block.tuff.break" since 755,
+    "block.tuff.fall" since 755,
+    "block.tuff.hit" since 755,
+    "block.tuff.place" since 755,
+    "block.tuff.step" since 755,
+    "block.vine.break" since 755,
+    "block.vine.fall" since 755,
+    "block.vine.hit" since 755,
+    "block.vine.place" since 755,
+    "block.vine.step" since 735,
+    "block.wart_block.break" since 735,
+    "block.wart_block.fall" since 735,
+    "block.wart_block.hit" since 735,
+    "block.wart_block.place" since 735,
+    "block.wart_block.step" since 735,
+    "block.water.ambient",
+    "block.waterlily.place" until 393,
+    "block.weeping_vines.break" since 735,
+    "block.weeping_vines.fall" since 735,
+    "block.weeping_vines.hit" since 735,
+    "block.weeping_vines.place" since 735,
+    "block.weeping_vines.step" since 735,
+    "block.wet_grass.break" since 393,
+    "block.wet_grass.fall" since 393,
+    "block.wet_grass.hit" since 393,
+    "block.wet_grass.place" since 393,
+    "block.wet_grass.step" since 393,
+    "block.wood.break",
+    "block.wood.fall",
+    "block.wood.hit",
+    "block.wood.place",
+    "block.wood.step",
+    "block.wood_button.click_off" until 393,
+    "block.wood_button.click_on" until 393,
+    "block.wood_pressureplate.click_off" until 393,
+    "block.wood_pressureplate.click_on" until 393,
+    "block.wooden_button.click_off" since 393,
+    "block.wooden_button.click_on" since 393,
+    "block.wooden_door.close",
+    "block.wooden_door.open",
+    "block.wooden_pressure_plate.click_off" since 393,
+    "block.wooden_pressure_plate.click_on" since 393,
+    "block.wooden_trapdoor.close",
+    "block.wooden_trapdoor.open",
+    "block.wool.break" since 393,
+    "block.wool.fall" since 393,
+    "block.wool.hit" since 393,
+    "block.wool.place" since 393,
+    "block.wool.step" since 393,
+    "enchant.thorns.hit",
+    "entity.armor_stand.break" since 393,
+    "entity.armor_stand.fall" since 393,
+    "entity.armor_stand.hit" since 393,
+    "entity.armor_stand.place" since 393,
+    "entity.armorstand.break" until 393,
+    "entity.armorstand.fall" until 393,
+    "entity.armorstand.hit" until 393,
+    "entity.armorstand.place" until 393,
+    "entity.arrow.hit",
+    "entity.arrow.hit_player",
+    "entity.arrow.shoot",
+    "entity.axolotl.attack" since 755,
+    "entity.axolotl.death" since 755,
+    "entity.axolotl.hurt" since 755,
+    "entity.axolotl.idle_air" since 755,
+    "entity.axolotl.idle_water" since 755,
+    "entity.axolotl.splash" since 755,
+    "entity.axolotl.swim" since 755,
+    "entity.bat.ambient",
+    "entity.bat.death",
+    "entity.bat.hurt",
+    "entity.bat.loop",
+    "entity.bat.takeoff",
+    "entity.bee.death" since 573,
+    "entity.bee.hurt" since 573,
+    "entity.bee.loop" since 573,
+    "entity.bee.loop_aggressive" since 573,
+    "entity.bee.pollinate" since 573,
+    "entity.bee.sting" since 573,
+    "entity.blaze.ambient",
+    "entity.blaze.burn",
+    "entity.blaze.death",
+    "entity.blaze.hurt",
+    "entity.blaze.shoot",
+    "entity.boat.paddle_land",
+    "entity.boat.paddle_water",
+    "entity.bobber.retrieve" since 335 until 393,
+    "entity.bobber.splash" until 393,
+    "entity.bobber.throw" until 393,
+    "entity.cat.ambient",
+    "entity.cat.beg_for_food" since 477,
+    "entity.cat.death",
+    "entity.cat.eat" since 477,
+    "entity.cat.hiss",
+    "entity.cat.hurt",
+    "entity.cat.purr",
+    "entity.cat.purreow",
+    "entity.cat.stray_ambient" since 477,
+    "entity.chicken.ambient",
+    "entity.chicken.death",
+    "entity.chicken.egg",
+    "entity.chicken.hurt",
+    "entity.chicken.step",
+    "entity.cod.ambient" since 393,
+    "entity.cod.death" since 393,
+    "entity.cod.flop" since 393,
+    "entity.cod.hurt" since 393,
+    "entity.cow.ambient",
+    "entity.cow.death",
+    "entity.cow.hurt",
+    "entity.cow.milk",
+    "entity.cow.step",
+    "entity.creeper.death",
+    "entity.creeper.hurt",
+    "entity.creeper.primed",
+    "entity.dolphin.ambient" since 393,
+    "entity.dolphin.ambient_water" since 393,
+    "entity.dolphin.attack" since 393,
+    "entity.dolphin.death" since 393,
+    "entity.dolphin.eat" since 393,
+    "entity.dolphin.hurt" since 393,
+    "entity.dolphin.jump" since 393,
+    "entity.dolphin.play" since 393,
+    "entity.dolphin.splash" since 393,
+    "entity.dolphin.swim" since 393,
+    "entity.donkey.ambient",
+    "entity.donkey.angry",
+    "entity.donkey.chest",
+    "entity.donkey.death",
+    "entity.donkey.hurt",
+    "entity.dragon_fireball.explode" since 393,
+    "entity.drowned.ambient" since 393,
+    "entity.drowned.ambient_water" since 393,
+    "entity.drowned.death" since 393,
+    "entity.drowned.death_water" since 393,
+    "entity.drowned.hurt" since 393,
+    "entity.drowned.hurt_water" since 393,
+    "entity.drowned.shoot" since 393,
+    "entity.drowned.step" since 393,
+    "entity.drowned.swim" since 393,
+    "entity.egg.throw",
+    "entity.elder_guardian.ambient",
+    "entity.elder_guardian.ambient_land",
+    "entity.elder_guardian.curse",
+    "entity.elder_guardian.death",
+    "entity.elder_guardian.death_land",
+    "entity.elder_guardian.flop",
+    "entity.elder_guardian.hurt",
+    "entity.elder_guardian.hurt_land",
+    "entity.ender_dragon.ambient" since 393,
+    "entity.ender_dragon.death" since 393,
+    "entity.ender_dragon.flap" since 393,
+    "entity.ender_dragon.growl" since 393,
+    "entity.ender_dragon.hurt" since 393,
+    "entity.ender_dragon.shoot" since 393,
+    "entity.ender_eye.death" since 393,
+    "entity.ender_eye.launch" since 393,
+    "entity.ender_pearl.throw" since 393,
+    "entity.enderdragon.ambient" until 393,
+    "entity.enderdragon.death" until 393,
+    "entity.enderdragon.flap" until 393,
+    "entity.enderdragon.growl" until 393,
+    "entity.enderdragon.hurt" until 393,
+    "entity.enderdragon.shoot" until 393,
+    "entity.enderdragon_fireball.explode" until 393,
+    "entity.endereye.death" since 315 until 393,
+    "entity.endereye.launch" until 393,
+    "entity.enderman.ambient" since 393,
+    "entity.enderman.death" since 393,
+    "entity.enderman.hurt" since 393,
+    "entity.enderman.scream" since 393,
+    "entity.enderman.stare" since 393,
+    "entity.enderman.teleport" since 393,
+    "entity.endermen.ambient" until 393,
+    "entity.endermen.death" until 393,
+    "entity.endermen.hurt" until 393,
+    "entity.endermen.scream" until 393,
+    "entity.endermen.stare" until 393,
+    "entity.endermen.teleport" until 393,
+    "entity.endermite.ambient",
+    "entity.endermite.death",
+    "entity.endermite.hurt",
+    "entity.endermite.step",
+    "entity.enderpearl.throw" until 393,
+    "entity.evocation_fangs.attack" since 315 until 393,
+    "entity.evocation_illager.ambient" since 315 until 393,
+    "entity.evocation_illager.cast_spell" since 315 until 393,
+    "entity.evocation_illager.death" since 315 until 393,
+    "entity.evocation_illager.hurt" since 315 until 393,
+    "entity.evocation_illager.prepare_attack" since 315 until 393,
+    "entity.evocation_illager.prepare_summon" since 315 until 393,
+    "entity.evocation_illager.prepare_wololo" since 315 until 393,
+    "entity.evoker.ambient" since 393,
+    "entity.evoker.cast_spell" since 393,
+    "entity.evoker.celebrate" since 477,
+    "entity.evoker.death" since 393,
+    "entity.evoker.hurt" since 393,
+    "entity.evoker.prepare_attack" since 393,
+    "entity.evoker.prepare_summon" since 393,
+    "entity.evoker.prepare_wololo" since 393,
+    "entity.evoker_fangs.attack" since 393,
+    "entity.experience_bottle.throw",
+    "entity.experience_orb.pickup",
+    "entity.firework.blast" until 393,
+    "entity.firework.blast_far" until 393,
+    "entity.firework.large_blast" until 393,
+    "entity.firework.large_blast_far" until 393,
+    "entity.firework.launch" until 393,
+    "entity.firework.shoot" until 393,
+    "entity.firework.twinkle" until 393,
+    "entity.firework.twinkle_far" until 393,
+    "entity.firework_rocket.blast" since 393,
+    "entity.firework_rocket.blast_far" since 393,
+    "entity.firework_rocket.large_blast" since 393,
+    "entity.firework_rocket.large_blast_far" since 393,
+    "entity.firework_rocket.launch" since 393,
+    "entity.firework_rocket.shoot" since 393,
+    "entity.firework_rocket.twinkle" since 393,
+    "entity.firework_rocket.twinkle_far" since 393,
+    "entity.fish.swim" since 393,
+    "entity.fishing_bobber.retrieve" since 393,
+    "entity.fishing_bobber.splash" since 393,
+    "entity.fishing_bobber.throw" since 393,
+    "entity.fox.aggro" since 477,
+    "entity.fox.ambient" since 477,
+    "entity.fox.bite" since 477,
+    "entity.fox.death" since 477,
+    "entity.fox.eat" since 477,
+    "entity.fox.hurt" since 477,
+    "entity.fox.screech" since 477,
+    "entity.fox.sleep" since 477,
+    "entity.fox.sniff" since 477,
+    "entity.fox.spit" since 477,
+    "entity.generic.big_fall",
+    "entity.generic.burn",
+    "entity.generic.death",
+    "entity.generic.drink",
+    "entity.generic.eat",
+    "entity.generic.explode",
+    "entity.generic.extinguish_fire",
+    "entity.generic.hurt",
+    "entity.generic.small_fall",
+    "entity.generic.splash",
+    "entity.generic.swim",
+    "entity.ghast.ambient",
+    "entity.ghast.death",
+    "entity.ghast.hurt",
+    "entity.ghast.scream",
+    "entity.ghast.shoot",
+    "entity.ghast.warn",
+    "entity.glow_item_frame.add_item" since 755,
+    "entity.glow_item_frame.break" since 755,
+    "entity.glow_item_frame.place" since 755,
+    "entity.glow_item_frame.remove_item" since 755,
+    "entity.glow_item_frame.rotate_item" since 755,
+    "entity.glow_squid.ambient" since 755,
+    "entity.glow_squid.death" since 755,
+    "entity.glow_squid.hurt" since 755,
+    "entity.glow_squid.squirt" since 755,
+    "entity.goat.ambient" since 755,
+    "entity.goat.death" since 755,
+    "entity.goat.eat" since 755,
+    "entity.goat.hurt" since 755,
+    "entity.goat.long_jump" since 755,
+    "entity.goat.milk" since 755,
+    "entity.goat.prepare_ram" since 755,
+    "entity.goat.ram_impact" since 755,
+    "entity.goat.screaming.ambient" since 755,
+    "entity.goat.screaming.death" since 755,
+    "entity.goat.screaming.eat" since 755,
+    "entity.goat.screaming.hurt" since 755,
+    "entity.goat.screaming.long_jump" since 755,
+    "entity.goat.screaming.milk" since 755,
+    "entity.goat.screaming.prepare_ram" since 755,
+    "entity.goat.screaming.ram_impact" since 755,
+    "entity.goat.step" since 755,
+    "entity.guardian.ambient",
+    "entity.guardian.ambient_land",
+    "entity.guardian.attack",
+    "entity.guardian.death",
+    "entity.guardian.death_land",
+    "entity.guardian.flop",
+    "entity.guardian.hurt",
+    "entity.guardian.hurt_land",
+    "entity.hoglin.ambient" since 735,
+    "entity.hoglin.angry" since 735,
+    "entity.hoglin.attack" since 735,
+    "entity.hoglin.converted_to_zombified" since 735,
+    "entity.hoglin.death" since 735,
+    "entity.hoglin.hurt" since 735,
+    "entity.hoglin.retreat" since 735,
+    "entity.hoglin.step" since 735,
+    "entity.horse.ambient",
+    "entity.horse.angry",
+    "entity.horse.armor",
+    "entity.horse.breathe",
+    "entity.horse.death",
+    "entity.horse.eat",
+    "entity.horse.gallop",
+    "entity.horse.hurt",
+    "entity.horse.jump",
+    "entity.horse.land",
+    "entity.horse.saddle",
+    "entity.horse.step",
+    "entity.horse.step_wood",
+    "entity.hostile.big_fall",
+    "entity.hostile.death",
+    "entity.hostile.hurt",
+    "entity.hostile.small_fall",
+    "entity.hostile.splash",
+    "entity.hostile.swim",
+    "entity.husk.ambient" since 210,
+    "entity.husk.converted_to_zombie" since 393,
+    "entity.husk.death" since 210,
+    "entity.husk.hurt" since 210,
+    "entity.husk.step" since 210,
+    "entity.illusion_illager.ambient" since 335 until 393,
+    "entity.illusion_illager.cast_spell" since 335 until 393,
+    "entity.illusion_illager.death" since 335 until 393,
+    "entity.illusion_illager.hurt" since 335 until 393,
+    "entity.illusion_illager.mirror_move" since 335 until 393,
+    "entity.illusion_illager.prepare_blindness" since 335 until 393,
+    "entity.illusion_illager.prepare_mirror" since 335 until 393,
+    "entity.illusioner.ambient" since 393,
+    "entity.illusioner.cast_spell" since 393,
+    "entity.illusioner.death" since 393,
+    "entity.illusioner.hurt" since 393,
+    "entity.illusioner.mirror_move" since 393,
+    "entity.illusioner.prepare_blindness" since 393,
+    "entity.illusioner.prepare_mirror" since 393,
+    "entity.iron_golem.attack" since 393,
+    "entity.iron_golem.damage" since 573,
+    "entity.iron_golem.death" since 393,
+    "entity.iron_golem.hurt" since 393,
+    "entity.iron_golem.repair" since 573,
+    "entity.iron_golem.step" since 393,
+    "entity.irongolem.attack" until 393,
+    "entity.irongolem.death" until 393,
+    "entity.irongolem.hurt" until 393,
+    "entity.irongolem.step" until 393,
+    "entity.item.break",
+    "entity.item.pickup",
+    "entity.item_frame.add_item" since 393,
+    "entity.item_frame.break" since 393,
+    "entity.item_frame.place" since 393,
+    "entity.item_frame.remove_item" since 393,
+    "entity.item_frame.rotate_item" since 393,
+    "entity.itemframe.add_item" until 393,
+    "entity.itemframe.break" until 393,
+    "entity.itemframe.place" until 393,
+    "entity.itemframe.remove_item" until 393,
+    "entity.itemframe.rotate_item" until 393,
+    "entity.leash_knot.break" since 393,
+    "entity.leash_knot.place" since 393,
+    "entity.leashknot.break" until 393,
+    "entity.leashknot.place" until 393,
+    "entity.lightning.impact" until 393,
+    "entity.lightning.thunder" until 393,
+    "entity.lightning_bolt.impact" since 393,
+    "entity.lightning_bolt.thunder" since 393,
+    "entity.lingering_potion.throw" since 393,
+    "entity.lingeringpotion.throw" until 393,
+    "entity.llama.ambient" since 315,
+    "entity.llama.angry" since 315,
+    "entity.llama.chest" since 315,
+    "entity.llama.death" since 315,
+    "entity.llama.eat" since 315,
+    "entity.llama.hurt" since 315,
+    "entity.llama.spit" since 315,
+    "entity.llama.step" since 315,
+    "entity.llama.swag" since 315,
+    "entity.magma_cube.death" since 393,
+    "entity.magma_cube.death_small" since 393,
+    "entity.magma_cube.hurt" since 393,
+    "entity.magma_cube.hurt_small" since 393,
+    "entity.magma_cube.jump" since 393,
+    "entity.magma_cube.squish" since 393,
+    "entity.magma_cube.squish_small" since 393,
+    "entity.magmacube.death" until 393,
+    "entity.magmacube.hurt" until 393,
+    "entity.magmacube.jump" until 393,
+    "entity.magmacube.squish" until 393,
+    "entity.minecart.inside",
+    "entity.minecart.riding",
+    "entity.mooshroom.convert" since 477,
+    "entity.mooshroom.eat" since 477,
+    "entity.mooshroom.milk" since 477,
+    "entity.mooshroom.shear",
+    "entity.mooshroom.suspicious_milk" since 477,
+    "entity.mule.ambient",
+    "entity.mule.chest" since 315,
+    "entity.mule.death",
+    "entity.mule.hurt",
+    "entity.ocelot.ambient" since 477,
+    "entity.ocelot.death" since 477,
+    "entity.ocelot.hurt" since 477,
+    "entity.painting.break",
+    "entity.painting.place",
+    "entity.panda.aggressive_ambient" since 477,
+    "entity.panda.ambient" since 477,
+    "entity.panda.bite" since 477,
+    "entity.panda.cant_breed" since 477,
+    "entity.panda.death" since 477,
+    "entity.panda.eat" since 477,
+    "entity.panda.hurt" since 477,
+    "entity.panda.pre_sneeze" since 477,
+    "entity.panda.sneeze" since 477,
+    "entity.panda.step" since 477,
+    "entity.panda.worried_ambient" since 477,
+    "entity.parrot.ambient" since 335,
+    "entity.parrot.death" since 335,
+    "entity.parrot.eat" since 335,
+    "entity.parrot.fly" since 335,
+    "entity.parrot.hurt" since 335,
+    "entity.parrot.imitate.blaze" since 335,
+    "entity.parrot.imitate.creeper" since 335,
+    "entity.parrot.imitate.drowned" since 393,
+    "entity.parrot.imitate.elder_guardian" since 335,
+    "entity.parrot.imitate.ender_dragon" since 393,
+    "entity.parrot.imitate.enderdragon" since 335 until 393,
+    "entity.parrot.imitate.enderman" since 335 until 573,
+    "entity.parrot.imitate.endermite" since 335,
+    "entity.parrot.imitate.evocation_illager" since 335 until 393,
+    "entity.parrot.imitate.evoker" since 393,
+    "entity.parrot.imitate.ghast" since 335,
+    "entity.parrot.imitate.guardian" since 573,
+    "entity.parrot.imitate.hoglin" since 735,
+    "entity.parrot.imitate.husk" since 335,
+    "entity.parrot.imitate.illusion_illager" since 335 until 393,
+    "entity.parrot.imitate.illusioner" since 393,
+    "entity.parrot.imitate.magma_cube" since 393,
+    "entity.parrot.imitate.magmacube" since 335 until 393,
+    "entity.parrot.imitate.panda" since 477 until 573,
+    "entity.parrot.imitate.phantom" since 393,
+    "entity.parrot.imitate.piglin" since 735,
+    "entity.parrot.imitate.piglin_brute" since 751,
+    "entity.parrot.imitate.pillager" since 477,
+    "entity.parrot.imitate.polar_bear" since 335 until 573,
+    "entity.parrot.imitate.ravager" since 477,
+    "entity.parrot.imitate.shulker" since 335,
+    "entity.parrot.imitate.silverfish" since 335,
+    "entity.parrot.imitate.skeleton" since 335,
+    "entity.parrot.imitate.slime" since 335,
+    "entity.parrot.imitate.spider" since 335,
+    "entity.parrot.imitate.stray" since 335,
+    "entity.parrot.imitate.vex" since 335,
+    "entity.parrot.imitate.vindication_illager" since 335 until 393,
+    "entity.parrot.imitate.vindicator" since 393,
+    "entity.parrot.imitate.witch" since 335,
+    "entity.parrot.imitate.wither" since 335,
+    "entity.parrot.imitate.wither_skeleton" since 335,
+    "entity.parrot.imitate.wolf" since 335 until 573,
+    "entity.parrot.imitate.zoglin" since 735,
+    "entity.parrot.imitate.zombie" since 335,
+    "entity.parrot.imitate.zombie_pigman" since 335 until 573,
+    "entity.parrot.imitate.zombie_villager" since 335,
+    "entity.parrot.step" since 335,
+    "entity.phantom.ambient" since 393,
+    "entity.phantom.bite" since 393,
+    "entity.phantom.death" since 393,
+    "entity.phantom.flap" since 393,
+    "entity.phantom.hurt" since 393,
+    "entity.phantom.swoop" since 393,
+    "entity.pig.ambient",
+    "entity.pig.death",
+    "entity.pig.hurt",
+    "entity.pig.saddle",
+    "entity.pig.step",
+    "entity.piglin.admiring_item" since 735,
+    "entity.piglin.ambient" since 735,
+    "entity.piglin.angry" since 735,
+    "entity.piglin.celebrate" since 735,
+    "entity.piglin.converted_to_zombified" since 735,
+    "entity.piglin.death" since 735,
+    "entity.piglin.hurt" since 735,
+    "entity.piglin.jealous" since 735,
+    "entity.piglin.retreat" since 735,
+    "entity.piglin.step" since 735,
+    "entity.piglin_brute.ambient" since 751,
+    "entity.piglin_brute.angry" since 751,
+    "entity.piglin_brute.converted_to_zombified" since 751,
+    "entity.piglin_brute.death" since 751,
+    "entity.piglin_brute.hurt" since 751,
+    "entity.piglin_brute.step" since 751,
+    "entity.pillager.ambient" since 477,
+    "entity.pillager.celebrate" since 477,
+    "entity.pillager.death" since 477,
+    "entity.pillager.hurt" since 477,
+    "entity.player.attack.crit",
+    "entity.player.attack.knockback",
+    "entity.player.attack.nodamage",
+    "entity.player.attack.strong",
+    "entity.player.attack.sweep",
+    "entity.player.attack.weak",
+    "entity.player.breath",
+    "entity.player.burp",
+    "entity.player.death",
+    "entity.player.hurt",
+    "entity.player.hurt_drown" since 335,
+    "entity.player.hurt_freeze" since 755,
+    "entity.player.hurt_on_fire" since 335,
+    "entity.player.hurt_sweet_berry_bush" since 477,
+    "entity.player.levelup",
+    "entity.player.small_fall",
+    "entity.player.splash",
+    "entity.player.splash.high_speed" since 393,
+    "entity.player.swim",
+    "entity.polar_bear.ambient" since 210,
+    "entity.polar_bear.ambient_baby" since 393,
+    "entity.polar_bear.baby_ambient" since 210 until 393,
+    "entity.polar_bear.death" since 210,
+    "entity.polar_bear.hurt" since 210,
+    "entity.polar_bear.step" since 210,
+    "entity.polar_bear.warning" since 210,
+    "entity.puffer_fish.ambient" since 393,
+    "entity.puffer_fish.blow_out" since 393,
+    "entity.puffer_fish.blow_up" since 393,
+    "entity.puffer_fish.death" since 393,
+    "entity.puffer_fish.flop" since 393,
+    "entity.puffer_fish.hurt" since 393,
+    "entity.puffer_fish.sting" since 393,
+    "entity.rabbit.ambient",
+    "entity.rabbit.attack",
+    "entity.rabbit.death",
+    "entity.rabbit.hurt",
+    "entity.rabbit.jump",
+    "entity.ravager.ambient" since 477,
+    "entity.ravager.attack" since 477,
+    "entity.ravager.celebrate" since 477,
+    "entity.ravager.death" since 477,
+    "entity.ravager.hurt" since 477,
+    "entity.ravager.roar" since 477,
+    "entity.ravager.step" since 477,
+    "entity.ravager.stunned" since 477,
+    "entity.salmon.ambient" since 393,
+    "entity.salmon.death" since 393,
+    "entity.salmon.flop" since 393,
+    "entity.salmon.hurt" since 393,
+    "entity.sheep.ambient",
+    "entity.sheep.death",
+    "entity.sheep.hurt",
+    "entity.sheep.shear",
+    "entity.sheep.step",
+    "entity.shulker.ambient",
+    "entity.shulker.close",
+    "entity.shulker.death",
+    "entity.shulker.hurt",
+    "entity.shulker.hurt_closed",
+    "entity.shulker.open",
+    "entity.shulker.shoot",
+    "entity.shulker.teleport",
+    "entity.shulker_bullet.hit",
+    "entity.shulker_bullet.hurt",
+    "entity.silverfish.ambient",
+    "entity.silverfish.death",
+    "entity.silverfish.hurt",
+    "entity.silverfish.step",
+    "entity.skeleton.ambient",
+    "entity.skeleton.converted_to_stray" since 755,
+    "entity.skeleton.death",
+    "entity.skeleton.hurt",
+    "entity.skeleton.shoot",
+    "entity.skeleton.step",
+    "entity.skeleton_horse.ambient",
+    "entity.skeleton_horse.ambient_water" since 393,
+    "entity.skeleton_horse.death",
+    "entity.skeleton_horse.gallop_water" since 393,
+    "entity.skeleton_horse.hurt",
+    "entity.skeleton_horse.jump_water" since 393,
+    "entity.skeleton_horse.step_water" since 393,
+    "entity.skeleton_horse.swim" since 393,
+    "entity.slime.attack",
+    "entity.slime.death",
+    "entity.slime.death_small" since 393,
+    "entity.slime.hurt",
+    "entity.slime.hurt_small" since 393,
+    "entity.slime.jump",
+    "entity.slime.jump_small" since 393,
+    "entity.slime.squish",
+    "entity.slime.squish_small" since 393,
+    "entity.small_magmacube.death" until 393,
+    "entity.small_magmacube.hurt" until 393,
+    "entity.small_magmacube.squish" until 393,
+    "entity.small_slime.death" until 393,
+    "entity.small_slime.hurt" until 393,
+    "entity.small_slime.jump" until 393,
+    "entity.small_slime.squish" until 393,
+    "entity.snow_golem.ambient" since 393,
+    "entity.snow_golem.death" since 393,
+    "entity.snow_golem.hurt" since 393,
+    "entity.snow_golem.shear" since 735,
+    "entity.snow_golem.shoot" since 393,
+    "entity.snowball.throw",
+    "entity.snowman.ambient" until 393,
+    "entity.snowman.death" until 393,
+    "entity.snowman.hurt" until 393,
+    "entity.snowman.shoot" until 393,
+    "entity.spider.ambient",
+    "entity.spider.death",
+    "entity.spider.hurt",
+    "entity.spider.step",
+    "entity.splash_potion.break",
+    "entity.splash_potion.throw",
+    "entity.squid.ambient",
+    "entity.squid.death",
+    "entity.squid.hurt",
+    "entity.squid.squirt" since 393,
+    "entity.stray.ambient" since 210,
+    "entity.stray.death" since 210,
+    "entity.stray.hurt" since 210,
+    "entity.stray.step" since 210,
+    "entity.strider.ambient" since 735,
+    "entity.strider.death" since 735,
+    "entity.strider.eat" since 735,
+    "entity.strider.happy" since 735,
+    "entity.strider.hurt" since 735,
+    "entity.strider.retreat" since 735,
+    "entity.strider.saddle" since 735,
+    "entity.strider.step" since 735,
+    "entity.strider.step_lava" since 735,
+    "entity.tnt.primed",
+    "entity.tropical_fish.ambient" since 393,
+    "entity.tropical_fish.death" since 393,
+    "entity.tropical_fish.flop" since 393,
+    "entity.tropical_fish.hurt" since 393,
+    "entity.turtle.ambient_land" since 393,
+    "entity.turtle.death" since 393,
+    "entity.turtle.death_baby" since 393,
+    "entity.turtle.egg_break" since 393,
+    "entity.turtle.egg_crack" since 393,
+    "entity.turtle.egg_hatch" since 393,
+    "entity.turtle.hurt" since 393,
+    "entity.turtle.hurt_baby" since 393,
+    "entity.turtle.lay_egg" since 393,
+    "entity.turtle.shamble" since 393,
+    "entity.turtle.shamble_baby" since 393,
+    "entity.turtle.swim" since 393,
+    "entity.vex.ambient" since 315,
+    "entity.vex.charge" since 315,
+    "entity.vex.death" since 315,
+    "entity.vex.hurt" since 315,
+    "entity.villager.ambient",
+    "entity.villager.celebrate" since 477,
+    "entity.villager.death",
+    "entity.villager.hurt",
+    "entity.villager.no",
+    "entity.villager.trade" since 477,
+    "entity.villager.trading" until 477,
+    "entity.villager.work_armorer" since 477,
+    "entity.villager.work_butcher" since 477,
+    "entity.villager.work_cartographer" since 477,
+    "entity.villager.work_cleric" since 477,
+    "entity.villager.work_farmer" since 477,
+    "entity.villager.work_fisherman" since 477,
+    "entity.villager.work_fletcher" since 477,
+    "entity.villager.work_leatherworker" since 477,
+    "entity.villager.work_librarian" since 477,
+    "entity.villager.work_mason" since 477,
+    "entity.villager.work_shepherd" since 477,
+    "entity.villager.work_toolsmith" since 477,
+    "entity.villager.work_weaponsmith" since 477,
+    "entity.villager.yes",
+    "entity.vindication_illager.ambient" since 315 until 393,
+    "entity.vindication_illager.death" since 315 until 393,
+    "entity.vindication_illager.hurt" since 315 until 393,
+    "entity.vindicator.ambient" since 393,
+    "entity.vindicator.celebrate" since 477,
+    "entity.vindicator.death" since 393,
+    "entity.vindicator.hurt" since 393,
+    "entity.wandering_trader.ambient" since 477,
+    "entity.wandering_trader.death" since 477,
+    "entity.wandering_trader.disappeared" since 477,
+    "entity.wandering_trader.drink_milk" since 477,
+    "entity.wandering_trader.drink_potion" since 477,
+    "entity.wandering_trader.hurt" since 477,
+    "entity.wandering_trader.no" since 477,
+    "entity.wandering_trader.reappeared" since 477,
+    "entity.wandering_trader.trade" since 477,
+    "entity.wandering_trader.yes" since 477,
+    "entity.witch.ambient",
+    "entity.witch.celebrate" since 477,
+    "entity.witch.death",
+    "entity.witch.drink",
+    "entity.witch.hurt",
+    "entity.witch.throw",
+    "entity.wither.ambient",
+    "entity.wither.break_block",
+    "entity.wither.death",
+    "entity.wither.hurt",
+    "entity.wither.shoot",
+    "entity.wither.spawn",
+    "entity.wither_skeleton.ambient",
+    "entity.wither_skeleton.death",
+    "entity.wither_skeleton.hurt",
+    "entity.wither_skeleton.step",
+    "entity.wolf.ambient",
+    "entity.wolf.death",
+    "entity.wolf.growl",
+    "entity.wolf.howl",
+    "entity.wolf.hurt",
+    "entity.wolf.pant",
+    "entity.wolf.shake",
+    "entity.wolf.step",
+    "entity.wolf.whine",
+    "entity.zoglin.ambient" since 735,
+    "entity.zoglin.angry" since 735,
+    "entity.zoglin.attack" since 735,
+    "entity.zoglin.death" since 735,
+    "entity.zoglin.hurt" since 735,
+    "entity.zoglin.step" since 735,
+    "entity.zombie.ambient",
+    "entity.zombie.attack_door_wood" until 393,
+    "entity.zombie.attack_iron_door",
+    "entity.zombie.attack_wooden_door" since 393,
+    "entity.zombie.break_door_wood" until 393,
+    "entity.zombie.break_wooden_door" since 393,
+    "entity.zombie.converted_to_drowned" since 393,
+    "entity.zombie.death",
+    "entity.zombie.destroy_egg" since 393,
+    "entity.zombie.hurt",
+    "entity.zombie.infect",
+    "entity.zombie.step",
+    "entity.zombie_horse.ambient",
+    "entity.zombie_horse.death",
+    "entity.zombie_horse.hurt",
+    "entity.zombie_pig.ambient" until 393,
+    "entity.zombie_pig.angry" until 393,
+    "entity.zombie_pig.death" until 393,
+    "entity.zombie_pig.hurt" until 393,
+    "entity.zombie_pigman.ambient" since 393 until 735,
+    "entity.zombie_pigman.angry" since 393 until 735,
+    "entity.zombie_pigman.death" since 393 until 735,
+    "entity.zombie_pigman.hurt" since 393 until 735,
+    "entity.zombie_villager.ambient",
+    "entity.zombie_villager.converted",
+    "entity.zombie_villager.cure",
+    "entity.zombie_villager.death",
+    "entity.zombie_villager.hurt",
+    "entity.zombie_villager.step",
+    "entity.zombified_piglin.ambient" since 735,
+    "entity.zombified_piglin.angry" since 735,
+    "entity.zombified_piglin.death" since 735,
+    "entity.zombified_piglin.hurt" since 735,
+    "event.raid.horn" since 477,
+    "item.armor.equip_chain",
+    "item.armor.equip_diamond",
+    "item.armor.equip_elytra" since 315,
+    "item.armor.equip_generic",
+    "item.armor.equip_gold",
+    "item.armor.equip_iron",
+    "item.armor.equip_leather",
+    "item.armor.equip_netherite" since 735,
+    "item.armor.equip_turtle" since 393,
+    "item.axe.scrape" since 755,
+    "item.axe.strip" since 393,
+    "item.axe.wax_off" since 755,
+    "item.bone_meal.use" since 755,
+    "item.book.page_turn" since 477,
+    "item.book.put" since 477,
+    "item.bottle.empty" since 315,
+    "item.bottle.fill",
+    "item.bottle.fill_dragonbreath",
+    "item.bucket.empty",
+    "item.bucket.empty_axolotl" since 755,
+    "item.bucket.empty_fish" since 393,
+    "item.bucket.empty_lava",
+    "item.bucket.empty_powder_snow" since 755,
+    "item.bucket.fill",
+    "item.bucket.fill_axolotl" since 755,
+    "item.bucket.fill_fish" since 393,
+    "item.bucket.fill_lava",
+    "item.bucket.fill_powder_snow" since 755,
+    "item.bundle.drop_contents" since 755,
+    "item.bundle.insert" since 755,
+    "item.bundle.remove_one" since 755,
+    "item.chorus_fruit.teleport",
+    "item.crop.plant" since 477,
+    "item.crossbow.hit" since 477,
+    "item.crossbow.loading_end" since 477,
+    "item.crossbow.loading_middle" since 477,
+    "item.crossbow.loading_start" since 477,
+    "item.crossbow.quick_charge_1" since 477,
+    "item.crossbow.quick_charge_2" since 477,
+    "item.crossbow.quick_charge_3" since 477,
+    "item.crossbow.shoot" since 477,
+    "item.dye.use" since 755,
+    "item.elytra.flying",
+    "item.firecharge.use",
+    "item.flintandsteel.use",
+    "item.glow_ink_sac.use" since 755,
+    "item.hoe.till",
+    "item.honey_bottle.drink" since 573,
+    "item.honeycomb.wax_on" since 755,
+    "item.ink_sac.use" since 755,
+    "item.lodestone_compass.lock" since 735,
+    "item.nether_wart.plant" since 477,
+    "item.shield.block",
+    "item.shield.break",
+    "item.shovel.flatten",
+    "item.spyglass.stop_using" since 755,
+    "item.spyglass.use" since 755,
+    "item.sweet_berries.pick_from_bush" since 477,
+    "item.totem.use" since 315,
+    "item.trident.hit" since 393,
+    "item.trident.hit_ground" since 393,
+    "item.trident.return" since 393,
+    "item.trident.riptide_1" since 393,
+    "item.trident.riptide_2" since 393,
+    "item.trident.riptide_3" since 393,
+    "item.trident.throw" since 393,
+    "item.trident.thunder" since 393,
+    "music.creative",
+    "music.credits",
+    "music.dragon",
+    "music.end",
+    "music.game",
+    "music.menu",
+    "music.nether" until 735,
+    "music.nether.basalt_deltas" since 735,
+    "music.nether.crimson_forest" since 735,
+    "music.nether.nether_wastes" since 735,
+    "music.nether.soul_sand_valley" since 735,
+    "music.nether.warped_forest" since 735,
+    "music.under_water" since 393,
+    "music_disc.11" since 393,
+    "music_disc.13" since 393,
+    "music_disc.blocks" since 393,
+    "music_disc.cat" since 393,
+    "music_disc.chirp" since 393,
+    "music_disc.far" since 393,
+    "music_disc.mall" since 393,
+    "music_disc.mellohi" since 393,
+    "music_disc.pigstep" since 735,
+    "music_disc.stal" since 393,
+    "music_disc.strad" since 393,
+    "music_disc.wait" since 393,
+    "music_disc.ward" since 393,
+    "particle.soul_escape" since 735,
+    "record.11" until 393,
+    "record.13" until 393,
+    "record.blocks" until 393,
+    "record.cat" until 393,
+    "record.chirp" until 393,
+    "record.far" until 393,
+    "record.mall" until 393,
+    "record.mellohi" until 393,
+    "record.stal" until 393,
+    "record.strad" until 393,
+    "record.wait" until 393,
+    "record.ward" until 393,
+    "ui.button.click",
+    "ui.cartography_table.take_result" since 477,
+    "ui.loom.select_pattern" since 477,
+    "ui.loom.take_result" since 477,
+    "ui.stonecutter.select_recipe" since 477,
+    "ui.stonecutter.take_result" since 477,
+    "ui.toast.challenge_complete" since 335,
+    "ui.toast.in" since 335,
+    "ui.toast.out" since 335,
+    "weather.rain",
+    "weather.rain.above",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id_of(name: &str, protocol_version: i32) -> Option<i32> {
+        (0..)
+            .map(|id| name_by_id(id, protocol_version))
+            .take_while(Option::is_some)
+            .position(|n| n == Some(name))
+            .map(|id| id as i32)
+    }
+
+    #[test]
+    fn first_and_last() {
+        for &version in &[107, 210, 316, 340, 404, 498, 578, 754, 756] {
+            assert_eq!(name_by_id(0, version), Some("ambient.cave"));
+            assert_eq!(name_by_id(-1, version), None);
+            assert_eq!(name_by_id(2000, version), None);
+        }
+        assert_eq!(name_by_id(1, 340), Some("block.anvil.break"));
+        assert_eq!(name_by_id(1, 404), Some("ambient.underwater.enter"));
+        assert_eq!(name_by_id(1, 754), Some("ambient.basalt_deltas.additions"));
+    }
+
+    #[test]
+    fn versions() {
+        assert!(id_of("block.cloth.break", 340).is_some());
+        assert_eq!(id_of("block.wool.break", 340), None);
+        assert!(id_of("block.wool.break", 404).is_some());
+        assert_eq!(id_of("block.cloth.break", 404), None);
+
+        assert_eq!(id_of("entity.llama.spit", 210), None);
+        assert!(id_of("entity.llama.spit", 316).is_some());
+        assert_eq!(id_of("entity.zombie_pigman.hurt", 754), None);
+        assert!(id_of("entity.zombified_piglin.hurt", 754).is_some());
+
+        // Events added by a version shift the ones after them
+        let before = id_of("weather.rain", 578).unwrap();
+        let after = id_of("weather.rain", 754).unwrap();
+        assert!(after > before);
+    }
+
+    #[test]
+    fn legacy_names() {
+        assert_eq!(
+            legacy_name("block.wool.break").as_deref(),
+            Some("block.cloth.break")
+        );
+        assert_eq!(
+            legacy_name("entity.evoker_fangs.attack").as_deref(),
+            Some("entity.evocation_fangs.attack")
+        );
+        assert_eq!(
+            legacy_name("entity.evoker.ambient").as_deref(),
+            Some("entity.evocation_illager.ambient")
+        );
+        assert_eq!(
+            legacy_name("entity.slime.jump_small").as_deref(),
+            Some("entity.small_slime.jump")
+        );
+        assert_eq!(legacy_name("music_disc.cat").as_deref(), Some("record.cat"));
+        assert_eq!(legacy_name("block.stone.break"), None);
+        assert_eq!(legacy_name("block.woolen"), None);
+
+        // Renamed events exist under their old name in 1.12.2, which is
+        // the version of the resources the client loads
+        for &(new, old) in RENAMED {
+            let old_event = SOUND_EVENTS.iter().any(|event| {
+                event.since <= 340 && 340 < event.until && event.name.starts_with(old)
+            });
+            let new_event = SOUND_EVENTS
+                .iter()
+                .any(|event| event.since >= 393 && event.name.starts_with(new));
+            assert!(old_event && new_event, "{} -> {}", new, old);
+        }
+    }
+}
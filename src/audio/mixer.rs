@@ -0,0 +1,305 @@
+use super::Category;
+use cgmath::{InnerSpace, Vector3};
+use std::sync::Arc;
+
+/// A decoded sound, downmixed to mono
+pub struct SoundBuffer {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+/// Where the sounds are heard from
+#[derive(Clone, Copy)]
+pub struct Listener {
+    pub position: Vector3<f64>,
+    /// Points to the listener's right, used to pan sounds
+    pub right: Vector3<f64>,
+}
+
+impl Default for Listener {
+    fn default() -> Self {
+        Listener {
+            position: Vector3::new(0.0, 0.0, 0.0),
+            right: Vector3::new(1.0, 0.0, 0.0),
+        }
+    }
+}
+
+/// A sound that is currently playing
+pub struct Source {
+    pub buffer: Arc<SoundBuffer>,
+    pub name: String,
+    pub category: Category,
+    /// `None` for sounds that aren't positioned in the world
+    pub position: Option<Vector3<f64>>,
+    pub volume: f32,
+    pub pitch: f32,
+    /// Current position in the buffer, in (fractional) samples
+    cursor: f64,
+}
+
+impl Source {
+    pub fn new(
+        buffer: Arc<SoundBuffer>,
+        name: String,
+        category: Category,
+        position: Option<Vector3<f64>>,
+        volume: f32,
+        pitch: f32,
+    ) -> Source {
+        Source {
+            buffer,
+            name,
+            category,
+            position,
+            volume,
+            pitch,
+            cursor: 0.0,
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.cursor as usize >= self.buffer.samples.len()
+    }
+
+    /// The gain of each output channel for the listener
+    fn gains(&self, listener: &Listener, category_volume: f32) -> (f32, f32) {
+        let volume = self.volume.min(1.0) * category_volume;
+        let position = match self.position {
+            Some(position) => position,
+            None => return (volume, volume),
+        };
+        // Louder sounds can be heard from further away
+        let range = 16.0 * f64::from(self.volume.max(1.0));
+        let offset = position - listener.position;
+        let distance = offset.magnitude();
+        if distance >= range {
+            return (0.0, 0.0);
+        }
+        let volume = volume * (1.0 - distance / range) as f32;
+        let pan = if distance > 0.001 {
+            offset.normalize().dot(listener.right) as f32
+        } else {
+            0.0
+        };
+        // Constant power panning
+        (
+            volume * ((1.0 - pan) / 2.0).sqrt(),
+            volume * ((1.0 + pan) / 2.0).sqrt(),
+        )
+    }
+}
+
+/// Mixes the playing sources into interleaved stereo samples
+pub struct Mixer {
+    sample_rate: u32,
+    pub listener: Listener,
+    /// Volume for each category, including the master volume
+    pub volumes: [f32; Category::COUNT],
+    sources: Vec<Source>,
+}
+
+impl Mixer {
+    pub fn new(sample_rate: u32) -> Mixer {
+        Mixer {
+            sample_rate,
+            listener: Listener::default(),
+            volumes: [1.0; Category::COUNT],
+            sources: vec![],
+        }
+    }
+
+    /// Changes the rate the output plays samples at
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate;
+    }
+
+    pub fn play(&mut self, source: Source) {
+        self.sources.push(source);
+    }
+
+    /// Stops the sources matching the category and name, `None` matches
+    /// anything.
+    pub fn stop(&mut self, category: Option<Category>, name: Option<&str>) {
+        self.sources.retain(|source| {
+            let category_matches = match category {
+                Some(category) => source.category == category,
+                None => true,
+            };
+            let name_matches = match name {
+                Some(name) => source.name == name,
+                None => true,
+            };
+            !(category_matches && name_matches)
+        });
+    }
+
+    pub fn playing(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Fills `out` with interleaved stereo samples, advancing every
+    /// source. Finished sources are removed.
+    pub fn mix(&mut self, out: &mut [f32]) {
+        out.fill(0.0);
+        let listener = self.listener;
+        for source in &mut self.sources {
+            let (left, right) = source.gains(&listener, self.volumes[source.category as usize]);
+            let step = f64::from(source.buffer.sample_rate) / f64::from(self.sample_rate)
+                * f64::from(source.pitch);
+            let samples = &source.buffer.samples;
+            for frame in out.chunks_mut(2) {
+                let index = source.cursor as usize;
+                if index >= samples.len() {
+                    break;
+                }
+                // Linear interpolation between neighbouring samples
+                let next = samples.get(index + 1).copied().unwrap_or(0.0);
+                let t = source.cursor.fract() as f32;
+                let value = samples[index] * (1.0 - t) + next * t;
+                frame[0] += value * left;
+                if let Some(r) = frame.get_mut(1) {
+                    *r += value * right;
+                }
+                source.cursor += step;
+            }
+        }
+        self.sources.retain(|source| !source.is_finished());
+        for sample in out.iter_mut() {
+            *sample = sample.clamp(-1.0, 1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::output::{NullOutput, Output};
+    use std::sync::Mutex;
+
+    const RATE: u32 = 1000;
+
+    fn buffer(seconds: f64) -> Arc<SoundBuffer> {
+        Arc::new(SoundBuffer {
+            samples: vec![1.0; (seconds * f64::from(RATE)) as usize],
+            sample_rate: RATE,
+        })
+    }
+
+    fn source(name: &str, category: Category, position: Option<Vector3<f64>>) -> Source {
+        Source::new(buffer(1.0), name.to_owned(), category, position, 1.0, 1.0)
+    }
+
+    /// Mixes a single frame and returns its left and right samples
+    fn mix_frame(mixer: &mut Mixer) -> (f32, f32) {
+        let mut out = [0.0; 2];
+        mixer.mix(&mut out);
+        (out[0], out[1])
+    }
+
+    #[test]
+    fn finishes_with_null_output() {
+        let mut output = NullOutput::new();
+        let mixer = Arc::new(Mutex::new(Mixer::new(output.sample_rate())));
+        mixer
+            .lock()
+            .unwrap()
+            .play(source("a", Category::Block, None));
+        let mut pitched = source("b", Category::Block, None);
+        pitched.pitch = 2.0;
+        mixer.lock().unwrap().play(pitched);
+
+        let mut advance = |hundredths| {
+            for _ in 0..hundredths {
+                output.tick(&mixer, 0.01);
+            }
+            mixer.lock().unwrap().playing()
+        };
+        assert_eq!(advance(49), 2);
+        // The sound at double pitch finishes in half the time
+        assert_eq!(advance(2), 1);
+        assert_eq!(advance(47), 1);
+        assert_eq!(advance(3), 0);
+    }
+
+    #[test]
+    fn category_volume() {
+        let mut mixer = Mixer::new(RATE);
+        mixer.volumes[Category::Music as usize] = 0.25;
+        mixer.play(source("a", Category::Music, None));
+        let (left, right) = mix_frame(&mut mixer);
+        assert!((left - 0.25).abs() < 1e-6);
+        assert!((right - 0.25).abs() < 1e-6);
+
+        mixer.volumes[Category::Music as usize] = 0.0;
+        assert_eq!(mix_frame(&mut mixer), (0.0, 0.0));
+    }
+
+    #[test]
+    fn distance_falloff() {
+        let mut mixer = Mixer::new(RATE);
+        let gain = |mixer: &mut Mixer, x: f64| {
+            mixer.stop(None, None);
+            mixer.play(source(
+                "a",
+                Category::Block,
+                Some(Vector3::new(0.0, x, 0.0)),
+            ));
+            let (left, right) = mix_frame(mixer);
+            (left * left + right * right).sqrt()
+        };
+        let near = gain(&mut mixer, 0.0);
+        let half = gain(&mut mixer, 8.0);
+        let far = gain(&mut mixer, 16.0);
+        assert!((near - 1.0).abs() < 1e-6);
+        assert!((half - 0.5).abs() < 1e-6);
+        assert_eq!(far, 0.0);
+
+        // Sounds to the right are louder in the right channel
+        mixer.stop(None, None);
+        mixer.play(source(
+            "a",
+            Category::Block,
+            Some(Vector3::new(4.0, 0.0, 0.0)),
+        ));
+        let (left, right) = mix_frame(&mut mixer);
+        assert!(right > 0.0);
+        assert!(left.abs() < 1e-6);
+    }
+
+    #[test]
+    fn stop_matches_category_and_name() {
+        let mut mixer = Mixer::new(RATE);
+        let names = |mixer: &Mixer| {
+            let mut names: Vec<String> = mixer
+                .sources
+                .iter()
+                .map(|s| format!("{:?}:{}", s.category, s.name))
+                .collect();
+            names.sort();
+            names
+        };
+        let play_all = |mixer: &mut Mixer| {
+            mixer.stop(None, None);
+            mixer.play(source("a", Category::Block, None));
+            mixer.play(source("b", Category::Block, None));
+            mixer.play(source("a", Category::Music, None));
+        };
+
+        play_all(&mut mixer);
+        mixer.stop(Some(Category::Block), None);
+        assert_eq!(names(&mixer), vec!["Music:a"]);
+
+        play_all(&mut mixer);
+        mixer.stop(None, Some("a"));
+        assert_eq!(names(&mixer), vec!["Block:b"]);
+
+        play_all(&mut mixer);
+        mixer.stop(Some(Category::Block), Some("a"));
+        assert_eq!(names(&mixer), vec!["Block:b", "Music:a"]);
+
+        play_all(&mut mixer);
+        mixer.stop(None, None);
+        assert_eq!(mixer.playing(), 0);
+    }
+}
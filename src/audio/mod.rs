@@ -0,0 +1,325 @@
+//! Plays the sounds sent by the server, positioned relative to the camera.
+
+mod events;
+mod mixer;
+pub mod output;
+mod sounds;
+
+pub use self::events::name_by_id;
+
+use self::mixer::{Listener, Mixer, SoundBuffer, Source};
+use self::output::Output;
+use crate::console;
+use crate::render;
+use crate::resources;
+use crate::settings;
+use cgmath::{EuclideanSpace, Vector3};
+use log::{debug, warn};
+use rand::Rng;
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// The categories sounds are grouped into for their volume controls,
+/// in the order of their protocol ids.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Category {
+    Master,
+    Music,
+    Record,
+    Weather,
+    Block,
+    Hostile,
+    Neutral,
+    Player,
+    Ambient,
+    Voice,
+}
+
+impl Category {
+    pub const COUNT: usize = 10;
+
+    pub fn from_id(id: i32) -> Category {
+        match id {
+            1 => Category::Music,
+            2 => Category::Record,
+            3 => Category::Weather,
+            4 => Category::Block,
+            5 => Category::Hostile,
+            6 => Category::Neutral,
+            7 => Category::Player,
+            8 => Category::Ambient,
+            9 => Category::Voice,
+            _ => Category::Master,
+        }
+    }
+
+    fn volume_var(self) -> console::CVar<i64> {
+        match self {
+            Category::Master => settings::CL_MASTER_VOLUME,
+            Category::Music => settings::CL_MUSIC_VOLUME,
+            Category::Record => settings::CL_RECORD_VOLUME,
+            Category::Weather => settings::CL_WEATHER_VOLUME,
+            Category::Block => settings::CL_BLOCK_VOLUME,
+            Category::Hostile => settings::CL_HOSTILE_VOLUME,
+            Category::Neutral => settings::CL_NEUTRAL_VOLUME,
+            Category::Player => settings::CL_PLAYER_VOLUME,
+            Category::Ambient => settings::CL_AMBIENT_VOLUME,
+            Category::Voice => settings::CL_VOICE_VOLUME,
+        }
+    }
+
+    fn all() -> [Category; Category::COUNT] {
+        [
+            Category::Master,
+            Category::Music,
+            Category::Record,
+            Category::Weather,
+            Category::Block,
+            Category::Hostile,
+            Category::Neutral,
+            Category::Player,
+            Category::Ambient,
+            Category::Voice,
+        ]
+    }
+}
+
+/// A request from the server to start or stop sounds
+#[derive(Clone, Debug)]
+pub enum Command {
+    Play {
+        /// The sound event, or before 1.9 the sound file
+        name: String,
+        category: Category,
+        /// `None` for sounds that play at the listener
+        position: Option<Vector3<f64>>,
+        volume: f32,
+        pitch: f32,
+    },
+    /// Stops every sound matching the category and name, `None`
+    /// matches anything.
+    Stop {
+        category: Option<Category>,
+        name: Option<String>,
+    },
+}
+
+pub struct Manager {
+    resources: Arc<RwLock<resources::Manager>>,
+    resources_version: Option<usize>,
+    sounds: sounds::Sounds,
+    /// Decoded sound files, `None` for files that are missing or
+    /// failed to decode.
+    buffers: HashMap<String, Option<Arc<SoundBuffer>>>,
+
+    mixer: Arc<Mutex<Mixer>>,
+    output: Box<dyn Output>,
+}
+
+impl Manager {
+    /// Plays through the default audio device, falling back to
+    /// discarding the audio if there isn't one.
+    pub fn new(resources: Arc<RwLock<resources::Manager>>) -> Manager {
+        #[cfg(feature = "cpal")]
+        {
+            let mixer = Arc::new(Mutex::new(Mixer::new(0)));
+            match output::DeviceOutput::new(mixer.clone()) {
+                Some(output) => return Manager::with_output(resources, mixer, Box::new(output)),
+                None => warn!("No audio output device found, sounds are disabled"),
+            }
+        }
+        Manager::headless(resources)
+    }
+
+    /// Processes sounds without playing them
+    pub fn headless(resources: Arc<RwLock<resources::Manager>>) -> Manager {
+        Manager::with_output(
+            resources,
+            Arc::new(Mutex::new(Mixer::new(0))),
+            Box::new(output::NullOutput::new()),
+        )
+    }
+
+    fn with_output(
+        resources: Arc<RwLock<resources::Manager>>,
+        mixer: Arc<Mutex<Mixer>>,
+        output: Box<dyn Output>,
+    ) -> Manager {
+        mixer.lock().unwrap().set_sample_rate(output.sample_rate());
+        Manager {
+            resources,
+            resources_version: None,
+            sounds: Default::default(),
+            buffers: HashMap::new(),
+            mixer,
+            output,
+        }
+    }
+
+    /// The number of sounds currently playing
+    pub fn playing(&self) -> usize {
+        self.mixer.lock().unwrap().playing()
+    }
+
+    pub fn tick(
+        &mut self,
+        vars: &console::Vars,
+        camera: &render::Camera,
+        commands: Vec<Command>,
+        delta: f64,
+    ) {
+        self.reload_if_changed();
+
+        {
+            let mut mixer = self.mixer.lock().unwrap();
+            let master = *vars.get(settings::CL_MASTER_VOLUME) as f32 / 100.0;
+            for category in Category::all().iter().copied() {
+                let volume = if category == Category::Master {
+                    master
+                } else {
+                    master * *vars.get(category.volume_var()) as f32 / 100.0
+                };
+                mixer.volumes[category as usize] = volume.max(0.0);
+            }
+            // Same direction as the renderer's view vector, ignoring pitch
+            let yaw = camera.yaw - std::f64::consts::PI / 2.0;
+            mixer.listener = Listener {
+                position: camera.pos.to_vec(),
+                right: Vector3::new(yaw.sin(), 0.0, yaw.cos()),
+            };
+        }
+
+        for command in commands {
+            match command {
+                Command::Play {
+                    name,
+                    category,
+                    position,
+                    volume,
+                    pitch,
+                } => self.play(&name, category, position, volume, pitch),
+                Command::Stop { category, name } => {
+                    let name = name.as_deref().map(strip_namespace);
+                    self.mixer.lock().unwrap().stop(category, name);
+                }
+            }
+        }
+
+        // The game's delta is measured in 60ths of a second
+        self.output.tick(&self.mixer, delta / 60.0);
+    }
+
+    fn reload_if_changed(&mut self) {
+        let res = self.resources.read().unwrap();
+        if self.resources_version != Some(res.version()) {
+            self.resources_version = Some(res.version());
+            self.sounds = sounds::Sounds::load(&res);
+            self.buffers.clear();
+        }
+    }
+
+    fn play(
+        &mut self,
+        name: &str,
+        category: Category,
+        position: Option<Vector3<f64>>,
+        volume: f32,
+        pitch: f32,
+    ) {
+        let name = strip_namespace(name);
+        // The resources are from 1.12.2, so newer servers may use names
+        // they don't have yet.
+        let entry = self
+            .sounds
+            .pick(name)
+            .or_else(|| events::legacy_name(name).and_then(|legacy| self.sounds.pick(&legacy)));
+        let (file, volume, pitch) = match entry {
+            Some(entry) => (entry.name, volume * entry.volume, pitch * entry.pitch),
+            None => match self.legacy_file(name) {
+                Some(file) => (file, volume, pitch),
+                None => {
+                    debug!("Unknown sound {}", name);
+                    return;
+                }
+            },
+        };
+        if let Some(buffer) = self.buffer(&file) {
+            self.mixer.lock().unwrap().play(Source::new(
+                buffer,
+                name.to_owned(),
+                category,
+                position,
+                volume,
+                pitch,
+            ));
+        }
+    }
+
+    /// Before 1.9 sounds were named after their files (e.g. `step.stone`
+    /// for `step/stone1.ogg` to `step/stone4.ogg`), find the matching
+    /// file for servers using those names.
+    fn legacy_file(&mut self, name: &str) -> Option<String> {
+        let path = name.replace('.', "/");
+        if self.buffer(&path).is_some() {
+            return Some(path);
+        }
+        let variants: Vec<String> = (1..=4)
+            .map(|i| format!("{}{}", path, i))
+            .filter(|variant| self.buffer(variant).is_some())
+            .collect();
+        if variants.is_empty() {
+            return None;
+        }
+        let index = rand::thread_rng().gen_range(0..variants.len());
+        Some(variants[index].clone())
+    }
+
+    fn buffer(&mut self, file: &str) -> Option<Arc<SoundBuffer>> {
+        if let Some(buffer) = self.buffers.get(file) {
+            return buffer.clone();
+        }
+        let buffer = {
+            let res = self.resources.read().unwrap();
+            res.open("minecraft", &format!("sounds/{}.ogg", file))
+                .and_then(|data| match decode(data) {
+                    Ok(buffer) => Some(Arc::new(buffer)),
+                    Err(err) => {
+                        warn!("Failed to decode sound {}: {}", file, err);
+                        None
+                    }
+                })
+        };
+        self.buffers.insert(file.to_owned(), buffer.clone());
+        buffer
+    }
+}
+
+fn strip_namespace(name: &str) -> &str {
+    name.strip_prefix("minecraft:").unwrap_or(name)
+}
+
+/// Decodes an Ogg Vorbis file, downmixing it to mono
+fn decode(mut data: Box<dyn io::Read>) -> Result<SoundBuffer, String> {
+    use lewton::inside_ogg::OggStreamReader;
+    let mut bytes = vec![];
+    data.read_to_end(&mut bytes)
+        .map_err(|err| err.to_string())?;
+    let mut reader = OggStreamReader::new(io::Cursor::new(bytes)).map_err(|err| err.to_string())?;
+    let channels = reader.ident_hdr.audio_channels.max(1) as usize;
+    let sample_rate = reader.ident_hdr.audio_sample_rate;
+    let mut samples = vec![];
+    while let Some(packet) = reader
+        .read_dec_packet_itl()
+        .map_err(|err| err.to_string())?
+    {
+        for frame in packet.chunks(channels) {
+            let sum: f32 = frame.iter().map(|&s| f32::from(s)).sum();
+            samples.push(sum / (channels as f32 * 32768.0));
+        }
+    }
+    Ok(SoundBuffer {
+        samples,
+        sample_rate,
+    })
+}
@@ -0,0 +1,143 @@
+use super::mixer::Mixer;
+use std::sync::{Arc, Mutex};
+
+/// Where the mixed audio goes
+pub trait Output {
+    fn sample_rate(&self) -> u32;
+
+    /// Called every frame with the time since the last one, in seconds.
+    /// Outputs which don't pull from the mixer themselves should advance
+    /// it here.
+    fn tick(&mut self, _mixer: &Arc<Mutex<Mixer>>, _delta: f64) {}
+}
+
+/// Discards all audio, used when there is no audio device or when
+/// running without a window.
+///
+/// The mixer is still advanced so that sounds finish playing in the
+/// same time as they would with a real output.
+pub struct NullOutput {
+    buffer: Vec<f32>,
+    /// Fraction of a frame left over from the previous tick
+    remainder: f64,
+}
+
+const NULL_SAMPLE_RATE: u32 = 44100;
+
+impl Default for NullOutput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NullOutput {
+    pub fn new() -> NullOutput {
+        NullOutput {
+            buffer: vec![],
+            remainder: 0.0,
+        }
+    }
+}
+
+impl Output for NullOutput {
+    fn sample_rate(&self) -> u32 {
+        NULL_SAMPLE_RATE
+    }
+
+    fn tick(&mut self, mixer: &Arc<Mutex<Mixer>>, delta: f64) {
+        let frames = delta * f64::from(NULL_SAMPLE_RATE) + self.remainder;
+        self.remainder = frames.fract();
+        self.buffer.resize(frames as usize * 2, 0.0);
+        mixer.lock().unwrap().mix(&mut self.buffer);
+    }
+}
+
+/// Plays through the system's default audio device
+#[cfg(feature = "cpal")]
+pub struct DeviceOutput {
+    sample_rate: u32,
+    _stream: cpal::Stream,
+}
+
+#[cfg(feature = "cpal")]
+impl DeviceOutput {
+    /// Opens the default output device, returning `None` if there isn't
+    /// one or it can't be used.
+    pub fn new(mixer: Arc<Mutex<Mixer>>) -> Option<DeviceOutput> {
+        use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+        use log::error;
+
+        let device = cpal::default_host().default_output_device()?;
+        let config = device.default_output_config().ok()?;
+        let sample_rate = config.sample_rate().0;
+        let channels = config.channels() as usize;
+        let sample_format = config.sample_format();
+        let config: cpal::StreamConfig = config.into();
+        let mut stereo = vec![];
+
+        let err_fn = |err| error!("Audio output error: {}", err);
+        let stream = match sample_format {
+            cpal::SampleFormat::F32 => device.build_output_stream(
+                &config,
+                move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                    fill(&mixer, &mut stereo, data, channels, |v| v)
+                },
+                err_fn,
+            ),
+            cpal::SampleFormat::I16 => device.build_output_stream(
+                &config,
+                move |data: &mut [i16], _: &cpal::OutputCallbackInfo| {
+                    fill(&mixer, &mut stereo, data, channels, |v| {
+                        (v * f32::from(i16::MAX)) as i16
+                    })
+                },
+                err_fn,
+            ),
+            cpal::SampleFormat::U16 => device.build_output_stream(
+                &config,
+                move |data: &mut [u16], _: &cpal::OutputCallbackInfo| {
+                    fill(&mixer, &mut stereo, data, channels, |v| {
+                        ((v + 1.0) * 0.5 * f32::from(u16::MAX)) as u16
+                    })
+                },
+                err_fn,
+            ),
+        }
+        .ok()?;
+        stream.play().ok()?;
+        Some(DeviceOutput {
+            sample_rate,
+            _stream: stream,
+        })
+    }
+}
+
+#[cfg(feature = "cpal")]
+impl Output for DeviceOutput {
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+/// Mixes into `data`, converting from stereo to the device's channels
+#[cfg(feature = "cpal")]
+fn fill<T: Copy>(
+    mixer: &Mutex<Mixer>,
+    stereo: &mut Vec<f32>,
+    data: &mut [T],
+    channels: usize,
+    convert: impl Fn(f32) -> T,
+) {
+    let frames = data.len() / channels;
+    stereo.resize(frames * 2, 0.0);
+    mixer.lock().unwrap().mix(stereo);
+    for (frame, out) in stereo.chunks(2).zip(data.chunks_mut(channels)) {
+        if channels == 1 {
+            out[0] = convert((frame[0] + frame[1]) * 0.5);
+        } else {
+            for (i, sample) in out.iter_mut().enumerate() {
+                *sample = convert(frame[i.min(1)]);
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::resources;
+use log::warn;
+use rand::Rng;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Sound events referencing other events are followed at most this deep
+const MAX_EVENT_DEPTH: usize = 8;
+
+/// A single choice for a sound event, from `sounds.json`
+#[derive(Clone, Debug)]
+pub struct SoundEntry {
+    /// Either a file under `sounds/` or another event's name
+    pub name: String,
+    pub volume: f32,
+    pub pitch: f32,
+    pub weight: u32,
+    /// Whether `name` is another sound event instead of a file
+    pub is_event: bool,
+}
+
+#[derive(Default)]
+struct SoundEvent {
+    entries: Vec<SoundEntry>,
+}
+
+/// The sound events defined by the loaded resource packs
+#[derive(Default)]
+pub struct Sounds {
+    events: BTreeMap<String, SoundEvent>,
+}
+
+impl Sounds {
+    pub fn load(res: &resources::Manager) -> Sounds {
+        let mut sounds = Sounds::default();
+        // Packs are returned highest priority first, later packs add to
+        // or replace the events of earlier ones.
+        let mut files = res.open_all("minecraft", "sounds.json");
+        files.reverse();
+        for file in files {
+            match serde_json::from_reader::<_, Value>(file) {
+                Ok(Value::Object(events)) => {
+                    for (name, event) in events {
+                        sounds.add_event(name, &event);
+                    }
+                }
+                Ok(_) => warn!("sounds.json isn't an object"),
+                Err(err) => warn!("Failed to parse sounds.json: {}", err),
+            }
+        }
+        sounds
+    }
+
+    fn add_event(&mut self, name: String, event: &Value) {
+        let replace = event
+            .get("replace")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let entries = event
+            .get("sounds")
+            .and_then(Value::as_array)
+            .map(|sounds| sounds.iter().filter_map(parse_entry).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let existing = self.events.entry(name).or_default();
+        if replace {
+            existing.entries = entries;
+        } else {
+            existing.entries.extend(entries);
+        }
+    }
+
+    /// Picks one of the files for the named event, following references
+    /// to other events. The volume and pitch of the entry are combined
+    /// with the ones of the events it was reached through.
+    pub fn pick(&self, name: &str) -> Option<SoundEntry> {
+        let mut name = name.to_owned();
+        let mut volume = 1.0;
+        let mut pitch = 1.0;
+        for _ in 0..MAX_EVENT_DEPTH {
+            let event = self.events.get(&name)?;
+            let total: u32 = event.entries.iter().map(|e| e.weight).sum();
+            if total == 0 {
+                return None;
+            }
+            let mut choice = rand::thread_rng().gen_range(0..total);
+            let entry = event
+                .entries
+                .iter()
+                .find(|e| {
+                    if choice < e.weight {
+                        true
+                    } else {
+                        choice -= e.weight;
+                        false
+                    }
+                })
+                .unwrap();
+            volume *= entry.volume;
+            pitch *= entry.pitch;
+            if !entry.is_event {
+                return Some(SoundEntry {
+                    volume,
+                    pitch,
+                    ..entry.clone()
+                });
+            }
+            name = entry.name.clone();
+        }
+        warn!("Sound event {} references too many other events", name);
+        None
+    }
+}
+
+fn parse_entry(value: &Value) -> Option<SoundEntry> {
+    match value {
+        Value::String(name) => Some(SoundEntry {
+            name: name.clone(),
+            volume: 1.0,
+            pitch: 1.0,
+            weight: 1,
+            is_event: false,
+        }),
+        Value::Object(entry) => Some(SoundEntry {
+            name: entry.get("name")?.as_str()?.to_owned(),
+            volume: entry.get("volume").and_then(Value::as_f64).unwrap_or(1.0) as f32,
+            pitch: entry.get("pitch").and_then(Value::as_f64).unwrap_or(1.0) as f32,
+            weight: entry.get("weight").and_then(Value::as_u64).unwrap_or(1) as u32,
+            is_event: entry.get("type").and_then(Value::as_str) == Some("event"),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sounds(events: Value) -> Sounds {
+        let mut sounds = Sounds::default();
+        if let Value::Object(events) = events {
+            for (name, event) in events {
+                sounds.add_event(name, &event);
+            }
+        }
+        sounds
+    }
+
+    #[test]
+    fn pick_file() {
+        let sounds = sounds(json!({
+            "block.stone.break": {"sounds": [{"name": "dig/stone1", "volume": 0.5, "pitch": 0.8}]},
+        }));
+        let entry = sounds.pick("block.stone.break").unwrap();
+        assert_eq!(entry.name, "dig/stone1");
+        assert_eq!(entry.volume, 0.5);
+        assert_eq!(entry.pitch, 0.8);
+        assert!(!entry.is_event);
+        assert!(sounds.pick("block.stone.place").is_none());
+    }
+
+    #[test]
+    fn pick_follows_events() {
+        let sounds = sounds(json!({
+            "a": {"sounds": [{"name": "b", "type": "event", "volume": 0.5, "pitch": 2.0}]},
+            "b": {"sounds": [{"name": "c", "type": "event", "volume": 0.5}]},
+            "c": {"sounds": [{"name": "file", "pitch": 0.5}]},
+        }));
+        let entry = sounds.pick("a").unwrap();
+        assert_eq!(entry.name, "file");
+        assert_eq!(entry.volume, 0.25);
+        assert_eq!(entry.pitch, 1.0);
+
+        let entry = sounds.pick("c").unwrap();
+        assert_eq!(entry.volume, 1.0);
+        assert_eq!(entry.pitch, 0.5);
+    }
+
+    #[test]
+    fn pick_broken_chains() {
+        let sounds = sounds(json!({
+            "loop": {"sounds": [{"name": "loop", "type": "event"}]},
+            "missing": {"sounds": [{"name": "nothing", "type": "event"}]},
+            "empty": {"sounds": []},
+        }));
+        assert!(sounds.pick("loop").is_none());
+        assert!(sounds.pick("missing").is_none());
+        assert!(sounds.pick("empty").is_none());
+    }
+
+    #[test]
+    fn pick_weights() {
+        let sounds = sounds(json!({
+            "a": {"sounds": [{"name": "never", "weight": 0}, "always"]},
+        }));
+        for _ in 0..100 {
+            assert_eq!(sounds.pick("a").unwrap().name, "always");
+        }
+    }
+
+    #[test]
+    fn replace() {
+        let mut sounds = sounds(json!({
+            "a": {"sounds": ["old"]},
+        }));
+        sounds.add_event("a".into(), &json!({"sounds": ["added"]}));
+        assert_eq!(sounds.events["a"].entries.len(), 2);
+        sounds.add_event("a".into(), &json!({"replace": true, "sounds": ["new"]}));
+        assert_eq!(sounds.pick("a").unwrap().name, "new");
+    }
+}
@@ -22,6 +22,7 @@ pub const CL_USERNAME: console::CVar<String> = console::CVar {
 to servers."#,
     mutable: false,
     serializable: true,
+    range: None,
     default: &|| "".to_owned(),
 };
 
@@ -32,6 +33,7 @@ pub const CL_UUID: console::CVar<String> = console::CVar {
 unlike their username."#,
     mutable: false,
     serializable: true,
+    range: None,
     default: &|| "".to_owned(),
 };
 
@@ -42,6 +44,7 @@ pub const AUTH_TOKEN: console::CVar<String> = console::CVar {
 or relogin to this account."#,
     mutable: false,
     serializable: true,
+    range: None,
     default: &|| "".to_owned(),
 };
 
@@ -52,6 +55,7 @@ pub const AUTH_CLIENT_TOKEN: console::CVar<String> = console::CVar {
 Used to identify this client vs others."#,
     mutable: false,
     serializable: true,
+    range: None,
     default: &|| "".to_owned(),
 };
 
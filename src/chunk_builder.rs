@@ -56,6 +56,11 @@ impl ChunkBuilder {
         }
     }
 
+    /// The block models shared with the builder threads
+    pub fn models(&self) -> &Arc<RwLock<model::Factory>> {
+        &self.models
+    }
+
     pub fn tick(
         &mut self,
         world: &mut world::World,
@@ -0,0 +1,423 @@
+//! Commands typed into the console.
+
+use super::Vars;
+use crate::format::{Color, Component, TextComponent};
+use crate::screen;
+use crate::settings;
+use std::cell::Cell;
+use std::io::{BufRead, BufReader};
+use std_or_web::fs;
+
+/// Limits how deeply files run by `exec` can run other files
+const MAX_EXEC_DEPTH: usize = 8;
+
+thread_local!(
+    static EXEC_DEPTH: Cell<usize> = Cell::new(0);
+);
+
+pub struct Command {
+    pub name: &'static str,
+    /// The arguments taken by the command, shown by `help`
+    pub usage: &'static str,
+    pub description: &'static str,
+    run: fn(&mut crate::Game, &[&str]) -> Result<(), String>,
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "help",
+        usage: "",
+        description: "Lists the available commands",
+        run: help,
+    },
+    Command {
+        name: "set",
+        usage: "<cvar> <value>",
+        description: "Changes the value of a variable",
+        run: set,
+    },
+    Command {
+        name: "get",
+        usage: "<cvar>",
+        description: "Shows the value of a variable",
+        run: get,
+    },
+    Command {
+        name: "list",
+        usage: "[filter]",
+        description: "Lists the variables containing the filter with their descriptions",
+        run: list,
+    },
+    Command {
+        name: "connect",
+        usage: "<address>",
+        description: "Connects to a server",
+        run: connect,
+    },
+    Command {
+        name: "disconnect",
+        usage: "",
+        description: "Leaves the current server",
+        run: disconnect,
+    },
+    Command {
+        name: "bind",
+        usage: "[key] [action]",
+        description: "Binds a key to an action, or lists the current bindings",
+        run: bind,
+    },
+    Command {
+        name: "exec",
+        usage: "<file>",
+        description: "Runs every command in a file",
+        run: exec,
+    },
+];
+
+/// Runs a line entered into the console, printing any errors.
+///
+/// A variable's name can be used as a command to show its value or, when
+/// followed by a value, to change it.
+pub fn execute(game: &mut crate::Game, line: &str) {
+    let result = match parse(&game.vars, line) {
+        Some(Ok((command, args))) => {
+            let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+            (command.run)(game, &args)
+        }
+        Some(Err(err)) => Err(err),
+        None => return,
+    };
+    if let Err(err) = result {
+        print_coloured(game, &err, Color::Red);
+    }
+}
+
+/// Finds the command a line runs with its arguments, `None` for empty
+/// lines.
+fn parse(vars: &Vars, line: &str) -> Option<Result<(&'static Command, Vec<String>), String>> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let (name, args) = words.split_first()?;
+    let name = name.to_lowercase();
+    let mut args = args.iter().map(|arg| (*arg).to_owned()).collect::<Vec<_>>();
+    Some(if let Some(command) = find_command(&name) {
+        Ok((command, args))
+    } else if vars.description(&name).is_some() {
+        let command = find_command(if args.is_empty() { "get" } else { "set" }).unwrap();
+        args.insert(0, name);
+        Ok((command, args))
+    } else {
+        Err(format!("Unknown command {}, try help", name))
+    })
+}
+
+fn find_command(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|c| c.name == name)
+}
+
+/// Completes the word being typed with a command, variable or action
+/// name. Returns the new input and every name that matched.
+pub fn complete(vars: &Vars, input: &str) -> (String, Vec<String>) {
+    let words = input.split_whitespace().collect::<Vec<_>>();
+    let typing_new_word = words.is_empty() || input.ends_with(char::is_whitespace);
+    let index = if typing_new_word {
+        words.len()
+    } else {
+        words.len() - 1
+    };
+    let prefix = if typing_new_word { "" } else { words[index] };
+
+    let names: Vec<String> = match (index, words.first()) {
+        (0, _) => COMMANDS
+            .iter()
+            .map(|c| c.name)
+            .chain(vars.names())
+            .map(|name| name.to_owned())
+            .collect(),
+        (1, Some(&"set")) | (1, Some(&"get")) => vars
+            .names()
+            .into_iter()
+            .map(|name| name.to_owned())
+            .collect(),
+        (2, Some(&"bind")) => settings::Stevenkey::values()
+            .iter()
+            .map(|key| key.name().to_owned())
+            .collect(),
+        _ => vec![],
+    };
+    let mut matches = names
+        .into_iter()
+        .filter(|name| name.starts_with(&prefix.to_lowercase()))
+        .collect::<Vec<_>>();
+    matches.sort();
+
+    let completed = match matches.len() {
+        0 => return (input.to_owned(), matches),
+        1 => format!("{} ", matches[0]),
+        _ => common_prefix(&matches),
+    };
+    let mut input = words[..index].join(" ");
+    if !input.is_empty() {
+        input.push(' ');
+    }
+    input.push_str(&completed);
+    (input, matches)
+}
+
+fn common_prefix(names: &[String]) -> String {
+    let mut prefix = names[0].clone();
+    for name in &names[1..] {
+        let len = prefix
+            .char_indices()
+            .zip(name.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(prefix.len(), |((i, _), _)| i);
+        prefix.truncate(len);
+    }
+    prefix
+}
+
+fn print(game: &crate::Game, text: &str) {
+    game.console
+        .lock()
+        .unwrap()
+        .print(Component::Text(TextComponent::new(text)));
+}
+
+fn print_coloured(game: &crate::Game, text: &str, colour: Color) {
+    let mut msg = TextComponent::new(text);
+    msg.modifier.color = Some(colour);
+    game.console.lock().unwrap().print(Component::Text(msg));
+}
+
+fn help(game: &mut crate::Game, _args: &[&str]) -> Result<(), String> {
+    for command in COMMANDS {
+        print(game, &format!("{} {}", command.name, command.usage));
+        print_coloured(game, &format!("    {}", command.description), Color::Gray);
+    }
+    Ok(())
+}
+
+fn set(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    if args.len() < 2 {
+        return Err("Usage: set <cvar> <value>".to_owned());
+    }
+    let name = args[0].to_lowercase();
+    game.vars.set_from_string(&name, &args[1..].join(" "))?;
+    get(game, &[&name])
+}
+
+fn get(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let name = match args {
+        [name] => name.to_lowercase(),
+        _ => return Err("Usage: get <cvar>".to_owned()),
+    };
+    match game.vars.value_string(&name) {
+        Some(value) => {
+            print(game, &format!("{} = {}", name, value));
+            Ok(())
+        }
+        None => Err(format!("Unknown variable {}", name)),
+    }
+}
+
+fn list(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let filter = args.first().map(|f| f.to_lowercase()).unwrap_or_default();
+    for name in game.vars.names() {
+        if !name.contains(&filter) {
+            continue;
+        }
+        let value = game.vars.value_string(name).unwrap_or_default();
+        print(game, &format!("{} = {}", name, value));
+        for line in game.vars.description(name).unwrap_or("").lines() {
+            print_coloured(game, &format!("    {}", line), Color::Gray);
+        }
+    }
+    Ok(())
+}
+
+fn connect(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let address = match args {
+        [address] => *address,
+        _ => return Err("Usage: connect <address>".to_owned()),
+    };
+    game.screen_sys
+        .replace_screen(Box::new(screen::connecting::Connecting::new(address)));
+    game.connect_to(address);
+    Ok(())
+}
+
+fn disconnect(game: &mut crate::Game, _args: &[&str]) -> Result<(), String> {
+    if !game.server.is_connected() {
+        return Err("Not connected to a server".to_owned());
+    }
+    game.server.disconnect(None);
+    game.screen_sys
+        .replace_screen(Box::new(screen::ServerList::new(None)));
+    Ok(())
+}
+
+fn bind(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let bind = match args.first() {
+        Some(name) => {
+            Some(settings::Keybind::parse(name).ok_or_else(|| format!("Unknown key {}", name))?)
+        }
+        None => None,
+    };
+    if let Some(name) = args.get(1) {
+        let action =
+            settings::Stevenkey::by_name(name).ok_or_else(|| format!("Unknown action {}", name))?;
+        game.vars.set(action.get_cvar(), bind.unwrap());
+    }
+    for action in settings::Stevenkey::values() {
+        let bound = *game.vars.get(action.get_cvar());
+        if bind.is_none() || Some(bound) == bind {
+            print(game, &format!("{} = {}", action.name(), bound));
+        }
+    }
+    Ok(())
+}
+
+fn exec(game: &mut crate::Game, args: &[&str]) -> Result<(), String> {
+    let filename = match args {
+        [filename] => *filename,
+        _ => return Err("Usage: exec <file>".to_owned()),
+    };
+    if EXEC_DEPTH.with(|depth| depth.get()) >= MAX_EXEC_DEPTH {
+        return Err(format!(
+            "Not running {}, too many nested exec commands",
+            filename
+        ));
+    }
+    let file =
+        fs::File::open(filename).map_err(|err| format!("Failed to open {}: {}", filename, err))?;
+    EXEC_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    for line in BufReader::new(file).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                print_coloured(
+                    game,
+                    &format!("Failed to read {}: {}", filename, err),
+                    Color::Red,
+                );
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        execute(game, line);
+    }
+    EXEC_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::CVar;
+    use std::marker::PhantomData;
+
+    const R_TEST: CVar<i64> = CVar {
+        ty: PhantomData,
+        name: "r_test",
+        description: "",
+        mutable: true,
+        serializable: false,
+        range: None,
+        default: &|| 0,
+    };
+
+    const R_TESTING: CVar<bool> = CVar {
+        ty: PhantomData,
+        name: "r_testing",
+        description: "",
+        mutable: true,
+        serializable: false,
+        range: None,
+        default: &|| false,
+    };
+
+    fn vars() -> Vars {
+        let mut vars = Vars::new();
+        vars.register(R_TEST);
+        vars.register(R_TESTING);
+        vars
+    }
+
+    /// The name of the command the line runs with its arguments
+    fn parsed(line: &str) -> Option<Result<(&'static str, Vec<String>), String>> {
+        parse(&vars(), line).map(|result| result.map(|(command, args)| (command.name, args)))
+    }
+
+    fn ok(
+        name: &'static str,
+        args: &[&str],
+    ) -> Option<Result<(&'static str, Vec<String>), String>> {
+        Some(Ok((
+            name,
+            args.iter().map(|arg| (*arg).to_owned()).collect(),
+        )))
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(parsed("connect localhost"), ok("connect", &["localhost"]));
+        assert_eq!(parsed("  HELP  "), ok("help", &[]));
+        assert_eq!(parsed("bind\tw  forward"), ok("bind", &["w", "forward"]));
+        assert_eq!(parsed(""), None);
+        assert_eq!(parsed(" \u{a0} "), None);
+        assert_eq!(
+            parsed("jump"),
+            Some(Err("Unknown command jump, try help".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_variable_names() {
+        assert_eq!(parsed("r_test"), ok("get", &["r_test"]));
+        assert_eq!(parsed("R_Test 5"), ok("set", &["r_test", "5"]));
+        assert_eq!(parsed("r_test 5 6"), ok("set", &["r_test", "5", "6"]));
+    }
+
+    #[test]
+    fn complete_names() {
+        let vars = vars();
+        let (input, matches) = complete(&vars, "con");
+        assert_eq!(input, "connect ");
+        assert_eq!(matches, vec!["connect"]);
+
+        // Both variables match, the input stops where they differ
+        let (input, matches) = complete(&vars, "r_t");
+        assert_eq!(input, "r_test");
+        assert_eq!(matches, vec!["r_test", "r_testing"]);
+
+        let (input, matches) = complete(&vars, "xyz");
+        assert_eq!(input, "xyz");
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn complete_arguments() {
+        let vars = vars();
+        assert_eq!(complete(&vars, "set r_testi").0, "set r_testing ");
+        assert_eq!(complete(&vars, "get ").1, vec!["r_test", "r_testing"]);
+        assert_eq!(complete(&vars, "bind w forw").0, "bind w forward ");
+        assert_eq!(
+            complete(&vars, "bind w ").1.len(),
+            settings::Stevenkey::values().len()
+        );
+        // Commands other than set, get and bind have nothing to complete
+        assert!(complete(&vars, "connect ").1.is_empty());
+    }
+
+    #[test]
+    fn complete_whitespace() {
+        let vars = vars();
+        let commands = COMMANDS.len() + vars.names().len();
+        assert_eq!(complete(&vars, "").1.len(), commands);
+        assert_eq!(complete(&vars, "\u{a0}").1.len(), commands);
+        assert_eq!(complete(&vars, "set\u{a0}").1, vec!["r_test", "r_testing"]);
+    }
+}
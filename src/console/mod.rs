@@ -23,6 +23,10 @@ use std_or_web::fs;
 use crate::format::{Color, Component, TextComponent};
 use crate::render;
 use crate::ui;
+use log::warn;
+use winit::event::VirtualKeyCode;
+
+pub mod commands;
 
 #[cfg(target_arch = "wasm32")]
 use web_sys;
@@ -43,6 +47,11 @@ fn println_level(_level: log::Level, s: String) {
     println!("{}", s);
 }
 
+/// Height of the command input at the bottom of the console
+const INPUT_HEIGHT: f64 = 24.0;
+/// Number of entered commands remembered for the up and down keys
+const MAX_COMMAND_HISTORY: usize = 50;
+
 const FILTERED_CRATES: &[&str] = &[
     //"reqwest", // TODO: needed?
     "mime",
@@ -54,15 +63,38 @@ pub struct CVar<T: Sized + Any + 'static> {
     pub description: &'static str,
     pub mutable: bool,
     pub serializable: bool,
+    /// Inclusive bounds for numeric variables, values outside of them
+    /// are rejected
+    pub range: Option<(T, T)>,
     pub default: &'static dyn Fn() -> T,
 }
 
+/// A type stored in a CVar as one of a fixed set of names
+pub trait CVarEnum: Copy + PartialEq + 'static {
+    /// Every value with the name used for it in the console and `conf.cfg`
+    const VALUES: &'static [(Self, &'static str)];
+}
+
+fn check_range<T: PartialOrd + std::fmt::Display>(
+    val: &T,
+    range: &Option<(T, T)>,
+) -> Result<(), String> {
+    match range {
+        Some((min, max)) if val < min || val > max => Err(format!(
+            "{} is outside of the range {} to {}",
+            val, min, max
+        )),
+        _ => Ok(()),
+    }
+}
+
 pub const LOG_LEVEL_TERM: CVar<String> = CVar {
     ty: PhantomData,
     name: "log_level_term",
     description: "log level of messages to log to the terminal",
     mutable: false,
     serializable: true,
+    range: None,
     default: &|| "info".to_owned(),
 };
 
@@ -72,6 +104,7 @@ pub const LOG_LEVEL_FILE: CVar<String> = CVar {
     description: "log level of messages to log to the log file",
     mutable: false,
     serializable: true,
+    range: None,
     default: &|| "trace".to_owned(),
 };
 
@@ -98,8 +131,15 @@ impl Var for CVar<i64> {
         val.downcast_ref::<i64>().unwrap().to_string()
     }
 
-    fn deserialize(&self, input: &str) -> Box<dyn Any> {
-        Box::new(input.parse::<i64>().unwrap())
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        match input.parse::<i64>() {
+            Ok(val) => Ok(Box::new(val)),
+            Err(err) => Err(format!("{} is not a number: {}", input, err)),
+        }
+    }
+
+    fn validate(&self, val: &dyn Any) -> Result<(), String> {
+        check_range(val.downcast_ref::<i64>().unwrap(), &self.range)
     }
 
     fn description(&self) -> &'static str {
@@ -109,6 +149,39 @@ impl Var for CVar<i64> {
     fn can_serialize(&self) -> bool {
         self.serializable
     }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
+impl Var for CVar<f64> {
+    fn serialize(&self, val: &Box<dyn Any>) -> String {
+        val.downcast_ref::<f64>().unwrap().to_string()
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        match input.parse::<f64>() {
+            Ok(val) if val.is_finite() => Ok(Box::new(val)),
+            _ => Err(format!("{} is not a number", input)),
+        }
+    }
+
+    fn validate(&self, val: &dyn Any) -> Result<(), String> {
+        check_range(val.downcast_ref::<f64>().unwrap(), &self.range)
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn can_serialize(&self) -> bool {
+        self.serializable
+    }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 impl Var for CVar<bool> {
@@ -116,8 +189,11 @@ impl Var for CVar<bool> {
         val.downcast_ref::<bool>().unwrap().to_string()
     }
 
-    fn deserialize(&self, input: &str) -> Box<dyn Any> {
-        Box::new(input.parse::<bool>().unwrap())
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        match input.parse::<bool>() {
+            Ok(val) => Ok(Box::new(val)),
+            Err(_) => Err(format!("{} is not true or false", input)),
+        }
     }
 
     fn description(&self) -> &'static str {
@@ -127,6 +203,10 @@ impl Var for CVar<bool> {
     fn can_serialize(&self) -> bool {
         self.serializable
     }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 impl Var for CVar<String> {
@@ -134,30 +214,98 @@ impl Var for CVar<String> {
         format!("\"{}\"", val.downcast_ref::<String>().unwrap())
     }
 
-    fn deserialize(&self, input: &str) -> Box<dyn Any> {
-        Box::new((&input[1..input.len() - 1]).to_owned())
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        // Quotes are optional when typed into the console
+        let input = input
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(input);
+        Ok(Box::new(input.to_owned()))
     }
 
     fn description(&self) -> &'static str {
         self.description
     }
+
     fn can_serialize(&self) -> bool {
         self.serializable
     }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
+impl<T: CVarEnum> Var for CVar<T> {
+    fn serialize(&self, val: &Box<dyn Any>) -> String {
+        let val = val.downcast_ref::<T>().unwrap();
+        T::VALUES
+            .iter()
+            .find(|(v, _)| v == val)
+            .map(|(_, name)| (*name).to_owned())
+            .unwrap()
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        // Older configs stored these as the index of the value
+        let by_index = input.parse::<usize>().ok().and_then(|i| T::VALUES.get(i));
+        match T::VALUES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(input))
+            .or(by_index)
+        {
+            Some((val, _)) => Ok(Box::new(*val)),
+            None => Err(format!(
+                "{} is not one of {}",
+                input,
+                T::VALUES
+                    .iter()
+                    .map(|(_, name)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn can_serialize(&self) -> bool {
+        self.serializable
+    }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 pub trait Var {
     fn serialize(&self, val: &Box<dyn Any>) -> String;
-    fn deserialize(&self, input: &str) -> Box<dyn Any>;
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String>;
+    /// Checks whether the value is allowed, e.g. within the variable's
+    /// range
+    fn validate(&self, _val: &dyn Any) -> Result<(), String> {
+        Ok(())
+    }
     fn description(&self) -> &'static str;
     fn can_serialize(&self) -> bool;
+    /// Whether the value can be changed from the console
+    fn is_mutable(&self) -> bool;
 }
 
+type Listener = Box<dyn Fn(&mut crate::Game, &Vars)>;
+
 #[derive(Default)]
 pub struct Vars {
     names: HashMap<String, &'static str>,
     vars: HashMap<&'static str, Box<dyn Var>>,
     var_values: HashMap<&'static str, RefCell<Box<dyn Any>>>,
+    listeners: RefCell<HashMap<&'static str, Vec<Listener>>>,
+    /// Variables changed since the listeners were last run
+    changed: RefCell<Vec<&'static str>>,
+    /// Where changes are saved, set once the config has been loaded
+    config_file: Option<String>,
 }
 
 impl Vars {
@@ -187,32 +335,152 @@ impl Vars {
         Ref::map(var, |v| v.downcast_ref::<T>().unwrap())
     }
 
+    /// Changes the variable, values it doesn't allow are logged and
+    /// ignored.
     pub fn set<T: Sized + Any>(&self, var: CVar<T>, val: T)
     where
         CVar<T>: Var,
     {
-        *self.var_values.get(var.name).unwrap().borrow_mut() = Box::new(val);
+        if let Err(err) = self.store(var.name, Box::new(val)) {
+            warn!("Not changing {}: {}", var.name, err);
+        }
+    }
+
+    fn store(&self, name: &'static str, val: Box<dyn Any>) -> Result<(), String> {
+        self.vars[name].validate(&*val)?;
+        *self.var_values.get(name).unwrap().borrow_mut() = val;
+        self.mark_changed(name);
         self.save_config();
+        Ok(())
+    }
+
+    /// Registers a function to run with the new value whenever the
+    /// variable changes. Listeners are run by `run_listeners` once a
+    /// tick so they can change the game.
+    pub fn add_listener<T: Sized + Any + Clone, F: Fn(&mut crate::Game, &T) + 'static>(
+        &self,
+        var: CVar<T>,
+        f: F,
+    ) where
+        CVar<T>: Var,
+    {
+        let name = var.name;
+        self.listeners
+            .borrow_mut()
+            .entry(name)
+            .or_default()
+            .push(Box::new(move |game, vars| {
+                // Cloned so the listener is free to change the variable
+                let val = vars.var_values[name]
+                    .borrow()
+                    .downcast_ref::<T>()
+                    .unwrap()
+                    .clone();
+                f(game, &val);
+            }));
+    }
+
+    fn mark_changed(&self, name: &'static str) {
+        let mut changed = self.changed.borrow_mut();
+        if !changed.contains(&name) {
+            changed.push(name);
+        }
+    }
+
+    /// Runs the listeners of every variable changed since the last call
+    pub fn run_listeners(&self, game: &mut crate::Game) {
+        let changed = std::mem::take(&mut *self.changed.borrow_mut());
+        for name in changed {
+            // Taken out while running so listeners can add more
+            let listeners = self.listeners.borrow_mut().remove(name);
+            if let Some(mut listeners) = listeners {
+                for listener in &listeners {
+                    listener(game, self);
+                }
+                let mut all = self.listeners.borrow_mut();
+                let added = all.entry(name).or_default();
+                listeners.append(added);
+                *added = listeners;
+            }
+        }
+    }
+
+    /// The names of every registered variable, sorted
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = self.vars.keys().copied().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    pub fn description(&self, name: &str) -> Option<&'static str> {
+        self.vars.get(name).map(|var| var.description())
+    }
+
+    /// The variable's value in the format used by `conf.cfg`
+    pub fn value_string(&self, name: &str) -> Option<String> {
+        let var = self.vars.get(name)?;
+        Some(var.serialize(&self.var_values.get(name).unwrap().borrow()))
+    }
+
+    /// Parses and sets the value of a variable by name, as typed into
+    /// the console. Variables that aren't mutable can't be changed.
+    pub fn set_from_string(&self, name: &str, input: &str) -> Result<(), String> {
+        let var = match self.vars.get(name) {
+            Some(var) => var,
+            None => return Err(format!("Unknown variable {}", name)),
+        };
+        if !var.is_mutable() {
+            return Err(format!("{} can't be changed", name));
+        }
+        let val = var.deserialize(input)?;
+        self.store(self.names[name], val)
     }
 
     pub fn load_config(&mut self) {
-        if let Ok(file) = fs::File::open("conf.cfg") {
+        self.load_config_file("conf.cfg");
+    }
+
+    /// Loads the variables from the file, later changes are saved to it
+    fn load_config_file(&mut self, path: &str) {
+        self.config_file = Some(path.to_owned());
+        if let Ok(file) = fs::File::open(path) {
             let reader = BufReader::new(file);
-            for line in reader.lines() {
-                let line = line.unwrap();
+            for (number, line) in reader.lines().enumerate() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => {
+                        warn!("Failed to read {}: {}", path, err);
+                        break;
+                    }
+                };
                 if line.starts_with('#') || line.is_empty() {
                     continue;
                 }
-                let parts = line
-                    .splitn(2, ' ')
-                    .map(|v| v.to_owned())
-                    .collect::<Vec<String>>();
-                let (name, arg) = (&parts[0], &parts[1]);
+                let (name, arg) = match line.split_once(' ') {
+                    Some(parts) => parts,
+                    None => {
+                        warn!("Line {} of {} is missing a value", number + 1, path);
+                        continue;
+                    }
+                };
                 if let Some(var_name) = self.names.get(name) {
                     let var = self.vars.get(var_name).unwrap();
-                    let val = var.deserialize(arg);
-                    if var.can_serialize() {
-                        self.var_values.insert(var_name, RefCell::new(val));
+                    match var
+                        .deserialize(arg)
+                        .and_then(|val| var.validate(&*val).map(|_| val))
+                    {
+                        Ok(val) => {
+                            if var.can_serialize() {
+                                self.var_values.insert(var_name, RefCell::new(val));
+                            }
+                        }
+                        Err(err) => warn!(
+                            "Keeping the default for {} on line {} of {}: {}",
+                            name,
+                            number + 1,
+                            path,
+                            err
+                        ),
                     }
                 }
             }
@@ -220,7 +488,11 @@ impl Vars {
     }
 
     pub fn save_config(&self) {
-        let mut file = BufWriter::new(fs::File::create("conf.cfg").unwrap());
+        let path = match self.config_file {
+            Some(ref path) => path,
+            None => return,
+        };
+        let mut file = BufWriter::new(fs::File::create(path).unwrap());
         for (name, var) in &self.vars {
             if !var.can_serialize() {
                 continue;
@@ -249,11 +521,20 @@ pub struct Console {
     elements: Option<ConsoleElements>,
     active: bool,
     position: f64,
+
+    input: String,
+    /// Previously entered commands, oldest first
+    command_history: Vec<String>,
+    /// The entry of `command_history` currently in the input
+    history_index: Option<usize>,
+    /// Entered commands waiting to be run by the game
+    pending_commands: Vec<String>,
 }
 
 struct ConsoleElements {
     background: ui::ImageRef,
     lines: Vec<ui::FormattedRef>,
+    input: ui::TextBoxRef,
 }
 
 impl Default for Console {
@@ -274,6 +555,11 @@ impl Console {
             elements: None,
             active: false,
             position: -220.0,
+
+            input: String::new(),
+            command_history: vec![],
+            history_index: None,
+            pending_commands: vec![],
         }
     }
 
@@ -294,6 +580,84 @@ impl Console {
         self.active = true;
     }
 
+    /// Adds a line to the console without logging it
+    pub fn print(&mut self, msg: Component) {
+        self.history.remove(0);
+        self.history.push(msg);
+        self.dirty = true;
+    }
+
+    /// Returns the commands entered since the last call
+    pub fn take_commands(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending_commands)
+    }
+
+    pub fn key_type(&mut self, c: char) {
+        match c {
+            // Backspace
+            '\x7f' | '\x08' => {
+                self.input.pop();
+            }
+            // The key that toggles the console
+            '`' => {}
+            c if c >= ' ' => self.input.push(c),
+            _ => {}
+        }
+    }
+
+    pub fn key_press(&mut self, key: VirtualKeyCode, vars: &Vars) {
+        match key {
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                let command = std::mem::take(&mut self.input);
+                self.history_index = None;
+                if command.trim().is_empty() {
+                    return;
+                }
+                self.print(Component::Text(TextComponent::new(&format!(
+                    "> {}",
+                    command
+                ))));
+                if self.command_history.last() != Some(&command) {
+                    self.command_history.push(command.clone());
+                    if self.command_history.len() > MAX_COMMAND_HISTORY {
+                        self.command_history.remove(0);
+                    }
+                }
+                self.pending_commands.push(command);
+            }
+            VirtualKeyCode::Tab => {
+                let (completed, options) = commands::complete(vars, &self.input);
+                self.input = completed;
+                if options.len() > 1 {
+                    let mut msg = TextComponent::new(&options.join("  "));
+                    msg.modifier.color = Some(Color::Gray);
+                    self.print(Component::Text(msg));
+                }
+            }
+            VirtualKeyCode::Up => {
+                let index = match self.history_index {
+                    Some(index) => index.saturating_sub(1),
+                    None if !self.command_history.is_empty() => self.command_history.len() - 1,
+                    None => return,
+                };
+                self.history_index = Some(index);
+                self.input = self.command_history[index].clone();
+            }
+            VirtualKeyCode::Down => {
+                if let Some(index) = self.history_index {
+                    if index + 1 < self.command_history.len() {
+                        self.history_index = Some(index + 1);
+                        self.input = self.command_history[index + 1].clone();
+                    } else {
+                        self.history_index = None;
+                        self.input.clear();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn tick(
         &mut self,
         ui_container: &mut ui::Container,
@@ -329,9 +693,15 @@ impl Console {
                 .colour((0, 0, 0, 180))
                 .draw_index(500)
                 .create(ui_container);
+            let input = ui::TextBoxBuilder::new()
+                .position(5.0, 5.0)
+                .size(w - 10.0, INPUT_HEIGHT)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                .attach(&mut *background.borrow_mut());
             self.elements = Some(ConsoleElements {
                 background,
                 lines: vec![],
+                input,
             });
             self.dirty = true;
         }
@@ -339,12 +709,20 @@ impl Console {
         let mut background = elements.background.borrow_mut();
         background.y = self.position;
         background.width = w;
+        {
+            let mut input = elements.input.borrow_mut();
+            input.width = w - 10.0;
+            input.set_focused(self.active);
+            if input.input != self.input {
+                input.input = self.input.clone();
+            }
+        }
 
         if self.dirty {
             self.dirty = false;
             elements.lines.clear();
 
-            let mut offset = 0.0;
+            let mut offset = INPUT_HEIGHT + 5.0;
             for line in self.history.iter().rev() {
                 if offset >= 210.0 {
                     break;
@@ -454,3 +832,156 @@ impl log::Log for ConsoleProxy {
 
 unsafe impl Send for ConsoleProxy {}
 unsafe impl Sync for ConsoleProxy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T_INT: CVar<i64> = CVar {
+        ty: PhantomData,
+        name: "t_int",
+        description: "",
+        mutable: true,
+        serializable: true,
+        range: Some((0, 10)),
+        default: &|| 5,
+    };
+
+    const T_FLOAT: CVar<f64> = CVar {
+        ty: PhantomData,
+        name: "t_float",
+        description: "",
+        mutable: true,
+        serializable: true,
+        range: Some((0.5, 2.0)),
+        default: &|| 1.0,
+    };
+
+    const T_FIXED: CVar<i64> = CVar {
+        ty: PhantomData,
+        name: "t_fixed",
+        description: "",
+        mutable: false,
+        serializable: true,
+        range: None,
+        default: &|| 1,
+    };
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum Size {
+        Small,
+        Large,
+    }
+
+    impl CVarEnum for Size {
+        const VALUES: &'static [(Self, &'static str)] =
+            &[(Size::Small, "small"), (Size::Large, "large")];
+    }
+
+    const T_SIZE: CVar<Size> = CVar {
+        ty: PhantomData,
+        name: "t_size",
+        description: "",
+        mutable: true,
+        serializable: true,
+        range: None,
+        default: &|| Size::Small,
+    };
+
+    fn vars() -> Vars {
+        let mut vars = Vars::new();
+        vars.register(T_INT);
+        vars.register(T_FLOAT);
+        vars.register(T_FIXED);
+        vars.register(T_SIZE);
+        vars
+    }
+
+    #[test]
+    fn set_checks_range() {
+        let vars = vars();
+        vars.set(T_INT, 10);
+        assert_eq!(*vars.get(T_INT), 10);
+        vars.set(T_INT, 11);
+        vars.set(T_INT, -1);
+        assert_eq!(*vars.get(T_INT), 10);
+
+        vars.set(T_FLOAT, 0.5);
+        vars.set(T_FLOAT, 2.5);
+        assert_eq!(*vars.get(T_FLOAT), 0.5);
+    }
+
+    #[test]
+    fn set_from_string() {
+        let vars = vars();
+        assert_eq!(vars.set_from_string("t_int", "3"), Ok(()));
+        assert_eq!(*vars.get(T_INT), 3);
+        assert_eq!(
+            vars.set_from_string("t_int", "11"),
+            Err("11 is outside of the range 0 to 10".to_owned())
+        );
+        assert!(vars.set_from_string("t_int", "three").is_err());
+        assert!(vars.set_from_string("t_float", "NaN").is_err());
+        assert!(vars.set_from_string("t_float", "0.1").is_err());
+        assert_eq!(*vars.get(T_INT), 3);
+        assert_eq!(*vars.get(T_FLOAT), 1.0);
+
+        assert_eq!(
+            vars.set_from_string("t_fixed", "2"),
+            Err("t_fixed can't be changed".to_owned())
+        );
+        assert_eq!(
+            vars.set_from_string("t_missing", "2"),
+            Err("Unknown variable t_missing".to_owned())
+        );
+    }
+
+    #[test]
+    fn enum_values() {
+        let vars = vars();
+        assert_eq!(vars.set_from_string("t_size", "LARGE"), Ok(()));
+        assert_eq!(*vars.get(T_SIZE), Size::Large);
+        assert_eq!(vars.value_string("t_size"), Some("large".to_owned()));
+        // The index older configs stored
+        assert_eq!(vars.set_from_string("t_size", "0"), Ok(()));
+        assert_eq!(*vars.get(T_SIZE), Size::Small);
+        assert_eq!(
+            vars.set_from_string("t_size", "medium"),
+            Err("medium is not one of small, large".to_owned())
+        );
+        assert!(vars.set_from_string("t_size", "2").is_err());
+    }
+
+    #[test]
+    fn config_file_errors() {
+        let path = std::env::temp_dir().join(format!("steven-test-{}.cfg", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(
+            path,
+            "# comment\n\
+             t_int 7\n\
+             t_float\n\
+             t_float 9.0\n\
+             t_size huge\n\
+             t_missing 1\n\
+             t_fixed 3\n",
+        )
+        .unwrap();
+
+        let mut vars = vars();
+        vars.load_config_file(path);
+        assert_eq!(*vars.get(T_INT), 7);
+        // Missing, out of range and unknown values keep the defaults
+        assert_eq!(*vars.get(T_FLOAT), 1.0);
+        assert_eq!(*vars.get(T_SIZE), Size::Small);
+        // Not changeable from the console but still loaded
+        assert_eq!(*vars.get(T_FIXED), 3);
+
+        // Changes are saved back to the file
+        vars.set(T_SIZE, Size::Large);
+        let saved = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert!(saved.contains("t_size large\n"));
+        assert!(saved.contains("t_int 7\n"));
+    }
+}
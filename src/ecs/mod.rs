@@ -75,14 +75,17 @@ impl Filter {
     }
 }
 
-/// A system processes entities
+/// A system processes entities.
+///
+/// The renderer is `None` when the game is running headless, systems
+/// that draw entities should do nothing then.
 pub trait System {
     fn filter(&self) -> &Filter;
     fn update(
         &mut self,
         m: &mut Manager,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     );
 
     fn entity_added(
@@ -90,7 +93,7 @@ pub trait System {
         _m: &mut Manager,
         _e: Entity,
         _world: &mut world::World,
-        _renderer: &mut render::Renderer,
+        _renderer: Option<&mut render::Renderer>,
     ) {
     }
 
@@ -99,7 +102,7 @@ pub trait System {
         _m: &mut Manager,
         _e: Entity,
         _world: &mut world::World,
-        _renderer: &mut render::Renderer,
+        _renderer: Option<&mut render::Renderer>,
     ) {
     }
 }
@@ -170,22 +173,26 @@ impl Manager {
     }
 
     /// Ticks all tick systems
-    pub fn tick(&mut self, world: &mut world::World, renderer: &mut render::Renderer) {
-        self.process_entity_changes(world, renderer);
+    pub fn tick(&mut self, world: &mut world::World, mut renderer: Option<&mut render::Renderer>) {
+        self.process_entity_changes(world, renderer.as_deref_mut());
         let mut systems = self.systems.take().unwrap();
         for sys in &mut systems {
-            sys.update(self, world, renderer);
+            sys.update(self, world, renderer.as_deref_mut());
         }
         self.systems = Some(systems);
         self.process_entity_changes(world, renderer);
     }
 
     /// Ticks all render systems
-    pub fn render_tick(&mut self, world: &mut world::World, renderer: &mut render::Renderer) {
-        self.process_entity_changes(world, renderer);
+    pub fn render_tick(
+        &mut self,
+        world: &mut world::World,
+        mut renderer: Option<&mut render::Renderer>,
+    ) {
+        self.process_entity_changes(world, renderer.as_deref_mut());
         let mut systems = self.render_systems.take().unwrap();
         for sys in &mut systems {
-            sys.update(self, world, renderer);
+            sys.update(self, world, renderer.as_deref_mut());
         }
         self.render_systems = Some(systems);
         self.process_entity_changes(world, renderer);
@@ -194,7 +201,7 @@ impl Manager {
     fn process_entity_changes(
         &mut self,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        mut renderer: Option<&mut render::Renderer>,
     ) {
         let changes = self.changed_entity_components.clone();
         self.changed_entity_components = HashSet::with_hasher(BuildHasherDefault::default());
@@ -211,28 +218,28 @@ impl Manager {
                 &state.last_components,
                 &state.components,
                 world,
-                renderer,
+                renderer.as_deref_mut(),
             );
             self.trigger_add_for_render_systems(
                 entity,
                 &state.last_components,
                 &state.components,
                 world,
-                renderer,
+                renderer.as_deref_mut(),
             );
             self.trigger_remove_for_systems(
                 entity,
                 &state.last_components,
                 &state.components,
                 world,
-                renderer,
+                renderer.as_deref_mut(),
             );
             self.trigger_remove_for_render_systems(
                 entity,
                 &state.last_components,
                 &state.components,
                 world,
-                renderer,
+                renderer.as_deref_mut(),
             );
             for i in 0..self.components.len() {
                 if !state.components.get(i) && state.last_components.get(i) {
@@ -310,7 +317,7 @@ impl Manager {
     pub fn remove_all_entities(
         &mut self,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     ) {
         for (id, e) in self.entities[1..].iter_mut().enumerate() {
             if let Some(set) = e.0.as_mut() {
@@ -401,13 +408,13 @@ impl Manager {
         old_set: &BSet,
         new_set: &BSet,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        mut renderer: Option<&mut render::Renderer>,
     ) {
         let mut systems = self.systems.take().unwrap();
         for sys in &mut systems {
             if new_set.includes_set(&sys.filter().bits) && !old_set.includes_set(&sys.filter().bits)
             {
-                sys.entity_added(self, e, world, renderer);
+                sys.entity_added(self, e, world, renderer.as_deref_mut());
             }
         }
         self.systems = Some(systems);
@@ -419,13 +426,13 @@ impl Manager {
         old_set: &BSet,
         new_set: &BSet,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        mut renderer: Option<&mut render::Renderer>,
     ) {
         let mut systems = self.render_systems.take().unwrap();
         for sys in &mut systems {
             if new_set.includes_set(&sys.filter().bits) && !old_set.includes_set(&sys.filter().bits)
             {
-                sys.entity_added(self, e, world, renderer);
+                sys.entity_added(self, e, world, renderer.as_deref_mut());
             }
         }
         self.render_systems = Some(systems);
@@ -482,13 +489,13 @@ impl Manager {
         old_set: &BSet,
         new_set: &BSet,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        mut renderer: Option<&mut render::Renderer>,
     ) {
         let mut systems = self.systems.take().unwrap();
         for sys in &mut systems {
             if !new_set.includes_set(&sys.filter().bits) && old_set.includes_set(&sys.filter().bits)
             {
-                sys.entity_removed(self, e, world, renderer);
+                sys.entity_removed(self, e, world, renderer.as_deref_mut());
             }
         }
         self.systems = Some(systems);
@@ -500,13 +507,13 @@ impl Manager {
         old_set: &BSet,
         new_set: &BSet,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        mut renderer: Option<&mut render::Renderer>,
     ) {
         let mut systems = self.render_systems.take().unwrap();
         for sys in &mut systems {
             if !new_set.includes_set(&sys.filter().bits) && old_set.includes_set(&sys.filter().bits)
             {
-                sys.entity_removed(self, e, world, renderer);
+                sys.entity_removed(self, e, world, renderer.as_deref_mut());
             }
         }
         self.render_systems = Some(systems);
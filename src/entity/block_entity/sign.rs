@@ -72,14 +72,18 @@ impl ecs::System for SignRenderer {
         &mut self,
         m: &mut ecs::Manager,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     ) {
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
         for e in m.find(&self.filter) {
             let position = *m.get_component(e, self.position).unwrap();
             let info = m.get_component_mut(e, self.sign_info).unwrap();
             if info.dirty {
-                self.entity_removed(m, e, world, renderer);
-                self.entity_added(m, e, world, renderer);
+                self.entity_removed(m, e, world, Some(&mut *renderer));
+                self.entity_added(m, e, world, Some(&mut *renderer));
             }
             if let Some(model) = info.model {
                 let mdl = renderer.model.get_model(model).unwrap();
@@ -94,10 +98,14 @@ impl ecs::System for SignRenderer {
         m: &mut ecs::Manager,
         e: ecs::Entity,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     ) {
         use cgmath::{Decomposed, Matrix4, Quaternion, Rad, Rotation3, Vector3};
         use std::f64::consts::PI;
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
         let position = *m.get_component(e, self.position).unwrap();
         let info = m.get_component_mut(e, self.sign_info).unwrap();
         info.dirty = false;
@@ -218,8 +226,12 @@ impl ecs::System for SignRenderer {
         m: &mut ecs::Manager,
         e: ecs::Entity,
         _: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     ) {
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
         let info = m.get_component_mut(e, self.sign_info).unwrap();
         if let Some(model) = info.model {
             renderer.model.remove_model(model);
@@ -0,0 +1,71 @@
+//! The items entities hold and the armor they wear.
+
+use crate::item;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EquipmentSlot {
+    MainHand = 0,
+    OffHand = 1,
+    Feet = 2,
+    Legs = 3,
+    Chest = 4,
+    Head = 5,
+}
+
+impl EquipmentSlot {
+    pub const ARMOR: [EquipmentSlot; 4] = [
+        EquipmentSlot::Feet,
+        EquipmentSlot::Legs,
+        EquipmentSlot::Chest,
+        EquipmentSlot::Head,
+    ];
+
+    /// Returns the slot with the id used since 1.9
+    pub fn from_id(id: i32) -> Option<EquipmentSlot> {
+        Some(match id {
+            0 => EquipmentSlot::MainHand,
+            1 => EquipmentSlot::OffHand,
+            2 => EquipmentSlot::Feet,
+            3 => EquipmentSlot::Legs,
+            4 => EquipmentSlot::Chest,
+            5 => EquipmentSlot::Head,
+            _ => return None,
+        })
+    }
+
+    /// Returns the slot with the id used before 1.9, when there was no off
+    /// hand
+    pub fn from_legacy_id(id: i32) -> Option<EquipmentSlot> {
+        if id == 0 {
+            Some(EquipmentSlot::MainHand)
+        } else {
+            EquipmentSlot::from_id(id + 1)
+        }
+    }
+}
+
+/// The items in an entity's hands and armor slots
+#[derive(Default)]
+pub struct Equipment {
+    slots: [Option<item::Stack>; 6],
+    /// Set when a slot changes, until the entity's model is rebuilt
+    pub dirty: bool,
+}
+
+impl Equipment {
+    pub fn new() -> Equipment {
+        Default::default()
+    }
+
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&item::Stack> {
+        self.slots[slot as usize].as_ref()
+    }
+
+    pub fn set(&mut self, slot: EquipmentSlot, stack: Option<item::Stack>) {
+        let stack = stack.filter(|stack| stack.id >= 0 && stack.count > 0);
+        if self.slots[slot as usize] != stack {
+            self.slots[slot as usize] = stack;
+            self.dirty = true;
+        }
+    }
+}
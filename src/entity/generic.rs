@@ -0,0 +1,203 @@
+use super::equipment::Equipment;
+use super::metadata::EntityMetadata;
+use super::types::EntityType;
+use super::{Bounds, Light, Position, Rotation, TargetPosition, TargetRotation, Velocity};
+use crate::ecs;
+use crate::render;
+use crate::render::model;
+use crate::world;
+use cgmath::{Decomposed, Matrix4, Point3, Quaternion, Rad, Rotation3, Vector3};
+use collision::Aabb3;
+
+pub fn add_systems(m: &mut ecs::Manager) {
+    let sys = GenericRenderer::new(m);
+    m.add_render_system(sys);
+}
+
+/// Creates a non-player entity of the given type
+pub fn create(m: &mut ecs::Manager, ty: EntityType) -> ecs::Entity {
+    let (width, height) = ty.size();
+    let entity = m.create_entity();
+    m.add_component_direct(entity, Position::new(0.0, 0.0, 0.0));
+    m.add_component_direct(entity, TargetPosition::new(0.0, 0.0, 0.0));
+    m.add_component_direct(entity, Rotation::new(0.0, 0.0));
+    m.add_component_direct(entity, TargetRotation::new(0.0, 0.0));
+    m.add_component_direct(entity, Velocity::new(0.0, 0.0, 0.0));
+    m.add_component_direct(
+        entity,
+        Bounds::new(Aabb3::new(
+            Point3::new(-width / 2.0, 0.0, -width / 2.0),
+            Point3::new(width / 2.0, height, width / 2.0),
+        )),
+    );
+    m.add_component_direct(entity, ty);
+    m.add_component_direct(entity, EntityMetadata::new());
+    m.add_component_direct(entity, Equipment::new());
+    m.add_component_direct(entity, GenericModel::new());
+    m.add_component_direct(entity, Light::new());
+    entity
+}
+
+/// A textured box the size of the entity, used until entities have
+/// proper models.
+#[derive(Default)]
+pub struct GenericModel {
+    model: Option<model::ModelKey>,
+    /// The height of the current model
+    height: f64,
+}
+
+impl GenericModel {
+    pub fn new() -> GenericModel {
+        Default::default()
+    }
+}
+
+struct GenericRenderer {
+    filter: ecs::Filter,
+    generic_model: ecs::Key<GenericModel>,
+    entity_type: ecs::Key<EntityType>,
+    position: ecs::Key<Position>,
+    rotation: ecs::Key<Rotation>,
+    light: ecs::Key<Light>,
+    metadata: ecs::Key<EntityMetadata>,
+}
+
+impl GenericRenderer {
+    fn new(m: &mut ecs::Manager) -> GenericRenderer {
+        let generic_model = m.get_key();
+        let entity_type = m.get_key();
+        let position = m.get_key();
+        let rotation = m.get_key();
+        let light = m.get_key();
+        GenericRenderer {
+            filter: ecs::Filter::new()
+                .with(generic_model)
+                .with(entity_type)
+                .with(position)
+                .with(rotation)
+                .with(light),
+            generic_model,
+            entity_type,
+            position,
+            rotation,
+            light,
+            metadata: m.get_key(),
+        }
+    }
+}
+
+impl ecs::System for GenericRenderer {
+    fn filter(&self) -> &ecs::Filter {
+        &self.filter
+    }
+
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        world: &mut world::World,
+        renderer: Option<&mut render::Renderer>,
+    ) {
+        use std::f32::consts::PI;
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
+        for e in m.find(&self.filter) {
+            if let Some(metadata) = m.get_component_mut(e, self.metadata) {
+                if metadata.dirty {
+                    metadata.dirty = false;
+                    self.entity_removed(m, e, world, Some(&mut *renderer));
+                    self.entity_added(m, e, world, Some(&mut *renderer));
+                }
+            }
+            let generic_model = m.get_component(e, self.generic_model).unwrap();
+            let height = generic_model.height;
+            let position = m.get_component(e, self.position).unwrap();
+            let rotation = m.get_component(e, self.rotation).unwrap();
+            let light = m.get_component(e, self.light).unwrap();
+
+            if let Some(key) = generic_model.model {
+                let mdl = renderer.model.get_model(key).unwrap();
+                mdl.block_light = light.block_light;
+                mdl.sky_light = light.sky_light;
+                mdl.matrix[0] = Matrix4::from(Decomposed {
+                    scale: 1.0,
+                    rot: Quaternion::from_angle_y(Rad(PI + rotation.yaw as f32)),
+                    disp: Vector3::new(
+                        position.position.x as f32,
+                        -(position.position.y + height / 2.0) as f32,
+                        position.position.z as f32,
+                    ),
+                });
+            }
+        }
+    }
+
+    fn entity_added(
+        &mut self,
+        m: &mut ecs::Manager,
+        e: ecs::Entity,
+        _: &mut world::World,
+        renderer: Option<&mut render::Renderer>,
+    ) {
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
+        let ty = *m.get_component(e, self.entity_type).unwrap();
+        let (mut width, mut height) = ty.size();
+        if let Some(metadata) = m.get_component(e, self.metadata) {
+            if metadata.invisible {
+                return;
+            }
+            if metadata.baby {
+                width /= 2.0;
+                height /= 2.0;
+            }
+        }
+        if width <= 0.0 || height <= 0.0 {
+            // Nothing to draw for markers and lightning
+            return;
+        }
+        let texture = render::Renderer::get_texture(renderer.get_textures_ref(), ty.texture());
+        let mut verts = vec![];
+        model::append_box(
+            &mut verts,
+            -width as f32 / 2.0,
+            -height as f32 / 2.0,
+            -width as f32 / 2.0,
+            width as f32,
+            height as f32,
+            width as f32,
+            [
+                Some(texture.clone()),
+                Some(texture.clone()),
+                Some(texture.clone()),
+                Some(texture.clone()),
+                Some(texture.clone()),
+                Some(texture),
+            ],
+        );
+        let generic_model = m.get_component_mut(e, self.generic_model).unwrap();
+        generic_model.model = Some(renderer.model.create_model(model::DEFAULT, vec![verts]));
+        generic_model.height = height;
+    }
+
+    fn entity_removed(
+        &mut self,
+        m: &mut ecs::Manager,
+        e: ecs::Entity,
+        _: &mut world::World,
+        renderer: Option<&mut render::Renderer>,
+    ) {
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
+        let generic_model = m.get_component_mut(e, self.generic_model).unwrap();
+        if let Some(model) = generic_model.model.take() {
+            renderer.model.remove_model(model);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use super::types::EntityType;
+use crate::format;
+use crate::types::{self, PoseData};
+
+/// Metadata indexes that moved between protocol versions
+struct Indexes {
+    custom_name: i32,
+    custom_name_visible: i32,
+    pose: Option<i32>,
+    health: i32,
+    baby: i32,
+}
+
+impl Indexes {
+    fn for_version(protocol_version: i32) -> Indexes {
+        if protocol_version >= 755 {
+            Indexes {
+                custom_name: 2,
+                custom_name_visible: 3,
+                pose: Some(6),
+                health: 9,
+                baby: 16,
+            }
+        } else if protocol_version >= 573 {
+            Indexes {
+                custom_name: 2,
+                custom_name_visible: 3,
+                pose: Some(6),
+                health: 8,
+                baby: 15,
+            }
+        } else if protocol_version >= 451 {
+            Indexes {
+                custom_name: 2,
+                custom_name_visible: 3,
+                pose: Some(6),
+                health: 8,
+                baby: 14,
+            }
+        } else if protocol_version >= 210 {
+            Indexes {
+                custom_name: 2,
+                custom_name_visible: 3,
+                pose: None,
+                health: 7,
+                baby: 12,
+            }
+        } else if protocol_version >= 74 {
+            Indexes {
+                custom_name: 2,
+                custom_name_visible: 3,
+                pose: None,
+                health: 6,
+                baby: 11,
+            }
+        } else if protocol_version >= 47 {
+            Indexes {
+                custom_name: 2,
+                custom_name_visible: 3,
+                pose: None,
+                health: 6,
+                baby: 12,
+            }
+        } else {
+            Indexes {
+                custom_name: 10,
+                custom_name_visible: 11,
+                pose: None,
+                health: 6,
+                baby: 12,
+            }
+        }
+    }
+}
+
+/// The state of an entity sent by the server as entity metadata
+pub struct EntityMetadata {
+    pub on_fire: bool,
+    pub sneaking: bool,
+    pub sprinting: bool,
+    pub swimming: bool,
+    pub invisible: bool,
+    pub glowing: bool,
+    pub custom_name: Option<format::Component>,
+    pub custom_name_visible: bool,
+    pub pose: PoseData,
+    /// Only sent for living entities
+    pub health: Option<f32>,
+    pub baby: bool,
+    /// Set whenever a value changes in a way that affects the model
+    pub dirty: bool,
+}
+
+impl Default for EntityMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityMetadata {
+    pub fn new() -> EntityMetadata {
+        EntityMetadata {
+            on_fire: false,
+            sneaking: false,
+            sprinting: false,
+            swimming: false,
+            invisible: false,
+            glowing: false,
+            custom_name: None,
+            custom_name_visible: false,
+            pose: PoseData::Standing,
+            health: None,
+            baby: false,
+            dirty: false,
+        }
+    }
+
+    /// Updates the state with the values present in the metadata, values
+    /// which are missing are left unchanged.
+    pub fn apply(&mut self, metadata: &types::Metadata, ty: EntityType, protocol_version: i32) {
+        use crate::types::Value;
+        let indexes = Indexes::for_version(protocol_version);
+        let (invisible, baby, pose) = (self.invisible, self.baby, self.pose);
+
+        if let Some(&Value::Byte(flags)) = metadata.get_value(0) {
+            self.on_fire = flags & 0x01 != 0;
+            self.sneaking = flags & 0x02 != 0;
+            self.sprinting = flags & 0x08 != 0;
+            // Before 1.13 this bit meant eating or blocking
+            self.swimming = protocol_version >= 393 && flags & 0x10 != 0;
+            self.invisible = flags & 0x20 != 0;
+            self.glowing = flags & 0x40 != 0;
+            if indexes.pose.is_none() {
+                self.pose = if self.sneaking {
+                    PoseData::Sneaking
+                } else {
+                    PoseData::Standing
+                };
+            }
+        }
+
+        // Before 1.8 only living entities could be named
+        if protocol_version >= 47 || ty.is_living() {
+            match metadata.get_value(indexes.custom_name) {
+                Some(Value::String(name)) => {
+                    self.custom_name = if name.is_empty() {
+                        None
+                    } else {
+                        let mut name = format::Component::Text(format::TextComponent::new(name));
+                        format::convert_legacy(&mut name);
+                        Some(name)
+                    };
+                }
+                Some(Value::OptionalFormatComponent(name)) => {
+                    self.custom_name = name.data.first().cloned();
+                }
+                _ => {}
+            }
+            match metadata.get_value(indexes.custom_name_visible) {
+                Some(&Value::Byte(visible)) => self.custom_name_visible = visible != 0,
+                Some(&Value::Bool(visible)) => self.custom_name_visible = visible,
+                _ => {}
+            }
+        }
+
+        if let Some(index) = indexes.pose {
+            if let Some(&Value::Pose(pose)) = metadata.get_value(index) {
+                self.pose = pose;
+            }
+        }
+
+        if ty.is_living() {
+            if let Some(&Value::Float(health)) = metadata.get_value(indexes.health) {
+                self.health = Some(health);
+            }
+        }
+
+        if ty.can_be_baby() {
+            match metadata.get_value(indexes.baby) {
+                Some(&Value::Bool(baby)) => self.baby = baby,
+                // Zombies use 1 for babies, other mobs a negative age
+                Some(&Value::Byte(age)) if protocol_version < 74 => {
+                    self.baby = if is_zombie(ty) { age == 1 } else { age < 0 };
+                }
+                Some(&Value::Int(age)) if protocol_version < 74 => self.baby = age < 0,
+                _ => {}
+            }
+        }
+
+        if self.invisible != invisible || self.baby != baby || self.pose != pose {
+            self.dirty = true;
+        }
+    }
+}
+
+fn is_zombie(ty: EntityType) -> bool {
+    matches!(
+        ty,
+        EntityType::Zombie | EntityType::ZombiePigman | EntityType::ZombieVillager
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_for_version() {
+        // Protocol version, custom name, custom name visible, pose,
+        // health and baby
+        let table = [
+            (5, 10, 11, None, 6, 12),
+            (47, 2, 3, None, 6, 12),
+            (107, 2, 3, None, 6, 11),
+            (210, 2, 3, None, 7, 12),
+            (404, 2, 3, None, 7, 12),
+            (477, 2, 3, Some(6), 8, 14),
+            (573, 2, 3, Some(6), 8, 15),
+            (755, 2, 3, Some(6), 9, 16),
+        ];
+        for &(version, custom_name, custom_name_visible, pose, health, baby) in table.iter() {
+            let indexes = Indexes::for_version(version);
+            assert_eq!(
+                (
+                    indexes.custom_name,
+                    indexes.custom_name_visible,
+                    indexes.pose,
+                    indexes.health,
+                    indexes.baby,
+                ),
+                (custom_name, custom_name_visible, pose, health, baby),
+                "protocol {}",
+                version
+            );
+        }
+    }
+}
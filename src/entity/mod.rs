@@ -1,9 +1,15 @@
 pub mod block_entity;
+pub mod equipment;
+pub mod generic;
+pub mod metadata;
 pub mod player;
+pub mod types;
 
 use crate::ecs;
-use cgmath::Vector3;
+use crate::model;
+use cgmath::{InnerSpace, Vector3};
 use collision::Aabb3;
+use std::sync::{Arc, RwLock};
 
 mod systems;
 
@@ -12,6 +18,7 @@ pub fn add_systems(m: &mut ecs::Manager) {
     m.add_system(sys);
 
     player::add_systems(m);
+    generic::add_systems(m);
 
     let sys = systems::ApplyVelocity::new(m);
     m.add_system(sys);
@@ -68,6 +75,14 @@ impl TargetPosition {
     }
 }
 
+/// How much of an entity's horizontal velocity is kept each tick on the
+/// ground
+pub const GROUND_FRICTION: f64 = 0.546;
+/// How much of an entity's velocity is kept each tick in the air
+pub const AIR_DRAG: f64 = 0.91;
+/// Velocities slower than this, in blocks per tick, are stopped
+pub const MIN_VELOCITY: f64 = 0.003;
+
 /// Velocity of an entity in the world.
 #[derive(Debug)]
 pub struct Velocity {
@@ -84,6 +99,29 @@ impl Velocity {
     pub fn zero() -> Velocity {
         Velocity::new(0.0, 0.0, 0.0)
     }
+
+    /// Slows the horizontal velocity of an entity that moves itself,
+    /// stopping each axis once it is slow enough.
+    pub fn apply_friction(&mut self, on_ground: bool) {
+        let friction = if on_ground { GROUND_FRICTION } else { AIR_DRAG };
+        self.velocity.x *= friction;
+        self.velocity.z *= friction;
+        if self.velocity.x.abs() < MIN_VELOCITY {
+            self.velocity.x = 0.0;
+        }
+        if self.velocity.z.abs() < MIN_VELOCITY {
+            self.velocity.z = 0.0;
+        }
+    }
+
+    /// Slows the velocity of an entity moved by the server as if it were
+    /// in the air, stopping it once it is slow enough.
+    pub fn apply_drag(&mut self) {
+        self.velocity *= AIR_DRAG;
+        if self.velocity.magnitude2() < MIN_VELOCITY * MIN_VELOCITY {
+            self.velocity = Vector3::new(0.0, 0.0, 0.0);
+        }
+    }
 }
 
 /// Rotation of an entity in the world
@@ -142,6 +180,9 @@ impl Bounds {
 #[derive(Default)]
 pub struct GameInfo {
     pub delta: f64,
+    /// The models of items held and worn by entities, only set when
+    /// rendering
+    pub models: Option<Arc<RwLock<model::Factory>>>,
 }
 
 impl GameInfo {
@@ -161,3 +202,50 @@ impl Light {
         Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn friction_on_ground_and_in_air() {
+        let mut ground = Velocity::new(1.0, 0.5, -1.0);
+        ground.apply_friction(true);
+        assert_eq!(ground.velocity, Vector3::new(0.546, 0.5, -0.546));
+
+        let mut air = Velocity::new(1.0, 0.5, -1.0);
+        air.apply_friction(false);
+        // Vertical velocity is left to gravity
+        assert_eq!(air.velocity, Vector3::new(0.91, 0.5, -0.91));
+    }
+
+    #[test]
+    fn friction_stops_each_axis() {
+        let mut velocity = Velocity::new(0.005, 0.0, 1.0);
+        velocity.apply_friction(true);
+        assert_eq!(velocity.velocity.x, 0.0);
+        assert_eq!(velocity.velocity.z, 0.546);
+
+        let mut ticks = 0;
+        while velocity.velocity.z != 0.0 {
+            velocity.apply_friction(true);
+            ticks += 1;
+        }
+        // 0.546^10 is the first speed under MIN_VELOCITY
+        assert_eq!(ticks, 9);
+    }
+
+    #[test]
+    fn drag_stops_slow_entities() {
+        let mut velocity = Velocity::new(0.0, -1.0, 0.0);
+        velocity.apply_drag();
+        assert_eq!(velocity.velocity, Vector3::new(0.0, -0.91, 0.0));
+
+        // Each axis is under MIN_VELOCITY but together they aren't
+        let mut velocity = Velocity::new(0.002, 0.002, 0.002);
+        velocity.apply_drag();
+        assert_ne!(velocity.velocity, Vector3::new(0.0, 0.0, 0.0));
+        velocity.apply_drag();
+        assert_eq!(velocity.velocity, Vector3::new(0.0, 0.0, 0.0));
+    }
+}
@@ -1,3 +1,6 @@
+use super::equipment::{Equipment, EquipmentSlot};
+use super::metadata::EntityMetadata;
+use super::types::EntityType;
 use super::{
     Bounds, GameInfo, Gravity, Light, Position, Rotation, TargetPosition, TargetRotation, Velocity,
 };
@@ -8,13 +11,14 @@ use crate::render::model::{self, FormatState};
 use crate::settings::Stevenkey;
 use crate::shared::Position as BPosition;
 use crate::types::hash::FNVHash;
-use crate::types::Gamemode;
+use crate::types::{Gamemode, PoseData};
 use crate::world;
 use cgmath::{self, Decomposed, Matrix4, Point3, Quaternion, Rad, Rotation3, Vector3};
 use collision::{Aabb, Aabb3};
 use instant::Instant;
 use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
+use std::sync::{Arc, RwLock};
 
 pub fn add_systems(m: &mut ecs::Manager) {
     let sys = MovementHandler::new(m);
@@ -43,6 +47,8 @@ pub fn create_local(m: &mut ecs::Manager) -> ecs::Entity {
     );
     m.add_component_direct(entity, PlayerModel::new("", false, false, true));
     m.add_component_direct(entity, Light::new());
+    m.add_component_direct(entity, EntityType::Player);
+    m.add_component_direct(entity, EntityMetadata::new());
     entity
 }
 
@@ -62,6 +68,9 @@ pub fn create_remote(m: &mut ecs::Manager, name: &str) -> ecs::Entity {
     );
     m.add_component_direct(entity, PlayerModel::new(name, true, true, false));
     m.add_component_direct(entity, Light::new());
+    m.add_component_direct(entity, EntityType::Player);
+    m.add_component_direct(entity, EntityMetadata::new());
+    m.add_component_direct(entity, Equipment::new());
     entity
 }
 
@@ -80,6 +89,9 @@ pub struct PlayerModel {
     still_time: f64,
     idle_time: f64,
     arm_time: f64,
+    off_arm_time: f64,
+    /// Whether the main and off hands hold an item, which raises the arm
+    holding: [bool; 2],
 }
 
 impl PlayerModel {
@@ -99,6 +111,8 @@ impl PlayerModel {
             still_time: 0.0,
             idle_time: 0.0,
             arm_time: 0.0,
+            off_arm_time: 0.0,
+            holding: [false, false],
         }
     }
 
@@ -106,6 +120,15 @@ impl PlayerModel {
         self.skin_url = skin;
         self.dirty = true;
     }
+
+    /// Starts swinging the main or off hand's arm, e.g. to hit something
+    pub fn swing_arm(&mut self, off_hand: bool) {
+        if off_hand {
+            self.off_arm_time = 15.0;
+        } else {
+            self.arm_time = 15.0;
+        }
+    }
 }
 
 struct PlayerRenderer {
@@ -115,6 +138,8 @@ struct PlayerRenderer {
     rotation: ecs::Key<Rotation>,
     game_info: ecs::Key<GameInfo>,
     light: ecs::Key<Light>,
+    metadata: ecs::Key<EntityMetadata>,
+    equipment: ecs::Key<Equipment>,
 }
 
 impl PlayerRenderer {
@@ -134,10 +159,13 @@ impl PlayerRenderer {
             rotation,
             game_info: m.get_key(),
             light,
+            metadata: m.get_key(),
+            equipment: m.get_key(),
         }
     }
 }
 
+#[derive(Clone, Copy)]
 enum PlayerModelPart {
     Head = 0,
     Body = 1,
@@ -159,10 +187,14 @@ impl ecs::System for PlayerRenderer {
         &mut self,
         m: &mut ecs::Manager,
         world: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     ) {
         use std::f32::consts::PI;
         use std::f64::consts::PI as PI64;
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
         let world_entity = m.get_world();
         let delta = m
             .get_component_mut(world_entity, self.game_info)
@@ -173,10 +205,23 @@ impl ecs::System for PlayerRenderer {
             let position = m.get_component_mut(e, self.position).unwrap();
             let rotation = m.get_component_mut(e, self.rotation).unwrap();
             let light = m.get_component(e, self.light).unwrap();
-
-            if player_model.dirty {
-                self.entity_removed(m, e, world, renderer);
-                self.entity_added(m, e, world, renderer);
+            let (invisible, sneaking) = match m.get_component_mut(e, self.metadata) {
+                Some(metadata) => {
+                    metadata.dirty = false;
+                    (metadata.invisible, metadata.pose == PoseData::Sneaking)
+                }
+                None => (false, false),
+            };
+            let equipment_changed = m
+                .get_component_mut(e, self.equipment)
+                .map_or(false, |equipment| std::mem::take(&mut equipment.dirty));
+
+            if player_model.dirty || equipment_changed || invisible != player_model.model.is_none()
+            {
+                self.entity_removed(m, e, world, Some(&mut *renderer));
+                if !invisible {
+                    self.entity_added(m, e, world, Some(&mut *renderer));
+                }
             }
 
             if let Some(pmodel) = player_model.model {
@@ -200,6 +245,12 @@ impl ecs::System for PlayerRenderer {
                         position.position.z as f32,
                     )
                 };
+                // Crouched players lean forward with their legs moved back
+                let (offset, body_angle, leg_offset) = if sneaking {
+                    (offset + Vector3::new(0.0, 0.2, 0.0), 0.5, 4.0 / 16.0)
+                } else {
+                    (offset, 0.0, 0.0)
+                };
                 let offset_matrix = Matrix4::from(Decomposed {
                     scale: 1.0,
                     rot: Quaternion::from_angle_y(Rad(PI + rotation.yaw as f32)),
@@ -227,7 +278,7 @@ impl ecs::System for PlayerRenderer {
                 mdl.matrix[PlayerModelPart::Body as usize] = offset_matrix
                     * Matrix4::from(Decomposed {
                         scale: 1.0,
-                        rot: Quaternion::from_angle_x(Rad(0.0)),
+                        rot: Quaternion::from_angle_x(Rad(body_angle)),
                         disp: Vector3::new(0.0, -12.0 / 16.0 - 6.0 / 16.0, 0.0),
                     });
 
@@ -243,13 +294,13 @@ impl ecs::System for PlayerRenderer {
                     * Matrix4::from(Decomposed {
                         scale: 1.0,
                         rot: Quaternion::from_angle_x(Rad(ang as f32)),
-                        disp: Vector3::new(2.0 / 16.0, -12.0 / 16.0, 0.0),
+                        disp: Vector3::new(2.0 / 16.0, -12.0 / 16.0, leg_offset),
                     });
                 mdl.matrix[PlayerModelPart::LegLeft as usize] = offset_matrix
                     * Matrix4::from(Decomposed {
                         scale: 1.0,
                         rot: Quaternion::from_angle_x(Rad(-ang as f32)),
-                        disp: Vector3::new(-2.0 / 16.0, -12.0 / 16.0, 0.0),
+                        disp: Vector3::new(-2.0 / 16.0, -12.0 / 16.0, leg_offset),
                     });
 
                 let mut i_time = player_model.idle_time;
@@ -264,6 +315,18 @@ impl ecs::System for PlayerRenderer {
                 } else {
                     player_model.arm_time -= delta;
                 }
+                if player_model.off_arm_time <= 0.0 {
+                    player_model.off_arm_time = 0.0;
+                } else {
+                    player_model.off_arm_time -= delta;
+                }
+
+                // Arms holding an item are raised a little
+                let held_angle = |holding: bool| if holding { PI64 / 10.0 } else { 0.0 };
+                let (right_held, left_held) = (
+                    held_angle(player_model.holding[0]),
+                    held_angle(player_model.holding[1]),
+                );
 
                 mdl.matrix[PlayerModelPart::ArmRight as usize] = offset_matrix
                     * Matrix4::from_translation(Vector3::new(
@@ -276,7 +339,8 @@ impl ecs::System for PlayerRenderer {
                         (i_time.cos() * 0.06 - 0.06) as f32
                     )))
                     * Matrix4::from(Quaternion::from_angle_x(Rad((i_time.sin() * 0.06
-                        - ((7.5 - (player_model.arm_time - 7.5).abs()) / 7.5))
+                        - ((7.5 - (player_model.arm_time - 7.5).abs()) / 7.5)
+                        - right_held)
                         as f32)));
 
                 mdl.matrix[PlayerModelPart::ArmLeft as usize] = offset_matrix
@@ -289,7 +353,10 @@ impl ecs::System for PlayerRenderer {
                     * Matrix4::from(Quaternion::from_angle_z(Rad(
                         -(i_time.cos() * 0.06 - 0.06) as f32
                     )))
-                    * Matrix4::from(Quaternion::from_angle_x(Rad(-(i_time.sin() * 0.06) as f32)));
+                    * Matrix4::from(Quaternion::from_angle_x(Rad((-(i_time.sin() * 0.06)
+                        - ((7.5 - (player_model.off_arm_time - 7.5).abs()) / 7.5)
+                        - left_held)
+                        as f32)));
 
                 let mut update = true;
                 if position.moved {
@@ -324,9 +391,16 @@ impl ecs::System for PlayerRenderer {
         &mut self,
         m: &mut ecs::Manager,
         e: ecs::Entity,
-        _: &mut world::World,
-        renderer: &mut render::Renderer,
+        world: &mut world::World,
+        renderer: Option<&mut render::Renderer>,
     ) {
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
+        let models = m
+            .get_component(m.get_world(), self.game_info)
+            .and_then(|info| info.models.clone());
         let player_model = m.get_component_mut(e, self.player_model).unwrap();
 
         player_model.dirty = false;
@@ -467,6 +541,24 @@ impl ecs::System for PlayerRenderer {
             );
         }
 
+        let mut parts = vec![head_verts, body_verts];
+        parts.extend(part_verts);
+        player_model.holding = [false, false];
+        if let Some(equipment) = m.get_component(e, self.equipment) {
+            let textures = renderer.get_textures_ref();
+            append_armor(
+                &mut parts,
+                equipment,
+                world,
+                textures,
+                player_model.has_head,
+            );
+            if let Some(models) = models {
+                player_model.holding =
+                    append_held_items(&mut parts, equipment, world, &models, textures);
+            }
+        }
+
         let mut name_verts = vec![];
         if player_model.has_name_tag {
             let mut state = FormatState {
@@ -500,18 +592,8 @@ impl ecs::System for PlayerRenderer {
             name_verts.extend_from_slice(&state.text);
         }
 
-        player_model.model = Some(renderer.model.create_model(
-            model::DEFAULT,
-            vec![
-                head_verts,
-                body_verts,
-                part_verts[0].clone(),
-                part_verts[1].clone(),
-                part_verts[2].clone(),
-                part_verts[3].clone(),
-                name_verts,
-            ],
-        ));
+        parts.push(name_verts);
+        player_model.model = Some(renderer.model.create_model(model::DEFAULT, parts));
     }
 
     fn entity_removed(
@@ -519,8 +601,12 @@ impl ecs::System for PlayerRenderer {
         m: &mut ecs::Manager,
         e: ecs::Entity,
         _: &mut world::World,
-        renderer: &mut render::Renderer,
+        renderer: Option<&mut render::Renderer>,
     ) {
+        let renderer = match renderer {
+            Some(renderer) => renderer,
+            None => return,
+        };
         let player_model = m.get_component_mut(e, self.player_model).unwrap();
         if let Some(model) = player_model.model.take() {
             renderer.model.remove_model(model);
@@ -535,6 +621,253 @@ impl ecs::System for PlayerRenderer {
     }
 }
 
+/// A box of an armor piece: the part it is on, where its texture starts,
+/// its corner and size in sixteenths of a block and how much more it is
+/// grown than the piece's other boxes.
+type ArmorBox = (PlayerModelPart, (f32, f32), [f32; 3], [f32; 3], f32);
+
+const HEAD_BOX: ([f32; 3], [f32; 3]) = ([-4.0, 0.0, -4.0], [8.0, 8.0, 8.0]);
+const BODY_BOX: ([f32; 3], [f32; 3]) = ([-4.0, -6.0, -2.0], [8.0, 12.0, 4.0]);
+const LIMB_BOX: ([f32; 3], [f32; 3]) = ([-2.0, -12.0, -2.0], [4.0, 12.0, 4.0]);
+
+/// Returns the name armor for the slot ends with, and the boxes it is
+/// drawn with like vanilla's biped model
+fn armor_boxes(slot: EquipmentSlot) -> Option<(&'static str, Vec<ArmorBox>)> {
+    use PlayerModelPart::*;
+    Some(match slot {
+        EquipmentSlot::Head => (
+            "_helmet",
+            vec![
+                (Head, (0.0, 0.0), HEAD_BOX.0, HEAD_BOX.1, 0.0),
+                (Head, (32.0, 0.0), HEAD_BOX.0, HEAD_BOX.1, 0.5),
+            ],
+        ),
+        EquipmentSlot::Chest => (
+            "_chestplate",
+            vec![
+                (Body, (16.0, 16.0), BODY_BOX.0, BODY_BOX.1, 0.0),
+                (ArmLeft, (40.0, 16.0), LIMB_BOX.0, LIMB_BOX.1, 0.0),
+                (ArmRight, (40.0, 16.0), LIMB_BOX.0, LIMB_BOX.1, 0.0),
+            ],
+        ),
+        EquipmentSlot::Legs => (
+            "_leggings",
+            vec![
+                (Body, (16.0, 16.0), BODY_BOX.0, BODY_BOX.1, 0.0),
+                (LegLeft, (0.0, 16.0), LIMB_BOX.0, LIMB_BOX.1, 0.0),
+                (LegRight, (0.0, 16.0), LIMB_BOX.0, LIMB_BOX.1, 0.0),
+            ],
+        ),
+        EquipmentSlot::Feet => (
+            "_boots",
+            vec![
+                (LegLeft, (0.0, 16.0), LIMB_BOX.0, LIMB_BOX.1, 0.0),
+                (LegRight, (0.0, 16.0), LIMB_BOX.0, LIMB_BOX.1, 0.0),
+            ],
+        ),
+        EquipmentSlot::MainHand | EquipmentSlot::OffHand => return None,
+    })
+}
+
+/// Adds the armor the entity wears to the parts of its model, drawn from
+/// the 64 by 32 pixel textures in `models/armor`
+fn append_armor(
+    parts: &mut [Vec<model::Vertex>],
+    equipment: &Equipment,
+    world: &world::World,
+    textures: &RwLock<render::TextureManager>,
+    has_head: bool,
+) {
+    for &slot in &EquipmentSlot::ARMOR {
+        let (suffix, boxes) = match armor_boxes(slot) {
+            Some(armor) => armor,
+            None => continue,
+        };
+        let stack = match equipment.get(slot) {
+            Some(stack) => stack,
+            None => continue,
+        };
+        let material = match world
+            .item_map
+            .by_vanilla_id(stack.id, stack.damage)
+            .and_then(|item| item.name.strip_suffix(suffix))
+        {
+            Some("golden") => "gold",
+            Some(material) => material,
+            None => continue,
+        };
+        // Leggings are drawn closer to the body so they are covered by
+        // the chestplate and boots
+        let (layer, grow) = if slot == EquipmentSlot::Legs {
+            (2, 0.5)
+        } else {
+            (1, 1.0)
+        };
+        let texture = format!("models/armor/{}_layer_{}", material, layer);
+        let mut layers = vec![];
+        if material == "leather" {
+            let colour = stack
+                .dye_colour()
+                .unwrap_or(crate::model::item::LEATHER_COLOUR);
+            layers.push((texture.clone(), colour));
+            layers.push((format!("{}_overlay", texture), (255, 255, 255)));
+        } else {
+            layers.push((texture, (255, 255, 255)));
+        }
+
+        for (i, (texture, colour)) in layers.into_iter().enumerate() {
+            let texture = render::Renderer::get_texture(textures, &texture);
+            // Each layer is slightly larger than the last to stop them
+            // fighting over which is drawn
+            let grow = grow + i as f32 * 0.05;
+            for &(part, uv, from, size, extra) in &boxes {
+                if !has_head && matches!(part, PlayerModelPart::Head) {
+                    continue;
+                }
+                let verts = &mut parts[part as usize];
+                let start = verts.len();
+                append_armor_box(verts, &texture, uv, from, size, grow + extra);
+                for vert in &mut verts[start..] {
+                    vert.r = (vert.r as u32 * colour.0 as u32 / 255) as u8;
+                    vert.g = (vert.g as u32 * colour.1 as u32 / 255) as u8;
+                    vert.b = (vert.b as u32 * colour.2 as u32 / 255) as u8;
+                }
+            }
+        }
+    }
+}
+
+/// Adds a box grown by `grow` sixteenths on every side, textured like
+/// the boxes of vanilla's models from the texture position `uv`
+fn append_armor_box(
+    verts: &mut Vec<model::Vertex>,
+    texture: &render::Texture,
+    (u, v): (f32, f32),
+    from: [f32; 3],
+    [w, h, d]: [f32; 3],
+    grow: f32,
+) {
+    let rel = |x: f32, y: f32, width: f32, height: f32| {
+        Some(texture.relative(x / 64.0, y / 32.0, width / 64.0, height / 32.0))
+    };
+    model::append_box(
+        verts,
+        (from[0] - grow) / 16.0,
+        (from[1] - grow) / 16.0,
+        (from[2] - grow) / 16.0,
+        (w + grow * 2.0) / 16.0,
+        (h + grow * 2.0) / 16.0,
+        (d + grow * 2.0) / 16.0,
+        [
+            rel(u + d + w, v, w, d),           // Down
+            rel(u + d, v, w, d),               // Up
+            rel(u + d, v + d, w, h),           // North
+            rel(u + d * 2.0 + w, v + d, w, h), // South
+            rel(u + d + w, v + d, d, h),       // West
+            rel(u, v + d, d, h),               // East
+        ],
+    );
+}
+
+/// Adds the items held in the entity's hands to its arms, returning
+/// which hands hold something
+fn append_held_items(
+    parts: &mut [Vec<model::Vertex>],
+    equipment: &Equipment,
+    world: &world::World,
+    models: &Arc<RwLock<crate::model::Factory>>,
+    textures: &RwLock<render::TextureManager>,
+) -> [bool; 2] {
+    let mut holding = [false, false];
+    for (i, &(slot, part, left)) in [
+        (EquipmentSlot::MainHand, PlayerModelPart::ArmRight, false),
+        (EquipmentSlot::OffHand, PlayerModelPart::ArmLeft, true),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let stack = match equipment.get(slot) {
+            Some(stack) => stack,
+            None => continue,
+        };
+        holding[i] = true;
+        let item = match world.item_map.by_vanilla_id(stack.id, stack.damage) {
+            Some(item) => item,
+            None => continue,
+        };
+        let block = item.block(&world.id_map);
+        let item_model = match crate::model::Factory::get_item_model(models, item, block) {
+            Some(item_model) => item_model,
+            None => continue,
+        };
+        let tint = models.read().unwrap().get_item_tint(item, block, stack);
+        for face in &item_model.faces {
+            let texture = render::Renderer::get_texture(textures, &face.texture);
+            let (r, g, b) = if face.tint_index == 0 {
+                tint
+            } else {
+                (255, 255, 255)
+            };
+            for (v, &(texture_x, texture_y)) in face.vertices.iter().zip(&face.texture_coords) {
+                let [x, y, z] = held_item_position(*v, &item_model.hand, left);
+                parts[part as usize].push(model::Vertex {
+                    x,
+                    y,
+                    z,
+                    texture: texture.clone(),
+                    texture_x,
+                    texture_y,
+                    r,
+                    g,
+                    b,
+                    a: 255,
+                    id: 0,
+                });
+            }
+        }
+    }
+    holding
+}
+
+/// Places a corner of an item model relative to the shoulder of the arm
+/// holding it, following vanilla's held item layer. Vanilla's arms turn
+/// around a point 1/16th lower and further in, with x and y flipped.
+fn held_item_position(v: [f64; 3], hand: &crate::model::ModelDisplay, left: bool) -> [f32; 3] {
+    let flip = if left { -1.0 } else { 1.0 };
+    let rotate_x = |[x, y, z]: [f64; 3], angle: f64| {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        [x, y * cos - z * sin, y * sin + z * cos]
+    };
+    let rotate_y = |[x, y, z]: [f64; 3], angle: f64| {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        [x * cos + z * sin, y, z * cos - x * sin]
+    };
+    let rotate_z = |[x, y, z]: [f64; 3], angle: f64| {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        [x * cos - y * sin, x * sin + y * cos, z]
+    };
+
+    let p = [
+        (v[0] - 0.5) * hand.scale[0],
+        (v[1] - 0.5) * hand.scale[1],
+        (v[2] - 0.5) * hand.scale[2],
+    ];
+    let p = rotate_z(p, hand.rotation[2] * flip);
+    let p = rotate_y(p, hand.rotation[1] * flip);
+    let p = rotate_x(p, hand.rotation[0]);
+    let p = [
+        p[0] + (hand.translation[0] * flip + flip) / 16.0,
+        p[1] + hand.translation[1] / 16.0 + 0.125,
+        p[2] + hand.translation[2] / 16.0 - 0.625,
+    ];
+    let p = rotate_x(rotate_y(p, 180.0), -90.0);
+    [
+        (-p[0] - flip / 16.0) as f32,
+        (-p[1] - 2.0 / 16.0) as f32,
+        p[2] as f32,
+    ]
+}
+
 #[derive(Default)]
 pub struct PlayerMovement {
     pub flying: bool,
@@ -625,7 +958,12 @@ impl ecs::System for MovementHandler {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, world: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        world: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         for e in m.find(&self.filter) {
             let movement = m.get_component_mut(e, self.movement).unwrap();
             if movement.flying && m.get_component(e, self.gravity).is_some() {
@@ -702,7 +1040,12 @@ impl ecs::System for MovementHandler {
                 velocity.velocity.y *= 0.98;
                 position.position.x += forward * yaw.cos() * speed;
                 position.position.z -= forward * yaw.sin() * speed;
+                // Horizontal velocity only comes from the server, e.g.
+                // knockback and explosions
+                position.position.x += velocity.velocity.x;
+                position.position.z += velocity.velocity.z;
                 position.position.y += velocity.velocity.y;
+                velocity.apply_friction(matches!(gravity, Some(ref gravity) if gravity.on_ground));
 
                 if !gamemode.noclip() {
                     let mut target = position.position;
@@ -716,12 +1059,18 @@ impl ecs::System for MovementHandler {
                         check_collisions(world, position, &last_position, player_bounds);
                     position.position.x = bounds.min.x + 0.3;
                     last_position.x = position.position.x;
+                    if xhit {
+                        velocity.velocity.x = 0.0;
+                    }
 
                     position.position.z = target.z;
                     let (bounds, zhit) =
                         check_collisions(world, position, &last_position, player_bounds);
                     position.position.z = bounds.min.z + 0.3;
                     last_position.z = position.position.z;
+                    if zhit {
+                        velocity.velocity.z = 0.0;
+                    }
 
                     // Half block jumps
                     // Minecraft lets you 'jump' up 0.5 blocks
@@ -30,15 +30,25 @@ impl ecs::System for ApplyVelocity {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        _: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         for e in m.find(&self.filter) {
             if m.get_component(e, self.movement).is_some() {
                 // Player's handle their own phyiscs
                 continue;
             }
+            let vel = m.get_component_mut(e, self.velocity).unwrap();
+            let velocity = vel.velocity;
+            // Other entities don't simulate physics, the server sends where
+            // they end up. Their velocity wears off like the local player's
+            // in the air so they don't drift away from where they're lerped.
+            vel.apply_drag();
             let pos = m.get_component_mut(e, self.position).unwrap();
-            let vel = m.get_component(e, self.velocity).unwrap();
-            pos.position += vel.velocity;
+            pos.position += velocity;
         }
     }
 }
@@ -66,7 +76,12 @@ impl ecs::System for ApplyGravity {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        _: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         for e in m.find(&self.filter) {
             if m.get_component(e, self.movement).is_some() {
                 // Player's handle their own phyiscs
@@ -102,7 +117,12 @@ impl ecs::System for UpdateLastPosition {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        _: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         for e in m.find(&self.filter) {
             let pos = m.get_component_mut(e, self.position).unwrap();
 
@@ -137,7 +157,12 @@ impl ecs::System for LerpPosition {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        _: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         let world_entity = m.get_world();
         let delta = m
             .get_component_mut(world_entity, self.game_info)
@@ -183,7 +208,12 @@ impl ecs::System for LerpRotation {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, _: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        _: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         use std::f64::consts::PI;
         let world_entity = m.get_world();
         let delta = m
@@ -241,7 +271,12 @@ impl ecs::System for LightEntity {
         &self.filter
     }
 
-    fn update(&mut self, m: &mut ecs::Manager, world: &mut world::World, _: &mut render::Renderer) {
+    fn update(
+        &mut self,
+        m: &mut ecs::Manager,
+        world: &mut world::World,
+        _: Option<&mut render::Renderer>,
+    ) {
         for e in m.find(&self.filter) {
             let pos = m.get_component(e, self.position).unwrap();
             let bounds = m.get_component(e, self.bounds).unwrap();
@@ -285,3 +320,50 @@ impl ecs::System for LightEntity {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::player::PlayerMovement;
+
+    #[test]
+    fn remote_velocity_decays() {
+        let mut m = ecs::Manager::new();
+        let sys = ApplyVelocity::new(&mut m);
+        m.add_system(sys);
+        let position = m.get_key::<Position>();
+        let velocity = m.get_key::<Velocity>();
+        let movement = m.get_key::<PlayerMovement>();
+
+        let remote = m.create_entity();
+        m.add_component(remote, position, Position::new(0.0, 64.0, 0.0));
+        m.add_component(remote, velocity, Velocity::new(1.0, 0.0, -0.5));
+        let player = m.create_entity();
+        m.add_component(player, position, Position::new(0.0, 64.0, 0.0));
+        m.add_component(player, velocity, Velocity::new(1.0, 0.0, -0.5));
+        m.add_component(player, movement, PlayerMovement::new());
+
+        let mut world = world::World::new(47);
+        m.tick(&mut world, None);
+        // Moved by the velocity from before the drag
+        let pos = m.get_component(remote, position).unwrap().position;
+        assert_eq!(pos, cgmath::Vector3::new(1.0, 64.0, -0.5));
+        let vel = m.get_component(remote, velocity).unwrap().velocity;
+        assert_eq!(vel, cgmath::Vector3::new(0.91, 0.0, -0.455));
+
+        for _ in 0..100 {
+            m.tick(&mut world, None);
+        }
+        let vel = m.get_component(remote, velocity).unwrap().velocity;
+        assert_eq!(vel, cgmath::Vector3::new(0.0, 0.0, 0.0));
+        let pos = m.get_component(remote, position).unwrap().position;
+        // A geometric series, short of the 1 / (1 - 0.91) it tends to
+        assert!(pos.x > 10.5 && pos.x < 1.0 / (1.0 - AIR_DRAG), "{:?}", pos);
+
+        // Players handle their own physics
+        let pos = m.get_component(player, position).unwrap().position;
+        assert_eq!(pos, cgmath::Vector3::new(0.0, 64.0, 0.0));
+        let vel = m.get_component(player, velocity).unwrap().velocity;
+        assert_eq!(vel, cgmath::Vector3::new(1.0, 0.0, -0.5));
+    }
+}
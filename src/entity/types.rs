@@ -0,0 +1,1002 @@
+use self::EntityType::*;
+
+macro_rules! define_entity_types {
+    ($($name:ident => ($width:expr, $height:expr, $texture:expr),)*) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum EntityType {
+            $($name,)*
+        }
+
+        impl EntityType {
+            /// The size of the entity's bounding box in blocks, as (width, height)
+            pub fn size(&self) -> (f64, f64) {
+                match *self {
+                    $(EntityType::$name => ($width, $height),)*
+                }
+            }
+
+            pub fn texture(&self) -> &'static str {
+                match *self {
+                    $(EntityType::$name => $texture,)*
+                }
+            }
+        }
+    };
+}
+
+define_entity_types! {
+    // Used for ids missing from the registry of the protocol version
+    Unknown => (0.5, 0.5, "steven:solid"),
+    AreaEffectCloud => (6.0, 0.5, "steven:solid"),
+    ArmorStand => (0.5, 1.975, "entity/armorstand/wood"),
+    Arrow => (0.5, 0.5, "entity/projectiles/arrow"),
+    Axolotl => (0.75, 0.42, "entity/axolotl/axolotl_lucy"),
+    Bat => (0.5, 0.9, "entity/bat"),
+    Bee => (0.7, 0.6, "entity/bee/bee"),
+    Blaze => (0.6, 1.8, "entity/blaze"),
+    Boat => (1.375, 0.5625, "entity/boat/boat_oak"),
+    Cat => (0.6, 0.7, "entity/cat/tabby"),
+    CaveSpider => (0.7, 0.5, "entity/spider/cave_spider"),
+    ChestMinecart => (0.98, 0.7, "entity/minecart"),
+    Chicken => (0.4, 0.7, "entity/chicken"),
+    Cod => (0.5, 0.3, "entity/fish/cod"),
+    CommandBlockMinecart => (0.98, 0.7, "entity/minecart"),
+    Cow => (0.9, 1.4, "entity/cow/cow"),
+    Creeper => (0.6, 1.7, "entity/creeper/creeper"),
+    Dolphin => (0.9, 0.6, "entity/dolphin"),
+    Donkey => (1.3965, 1.5, "entity/horse/donkey"),
+    DragonFireball => (1.0, 1.0, "entity/enderdragon/dragon_fireball"),
+    Drowned => (0.6, 1.95, "entity/zombie/drowned"),
+    Egg => (0.25, 0.25, "items/egg"),
+    ElderGuardian => (1.9975, 1.9975, "entity/guardian_elder"),
+    EndCrystal => (2.0, 2.0, "entity/endercrystal/endercrystal"),
+    EnderDragon => (16.0, 8.0, "entity/enderdragon/dragon"),
+    EnderPearl => (0.25, 0.25, "items/ender_pearl"),
+    Enderman => (0.6, 2.9, "entity/enderman/enderman"),
+    Endermite => (0.4, 0.3, "entity/endermite"),
+    Evoker => (0.6, 1.95, "entity/illager/evoker"),
+    EvokerFangs => (0.5, 0.8, "entity/illager/fangs"),
+    ExperienceBottle => (0.25, 0.25, "items/experience_bottle"),
+    ExperienceOrb => (0.5, 0.5, "entity/experience_orb"),
+    EyeOfEnder => (0.25, 0.25, "items/ender_eye"),
+    FallingBlock => (0.98, 0.98, "blocks/sand"),
+    Fireball => (1.0, 1.0, "items/fireball"),
+    FireworkRocket => (0.25, 0.25, "items/fireworks"),
+    FishingBobber => (0.25, 0.25, "entity/fishing_hook"),
+    Fox => (0.6, 0.7, "entity/fox/fox"),
+    FurnaceMinecart => (0.98, 0.7, "entity/minecart"),
+    Ghast => (4.0, 4.0, "entity/ghast/ghast"),
+    Giant => (3.6, 12.0, "entity/zombie/zombie"),
+    GlowItemFrame => (0.5, 0.5, "blocks/glow_item_frame"),
+    GlowSquid => (0.8, 0.8, "entity/squid/glow_squid"),
+    Goat => (0.9, 1.3, "entity/goat/goat"),
+    Guardian => (0.85, 0.85, "entity/guardian"),
+    Hoglin => (1.3965, 1.4, "entity/hoglin/hoglin"),
+    HopperMinecart => (0.98, 0.7, "entity/minecart"),
+    Horse => (1.3965, 1.6, "entity/horse/horse_brown"),
+    Husk => (0.6, 1.95, "entity/zombie/husk"),
+    Illusioner => (0.6, 1.95, "entity/illager/illusionist"),
+    IronGolem => (1.4, 2.7, "entity/iron_golem"),
+    Item => (0.25, 0.25, "steven:solid"),
+    ItemFrame => (0.5, 0.5, "blocks/itemframe_background"),
+    LeashKnot => (0.375, 0.5, "entity/lead_knot"),
+    LightningBolt => (0.0, 0.0, "steven:solid"),
+    Llama => (0.9, 1.87, "entity/llama/llama_creamy"),
+    LlamaSpit => (0.25, 0.25, "entity/llama/spit"),
+    MagmaCube => (2.04, 2.04, "entity/slime/magmacube"),
+    Marker => (0.0, 0.0, "steven:solid"),
+    Minecart => (0.98, 0.7, "entity/minecart"),
+    Mooshroom => (0.9, 1.4, "entity/cow/mooshroom"),
+    Mule => (1.3965, 1.6, "entity/horse/mule"),
+    Ocelot => (0.6, 0.7, "entity/cat/ocelot"),
+    Painting => (0.5, 0.5, "painting/back"),
+    Panda => (1.3, 1.25, "entity/panda/panda"),
+    Parrot => (0.5, 0.9, "entity/parrot/parrot_red_blue"),
+    Phantom => (0.9, 0.5, "entity/phantom"),
+    Pig => (0.9, 0.9, "entity/pig/pig"),
+    Piglin => (0.6, 1.95, "entity/piglin/piglin"),
+    PiglinBrute => (0.6, 1.95, "entity/piglin/piglin_brute"),
+    Pillager => (0.6, 1.95, "entity/illager/pillager"),
+    Player => (0.6, 1.8, "entity/steve"),
+    PolarBear => (1.4, 1.4, "entity/bear/polarbear"),
+    Potion => (0.25, 0.25, "items/potion_bottle_splash"),
+    Pufferfish => (0.7, 0.7, "entity/fish/pufferfish"),
+    Rabbit => (0.4, 0.5, "entity/rabbit/brown"),
+    Ravager => (1.95, 2.2, "entity/illager/ravager"),
+    Salmon => (0.7, 0.4, "entity/fish/salmon"),
+    Sheep => (0.9, 1.3, "entity/sheep/sheep"),
+    Shulker => (1.0, 1.0, "entity/shulker/shulker_purple"),
+    ShulkerBullet => (0.3125, 0.3125, "entity/shulker/spark"),
+    Silverfish => (0.4, 0.3, "entity/silverfish"),
+    Skeleton => (0.6, 1.99, "entity/skeleton/skeleton"),
+    SkeletonHorse => (1.3965, 1.6, "entity/horse/horse_skeleton"),
+    Slime => (2.04, 2.04, "entity/slime/slime"),
+    SmallFireball => (0.3125, 0.3125, "items/fireball"),
+    SnowGolem => (0.7, 1.9, "entity/snowman"),
+    Snowball => (0.25, 0.25, "items/snowball"),
+    SpawnerMinecart => (0.98, 0.7, "entity/minecart"),
+    SpectralArrow => (0.5, 0.5, "entity/projectiles/spectral_arrow"),
+    Spider => (1.4, 0.9, "entity/spider/spider"),
+    Squid => (0.8, 0.8, "entity/squid"),
+    Stray => (0.6, 1.99, "entity/skeleton/stray"),
+    Strider => (0.9, 1.7, "entity/strider/strider"),
+    Tnt => (0.98, 0.98, "blocks/tnt_side"),
+    TntMinecart => (0.98, 0.7, "entity/minecart"),
+    TraderLlama => (0.9, 1.87, "entity/llama/llama_creamy"),
+    Trident => (0.5, 0.5, "entity/trident"),
+    TropicalFish => (0.5, 0.4, "entity/fish/tropical_a"),
+    Turtle => (1.2, 0.4, "entity/turtle/big_sea_turtle"),
+    Vex => (0.4, 0.8, "entity/illager/vex"),
+    Villager => (0.6, 1.95, "entity/villager/villager"),
+    Vindicator => (0.6, 1.95, "entity/illager/vindicator"),
+    WanderingTrader => (0.6, 1.95, "entity/wandering_trader"),
+    Witch => (0.6, 1.95, "entity/witch"),
+    Wither => (0.9, 3.5, "entity/wither/wither"),
+    WitherSkeleton => (0.7, 2.4, "entity/skeleton/wither_skeleton"),
+    WitherSkull => (0.3125, 0.3125, "entity/wither/wither"),
+    Wolf => (0.6, 0.85, "entity/wolf/wolf"),
+    Zoglin => (1.3965, 1.4, "entity/hoglin/zoglin"),
+    Zombie => (0.6, 1.95, "entity/zombie/zombie"),
+    ZombieHorse => (1.3965, 1.6, "entity/horse/horse_zombie"),
+    ZombiePigman => (0.6, 1.95, "entity/zombie_pigman"),
+    ZombieVillager => (0.6, 1.95, "entity/zombie_villager/zombie_villager"),
+    ZombifiedPiglin => (0.6, 1.95, "entity/piglin/zombified_piglin"),
+}
+
+impl EntityType {
+    /// Looks up the type of an entity spawned by the SpawnObject packet
+    pub fn by_object_id(id: i32, data: i32, protocol_version: i32) -> EntityType {
+        if protocol_version >= 477 {
+            return EntityType::by_registry_id(id, protocol_version);
+        }
+        match id {
+            1 => Boat,
+            2 => Item,
+            3 => AreaEffectCloud,
+            // The kind of minecart is sent in the object data
+            10 => match data {
+                1 => ChestMinecart,
+                2 => FurnaceMinecart,
+                3 => TntMinecart,
+                4 => SpawnerMinecart,
+                5 => HopperMinecart,
+                6 => CommandBlockMinecart,
+                _ => Minecart,
+            },
+            50 => Tnt,
+            51 => EndCrystal,
+            60 => Arrow,
+            61 => Snowball,
+            62 => Egg,
+            63 => Fireball,
+            64 => SmallFireball,
+            65 => EnderPearl,
+            66 => WitherSkull,
+            67 => ShulkerBullet,
+            68 => LlamaSpit,
+            70 => FallingBlock,
+            71 => ItemFrame,
+            72 => EyeOfEnder,
+            73 => Potion,
+            75 => ExperienceBottle,
+            76 => FireworkRocket,
+            77 => LeashKnot,
+            78 => ArmorStand,
+            79 => EvokerFangs,
+            90 => FishingBobber,
+            91 => SpectralArrow,
+            93 => DragonFireball,
+            94 => Trident,
+            _ => Unknown,
+        }
+    }
+
+    /// Looks up the type of an entity spawned by the SpawnMob packet
+    pub fn by_mob_id(id: i32, protocol_version: i32) -> EntityType {
+        if protocol_version >= 393 {
+            return EntityType::by_registry_id(id, protocol_version);
+        }
+        match id {
+            4 => ElderGuardian,
+            5 => WitherSkeleton,
+            6 => Stray,
+            23 => Husk,
+            27 => ZombieVillager,
+            28 => SkeletonHorse,
+            29 => ZombieHorse,
+            30 => ArmorStand,
+            31 => Donkey,
+            32 => Mule,
+            34 => Evoker,
+            35 => Vex,
+            36 => Vindicator,
+            37 => Illusioner,
+            50 => Creeper,
+            51 => Skeleton,
+            52 => Spider,
+            53 => Giant,
+            54 => Zombie,
+            55 => Slime,
+            56 => Ghast,
+            57 => ZombiePigman,
+            58 => Enderman,
+            59 => CaveSpider,
+            60 => Silverfish,
+            61 => Blaze,
+            62 => MagmaCube,
+            63 => EnderDragon,
+            64 => Wither,
+            65 => Bat,
+            66 => Witch,
+            67 => Endermite,
+            68 => Guardian,
+            69 => Shulker,
+            90 => Pig,
+            91 => Sheep,
+            92 => Cow,
+            93 => Chicken,
+            94 => Squid,
+            95 => Wolf,
+            96 => Mooshroom,
+            97 => SnowGolem,
+            98 => Ocelot,
+            99 => IronGolem,
+            100 => Horse,
+            101 => Rabbit,
+            102 => PolarBear,
+            103 => Llama,
+            105 => Parrot,
+            120 => Villager,
+            _ => Unknown,
+        }
+    }
+
+    /// Looks up an entity type in the registry used since 1.13
+    pub fn by_registry_id(id: i32, protocol_version: i32) -> EntityType {
+        let registry = if protocol_version >= 755 {
+            REGISTRY_1_17
+        } else if protocol_version >= 751 {
+            REGISTRY_1_16_2
+        } else if protocol_version >= 735 {
+            REGISTRY_1_16
+        } else if protocol_version >= 573 {
+            REGISTRY_1_15
+        } else if protocol_version >= 451 {
+            // The 1.14 snapshots already use the 1.14 entity list
+            REGISTRY_1_14
+        } else {
+            REGISTRY_1_13
+        };
+        if id < 0 {
+            return Unknown;
+        }
+        registry.get(id as usize).copied().unwrap_or(Unknown)
+    }
+
+    /// Whether the entity has health, i.e. is a mob or a player
+    pub fn is_living(&self) -> bool {
+        !matches!(
+            *self,
+            AreaEffectCloud
+                | Arrow
+                | Boat
+                | ChestMinecart
+                | CommandBlockMinecart
+                | DragonFireball
+                | Egg
+                | EndCrystal
+                | EnderPearl
+                | EvokerFangs
+                | ExperienceBottle
+                | ExperienceOrb
+                | EyeOfEnder
+                | FallingBlock
+                | Fireball
+                | FireworkRocket
+                | FishingBobber
+                | FurnaceMinecart
+                | GlowItemFrame
+                | HopperMinecart
+                | Item
+                | ItemFrame
+                | LeashKnot
+                | LightningBolt
+                | LlamaSpit
+                | Marker
+                | Minecart
+                | Painting
+                | Potion
+                | ShulkerBullet
+                | SmallFireball
+                | Snowball
+                | SpawnerMinecart
+                | SpectralArrow
+                | Tnt
+                | TntMinecart
+                | Trident
+                | WitherSkull
+                | Unknown
+        )
+    }
+
+    /// Whether the entity has a baby variant set through its metadata
+    pub fn can_be_baby(&self) -> bool {
+        matches!(
+            *self,
+            Bee | Cat
+                | Chicken
+                | Cow
+                | Donkey
+                | Drowned
+                | Fox
+                | Goat
+                | Hoglin
+                | Horse
+                | Husk
+                | Llama
+                | Mooshroom
+                | Mule
+                | Ocelot
+                | Panda
+                | Pig
+                | PolarBear
+                | Rabbit
+                | Sheep
+                | SkeletonHorse
+                | Strider
+                | TraderLlama
+                | Turtle
+                | Villager
+                | WanderingTrader
+                | Wolf
+                | Zoglin
+                | Zombie
+                | ZombieHorse
+                | ZombiePigman
+                | ZombieVillager
+                | ZombifiedPiglin
+        )
+    }
+}
+
+// Entity type ids by protocol version, in registry order
+const REGISTRY_1_13: &[EntityType] = &[
+    AreaEffectCloud,
+    ArmorStand,
+    Arrow,
+    Bat,
+    Blaze,
+    Boat,
+    CaveSpider,
+    Chicken,
+    Cod,
+    Cow,
+    Creeper,
+    Donkey,
+    Dolphin,
+    DragonFireball,
+    Drowned,
+    ElderGuardian,
+    EndCrystal,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    EvokerFangs,
+    Evoker,
+    ExperienceOrb,
+    EyeOfEnder,
+    FallingBlock,
+    FireworkRocket,
+    Ghast,
+    Giant,
+    Guardian,
+    Horse,
+    Husk,
+    Illusioner,
+    Item,
+    ItemFrame,
+    Fireball,
+    LeashKnot,
+    Llama,
+    LlamaSpit,
+    MagmaCube,
+    Minecart,
+    ChestMinecart,
+    CommandBlockMinecart,
+    FurnaceMinecart,
+    HopperMinecart,
+    SpawnerMinecart,
+    TntMinecart,
+    Mule,
+    Mooshroom,
+    Ocelot,
+    Painting,
+    Parrot,
+    Pig,
+    Pufferfish,
+    ZombiePigman,
+    PolarBear,
+    Tnt,
+    Rabbit,
+    Salmon,
+    Sheep,
+    Shulker,
+    ShulkerBullet,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SmallFireball,
+    SnowGolem,
+    Snowball,
+    SpectralArrow,
+    Spider,
+    Squid,
+    Stray,
+    TropicalFish,
+    Turtle,
+    Egg,
+    EnderPearl,
+    ExperienceBottle,
+    Potion,
+    Vex,
+    Villager,
+    IronGolem,
+    Vindicator,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    WitherSkull,
+    Wolf,
+    Zombie,
+    ZombieHorse,
+    ZombieVillager,
+    Phantom,
+    LightningBolt,
+    Player,
+    FishingBobber,
+    Trident,
+];
+const REGISTRY_1_14: &[EntityType] = &[
+    AreaEffectCloud,
+    ArmorStand,
+    Arrow,
+    Bat,
+    Blaze,
+    Boat,
+    Cat,
+    CaveSpider,
+    Chicken,
+    Cod,
+    Cow,
+    Creeper,
+    Donkey,
+    Dolphin,
+    DragonFireball,
+    Drowned,
+    ElderGuardian,
+    EndCrystal,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    EvokerFangs,
+    Evoker,
+    ExperienceOrb,
+    EyeOfEnder,
+    FallingBlock,
+    FireworkRocket,
+    Fox,
+    Ghast,
+    Giant,
+    Guardian,
+    Horse,
+    Husk,
+    Illusioner,
+    Item,
+    ItemFrame,
+    Fireball,
+    LeashKnot,
+    Llama,
+    LlamaSpit,
+    MagmaCube,
+    Minecart,
+    ChestMinecart,
+    CommandBlockMinecart,
+    FurnaceMinecart,
+    HopperMinecart,
+    SpawnerMinecart,
+    TntMinecart,
+    Mule,
+    Mooshroom,
+    Ocelot,
+    Painting,
+    Panda,
+    Parrot,
+    Pig,
+    Pufferfish,
+    ZombiePigman,
+    PolarBear,
+    Tnt,
+    Rabbit,
+    Salmon,
+    Sheep,
+    Shulker,
+    ShulkerBullet,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SmallFireball,
+    SnowGolem,
+    Snowball,
+    SpectralArrow,
+    Spider,
+    Squid,
+    Stray,
+    TraderLlama,
+    TropicalFish,
+    Turtle,
+    Egg,
+    EnderPearl,
+    ExperienceBottle,
+    Potion,
+    Vex,
+    Villager,
+    IronGolem,
+    Vindicator,
+    Pillager,
+    WanderingTrader,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    WitherSkull,
+    Wolf,
+    Zombie,
+    ZombieHorse,
+    ZombieVillager,
+    Phantom,
+    Ravager,
+    LightningBolt,
+    Player,
+    FishingBobber,
+    Trident,
+];
+const REGISTRY_1_15: &[EntityType] = &[
+    AreaEffectCloud,
+    ArmorStand,
+    Arrow,
+    Bat,
+    Bee,
+    Blaze,
+    Boat,
+    Cat,
+    CaveSpider,
+    Chicken,
+    Cod,
+    Cow,
+    Creeper,
+    Donkey,
+    Dolphin,
+    DragonFireball,
+    Drowned,
+    ElderGuardian,
+    EndCrystal,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    EvokerFangs,
+    Evoker,
+    ExperienceOrb,
+    EyeOfEnder,
+    FallingBlock,
+    FireworkRocket,
+    Fox,
+    Ghast,
+    Giant,
+    Guardian,
+    Horse,
+    Husk,
+    Illusioner,
+    Item,
+    ItemFrame,
+    Fireball,
+    LeashKnot,
+    Llama,
+    LlamaSpit,
+    MagmaCube,
+    Minecart,
+    ChestMinecart,
+    CommandBlockMinecart,
+    FurnaceMinecart,
+    HopperMinecart,
+    SpawnerMinecart,
+    TntMinecart,
+    Mule,
+    Mooshroom,
+    Ocelot,
+    Painting,
+    Panda,
+    Parrot,
+    Pig,
+    Pufferfish,
+    ZombiePigman,
+    PolarBear,
+    Tnt,
+    Rabbit,
+    Salmon,
+    Sheep,
+    Shulker,
+    ShulkerBullet,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SmallFireball,
+    SnowGolem,
+    Snowball,
+    SpectralArrow,
+    Spider,
+    Squid,
+    Stray,
+    TraderLlama,
+    TropicalFish,
+    Turtle,
+    Egg,
+    EnderPearl,
+    ExperienceBottle,
+    Potion,
+    Vex,
+    Villager,
+    IronGolem,
+    Vindicator,
+    Pillager,
+    WanderingTrader,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    WitherSkull,
+    Wolf,
+    Zombie,
+    ZombieHorse,
+    ZombieVillager,
+    Phantom,
+    Ravager,
+    LightningBolt,
+    Player,
+    FishingBobber,
+    Trident,
+];
+const REGISTRY_1_16: &[EntityType] = &[
+    AreaEffectCloud,
+    ArmorStand,
+    Arrow,
+    Bat,
+    Bee,
+    Blaze,
+    Boat,
+    Cat,
+    CaveSpider,
+    Chicken,
+    Cod,
+    Cow,
+    Creeper,
+    Dolphin,
+    Donkey,
+    DragonFireball,
+    Drowned,
+    ElderGuardian,
+    EndCrystal,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    Evoker,
+    EvokerFangs,
+    ExperienceOrb,
+    EyeOfEnder,
+    FallingBlock,
+    FireworkRocket,
+    Fox,
+    Ghast,
+    Giant,
+    Guardian,
+    Hoglin,
+    Horse,
+    Husk,
+    Illusioner,
+    IronGolem,
+    Item,
+    ItemFrame,
+    Fireball,
+    LeashKnot,
+    LightningBolt,
+    Llama,
+    LlamaSpit,
+    MagmaCube,
+    Minecart,
+    ChestMinecart,
+    CommandBlockMinecart,
+    FurnaceMinecart,
+    HopperMinecart,
+    SpawnerMinecart,
+    TntMinecart,
+    Mule,
+    Mooshroom,
+    Ocelot,
+    Painting,
+    Panda,
+    Parrot,
+    Phantom,
+    Pig,
+    Piglin,
+    Pillager,
+    PolarBear,
+    Tnt,
+    Pufferfish,
+    Rabbit,
+    Ravager,
+    Salmon,
+    Sheep,
+    Shulker,
+    ShulkerBullet,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SmallFireball,
+    SnowGolem,
+    Snowball,
+    SpectralArrow,
+    Spider,
+    Squid,
+    Stray,
+    Strider,
+    Egg,
+    EnderPearl,
+    ExperienceBottle,
+    Potion,
+    Trident,
+    TraderLlama,
+    TropicalFish,
+    Turtle,
+    Vex,
+    Villager,
+    Vindicator,
+    WanderingTrader,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    WitherSkull,
+    Wolf,
+    Zoglin,
+    Zombie,
+    ZombieHorse,
+    ZombieVillager,
+    ZombifiedPiglin,
+    Player,
+    FishingBobber,
+];
+const REGISTRY_1_16_2: &[EntityType] = &[
+    AreaEffectCloud,
+    ArmorStand,
+    Arrow,
+    Bat,
+    Bee,
+    Blaze,
+    Boat,
+    Cat,
+    CaveSpider,
+    Chicken,
+    Cod,
+    Cow,
+    Creeper,
+    Dolphin,
+    Donkey,
+    DragonFireball,
+    Drowned,
+    ElderGuardian,
+    EndCrystal,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    Evoker,
+    EvokerFangs,
+    ExperienceOrb,
+    EyeOfEnder,
+    FallingBlock,
+    FireworkRocket,
+    Fox,
+    Ghast,
+    Giant,
+    Guardian,
+    Hoglin,
+    Horse,
+    Husk,
+    Illusioner,
+    IronGolem,
+    Item,
+    ItemFrame,
+    Fireball,
+    LeashKnot,
+    LightningBolt,
+    Llama,
+    LlamaSpit,
+    MagmaCube,
+    Minecart,
+    ChestMinecart,
+    CommandBlockMinecart,
+    FurnaceMinecart,
+    HopperMinecart,
+    SpawnerMinecart,
+    TntMinecart,
+    Mule,
+    Mooshroom,
+    Ocelot,
+    Painting,
+    Panda,
+    Parrot,
+    Phantom,
+    Pig,
+    Piglin,
+    PiglinBrute,
+    Pillager,
+    PolarBear,
+    Tnt,
+    Pufferfish,
+    Rabbit,
+    Ravager,
+    Salmon,
+    Sheep,
+    Shulker,
+    ShulkerBullet,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SmallFireball,
+    SnowGolem,
+    Snowball,
+    SpectralArrow,
+    Spider,
+    Squid,
+    Stray,
+    Strider,
+    Egg,
+    EnderPearl,
+    ExperienceBottle,
+    Potion,
+    Trident,
+    TraderLlama,
+    TropicalFish,
+    Turtle,
+    Vex,
+    Villager,
+    Vindicator,
+    WanderingTrader,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    WitherSkull,
+    Wolf,
+    Zoglin,
+    Zombie,
+    ZombieHorse,
+    ZombieVillager,
+    ZombifiedPiglin,
+    Player,
+    FishingBobber,
+];
+const REGISTRY_1_17: &[EntityType] = &[
+    AreaEffectCloud,
+    ArmorStand,
+    Arrow,
+    Axolotl,
+    Bat,
+    Bee,
+    Blaze,
+    Boat,
+    Cat,
+    CaveSpider,
+    Chicken,
+    Cod,
+    Cow,
+    Creeper,
+    Dolphin,
+    Donkey,
+    DragonFireball,
+    Drowned,
+    ElderGuardian,
+    EndCrystal,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    Evoker,
+    EvokerFangs,
+    ExperienceOrb,
+    EyeOfEnder,
+    FallingBlock,
+    FireworkRocket,
+    Fox,
+    Ghast,
+    Giant,
+    GlowItemFrame,
+    GlowSquid,
+    Goat,
+    Guardian,
+    Hoglin,
+    Horse,
+    Husk,
+    Illusioner,
+    IronGolem,
+    Item,
+    ItemFrame,
+    Fireball,
+    LeashKnot,
+    LightningBolt,
+    Llama,
+    LlamaSpit,
+    MagmaCube,
+    Marker,
+    Minecart,
+    ChestMinecart,
+    CommandBlockMinecart,
+    FurnaceMinecart,
+    HopperMinecart,
+    SpawnerMinecart,
+    TntMinecart,
+    Mule,
+    Mooshroom,
+    Ocelot,
+    Painting,
+    Panda,
+    Parrot,
+    Phantom,
+    Pig,
+    Piglin,
+    PiglinBrute,
+    Pillager,
+    PolarBear,
+    Tnt,
+    Pufferfish,
+    Rabbit,
+    Ravager,
+    Salmon,
+    Sheep,
+    Shulker,
+    ShulkerBullet,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SmallFireball,
+    SnowGolem,
+    Snowball,
+    SpectralArrow,
+    Spider,
+    Squid,
+    Stray,
+    Strider,
+    Egg,
+    EnderPearl,
+    ExperienceBottle,
+    Potion,
+    Trident,
+    TraderLlama,
+    TropicalFish,
+    Turtle,
+    Vex,
+    Villager,
+    Vindicator,
+    WanderingTrader,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    WitherSkull,
+    Wolf,
+    Zoglin,
+    Zombie,
+    ZombieHorse,
+    ZombieVillager,
+    ZombifiedPiglin,
+    Player,
+    FishingBobber,
+];
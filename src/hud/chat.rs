@@ -0,0 +1,311 @@
+use crate::format;
+use crate::render;
+use crate::screen;
+use crate::server::chat::{Chat, ChatLine};
+use crate::ui;
+use instant::{Duration, Instant};
+
+/// Maximum number of lines shown while the chat input is closed
+const MAX_VISIBLE_LINES: usize = 10;
+/// Maximum number of lines shown while the chat input is open
+const MAX_OPEN_LINES: usize = 20;
+
+const CHAT_WIDTH: f64 = 500.0;
+const CHAT_BOTTOM_OFFSET: f64 = 96.0;
+const ACTION_BAR_BOTTOM_OFFSET: f64 = 118.0;
+const TOOLTIP_WIDTH: f64 = 300.0;
+
+/// How long a message stays visible after being received, including the fade out
+const MESSAGE_LIFETIME: Duration = Duration::from_secs(10);
+const ACTION_BAR_LIFETIME: Duration = Duration::from_secs(3);
+/// How long a message takes to fade out at the end of its lifetime
+const FADE_TIME: Duration = Duration::from_secs(1);
+
+fn line_alpha(line: &ChatLine, now: Instant, lifetime: Duration) -> u8 {
+    let age = now.duration_since(line.received);
+    if age >= lifetime {
+        return 0;
+    }
+    let remaining = lifetime - age;
+    if remaining >= FADE_TIME {
+        255
+    } else {
+        (255.0 * remaining.as_secs_f64() / FADE_TIME.as_secs_f64()) as u8
+    }
+}
+
+/// Runs the action of a clicked chat component
+fn handle_click(game: &mut crate::Game, event: &format::ClickEvent) {
+    match event {
+        format::ClickEvent::OpenUrl(url) => {
+            if screen::is_web_link(url) {
+                game.screen_sys
+                    .add_screen(Box::new(screen::OpenLink::new(url)));
+            }
+        }
+        format::ClickEvent::RunCommand(command) => {
+            if game.server.is_connected() {
+                game.server.send_chat_message(command);
+            }
+        }
+        format::ClickEvent::SuggestCommand(command) => {
+            if !game.screen_sys.is_current_death_screen() {
+                game.screen_sys
+                    .replace_screen(Box::new(screen::Chat::new(command)));
+            }
+        }
+        format::ClickEvent::CopyToClipboard(text) => ui::copy_to_clipboard(text),
+        // Files and book pages can't be opened from chat
+        format::ClickEvent::OpenFile(_) | format::ClickEvent::ChangePage(_) => {}
+    }
+}
+
+fn gray_text(text: &str) -> format::Component {
+    let mut text = format::TextComponent::new(text);
+    text.modifier.color = Some(format::Color::Gray);
+    format::Component::Text(text)
+}
+
+/// Builds the text of a hover event's tooltip
+fn hover_text(event: &format::HoverEvent) -> format::Component {
+    match event {
+        format::HoverEvent::ShowText(text) => (**text).clone(),
+        format::HoverEvent::ShowItem(item) => {
+            let name = item
+                .tag
+                .as_ref()
+                .and_then(|tag| tag.pointer("/display/Name"))
+                .and_then(|name| name.as_str())
+                .map(format::Component::from_string)
+                .unwrap_or_else(|| format::Component::Text(format::TextComponent::new(&item.id)));
+            let mut text = format::TextComponent::new("");
+            text.modifier.extra = Some(vec![name, gray_text(&format!("\n{}", item.id))]);
+            format::Component::Text(text)
+        }
+        format::HoverEvent::ShowEntity(entity) => {
+            let mut extra = vec![];
+            if let Some(name) = &entity.name {
+                extra.push((**name).clone());
+                extra.push(format::Component::Text(format::TextComponent::new("\n")));
+            }
+            extra.push(gray_text(&format!("Type: {}\n{}", entity.kind, entity.id)));
+            let mut text = format::TextComponent::new("");
+            text.modifier.extra = Some(extra);
+            format::Component::Text(text)
+        }
+    }
+}
+
+pub struct ChatOverlay {
+    elements: Option<ChatElements>,
+    last_open: bool,
+    last_visible: usize,
+}
+
+struct ChatElements {
+    lines: Vec<ChatLineElements>,
+    action_bar: Option<ui::FormattedRef>,
+    tooltip: Option<ChatTooltip>,
+}
+
+struct ChatLineElements {
+    background: ui::ImageRef,
+    text: ui::FormattedRef,
+    /// Distance from the bottom of the screen
+    offset: f64,
+    height: f64,
+}
+
+struct ChatTooltip {
+    /// The line and span the tooltip is for
+    key: (usize, usize),
+    _background: ui::ImageRef,
+    _text: ui::FormattedRef,
+}
+
+impl Default for ChatOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChatOverlay {
+    pub fn new() -> ChatOverlay {
+        ChatOverlay {
+            elements: None,
+            last_open: false,
+            last_visible: 0,
+        }
+    }
+
+    /// Returns the number of most recent lines that should be displayed
+    fn visible_lines(chat: &Chat, now: Instant, open: bool) -> usize {
+        if open {
+            return chat.lines().len().min(MAX_OPEN_LINES);
+        }
+        chat.lines()
+            .iter()
+            .rev()
+            .take(MAX_VISIBLE_LINES)
+            .take_while(|line| line_alpha(line, now, MESSAGE_LIFETIME) > 0)
+            .count()
+    }
+
+    pub fn tick(
+        &mut self,
+        chat: &mut Chat,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        open: bool,
+    ) {
+        let now = Instant::now();
+        let visible = Self::visible_lines(chat, now, open);
+
+        let action_bar_expired = match chat.action_bar() {
+            Some(line) => line_alpha(line, now, ACTION_BAR_LIFETIME) == 0,
+            None => false,
+        };
+        if action_bar_expired {
+            chat.clear_action_bar();
+        }
+
+        if chat.take_dirty()
+            || self.elements.is_none()
+            || open != self.last_open
+            || visible != self.last_visible
+        {
+            self.last_open = open;
+            self.last_visible = visible;
+
+            let mut elements = ChatElements {
+                lines: vec![],
+                action_bar: None,
+                tooltip: None,
+            };
+
+            let mut offset = CHAT_BOTTOM_OFFSET;
+            for line in chat.lines().iter().rev().take(visible) {
+                let (_, height) = ui::Formatted::compute_size(renderer, &line.text, CHAT_WIDTH);
+                let background = ui::ImageBuilder::new()
+                    .texture("steven:solid")
+                    .position(0.0, offset)
+                    .size(CHAT_WIDTH + 8.0, height)
+                    .colour((0, 0, 0, 100))
+                    .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+                    .draw_index(100)
+                    .create(ui_container);
+                let text = ui::FormattedBuilder::new()
+                    .text(line.text.clone())
+                    .position(4.0, 0.0)
+                    .max_width(CHAT_WIDTH)
+                    .alignment(ui::VAttach::Top, ui::HAttach::Left)
+                    .attach(&mut *background.borrow_mut());
+                text.borrow_mut().add_click_func(|text, game| {
+                    let event = match text.span_at_mouse() {
+                        Some((_, span)) => span.click_event.clone(),
+                        None => None,
+                    };
+                    match event {
+                        Some(event) => {
+                            handle_click(game, &event);
+                            true
+                        }
+                        None => false,
+                    }
+                });
+                elements.lines.push(ChatLineElements {
+                    background,
+                    text,
+                    offset,
+                    height,
+                });
+                offset += height;
+            }
+
+            if let Some(action_bar) = chat.action_bar() {
+                elements.action_bar = Some(
+                    ui::FormattedBuilder::new()
+                        .text(action_bar.text.clone())
+                        .position(0.0, ACTION_BAR_BOTTOM_OFFSET)
+                        .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                        .draw_index(100)
+                        .create(ui_container),
+                );
+            }
+
+            self.elements = Some(elements);
+        }
+
+        // Fade out old messages
+        let elements = self.elements.as_mut().unwrap();
+        for (line, elems) in chat.lines().iter().rev().zip(&elements.lines) {
+            let alpha = if open {
+                255
+            } else {
+                line_alpha(line, now, MESSAGE_LIFETIME)
+            };
+            elems.background.borrow_mut().colour.3 = (alpha as f64 * (100.0 / 255.0)) as u8;
+            elems.text.borrow_mut().alpha = alpha;
+        }
+        if let (Some(line), Some(text)) = (chat.action_bar(), elements.action_bar.as_ref()) {
+            text.borrow_mut().alpha = line_alpha(line, now, ACTION_BAR_LIFETIME);
+        }
+
+        Self::update_tooltip(elements, ui_container, renderer, open);
+    }
+
+    /// Shows the hover event of the component under the mouse while the
+    /// chat is open.
+    fn update_tooltip(
+        elements: &mut ChatElements,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        open: bool,
+    ) {
+        let mut hovered = None;
+        if open {
+            for (index, line) in elements.lines.iter().enumerate() {
+                let text = line.text.borrow();
+                if let Some((span_index, span)) = text.span_at_mouse() {
+                    if let Some(event) = &span.hover_event {
+                        let x = 4.0 + span.x;
+                        let y = line.offset + line.height - span.y + 2.0;
+                        hovered = Some(((index, span_index), hover_text(event), x, y));
+                    }
+                    break;
+                }
+            }
+        }
+
+        let (key, text, x, y) = match hovered {
+            Some(hovered) => hovered,
+            None => {
+                elements.tooltip = None;
+                return;
+            }
+        };
+        if elements.tooltip.as_ref().map_or(false, |t| t.key == key) {
+            return;
+        }
+        let (width, height) = ui::Formatted::compute_size(renderer, &text, TOOLTIP_WIDTH);
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(x, y)
+            .size(width + 8.0, height + 4.0)
+            .colour((16, 0, 16, 240))
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+            .draw_index(150)
+            .create(ui_container);
+        let text = ui::FormattedBuilder::new()
+            .text(text)
+            .position(4.0, 2.0)
+            .max_width(TOOLTIP_WIDTH)
+            .alignment(ui::VAttach::Top, ui::HAttach::Left)
+            .attach(&mut *background.borrow_mut());
+        elements.tooltip = Some(ChatTooltip {
+            key,
+            _background: background,
+            _text: text,
+        });
+    }
+}
@@ -0,0 +1,94 @@
+use crate::inventory::{Inventory, HOTBAR_SIZE};
+use crate::ui;
+
+/// Vanilla's gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const HOTBAR_WIDTH: f64 = 182.0;
+const HOTBAR_HEIGHT: f64 = 22.0;
+const SLOT_SPACING: f64 = 20.0;
+
+pub struct HotbarOverlay {
+    elements: Option<HotbarElements>,
+    last_revision: u64,
+}
+
+struct HotbarElements {
+    _background: ui::ImageRef,
+    selection: ui::ImageRef,
+    slots: Vec<ui::item::Icon>,
+}
+
+impl Default for HotbarOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HotbarOverlay {
+    pub fn new() -> HotbarOverlay {
+        HotbarOverlay {
+            elements: None,
+            last_revision: 0,
+        }
+    }
+
+    fn build(ui_container: &mut ui::Container) -> HotbarElements {
+        let background = ui::ImageBuilder::new()
+            .texture("gui/widgets")
+            .texture_coords((0.0, 0.0, HOTBAR_WIDTH / 256.0, HOTBAR_HEIGHT / 256.0))
+            .position(0.0, 0.0)
+            .size(HOTBAR_WIDTH * SCALE, HOTBAR_HEIGHT * SCALE)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .create(ui_container);
+        let selection = ui::ImageBuilder::new()
+            .texture("gui/widgets")
+            .texture_coords((0.0, 22.0 / 256.0, 24.0 / 256.0, 24.0 / 256.0))
+            .position(-SCALE, -SCALE)
+            .size(24.0 * SCALE, 24.0 * SCALE)
+            .draw_index(1)
+            .attach(&mut *background.borrow_mut());
+
+        let mut slots = vec![];
+        for i in 0..HOTBAR_SIZE {
+            slots.push(ui::item::Icon::new(
+                &mut *background.borrow_mut(),
+                (3.0 + i as f64 * SLOT_SPACING) * SCALE,
+                3.0 * SCALE,
+                16.0 * SCALE,
+                2,
+            ));
+        }
+
+        HotbarElements {
+            _background: background,
+            selection,
+            slots,
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        inventory: &Inventory,
+        models: &ui::item::ItemModels,
+        ui_container: &mut ui::Container,
+        visible: bool,
+    ) {
+        if !visible {
+            self.elements = None;
+            return;
+        }
+        if self.elements.is_some() && inventory.revision() == self.last_revision {
+            return;
+        }
+        self.last_revision = inventory.revision();
+        let elements = self
+            .elements
+            .get_or_insert_with(|| Self::build(ui_container));
+
+        elements.selection.borrow_mut().x =
+            (inventory.held_slot() as f64 * SLOT_SPACING - 1.0) * SCALE;
+        for (i, icon) in elements.slots.iter_mut().enumerate() {
+            icon.set_stack(models, inventory.hotbar_item(i as u8));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+pub mod chat;
+pub mod hotbar;
+pub mod status;
+
+use crate::model;
+use crate::render;
+use crate::server;
+use crate::types::Gamemode;
+use crate::ui;
+use std::sync::{Arc, RwLock};
+
+/// The in-game overlay drawn on top of the world while connected
+/// to a server.
+pub struct Hud {
+    chat: chat::ChatOverlay,
+    hotbar: hotbar::HotbarOverlay,
+    status: status::StatusOverlay,
+}
+
+impl Default for Hud {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hud {
+    pub fn new() -> Hud {
+        Hud {
+            chat: chat::ChatOverlay::new(),
+            hotbar: hotbar::HotbarOverlay::new(),
+            status: status::StatusOverlay::new(),
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        server: &mut server::Server,
+        ui_container: &mut ui::Container,
+        renderer: &render::Renderer,
+        models: &Arc<RwLock<model::Factory>>,
+        chat_open: bool,
+    ) {
+        self.chat
+            .tick(&mut server.chat, ui_container, renderer, chat_open);
+        self.hotbar.tick(
+            &server.inventory.read().unwrap(),
+            &ui::item::ItemModels::new(models, &server.world),
+            ui_container,
+            server.is_connected(),
+        );
+        // Creative and spectator players can't be hurt or get hungry
+        let survival = match server.player {
+            Some(player) => match server.entities.get_component_direct::<Gamemode>(player) {
+                Some(gamemode) => !gamemode.can_fly(),
+                None => true,
+            },
+            None => true,
+        };
+        self.status.tick(
+            &server.status,
+            ui_container,
+            server.is_connected(),
+            survival,
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::server::status::Status;
+use crate::ui;
+
+/// Vanilla's gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const ICON_SIZE: f64 = 9.0;
+const ICON_SPACING: f64 = 8.0;
+/// Each icon shows two points of health or hunger
+const ICON_COUNT: usize = 10;
+const BAR_WIDTH: f64 = 182.0;
+const BAR_HEIGHT: f64 = 5.0;
+/// Distance from the bottom of the screen to the experience bar
+const BAR_OFFSET: f64 = 24.0;
+/// Distance from the bottom of the screen to the hearts and food
+const ICON_OFFSET: f64 = 30.0;
+
+/// Coordinates of the filled icons in gui/icons
+const HEART_V: f64 = 0.0;
+const FOOD_V: f64 = 27.0;
+const FULL_U: f64 = 52.0;
+const HALF_U: f64 = 61.0;
+const CONTAINER_U: f64 = 16.0;
+
+/// Draws the player's health, food and experience above the hotbar
+pub struct StatusOverlay {
+    elements: Option<StatusElements>,
+    last_revision: u64,
+    last_survival: bool,
+}
+
+struct StatusElements {
+    /// The filled part of each heart
+    hearts: Vec<(ui::ImageRef, ui::ImageRef)>,
+    /// The filled part of each food icon, right to left
+    food: Vec<(ui::ImageRef, ui::ImageRef)>,
+    _experience_bar: ui::ImageRef,
+    experience_progress: ui::ImageRef,
+    level: ui::TextRef,
+}
+
+impl Default for StatusOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn icon_coords(u: f64, v: f64) -> (f64, f64, f64, f64) {
+    (u / 256.0, v / 256.0, ICON_SIZE / 256.0, ICON_SIZE / 256.0)
+}
+
+impl StatusOverlay {
+    pub fn new() -> StatusOverlay {
+        StatusOverlay {
+            elements: None,
+            last_revision: 0,
+            last_survival: false,
+        }
+    }
+
+    fn build_icons(
+        ui_container: &mut ui::Container,
+        v: f64,
+        x_of: impl Fn(usize) -> f64,
+    ) -> Vec<(ui::ImageRef, ui::ImageRef)> {
+        let mut icons = vec![];
+        for i in 0..ICON_COUNT {
+            let container = ui::ImageBuilder::new()
+                .texture("gui/icons")
+                .texture_coords(icon_coords(CONTAINER_U, v))
+                .position(x_of(i) * SCALE, ICON_OFFSET * SCALE)
+                .size(ICON_SIZE * SCALE, ICON_SIZE * SCALE)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                .create(ui_container);
+            let fill = ui::ImageBuilder::new()
+                .texture("gui/icons")
+                .texture_coords(icon_coords(FULL_U, v))
+                .position(0.0, 0.0)
+                .size(ICON_SIZE * SCALE, ICON_SIZE * SCALE)
+                .draw_index(1)
+                .attach(&mut *container.borrow_mut());
+            icons.push((container, fill));
+        }
+        icons
+    }
+
+    fn build(ui_container: &mut ui::Container, survival: bool) -> StatusElements {
+        let (hearts, food) = if survival {
+            // Hearts fill from the left edge of the hotbar, food from the right
+            let hearts = Self::build_icons(ui_container, HEART_V, |i| {
+                -BAR_WIDTH / 2.0 + i as f64 * ICON_SPACING + ICON_SIZE / 2.0
+            });
+            let food = Self::build_icons(ui_container, FOOD_V, |i| {
+                BAR_WIDTH / 2.0 - i as f64 * ICON_SPACING - ICON_SIZE / 2.0
+            });
+            (hearts, food)
+        } else {
+            (vec![], vec![])
+        };
+
+        let experience_bar = ui::ImageBuilder::new()
+            .texture("gui/icons")
+            .texture_coords((0.0, 64.0 / 256.0, BAR_WIDTH / 256.0, BAR_HEIGHT / 256.0))
+            .position(0.0, BAR_OFFSET * SCALE)
+            .size(BAR_WIDTH * SCALE, BAR_HEIGHT * SCALE)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .create(ui_container);
+        let experience_progress = ui::ImageBuilder::new()
+            .texture("gui/icons")
+            .texture_coords((0.0, 69.0 / 256.0, 0.0, BAR_HEIGHT / 256.0))
+            .position(0.0, 0.0)
+            .size(0.0, BAR_HEIGHT * SCALE)
+            .draw_index(1)
+            .attach(&mut *experience_bar.borrow_mut());
+        let level = ui::TextBuilder::new()
+            .text("")
+            .position(0.0, (BAR_OFFSET + 2.0) * SCALE)
+            .colour((128, 255, 32, 255))
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .draw_index(2)
+            .create(ui_container);
+
+        StatusElements {
+            hearts,
+            food,
+            _experience_bar: experience_bar,
+            experience_progress,
+            level,
+        }
+    }
+
+    /// Shows `value` points over the icons, two points per icon
+    fn fill_icons(icons: &[(ui::ImageRef, ui::ImageRef)], value: i32, v: f64) {
+        for (i, (_, fill)) in icons.iter().enumerate() {
+            let points = value - i as i32 * 2;
+            let mut fill = fill.borrow_mut();
+            if points >= 2 {
+                fill.texture_coords = icon_coords(FULL_U, v);
+                fill.colour.3 = 255;
+            } else if points == 1 {
+                fill.texture_coords = icon_coords(HALF_U, v);
+                fill.colour.3 = 255;
+            } else {
+                fill.colour.3 = 0;
+            }
+        }
+    }
+
+    /// `survival` controls whether health and food are shown, the
+    /// experience bar is always visible.
+    pub fn tick(
+        &mut self,
+        status: &Status,
+        ui_container: &mut ui::Container,
+        visible: bool,
+        survival: bool,
+    ) {
+        if !visible {
+            self.elements = None;
+            return;
+        }
+        if self.elements.is_some()
+            && status.revision() == self.last_revision
+            && survival == self.last_survival
+        {
+            return;
+        }
+        if survival != self.last_survival {
+            self.elements = None;
+        }
+        self.last_revision = status.revision();
+        self.last_survival = survival;
+        let elements = self
+            .elements
+            .get_or_insert_with(|| Self::build(ui_container, survival));
+
+        Self::fill_icons(&elements.hearts, status.health.ceil() as i32, HEART_V);
+        Self::fill_icons(&elements.food, status.food, FOOD_V);
+
+        let progress = status.experience_bar.clamp(0.0, 1.0) as f64;
+        {
+            let mut experience_progress = elements.experience_progress.borrow_mut();
+            experience_progress.width = BAR_WIDTH * progress * SCALE;
+            experience_progress.texture_coords.2 = BAR_WIDTH * progress / 256.0;
+        }
+        elements.level.borrow_mut().text = if status.level > 0 {
+            status.level.to_string()
+        } else {
+            String::new()
+        };
+    }
+}
@@ -0,0 +1,1061 @@
+pub mod window;
+
+pub use self::window::{Window, WindowKind};
+
+use self::window::{player, PLAYER_INVENTORY_SLOTS};
+use crate::item;
+use crate::world::block;
+use std::sync::Arc;
+
+/// Slot number used for clicks outside of the window, dropping the
+/// held item.
+pub const OUTSIDE_SLOT: i16 = -999;
+
+/// Window id used by the server to set the item held by the cursor
+const CURSOR_WINDOW_ID: u8 = 255; // -1
+/// Window id used by 1.13+ servers to set a slot of the player's
+/// inventory regardless of the open window.
+const PLAYER_INVENTORY_WINDOW_ID: u8 = 254; // -2
+
+/// Number of slots in the hotbar
+pub const HOTBAR_SIZE: u8 = 9;
+
+/// Clicks kept around for rolling back until the server confirms them
+const MAX_TRANSACTIONS: usize = 64;
+
+/// Stack size of items missing from the item registry
+const DEFAULT_STACK_SIZE: isize = 64;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Button {
+    Left,
+    Right,
+}
+
+/// Packets that need to be sent to the server
+#[allow(clippy::large_enum_variant)]
+pub enum Action {
+    Click {
+        window_id: u8,
+        slot: i16,
+        button: u8,
+        mode: u8,
+        action_number: u16,
+        clicked_item: Option<item::Stack>,
+        /// The slots the click changed, set for the last click of a
+        /// mouse action
+        changed_slots: Vec<(i16, Option<item::Stack>)>,
+        /// The item held by the cursor after the click
+        carried_item: Option<item::Stack>,
+    },
+    /// Tells the server that a rejected transaction was rolled back
+    Rollback {
+        window_id: u8,
+        action_number: u16,
+    },
+    Close {
+        window_id: u8,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Event {
+    Opened,
+    Closed,
+}
+
+/// A click predicted locally but not yet confirmed by the server
+struct Transaction {
+    window_id: u8,
+    action_number: u16,
+    player: Vec<Option<item::Stack>>,
+    window: Option<Vec<Option<item::Stack>>>,
+    cursor: Option<item::Stack>,
+}
+
+struct Drag {
+    button: Button,
+    slots: Vec<i16>,
+}
+
+pub struct Inventory {
+    /// Looks up items to find how many fit in a slot
+    items: Arc<block::item::VanillaIDMap>,
+    player: Window,
+    window: Option<Window>,
+    cursor: Option<item::Stack>,
+    /// Whether the player's inventory or a container is currently shown
+    open: bool,
+    /// The selected hotbar slot, between 0 and 8
+    held_slot: u8,
+
+    drag: Option<Drag>,
+    next_action: u16,
+    /// Whether the server confirms clicks, 1.17 removed confirmations
+    /// and the server resends the slots it disagrees with instead.
+    confirms_clicks: bool,
+    /// Sent by 1.17.1+ servers with the window's items and sent back
+    /// with clicks
+    state_id: i32,
+    transactions: Vec<Transaction>,
+    actions: Vec<Action>,
+    event: Option<Event>,
+    /// Incremented on every change so views know when to update
+    revision: u64,
+}
+
+impl Inventory {
+    pub fn new(items: Arc<block::item::VanillaIDMap>, protocol_version: i32) -> Inventory {
+        Inventory {
+            items,
+            player: Window::player(),
+            window: None,
+            cursor: None,
+            open: false,
+            held_slot: 0,
+
+            drag: None,
+            next_action: 1,
+            confirms_clicks: protocol_version < 755,
+            state_id: 0,
+            transactions: vec![],
+            actions: vec![],
+            event: None,
+            revision: 0,
+        }
+    }
+
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    fn changed(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// The window currently shown, the player's own one if no container
+    /// is open.
+    pub fn current(&self) -> &Window {
+        self.window.as_ref().unwrap_or(&self.player)
+    }
+
+    pub fn player(&self) -> &Window {
+        &self.player
+    }
+
+    pub fn cursor(&self) -> Option<&item::Stack> {
+        self.cursor.as_ref()
+    }
+
+    pub fn held_slot(&self) -> u8 {
+        self.held_slot
+    }
+
+    pub fn set_held_slot(&mut self, slot: u8) {
+        if slot < HOTBAR_SIZE && slot != self.held_slot {
+            self.held_slot = slot;
+            self.changed();
+        }
+    }
+
+    /// The item in the selected hotbar slot
+    pub fn held_item(&self) -> Option<&item::Stack> {
+        self.hotbar_item(self.held_slot)
+    }
+
+    pub fn hotbar_item(&self, slot: u8) -> Option<&item::Stack> {
+        self.player
+            .slots
+            .get(player::HOTBAR_START + slot as usize)
+            .and_then(|v| v.as_ref())
+    }
+
+    /// The number of slots in the current window, including the player's
+    /// inventory below containers.
+    pub fn slot_count(&self) -> usize {
+        match self.window {
+            Some(ref window) => window.size() + PLAYER_INVENTORY_SLOTS,
+            None => self.player.size(),
+        }
+    }
+
+    pub fn slot(&self, index: i16) -> Option<&item::Stack> {
+        if index < 0 {
+            return None;
+        }
+        let index = index as usize;
+        match self.window {
+            Some(ref window) if index < window.size() => window.slots[index].as_ref(),
+            Some(ref window) => self
+                .player
+                .slots
+                .get(player::MAIN_START + index - window.size())
+                .and_then(|v| v.as_ref()),
+            None => self.player.slots.get(index).and_then(|v| v.as_ref()),
+        }
+    }
+
+    fn slot_mut(&mut self, index: i16) -> Option<&mut Option<item::Stack>> {
+        if index < 0 {
+            return None;
+        }
+        let index = index as usize;
+        let window_size = match self.window {
+            Some(ref window) => window.size(),
+            None => return self.player.slots.get_mut(index),
+        };
+        if index < window_size {
+            self.window.as_mut().unwrap().slots.get_mut(index)
+        } else {
+            self.player
+                .slots
+                .get_mut(player::MAIN_START + index - window_size)
+        }
+    }
+
+    /// Whether the slot exists in the current window
+    fn is_valid_slot(&self, index: i16) -> bool {
+        index >= 0 && (index as usize) < self.slot_count()
+    }
+
+    /// How many of the stack's item fit in a single slot
+    fn max_stack_size(&self, stack: &item::Stack) -> isize {
+        self.items
+            .by_vanilla_id(stack.id, stack.damage)
+            .map_or(DEFAULT_STACK_SIZE, |item| item.max_stack_size() as isize)
+    }
+
+    fn is_output(&self, index: i16) -> bool {
+        index >= 0 && self.current().kind.is_output(index as usize)
+    }
+
+    /// Drains the packets to send to the server
+    pub fn take_actions(&mut self) -> Vec<Action> {
+        std::mem::take(&mut self.actions)
+    }
+
+    /// Returns whether the window was opened or closed by the server
+    /// since the last call.
+    pub fn take_event(&mut self) -> Option<Event> {
+        self.event.take()
+    }
+
+    pub fn open_player_window(&mut self) {
+        self.window = None;
+        self.open = true;
+        self.changed();
+    }
+
+    pub fn open_window(&mut self, window: Window) {
+        self.window = Some(window);
+        self.open = true;
+        self.drag = None;
+        self.event = Some(Event::Opened);
+        self.changed();
+    }
+
+    /// Closes the window because the server asked to
+    pub fn close_window(&mut self) {
+        if !self.open {
+            return;
+        }
+        self.reset();
+        self.event = Some(Event::Closed);
+    }
+
+    /// Closes the window because the player closed it
+    pub fn request_close(&mut self) {
+        if !self.open {
+            return;
+        }
+        let window_id = self.current().id;
+        self.actions.push(Action::Close { window_id });
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.window = None;
+        self.open = false;
+        // The server drops whatever was held
+        self.cursor = None;
+        self.drag = None;
+        self.transactions.clear();
+        self.changed();
+    }
+
+    pub fn state_id(&self) -> i32 {
+        self.state_id
+    }
+
+    pub fn set_state_id(&mut self, state_id: i32) {
+        self.state_id = state_id;
+    }
+
+    pub fn set_items(&mut self, window_id: u8, items: Vec<Option<item::Stack>>) {
+        if window_id == 0 {
+            for (slot, item) in self.player.slots.iter_mut().zip(items) {
+                *slot = item;
+            }
+        } else if self.window.as_ref().map(|w| w.id) == Some(window_id) {
+            let window = self.window.as_mut().unwrap();
+            if let WindowKind::Generic { ref mut size } = window.kind {
+                *size = items.len().saturating_sub(PLAYER_INVENTORY_SLOTS);
+                window.slots.resize(*size, None);
+            }
+            for (index, item) in items.into_iter().enumerate() {
+                if let Some(slot) = self.slot_mut(index as i16) {
+                    *slot = item;
+                }
+            }
+        } else {
+            return;
+        }
+        self.changed();
+    }
+
+    pub fn set_slot(&mut self, window_id: u8, index: i16, item: Option<item::Stack>) {
+        match window_id {
+            CURSOR_WINDOW_ID if index == -1 => self.cursor = item,
+            0 | PLAYER_INVENTORY_WINDOW_ID => {
+                if let Some(slot) = self.player.slots.get_mut(index as usize) {
+                    *slot = item;
+                }
+            }
+            _ if self.window.as_ref().map(|w| w.id) == Some(window_id) => {
+                if let Some(slot) = self.slot_mut(index) {
+                    *slot = item;
+                }
+            }
+            _ => return,
+        }
+        self.changed();
+    }
+
+    /// Sets the item held by the cursor
+    pub fn set_cursor(&mut self, item: Option<item::Stack>) {
+        self.cursor = item;
+        self.changed();
+    }
+
+    pub fn set_property(&mut self, window_id: u8, property: i16, value: i16) {
+        if let Some(window) = self.window.as_mut() {
+            if window.id == window_id {
+                window.properties.insert(property, value);
+                self.changed();
+            }
+        }
+    }
+
+    /// Handles the server's answer to a click. Rejected clicks are rolled
+    /// back, the server resends the window's items afterwards.
+    pub fn confirm_transaction(&mut self, window_id: u8, action_number: u16, accepted: bool) {
+        let index = match self
+            .transactions
+            .iter()
+            .position(|t| t.window_id == window_id && t.action_number == action_number)
+        {
+            Some(index) => index,
+            None => return,
+        };
+        if accepted {
+            self.transactions.drain(..=index);
+            return;
+        }
+        let transaction = self.transactions.drain(index..).next().unwrap();
+        self.player.slots = transaction.player;
+        if let (Some(window), Some(slots)) = (self.window.as_mut(), transaction.window) {
+            window.slots = slots;
+        }
+        self.cursor = transaction.cursor;
+        self.actions.push(Action::Rollback {
+            window_id,
+            action_number,
+        });
+        self.changed();
+    }
+
+    /// Handles a mouse button being pressed over the given slot
+    pub fn mouse_down(&mut self, button: Button, slot: Option<i16>, shift: bool) {
+        let (before, queued) = (self.slot_items(), self.actions.len());
+        self.press(button, slot, shift);
+        self.record_changes(&before, queued);
+    }
+
+    fn press(&mut self, button: Button, slot: Option<i16>, shift: bool) {
+        if !self.open || self.drag.is_some() {
+            return;
+        }
+        let slot = match slot {
+            Some(slot) => slot,
+            None => return,
+        };
+        if slot == OUTSIDE_SLOT {
+            self.click(slot, button);
+        } else if shift {
+            self.shift_click(slot, button);
+        } else if self.cursor.is_some() {
+            // Might become a drag if the mouse moves to other slots
+            // before the button is released.
+            self.drag = Some(Drag {
+                button,
+                slots: vec![slot],
+            });
+        } else {
+            self.click(slot, button);
+        }
+    }
+
+    /// Handles the mouse moving over a slot
+    pub fn mouse_over(&mut self, slot: i16) {
+        let cursor = match self.cursor {
+            Some(ref cursor) => cursor,
+            None => return,
+        };
+        let accepts = self.is_valid_slot(slot)
+            && !self.is_output(slot)
+            && match self.slot(slot) {
+                Some(item) => can_stack(item, cursor) && item.count < self.max_stack_size(item),
+                None => true,
+            };
+        let cursor_count = cursor.count as usize;
+        if let Some(drag) = self.drag.as_mut() {
+            // Each slot needs at least one item
+            if accepts && !drag.slots.contains(&slot) && drag.slots.len() < cursor_count {
+                drag.slots.push(slot);
+            }
+        }
+    }
+
+    /// Handles a mouse button being released
+    pub fn mouse_up(&mut self, button: Button) {
+        let (before, queued) = (self.slot_items(), self.actions.len());
+        self.release(button);
+        self.record_changes(&before, queued);
+    }
+
+    fn release(&mut self, button: Button) {
+        let drag = match self.drag.take() {
+            Some(drag) if drag.button == button => drag,
+            other => {
+                self.drag = other;
+                return;
+            }
+        };
+        if drag.slots.len() == 1 {
+            self.click(drag.slots[0], button);
+        } else {
+            self.drag_click(&drag);
+        }
+    }
+
+    /// The slots currently covered by a drag, for highlighting
+    pub fn drag_slots(&self) -> &[i16] {
+        match self.drag {
+            Some(ref drag) if drag.slots.len() > 1 => &drag.slots,
+            _ => &[],
+        }
+    }
+
+    /// The items in every slot of the current window
+    fn slot_items(&self) -> Vec<Option<item::Stack>> {
+        (0..self.slot_count() as i16)
+            .map(|index| self.slot(index).cloned())
+            .collect()
+    }
+
+    /// Fills in what changed since `before` for the last click, if any
+    /// were queued after the first `queued` actions.
+    fn record_changes(&mut self, before: &[Option<item::Stack>], queued: usize) {
+        if self.actions.len() == queued {
+            return;
+        }
+        let changed = self
+            .slot_items()
+            .into_iter()
+            .zip(before)
+            .enumerate()
+            .filter(|(_, (item, before))| item != *before)
+            .map(|(index, (item, _))| (index as i16, item))
+            .collect();
+        let cursor = self.cursor.clone();
+        if let Some(Action::Click {
+            changed_slots,
+            carried_item,
+            ..
+        }) = self.actions.last_mut()
+        {
+            *changed_slots = changed;
+            *carried_item = cursor;
+        }
+    }
+
+    /// Records the state before a click so it can be rolled back, and
+    /// queues the click's packet.
+    fn send_click(&mut self, slot: i16, button: u8, mode: u8) {
+        let window_id = self.current().id;
+        let action_number = self.next_action;
+        self.next_action = self.next_action.wrapping_add(1);
+
+        if self.confirms_clicks {
+            if self.transactions.len() >= MAX_TRANSACTIONS {
+                self.transactions.remove(0);
+            }
+            self.transactions.push(Transaction {
+                window_id,
+                action_number,
+                player: self.player.slots.clone(),
+                window: self.window.as_ref().map(|w| w.slots.clone()),
+                cursor: self.cursor.clone(),
+            });
+        }
+        let clicked_item = if mode == 5 {
+            None
+        } else {
+            self.slot(slot).cloned()
+        };
+        self.actions.push(Action::Click {
+            window_id,
+            slot,
+            button,
+            mode,
+            action_number,
+            clicked_item,
+            changed_slots: vec![],
+            carried_item: self.cursor.clone(),
+        });
+    }
+
+    fn click(&mut self, slot: i16, button: Button) {
+        if slot != OUTSIDE_SLOT && !self.is_valid_slot(slot) {
+            return;
+        }
+        let button_id = match button {
+            Button::Left => 0,
+            Button::Right => 1,
+        };
+        self.send_click(slot, button_id, 0);
+
+        if slot == OUTSIDE_SLOT {
+            match button {
+                Button::Left => self.cursor = None,
+                Button::Right => self.cursor = take_one(self.cursor.take()).1,
+            }
+            self.changed();
+            return;
+        }
+
+        let output = self.is_output(slot);
+        let cursor = self.cursor.take();
+        let item = match self.slot_mut(slot) {
+            Some(item) => item.take(),
+            None => {
+                self.cursor = cursor;
+                return;
+            }
+        };
+        let (cursor, item) = match (cursor, item) {
+            (None, None) => (None, None),
+            // Outputs can only be picked up as a whole
+            (cursor, Some(item)) if output => match cursor {
+                None => (Some(item), None),
+                Some(mut cursor)
+                    if can_stack(&cursor, &item)
+                        && cursor.count + item.count <= self.max_stack_size(&item) =>
+                {
+                    cursor.count += item.count;
+                    (Some(cursor), None)
+                }
+                cursor => (cursor, Some(item)),
+            },
+            (cursor, None) if output => (cursor, None),
+            (None, Some(mut item)) => match button {
+                Button::Left => (Some(item), None),
+                Button::Right => {
+                    let mut half = item.clone();
+                    half.count = (item.count + 1) / 2;
+                    item.count -= half.count;
+                    (Some(half), if item.count > 0 { Some(item) } else { None })
+                }
+            },
+            (Some(cursor), None) => match button {
+                Button::Left => (None, Some(cursor)),
+                Button::Right => {
+                    let (one, rest) = take_one(Some(cursor));
+                    (rest, one)
+                }
+            },
+            (Some(mut cursor), Some(mut item)) => {
+                if can_stack(&cursor, &item) {
+                    let amount = match button {
+                        Button::Left => cursor.count,
+                        Button::Right => 1,
+                    }
+                    .min(self.max_stack_size(&item) - item.count)
+                    .max(0);
+                    item.count += amount;
+                    cursor.count -= amount;
+                    (
+                        if cursor.count > 0 { Some(cursor) } else { None },
+                        Some(item),
+                    )
+                } else {
+                    (Some(item), Some(cursor))
+                }
+            }
+        };
+        self.cursor = cursor;
+        *self.slot_mut(slot).unwrap() = item;
+        self.changed();
+    }
+
+    fn shift_click(&mut self, slot: i16, button: Button) {
+        if !self.is_valid_slot(slot) {
+            return;
+        }
+        let button_id = match button {
+            Button::Left => 0,
+            Button::Right => 1,
+        };
+        self.send_click(slot, button_id, 1);
+
+        let mut stack = match self.slot_mut(slot).and_then(|v| v.take()) {
+            Some(stack) => stack,
+            None => return,
+        };
+        let max_stack_size = self.max_stack_size(&stack);
+        let targets = self.shift_targets(slot);
+        // Top up existing stacks before using empty slots
+        for target in &targets {
+            if let Some(Some(item)) = self.slot_mut(*target) {
+                if can_stack(item, &stack) && item.count < max_stack_size {
+                    let amount = stack.count.min(max_stack_size - item.count);
+                    item.count += amount;
+                    stack.count -= amount;
+                }
+            }
+            if stack.count <= 0 {
+                break;
+            }
+        }
+        if stack.count > 0 {
+            for target in &targets {
+                if let Some(item @ None) = self.slot_mut(*target) {
+                    *item = Some(stack.clone());
+                    stack.count = 0;
+                    break;
+                }
+            }
+        }
+        if stack.count > 0 {
+            *self.slot_mut(slot).unwrap() = Some(stack);
+        }
+        self.changed();
+    }
+
+    /// The slots a shift clicked item moves to, in order of preference
+    fn shift_targets(&self, slot: i16) -> Vec<i16> {
+        let slot = slot as usize;
+        let to_vec = |range: std::ops::Range<usize>| range.map(|v| v as i16).collect::<Vec<_>>();
+        let window = match self.window {
+            Some(ref window) => window,
+            None => {
+                return if (player::MAIN_START..player::HOTBAR_START).contains(&slot) {
+                    to_vec(player::HOTBAR_START..player::OFFHAND)
+                } else if (player::HOTBAR_START..player::OFFHAND).contains(&slot) {
+                    to_vec(player::MAIN_START..player::HOTBAR_START)
+                } else {
+                    to_vec(player::MAIN_START..player::OFFHAND)
+                };
+            }
+        };
+        let size = window.size();
+        let main = size..size + (player::HOTBAR_START - player::MAIN_START);
+        let hotbar = main.end..size + PLAYER_INVENTORY_SLOTS;
+        if slot < size {
+            // Vanilla fills the player's inventory from the end of the hotbar
+            let mut targets = to_vec(main.start..hotbar.end);
+            targets.reverse();
+            return targets;
+        }
+        match window.kind {
+            WindowKind::Furnace => vec![0],
+            WindowKind::CraftingTable => {
+                if main.contains(&slot) {
+                    to_vec(hotbar)
+                } else {
+                    to_vec(main)
+                }
+            }
+            _ => to_vec(0..size),
+        }
+    }
+
+    /// Spreads the held item over the dragged slots, evenly when
+    /// dragging with the left button and one each with the right.
+    fn drag_click(&mut self, drag: &Drag) {
+        let (start, add, end) = match drag.button {
+            Button::Left => (0, 1, 2),
+            Button::Right => (4, 5, 6),
+        };
+        self.send_click(OUTSIDE_SLOT, start, 5);
+        for slot in &drag.slots {
+            self.send_click(*slot, add, 5);
+        }
+        self.send_click(OUTSIDE_SLOT, end, 5);
+
+        let mut cursor = match self.cursor.take() {
+            Some(cursor) => cursor,
+            None => return,
+        };
+        let max_stack_size = self.max_stack_size(&cursor);
+        let per_slot = match drag.button {
+            Button::Left => cursor.count / drag.slots.len() as isize,
+            Button::Right => 1,
+        };
+        for slot in &drag.slots {
+            let amount = per_slot.min(cursor.count);
+            if amount <= 0 {
+                break;
+            }
+            let item = match self.slot_mut(*slot) {
+                Some(item) => item,
+                None => continue,
+            };
+            match item {
+                Some(item) => {
+                    let amount = amount.min(max_stack_size - item.count).max(0);
+                    item.count += amount;
+                    cursor.count -= amount;
+                }
+                None => {
+                    let mut stack = cursor.clone();
+                    stack.count = amount;
+                    *item = Some(stack);
+                    cursor.count -= amount;
+                }
+            }
+        }
+        if cursor.count > 0 {
+            self.cursor = Some(cursor);
+        }
+        self.changed();
+    }
+}
+
+/// Whether two stacks are of the same item and can be merged
+fn can_stack(a: &item::Stack, b: &item::Stack) -> bool {
+    a.id == b.id && a.damage == b.damage && a.tag == b.tag
+}
+
+/// Splits a single item off the stack, returning it and what remains
+fn take_one(stack: Option<item::Stack>) -> (Option<item::Stack>, Option<item::Stack>) {
+    match stack {
+        Some(mut stack) => {
+            let mut one = stack.clone();
+            one.count = 1;
+            stack.count -= 1;
+            (Some(one), if stack.count > 0 { Some(stack) } else { None })
+        }
+        None => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STONE: isize = 1;
+    const DIRT: isize = 3;
+    const ENDER_PEARL: isize = 368;
+
+    fn stack(id: isize, count: isize) -> Option<item::Stack> {
+        Some(item::Stack {
+            id,
+            count,
+            damage: None,
+            tag: None,
+        })
+    }
+
+    /// The player's own window, opened on a 1.12.2 server
+    fn inventory() -> Inventory {
+        let mut inventory = Inventory::new(Arc::new(block::item::VanillaIDMap::new(340)), 340);
+        inventory.open_player_window();
+        inventory
+    }
+
+    fn count(inventory: &Inventory, slot: i16) -> Option<isize> {
+        inventory.slot(slot).map(|item| item.count)
+    }
+
+    fn cursor_count(inventory: &Inventory) -> Option<isize> {
+        inventory.cursor().map(|item| item.count)
+    }
+
+    /// The slot, button and mode of each queued click
+    fn clicks(inventory: &mut Inventory) -> Vec<(i16, u8, u8)> {
+        inventory
+            .take_actions()
+            .into_iter()
+            .filter_map(|action| match action {
+                Action::Click {
+                    slot, button, mode, ..
+                } => Some((slot, button, mode)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Clicks a slot, placing into it if an item is held
+    fn click(inventory: &mut Inventory, button: Button, slot: i16) {
+        inventory.mouse_down(button, Some(slot), false);
+        inventory.mouse_up(button);
+    }
+
+    #[test]
+    fn pick_up_and_place() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(STONE, 10));
+        click(&mut inventory, Button::Left, 9);
+        assert_eq!(count(&inventory, 9), None);
+        assert_eq!(cursor_count(&inventory), Some(10));
+        click(&mut inventory, Button::Left, 10);
+        assert_eq!(count(&inventory, 10), Some(10));
+        assert_eq!(cursor_count(&inventory), None);
+        assert_eq!(clicks(&mut inventory), vec![(9, 0, 0), (10, 0, 0)]);
+    }
+
+    #[test]
+    fn swap() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(STONE, 10));
+        inventory.set_slot(0, 10, stack(DIRT, 5));
+        click(&mut inventory, Button::Left, 9);
+        click(&mut inventory, Button::Left, 10);
+        assert_eq!(inventory.slot(10).map(|item| item.id), Some(STONE));
+        assert_eq!(inventory.cursor().map(|item| item.id), Some(DIRT));
+        assert_eq!(cursor_count(&inventory), Some(5));
+    }
+
+    #[test]
+    fn right_click_split() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(STONE, 5));
+        click(&mut inventory, Button::Right, 9);
+        assert_eq!(cursor_count(&inventory), Some(3));
+        assert_eq!(count(&inventory, 9), Some(2));
+        // Places a single item
+        click(&mut inventory, Button::Right, 10);
+        assert_eq!(count(&inventory, 10), Some(1));
+        assert_eq!(cursor_count(&inventory), Some(2));
+        click(&mut inventory, Button::Right, 9);
+        assert_eq!(count(&inventory, 9), Some(3));
+        assert_eq!(cursor_count(&inventory), Some(1));
+    }
+
+    #[test]
+    fn merge_respects_stack_size() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(ENDER_PEARL, 10));
+        inventory.set_slot(0, 10, stack(ENDER_PEARL, 10));
+        click(&mut inventory, Button::Left, 9);
+        click(&mut inventory, Button::Left, 10);
+        assert_eq!(count(&inventory, 10), Some(16));
+        assert_eq!(cursor_count(&inventory), Some(4));
+    }
+
+    #[test]
+    fn shift_click() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(ENDER_PEARL, 10));
+        inventory.set_slot(0, 36, stack(ENDER_PEARL, 10));
+        inventory.mouse_down(Button::Left, Some(36), true);
+        // Tops up the main inventory's stack before using empty slots
+        assert_eq!(count(&inventory, 9), Some(16));
+        assert_eq!(count(&inventory, 10), Some(4));
+        assert_eq!(count(&inventory, 36), None);
+        assert_eq!(clicks(&mut inventory), vec![(36, 0, 1)]);
+    }
+
+    #[test]
+    fn drag_distribution() {
+        let mut inventory = inventory();
+        inventory.set_slot(CURSOR_WINDOW_ID, -1, stack(STONE, 10));
+        inventory.mouse_down(Button::Left, Some(9), false);
+        inventory.mouse_over(10);
+        inventory.mouse_over(11);
+        assert_eq!(inventory.drag_slots(), &[9, 10, 11]);
+        inventory.mouse_up(Button::Left);
+        for slot in 9..12 {
+            assert_eq!(count(&inventory, slot), Some(3));
+        }
+        assert_eq!(cursor_count(&inventory), Some(1));
+        assert_eq!(
+            clicks(&mut inventory),
+            vec![
+                (OUTSIDE_SLOT, 0, 5),
+                (9, 1, 5),
+                (10, 1, 5),
+                (11, 1, 5),
+                (OUTSIDE_SLOT, 2, 5),
+            ]
+        );
+
+        // One each with the right button, skipping full stacks
+        inventory.set_slot(CURSOR_WINDOW_ID, -1, stack(STONE, 10));
+        inventory.set_slot(0, 13, stack(STONE, 64));
+        inventory.mouse_down(Button::Right, Some(12), false);
+        inventory.mouse_over(13);
+        inventory.mouse_over(14);
+        inventory.mouse_up(Button::Right);
+        assert_eq!(count(&inventory, 12), Some(1));
+        assert_eq!(count(&inventory, 13), Some(64));
+        assert_eq!(count(&inventory, 14), Some(1));
+        assert_eq!(cursor_count(&inventory), Some(8));
+    }
+
+    #[test]
+    fn output_slot_pickup() {
+        let output = player::CRAFTING_OUTPUT as i16;
+        let mut inventory = inventory();
+        inventory.set_slot(0, output, stack(STONE, 4));
+        click(&mut inventory, Button::Right, output);
+        // Outputs can't be split
+        assert_eq!(cursor_count(&inventory), Some(4));
+        assert_eq!(count(&inventory, output), None);
+
+        inventory.set_slot(0, output, stack(STONE, 4));
+        click(&mut inventory, Button::Left, output);
+        assert_eq!(cursor_count(&inventory), Some(8));
+
+        // Nothing can be placed into an output, and a different item
+        // can't be picked up on top of the held one
+        click(&mut inventory, Button::Left, output);
+        assert_eq!(count(&inventory, output), None);
+        inventory.set_slot(0, output, stack(DIRT, 1));
+        click(&mut inventory, Button::Left, output);
+        assert_eq!(count(&inventory, output), Some(1));
+        assert_eq!(cursor_count(&inventory), Some(8));
+    }
+
+    #[test]
+    fn rollback_rejected_click() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(STONE, 10));
+        click(&mut inventory, Button::Left, 9);
+        click(&mut inventory, Button::Left, 10);
+        let action_numbers = inventory
+            .take_actions()
+            .into_iter()
+            .filter_map(|action| match action {
+                Action::Click { action_number, .. } => Some(action_number),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(action_numbers.len(), 2);
+
+        // Rejecting the first click also undoes the one after it
+        inventory.confirm_transaction(0, action_numbers[0], false);
+        assert_eq!(count(&inventory, 9), Some(10));
+        assert_eq!(count(&inventory, 10), None);
+        assert_eq!(cursor_count(&inventory), None);
+        match inventory.take_actions().as_slice() {
+            [Action::Rollback {
+                window_id: 0,
+                action_number,
+            }] => assert_eq!(*action_number, action_numbers[0]),
+            _ => panic!("expected a rollback"),
+        }
+        // Already rolled back
+        inventory.confirm_transaction(0, action_numbers[1], false);
+        assert!(inventory.take_actions().is_empty());
+    }
+
+    #[test]
+    fn accepted_click_is_kept() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(STONE, 10));
+        click(&mut inventory, Button::Left, 9);
+        let action_number = match inventory.take_actions().as_slice() {
+            [Action::Click { action_number, .. }] => *action_number,
+            _ => panic!("expected a click"),
+        };
+        inventory.confirm_transaction(0, action_number, true);
+        inventory.confirm_transaction(0, action_number, false);
+        assert_eq!(cursor_count(&inventory), Some(10));
+        assert!(inventory.take_actions().is_empty());
+    }
+
+    #[test]
+    fn changed_slots_are_sent() {
+        let mut inventory = inventory();
+        inventory.set_slot(0, 9, stack(STONE, 10));
+        click(&mut inventory, Button::Right, 9);
+        match inventory.take_actions().as_slice() {
+            [Action::Click {
+                changed_slots,
+                carried_item,
+                ..
+            }] => {
+                assert_eq!(changed_slots, &[(9, stack(STONE, 5))]);
+                assert_eq!(carried_item, &stack(STONE, 5));
+            }
+            _ => panic!("expected a click"),
+        }
+
+        // Only the drag's last click has the changes
+        inventory.mouse_down(Button::Left, Some(10), false);
+        inventory.mouse_over(11);
+        inventory.mouse_up(Button::Left);
+        let changes = inventory
+            .take_actions()
+            .into_iter()
+            .filter_map(|action| match action {
+                Action::Click { changed_slots, .. } => Some(changed_slots),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                vec![],
+                vec![],
+                vec![],
+                vec![(10, stack(STONE, 2)), (11, stack(STONE, 2))],
+            ]
+        );
+    }
+
+    #[test]
+    fn clicks_are_not_confirmed_on_1_17() {
+        let mut inventory = Inventory::new(Arc::new(block::item::VanillaIDMap::new(755)), 755);
+        inventory.open_player_window();
+        inventory.set_slot(0, 9, stack(STONE, 10));
+        click(&mut inventory, Button::Left, 9);
+        let action_number = match inventory.take_actions().as_slice() {
+            [Action::Click { action_number, .. }] => *action_number,
+            _ => panic!("expected a click"),
+        };
+        // The server resends the slots instead
+        inventory.confirm_transaction(0, action_number, false);
+        assert!(inventory.take_actions().is_empty());
+        assert_eq!(cursor_count(&inventory), Some(10));
+    }
+
+    #[test]
+    fn invalid_slot_is_not_sent() {
+        let mut inventory = inventory();
+        click(&mut inventory, Button::Left, player::SIZE as i16);
+        inventory.mouse_down(Button::Left, Some(-5), true);
+        assert!(clicks(&mut inventory).is_empty());
+
+        inventory.set_slot(CURSOR_WINDOW_ID, -1, stack(STONE, 10));
+        inventory.mouse_down(Button::Left, Some(9), false);
+        inventory.mouse_over(player::SIZE as i16);
+        assert_eq!(inventory.drag_slots(), &[] as &[i16]);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::format;
+use crate::item;
+use std::collections::HashMap;
+
+/// Number of slots in the player's main inventory and hotbar, which
+/// follow the slots of every container window.
+pub const PLAYER_INVENTORY_SLOTS: usize = 36;
+
+/// Slots of the player's own window (id 0)
+pub mod player {
+    pub const CRAFTING_OUTPUT: usize = 0;
+    pub const ARMOR_START: usize = 5;
+    pub const MAIN_START: usize = 9;
+    pub const HOTBAR_START: usize = 36;
+    pub const OFFHAND: usize = 45;
+    pub const SIZE: usize = 46;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindowKind {
+    Player,
+    /// Rows of 9 slots, used by chests, shulker boxes and the like
+    Chest {
+        rows: usize,
+    },
+    Furnace,
+    CraftingTable,
+    /// Windows without a known layout. The size is updated once the
+    /// window's items are received.
+    Generic {
+        size: usize,
+    },
+}
+
+impl WindowKind {
+    /// Window types by name, used from 1.8 until 1.13.2
+    pub fn from_name(name: &str, slot_count: u8) -> WindowKind {
+        let slot_count = slot_count as usize;
+        match name {
+            "minecraft:chest" | "minecraft:container" | "minecraft:shulker_box"
+                if slot_count > 0 && slot_count / 9 * 9 == slot_count =>
+            {
+                WindowKind::Chest {
+                    rows: slot_count / 9,
+                }
+            }
+            "minecraft:crafting_table" => WindowKind::CraftingTable,
+            "minecraft:furnace" => WindowKind::Furnace,
+            _ => WindowKind::Generic { size: slot_count },
+        }
+    }
+
+    /// Window types by numeric id, used by 1.7
+    pub fn from_legacy_id(id: u8, slot_count: u8) -> WindowKind {
+        let slot_count = slot_count as usize;
+        match id {
+            0 if slot_count > 0 && slot_count / 9 * 9 == slot_count => WindowKind::Chest {
+                rows: slot_count / 9,
+            },
+            1 => WindowKind::CraftingTable,
+            2 => WindowKind::Furnace,
+            _ => WindowKind::Generic { size: slot_count },
+        }
+    }
+
+    /// Window types by their id in the menu registry, used from 1.14
+    pub fn from_registry_id(id: i32, protocol_version: i32) -> WindowKind {
+        // 1.16 added the smithing table before the smoker
+        let smoker = if protocol_version >= 735 { 21 } else { 20 };
+        match id {
+            0..=5 => WindowKind::Chest {
+                rows: id as usize + 1,
+            },
+            6 => WindowKind::Generic { size: 9 },
+            11 => WindowKind::CraftingTable,
+            // Blast furnaces and smokers share the furnace's layout
+            9 | 13 => WindowKind::Furnace,
+            id if id == smoker => WindowKind::Furnace,
+            15 => WindowKind::Generic { size: 5 },
+            19 => WindowKind::Chest { rows: 3 },
+            _ => WindowKind::Generic { size: 0 },
+        }
+    }
+
+    /// The number of slots owned by the window, excluding the player's
+    /// inventory shown below it.
+    pub fn size(&self) -> usize {
+        match *self {
+            WindowKind::Player => player::SIZE,
+            WindowKind::Chest { rows } => rows * 9,
+            WindowKind::Furnace => 3,
+            WindowKind::CraftingTable => 10,
+            WindowKind::Generic { size } => size,
+        }
+    }
+
+    /// Whether items can only be taken out of the slot, like the result
+    /// of a recipe.
+    pub fn is_output(&self, slot: usize) -> bool {
+        match *self {
+            WindowKind::Player => slot == player::CRAFTING_OUTPUT,
+            WindowKind::Furnace => slot == 2,
+            WindowKind::CraftingTable => slot == 0,
+            _ => false,
+        }
+    }
+}
+
+pub struct Window {
+    pub id: u8,
+    pub kind: WindowKind,
+    pub title: Option<format::Component>,
+    pub slots: Vec<Option<item::Stack>>,
+    /// Window specific values, e.g. the progress of a furnace
+    pub properties: HashMap<i16, i16>,
+}
+
+impl Window {
+    pub fn new(id: u8, kind: WindowKind, title: Option<format::Component>) -> Window {
+        Window {
+            id,
+            kind,
+            title,
+            slots: vec![None; kind.size()],
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn player() -> Window {
+        Window::new(0, WindowKind::Player, None)
+    }
+
+    pub fn size(&self) -> usize {
+        self.slots.len()
+    }
+}
@@ -0,0 +1,166 @@
+//! Loads the language files used to display translated chat components.
+
+use crate::console;
+use crate::format;
+use crate::resources;
+use crate::settings;
+use log::warn;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, BufReader, Read};
+use std::sync::{Arc, RwLock};
+
+/// Used for keys missing from the selected language
+const FALLBACK_LOCALE: &str = "en_us";
+
+/// A language listed by the resource packs
+#[derive(Clone, Debug)]
+pub struct Language {
+    /// The locale, e.g. `en_us`
+    pub code: String,
+    pub name: String,
+    pub region: String,
+}
+
+impl Language {
+    pub fn display_name(&self) -> String {
+        format!("{} ({})", self.name, self.region)
+    }
+}
+
+pub struct Manager {
+    resources: Arc<RwLock<resources::Manager>>,
+    resources_version: Option<usize>,
+    locale: String,
+}
+
+impl Manager {
+    pub fn new(resources: Arc<RwLock<resources::Manager>>) -> Manager {
+        Manager {
+            resources,
+            resources_version: None,
+            locale: String::new(),
+        }
+    }
+
+    /// Reloads the translations when the resource packs or the selected
+    /// language change.
+    pub fn tick(&mut self, vars: &console::Vars) {
+        let res = self.resources.read().unwrap();
+        let locale = vars.get(settings::CL_LANGUAGE).to_lowercase();
+        if self.resources_version != Some(res.version()) || self.locale != locale {
+            self.resources_version = Some(res.version());
+            let mut translations = load(&res, FALLBACK_LOCALE);
+            if locale != FALLBACK_LOCALE {
+                translations.extend(load(&res, &locale));
+            }
+            format::set_translations(translations);
+            self.locale = locale;
+        }
+    }
+}
+
+/// Translates a key for text in the client's own screens, returning the
+/// fallback when the key is missing.
+pub fn text(key: &str, fallback: &str) -> String {
+    format::translate(key).unwrap_or_else(|| fallback.to_owned())
+}
+
+/// Lists the languages declared in the packs' `pack.mcmeta`, sorted by
+/// their code.
+pub fn languages(res: &resources::Manager) -> Vec<Language> {
+    let mut languages = BTreeMap::new();
+    // English is built into the game so it isn't always declared
+    languages.insert(
+        FALLBACK_LOCALE.to_owned(),
+        Language {
+            code: FALLBACK_LOCALE.to_owned(),
+            name: "English".to_owned(),
+            region: "United States".to_owned(),
+        },
+    );
+    let mut metas = res.open_all_meta();
+    metas.reverse();
+    for meta in metas {
+        let meta: serde_json::Value = match serde_json::from_reader(meta) {
+            Ok(meta) => meta,
+            Err(err) => {
+                warn!("Failed to parse pack.mcmeta: {}", err);
+                continue;
+            }
+        };
+        let declared = match meta.get("language").and_then(|v| v.as_object()) {
+            Some(declared) => declared,
+            None => continue,
+        };
+        for (code, info) in declared {
+            let field = |name| {
+                info.get(name)
+                    .and_then(|v: &serde_json::Value| v.as_str())
+                    .unwrap_or("")
+                    .to_owned()
+            };
+            let code = code.to_lowercase();
+            languages.insert(
+                code.clone(),
+                Language {
+                    code,
+                    name: field("name"),
+                    region: field("region"),
+                },
+            );
+        }
+    }
+    languages.into_iter().map(|(_, v)| v).collect()
+}
+
+/// Merges the translations for the locale from every resource pack.
+///
+/// 1.13+ packs use `lang/<locale>.json`, older packs use
+/// `lang/<locale>.lang` (`en_US.lang` before 1.11).
+pub fn load(res: &resources::Manager, locale: &str) -> HashMap<String, String> {
+    let mut translations = HashMap::new();
+    let legacy_locale = match locale.split_once('_') {
+        Some((lang, region)) => format!("{}_{}", lang, region.to_uppercase()),
+        None => locale.to_owned(),
+    };
+    let mut files: Vec<(bool, Box<dyn Read>)> = vec![];
+    for (name, is_json) in &[
+        (format!("lang/{}.json", locale), true),
+        (format!("lang/{}.lang", locale), false),
+        (format!("lang/{}.lang", legacy_locale), false),
+    ] {
+        files.extend(
+            res.open_all("minecraft", name)
+                .into_iter()
+                .map(|file| (*is_json, file)),
+        );
+    }
+    // Packs are returned highest priority first, apply them lowest
+    // priority first so they override each other correctly.
+    files.reverse();
+    for (is_json, file) in files {
+        if is_json {
+            match serde_json::from_reader::<_, HashMap<String, String>>(file) {
+                Ok(entries) => translations.extend(entries),
+                Err(err) => warn!("Failed to parse {}.json: {}", locale, err),
+            }
+        } else {
+            for line in BufReader::new(file).lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => {
+                        warn!("Failed to read {}.lang: {}", locale, err);
+                        break;
+                    }
+                };
+                if line.starts_with('#') {
+                    continue;
+                }
+                if let Some((key, value)) = line.split_once('=') {
+                    translations.insert(key.to_owned(), value.to_owned());
+                }
+            }
+        }
+    }
+    translations
+}
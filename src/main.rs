@@ -28,15 +28,21 @@ extern crate steven_protocol;
 
 pub mod ecs;
 use steven_protocol::format;
+use steven_protocol::item;
 use steven_protocol::nbt;
 use steven_protocol::protocol;
 pub mod gl;
 use steven_protocol::types;
+pub mod audio;
 pub mod auth;
 pub mod chunk_builder;
 pub mod console;
 pub mod entity;
+pub mod hud;
+pub mod inventory;
+pub mod lang;
 pub mod model;
+pub mod particle;
 pub mod render;
 pub mod resources;
 pub mod screen;
@@ -61,6 +67,7 @@ const CL_BRAND: console::CVar<String> = console::CVar {
                   \"Vanilla\"",
     mutable: false,
     serializable: false,
+    range: None,
     default: &|| "Steven".to_owned(),
 };
 
@@ -73,6 +80,10 @@ pub struct Game {
     should_close: bool,
 
     server: server::Server,
+    hud: hud::Hud,
+    audio: audio::Manager,
+    lang: lang::Manager,
+    particles: particle::Manager,
     focused: bool,
     chunk_builder: chunk_builder::ChunkBuilder,
 
@@ -85,56 +96,129 @@ pub struct Game {
     last_mouse_yrel: f64,
     is_ctrl_pressed: bool,
     is_logo_pressed: bool,
+    is_shift_pressed: bool,
+    is_alt_pressed: bool,
     is_fullscreen: bool,
     default_protocol_version: i32,
 }
 
+/// Pings the server for its protocol version then logs in on another
+/// thread, sending back the connected server.
+fn connect(
+    address: &str,
+    vars: &console::Vars,
+    resources: Arc<RwLock<resources::Manager>>,
+    default_protocol_version: i32,
+) -> mpsc::Receiver<Result<server::Server, protocol::Error>> {
+    let (protocol_version, forge_mods, fml_network_version) =
+        match protocol::Conn::new(address, default_protocol_version)
+            .and_then(|conn| conn.do_status())
+        {
+            Ok(res) => {
+                info!(
+                    "Detected server protocol version {}",
+                    res.0.version.protocol
+                );
+                (
+                    res.0.version.protocol,
+                    res.0.forge_mods,
+                    res.0.fml_network_version,
+                )
+            }
+            Err(err) => {
+                warn!(
+                    "Error pinging server {} to get protocol version: {:?}, defaulting to {}",
+                    address, err, default_protocol_version
+                );
+                (default_protocol_version, vec![], None)
+            }
+        };
+
+    let profile = mojang::Profile {
+        username: vars.get(auth::CL_USERNAME).clone(),
+        id: vars.get(auth::CL_UUID).clone(),
+        access_token: vars.get(auth::AUTH_TOKEN).clone(),
+    };
+    let (tx, rx) = mpsc::channel();
+    let address = address.to_owned();
+    thread::spawn(move || {
+        tx.send(server::Server::connect(
+            resources,
+            profile,
+            &address,
+            protocol_version,
+            forge_mods,
+            fml_network_version,
+        ))
+        .unwrap();
+    });
+    rx
+}
+
 impl Game {
     pub fn connect_to(&mut self, address: &str) {
-        let (protocol_version, forge_mods, fml_network_version) =
-            match protocol::Conn::new(&address, self.default_protocol_version)
-                .and_then(|conn| conn.do_status())
-            {
-                Ok(res) => {
-                    info!(
-                        "Detected server protocol version {}",
-                        res.0.version.protocol
-                    );
-                    (
-                        res.0.version.protocol,
-                        res.0.forge_mods,
-                        res.0.fml_network_version,
-                    )
-                }
-                Err(err) => {
-                    warn!(
-                        "Error pinging server {} to get protocol version: {:?}, defaulting to {}",
-                        address, err, self.default_protocol_version
-                    );
-                    (self.default_protocol_version, vec![], None)
-                }
-            };
+        self.connect_reply = Some(connect(
+            address,
+            &self.vars,
+            self.resource_manager.clone(),
+            self.default_protocol_version,
+        ));
+    }
 
-        let (tx, rx) = mpsc::channel();
-        self.connect_reply = Some(rx);
-        let address = address.to_owned();
-        let resources = self.resource_manager.clone();
-        let profile = mojang::Profile {
-            username: self.vars.get(auth::CL_USERNAME).clone(),
-            id: self.vars.get(auth::CL_UUID).clone(),
-            access_token: self.vars.get(auth::AUTH_TOKEN).clone(),
-        };
-        thread::spawn(move || {
-            tx.send(server::Server::connect(
-                resources,
-                profile,
-                &address,
-                protocol_version,
-                forge_mods,
-                fml_network_version,
-            ))
-            .unwrap();
-        });
+    pub fn open_chat(&mut self, window: &winit::window::Window, initial_input: &str) {
+        if !self.server.is_connected() {
+            return;
+        }
+        window.set_cursor_grab(false).unwrap();
+        window.set_cursor_visible(true);
+        self.focused = false;
+        self.screen_sys
+            .add_screen(Box::new(screen::Chat::new(initial_input)));
+    }
+
+    pub fn open_inventory(&mut self, window: &winit::window::Window) {
+        if !self.server.is_connected() {
+            return;
+        }
+        window.set_cursor_grab(false).unwrap();
+        window.set_cursor_visible(true);
+        self.focused = false;
+        self.server.inventory.write().unwrap().open_player_window();
+        self.screen_sys.add_screen(Box::new(screen::Inventory::new(
+            self.server.inventory.clone(),
+            self.item_models(),
+        )));
+    }
+
+    /// Returns what screens need to draw the server's items
+    fn item_models(&self) -> ui::item::ItemModels {
+        ui::item::ItemModels::new(self.chunk_builder.models(), &self.server.world)
+    }
+
+    fn modifiers(&self) -> settings::Modifiers {
+        settings::Modifiers {
+            shift: self.is_shift_pressed,
+            ctrl: self.is_ctrl_pressed,
+            alt: self.is_alt_pressed,
+        }
+    }
+
+    /// Starts the action bound to a key or mouse button while playing
+    fn press_action(&mut self, window: &winit::window::Window, action: settings::Stevenkey) {
+        match action {
+            settings::Stevenkey::Chat => self.open_chat(window, ""),
+            settings::Stevenkey::Command => self.open_chat(window, "/"),
+            settings::Stevenkey::OpenInv => self.open_inventory(window),
+            settings::Stevenkey::Hotbar(slot) => self.server.select_hotbar_slot(slot),
+            action => self.server.key_press(true, action),
+        }
+    }
+
+    /// Stops the actions bound to a released key or mouse button
+    fn release_actions(&mut self, input: settings::Input) {
+        for action in settings::Stevenkey::all_by_input(input, &self.vars) {
+            self.server.key_press(false, action);
+        }
     }
 
     pub fn tick(&mut self, delta: f64) {
@@ -153,34 +237,77 @@ impl Game {
             self.focused = false;
         }
 
-        let mut clear_reply = false;
-        if let Some(ref recv) = self.connect_reply {
-            if let Ok(server) = recv.try_recv() {
-                clear_reply = true;
-                match server {
-                    Ok(val) => {
-                        self.screen_sys.pop_screen();
-                        self.focused = true;
-                        self.server.remove(&mut self.renderer);
-                        self.server = val;
-                    }
-                    Err(err) => {
-                        let msg = match err {
-                            protocol::Error::Disconnect(val) => val,
-                            err => {
-                                let mut msg = format::TextComponent::new(&format!("{}", err));
-                                msg.modifier.color = Some(format::Color::Red);
-                                format::Component::Text(msg)
-                            }
-                        };
-                        self.screen_sys
-                            .replace_screen(Box::new(screen::ServerList::new(Some(msg))));
-                    }
+        let inventory_event = self.server.inventory.write().unwrap().take_event();
+        match inventory_event {
+            Some(inventory::Event::Opened) if !self.screen_sys.is_current_inventory() => {
+                self.focused = false;
+                self.screen_sys.add_screen(Box::new(screen::Inventory::new(
+                    self.server.inventory.clone(),
+                    self.item_models(),
+                )));
+            }
+            Some(inventory::Event::Closed) if self.screen_sys.is_current_inventory() => {
+                self.focused = true;
+                self.screen_sys.pop_screen();
+            }
+            _ => {}
+        }
+
+        match self.server.status.take_event() {
+            Some(server::status::Event::Died(message)) => {
+                self.focused = false;
+                let screen = Box::new(screen::Death::new(
+                    message,
+                    self.server.status.total_experience,
+                ));
+                if self.screen_sys.is_current_death_screen() {
+                    self.screen_sys.replace_screen(screen);
+                } else {
+                    self.screen_sys.add_screen(screen);
                 }
             }
+            Some(server::status::Event::Respawned) if self.screen_sys.is_current_death_screen() => {
+                self.focused = true;
+                self.screen_sys.pop_screen();
+            }
+            _ => {}
         }
-        if clear_reply {
+
+        let reply = match self.connect_reply {
+            Some(ref recv) => match recv.try_recv() {
+                Ok(server) => Some(server),
+                Err(mpsc::TryRecvError::Empty) => None,
+                // The connecting thread died without replying
+                Err(mpsc::TryRecvError::Disconnected) => Some(Err(protocol::Error::Err(
+                    "Connecting to the server failed".to_owned(),
+                ))),
+            },
+            None => None,
+        };
+        if let Some(server) = reply {
             self.connect_reply = None;
+            match server {
+                Ok(val) => {
+                    self.screen_sys.pop_screen();
+                    self.focused = true;
+                    self.server.remove(&mut self.renderer);
+                    self.server = val;
+                }
+                Err(err) => {
+                    self.screen_sys
+                        .replace_screen(Box::new(screen::ServerList::new(Some(
+                            server::error_reason(err),
+                        ))));
+                }
+            }
+        }
+
+        let vars = self.vars.clone();
+        vars.run_listeners(self);
+
+        let commands = self.console.lock().unwrap().take_commands();
+        for command in commands {
+            console::commands::execute(self, &command);
         }
     }
 }
@@ -207,6 +334,10 @@ struct Opt {
     /// Protocol version to use in the autodetection ping
     #[structopt(short = "p", long = "default-protocol-version")]
     default_protocol_version: Option<String>,
+
+    /// Play on the server given by --server without opening a window
+    #[structopt(long = "headless")]
+    headless: bool,
 }
 
 cfg_if! {
@@ -253,7 +384,7 @@ fn main2() {
 
     info!("Starting steven");
 
-    let (vars, mut vsync) = {
+    let (vars, vsync) = {
         let mut vars = console::Vars::new();
         vars.register(CL_BRAND);
         console::register_vars(&mut vars);
@@ -269,6 +400,27 @@ fn main2() {
     let (res, mut resui) = resources::Manager::new();
     let resource_manager = Arc::new(RwLock::new(res));
 
+    if let Some(username) = opt.username {
+        vars.set(auth::CL_USERNAME, username);
+    }
+    if opt.network_debug {
+        protocol::enable_network_debug();
+    }
+    let default_protocol_version = protocol::versions::protocol_name_to_protocol_version(
+        opt.default_protocol_version
+            .unwrap_or_else(|| "".to_string()),
+    );
+
+    if opt.headless {
+        match opt.server {
+            Some(address) => {
+                run_headless(&address, &vars, resource_manager, default_protocol_version)
+            }
+            None => error!("--headless requires a server to connect to with --server"),
+        }
+        return;
+    }
+
     let events_loop = winit::event_loop::EventLoop::new();
 
     let window_builder = winit::window::WindowBuilder::new()
@@ -363,17 +515,13 @@ fn main2() {
         }
     }
 
-    if let Some(username) = opt.username {
-        vars.set(auth::CL_USERNAME, username);
-    }
-
     let textures = renderer.get_textures();
-    let default_protocol_version = protocol::versions::protocol_name_to_protocol_version(
-        opt.default_protocol_version
-            .unwrap_or_else(|| "".to_string()),
-    );
     let mut game = Game {
         server: server::Server::dummy_server(resource_manager.clone()),
+        hud: hud::Hud::new(),
+        audio: audio::Manager::new(resource_manager.clone()),
+        lang: lang::Manager::new(resource_manager.clone()),
+        particles: particle::Manager::new(),
         focused: false,
         renderer,
         screen_sys,
@@ -390,14 +538,22 @@ fn main2() {
         last_mouse_yrel: 0.0,
         is_ctrl_pressed: false,
         is_logo_pressed: false,
+        is_shift_pressed: false,
+        is_alt_pressed: false,
         is_fullscreen: false,
         default_protocol_version,
     };
     game.renderer.camera.pos = cgmath::Point3::new(0.5, 13.2, 0.5);
-
-    if opt.network_debug {
-        protocol::enable_network_debug();
-    }
+    game.renderer
+        .set_fov(*game.vars.get(settings::R_FOV) as f32);
+    game.vars.add_listener(settings::R_FOV, |game, fov| {
+        game.renderer.set_fov(*fov as f32);
+    });
+    game.vars.add_listener(settings::R_VSYNC, |game, _| {
+        error!("Changing vsync currently requires restarting");
+        game.should_close = true;
+        // TODO: after https://github.com/tomaka/glutin/issues/693 Allow changing vsync on a Window
+    });
 
     if let Some(filename) = opt.network_parse_packet {
         let data = fs::read(filename).unwrap();
@@ -459,7 +615,7 @@ fn main2() {
                     &mut last_frame,
                     &mut resui,
                     &mut last_resource_version,
-                    &mut vsync,
+                    vsync,
                 );
                 println!("render_loop");
 
@@ -522,7 +678,7 @@ fn main2() {
                 &mut last_frame,
                 &mut resui,
                 &mut last_resource_version,
-                &mut vsync,
+                vsync,
             );
 
             glutin_window
@@ -536,6 +692,71 @@ fn main2() {
     });
 }
 
+/// Plays on the server without a window or renderer until it disconnects,
+/// logging the chat.
+fn run_headless(
+    address: &str,
+    vars: &console::Vars,
+    resources: Arc<RwLock<resources::Manager>>,
+    default_protocol_version: i32,
+) {
+    let reply = connect(address, vars, resources, default_protocol_version);
+    let mut server = match reply.recv() {
+        Ok(Ok(server)) => server,
+        Ok(Err(err)) => {
+            error!("Failed to connect to {}: {}", address, err);
+            return;
+        }
+        Err(_) => return,
+    };
+    info!("Connected to {}", address);
+
+    let mut last_frame = Instant::now();
+    let mut last_message = last_frame;
+    let frame_interval = Duration::from_secs(1) / 60;
+    while server.is_connected() {
+        let now = Instant::now();
+        let delta = now.duration_since(last_frame).as_secs_f64() * 60.0;
+        last_frame = now;
+
+        server.update_client_settings(client_settings(vars));
+        server.tick(None, None, delta);
+        // Nothing plays sounds or shows particles
+        server.sounds.clear();
+        server.particles.clear();
+
+        if server.chat.take_dirty() {
+            for line in server.chat.lines() {
+                if line.received > last_message {
+                    info!("[Chat] {}", line.text);
+                    last_message = line.received;
+                }
+            }
+        }
+
+        let frame_time = now.elapsed();
+        if frame_time < frame_interval {
+            thread::sleep(frame_interval - frame_time);
+        }
+    }
+    match server.disconnect_reason.take() {
+        Some(reason) => info!("Disconnected: {}", reason),
+        None => info!("Disconnected"),
+    }
+}
+
+fn client_settings(vars: &console::Vars) -> server::ClientSettings {
+    let render_distance = (*vars.get(settings::R_RENDER_DISTANCE)).clamp(2, 32);
+    server::ClientSettings {
+        locale: vars.get(settings::CL_LANGUAGE).to_lowercase(),
+        view_distance: render_distance as u8,
+        chat_mode: *vars.get(settings::CL_CHAT_VISIBILITY) as u8,
+        chat_colors: *vars.get(settings::CL_CHAT_COLORS),
+        displayed_skin_parts: (*vars.get(settings::CL_SKIN_PARTS) & 0x7f) as u8,
+        main_hand: *vars.get(settings::CL_MAIN_HAND) as u8,
+    }
+}
+
 fn tick_all(
     window: &winit::window::Window,
     game: &mut Game,
@@ -543,7 +764,7 @@ fn tick_all(
     last_frame: &mut Instant,
     mut resui: &mut resources::ManagerUI,
     last_resource_version: &mut usize,
-    vsync: &mut bool,
+    vsync: bool,
 ) {
     let now = Instant::now();
     let diff = now.duration_since(*last_frame);
@@ -567,17 +788,30 @@ fn tick_all(
     };
     *last_resource_version = version;
 
-    let vsync_changed = *game.vars.get(settings::R_VSYNC);
-    if *vsync != vsync_changed {
-        error!("Changing vsync currently requires restarting");
-        game.should_close = true;
-        // TODO: after https://github.com/tomaka/glutin/issues/693 Allow changing vsync on a Window
-        //vsync = vsync_changed;
-    }
     let fps_cap = *game.vars.get(settings::R_MAX_FPS);
 
+    game.lang.tick(&game.vars);
+    let render_distance = (*game.vars.get(settings::R_RENDER_DISTANCE)).clamp(2, 32);
+    game.server
+        .update_client_settings(client_settings(&game.vars));
     game.tick(delta);
-    game.server.tick(&mut game.renderer, delta);
+    game.server.tick(
+        Some(&mut game.renderer),
+        Some(game.chunk_builder.models()),
+        delta,
+    );
+    let sounds = std::mem::take(&mut game.server.sounds);
+    game.audio
+        .tick(&game.vars, &game.renderer.camera, sounds, delta);
+    let particles = std::mem::take(&mut game.server.particles);
+    game.particles.tick(
+        &mut game.renderer,
+        &game.server.world,
+        game.chunk_builder.models(),
+        &game.vars,
+        particles,
+        delta,
+    );
 
     // Check if window is valid, it might be minimized
     if physical_width == 0 || physical_height == 0 {
@@ -585,20 +819,22 @@ fn tick_all(
     }
 
     game.renderer.update_camera(physical_width, physical_height);
-    game.server.world.compute_render_list(&mut game.renderer);
+    game.server
+        .world
+        .compute_render_list(&mut game.renderer, render_distance as i32);
     game.chunk_builder
         .tick(&mut game.server.world, &mut game.renderer, version);
 
     game.screen_sys
         .tick(delta, &mut game.renderer, &mut ui_container);
-    /* TODO: open console for chat messages
-    if let Some(received_chat_at) = game.server.received_chat_at {
-        if Instant::now().duration_since(received_chat_at).as_secs() < 5 {
-            game.console.lock().unwrap().activate()
-            // TODO: automatically deactivate the console after inactivity
-        }
-    }
-    */
+    let chat_open = game.screen_sys.is_current_showing_chat();
+    game.hud.tick(
+        &mut game.server,
+        ui_container,
+        &game.renderer,
+        game.chunk_builder.models(),
+        chat_open,
+    );
     game.console
         .lock()
         .unwrap()
@@ -613,7 +849,7 @@ fn tick_all(
         physical_height,
     );
 
-    if fps_cap > 0 && !*vsync {
+    if fps_cap > 0 && !vsync {
         let frame_time = now.elapsed();
         let sleep_interval = Duration::from_millis(1000 / fps_cap as u64);
         if frame_time < sleep_interval {
@@ -657,6 +893,9 @@ fn handle_window_event<T>(
 
             use std::f64::consts::PI;
 
+            let sensitivity = *game.vars.get(settings::CL_MOUSE_SENSITIVITY);
+            let (rx, ry) = (rx * sensitivity, ry * sensitivity);
+
             if game.focused {
                 window.set_cursor_grab(true).unwrap();
                 window.set_cursor_visible(false);
@@ -686,6 +925,8 @@ fn handle_window_event<T>(
                 WindowEvent::ModifiersChanged(modifiers_state) => {
                     game.is_ctrl_pressed = modifiers_state.ctrl();
                     game.is_logo_pressed = modifiers_state.logo();
+                    game.is_shift_pressed = modifiers_state.shift();
+                    game.is_alt_pressed = modifiers_state.alt();
                 }
                 WindowEvent::CloseRequested => game.should_close = true,
                 WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
@@ -693,7 +934,11 @@ fn handle_window_event<T>(
                 }
 
                 WindowEvent::ReceivedCharacter(codepoint) => {
-                    if !game.focused && !game.is_ctrl_pressed && !game.is_logo_pressed {
+                    if game.console.lock().unwrap().is_active() {
+                        if !game.is_ctrl_pressed && !game.is_logo_pressed {
+                            game.console.lock().unwrap().key_type(codepoint);
+                        }
+                    } else if !game.focused && !game.is_ctrl_pressed && !game.is_logo_pressed {
                         ui_container.key_type(game, codepoint);
                     }
 
@@ -703,40 +948,73 @@ fn handle_window_event<T>(
                     }
                 }
 
-                WindowEvent::MouseInput { state, button, .. } => match (state, button) {
-                    (ElementState::Released, MouseButton::Left) => {
-                        let physical_size = window.inner_size();
-                        let (width, height) =
-                            physical_size.to_logical::<f64>(game.dpi_factor).into();
-
-                        if game.server.is_connected()
-                            && !game.focused
-                            && !game.screen_sys.is_current_closable()
-                        {
-                            game.focused = true;
-                            window.set_cursor_grab(true).unwrap();
-                            window.set_cursor_visible(false);
-                        } else if !game.focused {
-                            #[cfg(not(target_arch = "wasm32"))]
-                            // TODO: after Pointer Lock https://github.com/rust-windowing/winit/issues/1674
-                            window.set_cursor_grab(false).unwrap();
-                            window.set_cursor_visible(true);
-                            ui_container.click_at(
-                                game,
-                                game.last_mouse_x,
-                                game.last_mouse_y,
-                                width,
-                                height,
-                            );
+                WindowEvent::MouseInput { state, button, .. } => {
+                    if !game.focused {
+                        let modifiers = game.modifiers();
+                        game.screen_sys.on_mouse_button(
+                            button,
+                            state == ElementState::Pressed,
+                            modifiers,
+                        );
+                    } else {
+                        // Buttons bound to actions replace attacking and using items
+                        let input = settings::Input::Mouse(button);
+                        if state == ElementState::Pressed {
+                            if let Some(action) = settings::Stevenkey::get_by_input(
+                                input,
+                                game.modifiers(),
+                                &game.vars,
+                            ) {
+                                game.press_action(window, action);
+                                return false;
+                            }
+                        } else if !settings::Stevenkey::all_by_input(input, &game.vars).is_empty() {
+                            game.release_actions(input);
+                            return false;
                         }
                     }
-                    (ElementState::Pressed, MouseButton::Right) => {
-                        if game.focused {
-                            game.server.on_right_click(&mut game.renderer);
+                    match (state, button) {
+                        (ElementState::Released, MouseButton::Left) => {
+                            game.server.on_left_click(false);
+                            let physical_size = window.inner_size();
+                            let (width, height) =
+                                physical_size.to_logical::<f64>(game.dpi_factor).into();
+
+                            if game.server.is_connected()
+                                && !game.focused
+                                && !game.screen_sys.is_current_closable()
+                                && !game.screen_sys.is_current_death_screen()
+                            {
+                                game.focused = true;
+                                window.set_cursor_grab(true).unwrap();
+                                window.set_cursor_visible(false);
+                            } else if !game.focused {
+                                #[cfg(not(target_arch = "wasm32"))]
+                                // TODO: after Pointer Lock https://github.com/rust-windowing/winit/issues/1674
+                                window.set_cursor_grab(false).unwrap();
+                                window.set_cursor_visible(true);
+                                ui_container.click_at(
+                                    game,
+                                    game.last_mouse_x,
+                                    game.last_mouse_y,
+                                    width,
+                                    height,
+                                );
+                            }
                         }
+                        (ElementState::Pressed, MouseButton::Left) => {
+                            if game.focused {
+                                game.server.on_left_click(true);
+                            }
+                        }
+                        (ElementState::Pressed, MouseButton::Right) => {
+                            if game.focused {
+                                game.server.on_right_click(&mut game.renderer);
+                            }
+                        }
+                        (_, _) => (),
                     }
-                    (_, _) => (),
-                },
+                }
                 WindowEvent::CursorMoved { position, .. } => {
                     let (x, y) = position.to_logical::<f64>(game.dpi_factor).into();
                     game.last_mouse_x = x;
@@ -751,18 +1029,42 @@ fn handle_window_event<T>(
                 }
                 WindowEvent::MouseWheel { delta, .. } => {
                     // TODO: line vs pixel delta? does pixel scrolling (e.g. touchpad) need scaling?
-                    match delta {
-                        MouseScrollDelta::LineDelta(x, y) => {
-                            game.screen_sys.on_scroll(x.into(), y.into());
-                        }
-                        MouseScrollDelta::PixelDelta(position) => {
-                            let (x, y) = position.into();
-                            game.screen_sys.on_scroll(x, y);
-                        }
+                    let (x, y) = match delta {
+                        MouseScrollDelta::LineDelta(x, y) => (x.into(), y.into()),
+                        MouseScrollDelta::PixelDelta(position) => position.into(),
+                    };
+                    if game.focused {
+                        game.server.scroll_hotbar(y);
+                    } else {
+                        game.screen_sys.on_scroll(x, y);
                     }
                 }
                 WindowEvent::KeyboardInput { input, .. } => {
+                    let console_active = game.console.lock().unwrap().is_active();
+                    let modifiers = game.modifiers();
+                    let used_by_screen = match input.virtual_keycode {
+                        Some(key) if !game.focused && !console_active => game
+                            .screen_sys
+                            .on_key_press(key, input.state == ElementState::Pressed, modifiers),
+                        _ => false,
+                    };
                     match (input.state, input.virtual_keycode) {
+                        _ if used_by_screen => {}
+                        // The console takes every key press while it is open
+                        (ElementState::Released, Some(VirtualKeyCode::Escape))
+                            if console_active =>
+                        {
+                            game.console.lock().unwrap().toggle();
+                        }
+                        (_, Some(key)) if console_active && key != VirtualKeyCode::Grave => {
+                            if input.state == ElementState::Pressed {
+                                game.console.lock().unwrap().key_press(key, &game.vars);
+                            } else if game.focused {
+                                // Let movement stop if the key was held when the
+                                // console opened
+                                game.release_actions(settings::Input::Key(key));
+                            }
+                        }
                         (ElementState::Released, Some(VirtualKeyCode::Escape)) => {
                             if game.focused {
                                 window.set_cursor_grab(false).unwrap();
@@ -795,12 +1097,22 @@ fn handle_window_event<T>(
                             game.is_fullscreen = !game.is_fullscreen;
                         }
                         (ElementState::Pressed, Some(key)) => {
+                            let action = settings::Stevenkey::get_by_input(
+                                settings::Input::Key(key),
+                                modifiers,
+                                &game.vars,
+                            );
                             if game.focused {
-                                if let Some(steven_key) =
-                                    settings::Stevenkey::get_by_keycode(key, &game.vars)
-                                {
-                                    game.server.key_press(true, steven_key);
+                                if let Some(action) = action {
+                                    game.press_action(window, action);
                                 }
+                            } else if game.screen_sys.is_current_inventory()
+                                && action == Some(settings::Stevenkey::OpenInv)
+                            {
+                                window.set_cursor_grab(true).unwrap();
+                                window.set_cursor_visible(false);
+                                game.focused = true;
+                                game.screen_sys.pop_screen();
                             } else {
                                 let ctrl_pressed = game.is_ctrl_pressed || game.is_logo_pressed;
                                 ui_container.key_press(game, key, true, ctrl_pressed);
@@ -808,11 +1120,7 @@ fn handle_window_event<T>(
                         }
                         (ElementState::Released, Some(key)) => {
                             if game.focused {
-                                if let Some(steven_key) =
-                                    settings::Stevenkey::get_by_keycode(key, &game.vars)
-                                {
-                                    game.server.key_press(false, steven_key);
-                                }
+                                game.release_actions(settings::Input::Key(key));
                             } else {
                                 let ctrl_pressed = game.is_ctrl_pressed;
                                 ui_container.key_press(game, key, false, ctrl_pressed);
@@ -0,0 +1,337 @@
+//! Item models, used to draw items in inventories.
+//!
+//! Items without an item model of their own, like most blocks before
+//! 1.13's resources, are drawn from their block state's model instead.
+
+use super::{BlockFace, BuiltinType, Factory, Key, Model, ModelDisplay, ModelElement, RawModel};
+use crate::item::Stack;
+use crate::shared::Direction;
+use crate::world::block::{item::Item, Block, TintType};
+use image::GenericImageView;
+use log::error;
+use std::io::Read;
+use std::iter;
+use std::sync::{Arc, RwLock};
+
+/// The `gui` transform of vanilla's `block/block` model, used for block
+/// items drawn from their block state
+const BLOCK_GUI: ModelDisplay = ModelDisplay {
+    rotation: [30.0, 225.0, 0.0],
+    translation: [0.0, 0.0, 0.0],
+    scale: [0.625, 0.625, 0.625],
+};
+
+/// The `thirdperson_righthand` transform of vanilla's `block/block` model
+const BLOCK_HAND: ModelDisplay = ModelDisplay {
+    rotation: [75.0, 45.0, 0.0],
+    translation: [0.0, 2.5, 0.0],
+    scale: [0.375, 0.375, 0.375],
+};
+
+/// The colour of undyed leather armor
+pub const LEATHER_COLOUR: (u8, u8, u8) = (160, 101, 64);
+
+/// Item models with another name in the 1.12 resources, by the name the
+/// item has since the flattening. Music discs and spawn eggs are renamed
+/// by pattern instead.
+const LEGACY_MODELS: &[(&str, &str)] = &[
+    ("bone_meal", "dye_white"),
+    ("orange_dye", "dye_orange"),
+    ("magenta_dye", "dye_magenta"),
+    ("light_blue_dye", "dye_light_blue"),
+    ("dandelion_yellow", "dye_yellow"),
+    ("yellow_dye", "dye_yellow"),
+    ("lime_dye", "dye_lime"),
+    ("pink_dye", "dye_pink"),
+    ("gray_dye", "dye_gray"),
+    ("light_gray_dye", "dye_silver"),
+    ("cyan_dye", "dye_cyan"),
+    ("purple_dye", "dye_purple"),
+    ("lapis_lazuli", "dye_blue"),
+    ("cocoa_beans", "dye_brown"),
+    ("cactus_green", "dye_green"),
+    ("green_dye", "dye_green"),
+    ("rose_red", "dye_red"),
+    ("red_dye", "dye_red"),
+    ("ink_sac", "dye_black"),
+    ("tropical_fish", "clownfish"),
+    ("potion", "bottle_drinkable"),
+    ("splash_potion", "bottle_splash"),
+    ("lingering_potion", "bottle_lingering"),
+    ("firework_rocket", "fireworks"),
+    ("firework_star", "firework_charge"),
+    ("melon_slice", "melon"),
+    ("glistering_melon_slice", "speckled_melon"),
+    ("nether_brick", "netherbrick"),
+    ("popped_chorus_fruit", "chorus_fruit_popped"),
+    ("totem_of_undying", "totem"),
+    ("sugar_cane", "reeds"),
+    ("oak_door", "wooden_door"),
+    ("oak_sign", "sign"),
+    ("grass_block", "grass"),
+    ("grass", "tall_grass"),
+    ("tall_grass", "double_grass"),
+];
+
+/// A model ready to be drawn as an item
+pub struct ItemModel {
+    pub faces: Vec<ItemFace>,
+    /// How the model is placed in inventory slots
+    pub gui: ModelDisplay,
+    /// How the model is placed in the right hand of other players, it is
+    /// mirrored for the left hand
+    pub hand: ModelDisplay,
+}
+
+pub struct ItemFace {
+    /// The corners from 0 to 1, in the order used for quads
+    pub vertices: [[f64; 3]; 4],
+    pub facing: Direction,
+    pub texture: String,
+    /// The texture coordinates of each corner from 0 to 1
+    pub texture_coords: [(f64, f64); 4],
+    /// The colour that tints the face, -1 for none
+    pub tint_index: i32,
+}
+
+impl ItemModel {
+    fn new(model: &Model, gui: ModelDisplay, hand: ModelDisplay) -> ItemModel {
+        let faces = model
+            .faces
+            .iter()
+            .filter_map(|face| {
+                let texture = face.vertices_texture.first()?;
+                let width = texture.get_width() as f64 * 16.0;
+                let height = texture.get_height() as f64 * 16.0;
+                let mut vertices = [[0.0; 3]; 4];
+                let mut texture_coords = [(0.0, 0.0); 4];
+                for (i, v) in face.vertices.iter().take(4).enumerate() {
+                    vertices[i] = [v.x as f64, v.y as f64, v.z as f64];
+                    texture_coords[i] = (v.toffsetx as f64 / width, v.toffsety as f64 / height);
+                }
+                Some(ItemFace {
+                    vertices,
+                    facing: face.facing,
+                    texture: texture.name.clone(),
+                    texture_coords,
+                    tint_index: face.tint_index,
+                })
+            })
+            .collect();
+        ItemModel { faces, gui, hand }
+    }
+}
+
+impl Factory {
+    /// Returns the model of the item, falling back to the model of the
+    /// block it places.
+    pub fn get_item_model(
+        models: &Arc<RwLock<Factory>>,
+        item: Item,
+        block: Option<Block>,
+    ) -> Option<Arc<ItemModel>> {
+        if let Some(model) = models.read().unwrap().item_models.get(item.name) {
+            return model.clone();
+        }
+        let mut m = models.write().unwrap();
+        let model = m
+            .load_item_model(item.name)
+            .or_else(|| block.and_then(|block| m.load_block_item_model(block)))
+            .map(Arc::new);
+        m.item_models.insert(item.name.to_owned(), model.clone());
+        model
+    }
+
+    /// Returns the colour of an item's faces with a tint index of 0, the
+    /// dye of leather armor or the tint of the block placed. Grass and
+    /// foliage are coloured like in a temperate biome.
+    pub fn get_item_tint(&self, item: Item, block: Option<Block>, stack: &Stack) -> (u8, u8, u8) {
+        if item.name.starts_with("leather_") {
+            return stack.dye_colour().unwrap_or(LEATHER_COLOUR);
+        }
+        let block = match block {
+            Some(block) => block,
+            None => return (255, 255, 255),
+        };
+        let colors = match block.get_tint() {
+            TintType::Default => return (255, 255, 255),
+            TintType::Color { r, g, b } => return (r, g, b),
+            TintType::Grass => &self.grass_colors,
+            TintType::Foliage => &self.foliage_colors,
+        };
+        let pixel = colors.get_pixel(127, 127);
+        (pixel[0], pixel[1], pixel[2])
+    }
+
+    fn load_item_model(&self, name: &str) -> Option<ItemModel> {
+        let legacy = LEGACY_MODELS
+            .iter()
+            .filter(|(new, _)| *new == name)
+            .map(|(_, old)| (*old).to_owned());
+        let disc = name
+            .strip_prefix("music_disc_")
+            .map(|disc| format!("record_{}", disc));
+        let spawn_egg = if name.ends_with("_spawn_egg") {
+            Some("spawn_egg".to_owned())
+        } else {
+            None
+        };
+        legacy
+            .chain(disc)
+            .chain(spawn_egg)
+            .chain(iter::once(name.to_owned()))
+            .find_map(|name| self.load_item_model_file(&name))
+    }
+
+    fn load_item_model_file(&self, name: &str) -> Option<ItemModel> {
+        let path = format!("models/item/{}.json", name);
+        let file = self.resources.read().unwrap().open("minecraft", &path)?;
+        let v: serde_json::Value = match serde_json::from_reader(file) {
+            Ok(val) => val,
+            Err(err) => {
+                error!("Error loading model {}: {}", path, err);
+                return None;
+            }
+        };
+        let mut raw = self.parse_model("minecraft", &v)?;
+        if let BuiltinType::Generated = raw.builtin {
+            raw.elements = self.generate_layers(&raw);
+        }
+        let gui = raw.display.get("gui").copied().unwrap_or_default();
+        let hand = raw
+            .display
+            .get("thirdperson_righthand")
+            .copied()
+            .unwrap_or_default();
+        Some(ItemModel::new(&self.process_model(raw), gui, hand))
+    }
+
+    /// Builds the elements of a `builtin/generated` model. Like vanilla,
+    /// each `layerN` texture becomes a slab 1/16th of a block thick with
+    /// the edges of its opaque pixels as the sides.
+    fn generate_layers(&self, raw: &RawModel) -> Vec<ModelElement> {
+        let mut elements = vec![];
+        for layer in 0.. {
+            let var = format!("#layer{}", layer);
+            let texture = raw.lookup_texture(&var);
+            if texture.is_empty() {
+                break;
+            }
+            let face = |uv| {
+                Some(BlockFace {
+                    uv,
+                    texture: var.clone(),
+                    cull_face: Direction::Invalid,
+                    rotation: 0,
+                    tint_index: layer,
+                })
+            };
+            let mut front = ModelElement {
+                from: [0.0, 0.0, 7.5],
+                to: [16.0, 16.0, 8.5],
+                shade: false,
+                rotation: None,
+                faces: [None, None, None, None, None, None],
+            };
+            front.faces[Direction::South.index()] = face([0.0, 0.0, 16.0, 16.0]);
+            front.faces[Direction::North.index()] = face([16.0, 0.0, 0.0, 16.0]);
+            elements.push(front);
+
+            let img = match self.load_texture_image(&texture) {
+                Some(img) => img,
+                None => continue,
+            };
+            let width = img.width() as i64;
+            // Animated textures are frames stacked vertically
+            let height = (img.height() as i64).min(width);
+            let transparent = |x: i64, y: i64| {
+                x < 0
+                    || y < 0
+                    || x >= width
+                    || y >= height
+                    || img.get_pixel(x as u32, y as u32)[3] == 0
+            };
+            for y in 0..height {
+                for x in 0..width {
+                    if transparent(x, y) {
+                        continue;
+                    }
+                    let (x1, x2) = (
+                        x as f64 * 16.0 / width as f64,
+                        (x + 1) as f64 * 16.0 / width as f64,
+                    );
+                    let (y1, y2) = (
+                        y as f64 * 16.0 / height as f64,
+                        (y + 1) as f64 * 16.0 / height as f64,
+                    );
+                    let mut edge = ModelElement {
+                        from: [x1, 16.0 - y2, 7.5],
+                        to: [x2, 16.0 - y1, 8.5],
+                        shade: false,
+                        rotation: None,
+                        faces: [None, None, None, None, None, None],
+                    };
+                    for &(dir, (ox, oy)) in &[
+                        (Direction::Up, (0, -1)),
+                        (Direction::Down, (0, 1)),
+                        (Direction::West, (-1, 0)),
+                        (Direction::East, (1, 0)),
+                    ] {
+                        if transparent(x + ox, y + oy) {
+                            edge.faces[dir.index()] = face([x1, y1, x2, y2]);
+                        }
+                    }
+                    if edge.faces.iter().any(|face| face.is_some()) {
+                        elements.push(edge);
+                    }
+                }
+            }
+        }
+        elements
+    }
+
+    fn load_texture_image(&self, name: &str) -> Option<image::DynamicImage> {
+        let (plugin, name) = name.split_once(':').unwrap_or(("minecraft", name));
+        let mut file = self
+            .resources
+            .read()
+            .unwrap()
+            .open(plugin, &format!("textures/{}.png", name))?;
+        let mut data = vec![];
+        file.read_to_end(&mut data).ok()?;
+        image::load_from_memory(&data).ok()
+    }
+
+    /// Draws a block item with its block state's model, placed like
+    /// vanilla's `block/block` model.
+    fn load_block_item_model(&mut self, block: Block) -> Option<ItemModel> {
+        let (plugin, name) = block.get_model();
+        let key = Key(plugin.to_owned(), name.to_owned());
+        if !self.models.contains_key(&key) && !self.load_model(&plugin, &name) {
+            return None;
+        }
+        let model = self.models.get(&key)?;
+        let joined = if model.multipart.is_empty() {
+            model
+                .get_variants(&block.get_model_variant())?
+                .models
+                .first()?
+                .clone()
+        } else {
+            let mut joined: Option<Model> = None;
+            for rule in &model.multipart {
+                if !Self::eval_rules(block, &rule.rules) {
+                    continue;
+                }
+                if let Some(part) = rule.apply.models.first() {
+                    match joined {
+                        Some(ref mut joined) => joined.join(part),
+                        None => joined = Some(part.clone()),
+                    }
+                }
+            }
+            joined?
+        };
+        Some(ItemModel::new(&joined, BLOCK_GUI, BLOCK_HAND))
+    }
+}
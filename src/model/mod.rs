@@ -1,3 +1,4 @@
+pub mod item;
 pub mod liquid;
 
 use crate::render;
@@ -6,6 +7,7 @@ use crate::shared::Direction;
 use crate::world;
 use crate::world::block::{Block, TintType};
 use byteorder::{NativeEndian, WriteBytesExt};
+use collision::Aabb;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::Write;
@@ -24,6 +26,8 @@ pub struct Factory {
     pub textures: Arc<RwLock<render::TextureManager>>,
 
     models: HashMap<Key, StateModel, BuildHasherDefault<FNVHash>>,
+    /// Item models by item name, `None` when the item has no model
+    item_models: HashMap<String, Option<Arc<item::ItemModel>>, BuildHasherDefault<FNVHash>>,
 
     grass_colors: image::DynamicImage,
     foliage_colors: image::DynamicImage,
@@ -69,6 +73,7 @@ impl Factory {
             textures,
 
             models: HashMap::with_hasher(BuildHasherDefault::default()),
+            item_models: HashMap::with_hasher(BuildHasherDefault::default()),
         }
     }
 
@@ -88,6 +93,7 @@ impl Factory {
 
     pub fn version_change(&mut self) {
         self.models.clear();
+        self.item_models.clear();
         self.grass_colors = Factory::load_biome_colors(self.resources.clone(), "grass");
         self.foliage_colors = Factory::load_biome_colors(self.resources.clone(), "foliage");
     }
@@ -214,6 +220,79 @@ impl Factory {
         ret
     }
 
+    /// Returns the texture used for the particles of the block, e.g. when
+    /// it is broken.
+    pub fn get_particle_texture(
+        models: &Arc<RwLock<Factory>>,
+        block: Block,
+    ) -> Option<render::Texture> {
+        let (plugin, name) = block.get_model();
+        let key = Key(plugin.to_owned(), name.to_owned());
+        if !models.read().unwrap().models.contains_key(&key) {
+            let mut m = models.write().unwrap();
+            if !m.models.contains_key(&key) && !m.load_model(&plugin, &name) {
+                return None;
+            }
+        }
+        let m = models.read().unwrap();
+        let model = m.models.get(&key)?;
+        let variants = if model.multipart.is_empty() {
+            model.get_variants(&block.get_model_variant())
+        } else {
+            model
+                .multipart
+                .iter()
+                .find(|rule| Self::eval_rules(block, &rule.rules))
+                .map(|rule| &rule.apply)
+        };
+        variants?.models.first()?.particle.clone()
+    }
+
+    /// Returns the box around the faces of the block's model, like the
+    /// selection box vanilla draws cracks on. `None` for blocks without
+    /// faces, e.g. ones drawn as entities.
+    pub fn get_block_bounds(
+        models: &Arc<RwLock<Factory>>,
+        block: Block,
+    ) -> Option<collision::Aabb3<f64>> {
+        let (plugin, name) = block.get_model();
+        let key = Key(plugin.to_owned(), name.to_owned());
+        if !models.read().unwrap().models.contains_key(&key) {
+            let mut m = models.write().unwrap();
+            if !m.models.contains_key(&key) && !m.load_model(&plugin, &name) {
+                return None;
+            }
+        }
+        let m = models.read().unwrap();
+        let model = m.models.get(&key)?;
+        let parts: Vec<&Model> = if model.multipart.is_empty() {
+            model
+                .get_variants(&block.get_model_variant())?
+                .models
+                .first()
+                .into_iter()
+                .collect()
+        } else {
+            model
+                .multipart
+                .iter()
+                .filter(|rule| Self::eval_rules(block, &rule.rules))
+                .filter_map(|rule| rule.apply.models.first())
+                .collect()
+        };
+        let mut vertices = parts
+            .iter()
+            .flat_map(|part| &part.faces)
+            .flat_map(|face| &face.vertices)
+            .map(|v| cgmath::Point3::new(v.x as f64, v.y as f64, v.z as f64));
+        let first = vertices.next()?;
+        Some(
+            vertices.fold(collision::Aabb3::new(first, first), |bounds, v| {
+                bounds.grow(v)
+            }),
+        )
+    }
+
     fn load_model(&mut self, plugin: &str, name: &str) -> bool {
         let file = match self
             .resources
@@ -409,7 +488,11 @@ impl Factory {
             }
         }
 
-        // TODO: Display
+        if let Some(display) = v.get("display").and_then(|v| v.as_object()) {
+            for (k, v) in display {
+                model.display.insert(k.clone(), ModelDisplay::parse(v));
+            }
+        }
 
         Some(model)
     }
@@ -542,10 +625,16 @@ impl Factory {
     }
 
     fn process_model(&self, mut raw: RawModel) -> Model {
+        let particle = raw.lookup_texture("#particle");
         let mut model = Model {
             faces: vec![],
             ambient_occlusion: raw.ambient_occlusion,
             weight: raw.weight,
+            particle: if particle.is_empty() {
+                None
+            } else {
+                Some(render::Renderer::get_texture(&self.textures, &particle))
+            },
         };
         let elements = std::mem::take(&mut raw.elements);
         for el in elements {
@@ -898,11 +987,45 @@ impl RawModel {
     }
 }
 
-#[derive(Debug)]
-struct ModelDisplay {
-    rotation: [f64; 3],
-    translation: [f64; 3],
-    scale: [f64; 3],
+/// How a model is placed when drawn in a particular context, e.g. `gui`
+/// for inventory slots
+#[derive(Clone, Copy, Debug)]
+pub struct ModelDisplay {
+    /// Rotations around the x, y then z axes in degrees
+    pub rotation: [f64; 3],
+    /// The offset in 1/16ths of a block, applied after rotating
+    pub translation: [f64; 3],
+    pub scale: [f64; 3],
+}
+
+impl Default for ModelDisplay {
+    fn default() -> ModelDisplay {
+        ModelDisplay {
+            rotation: [0.0, 0.0, 0.0],
+            translation: [0.0, 0.0, 0.0],
+            scale: [1.0, 1.0, 1.0],
+        }
+    }
+}
+
+impl ModelDisplay {
+    fn parse(v: &serde_json::Value) -> ModelDisplay {
+        let vector = |name, default: [f64; 3]| {
+            v.get(name).and_then(|v| v.as_array()).map_or(default, |v| {
+                let mut val = default;
+                for (i, v) in v.iter().take(3).enumerate() {
+                    val[i] = v.as_f64().unwrap_or(default[i]);
+                }
+                val
+            })
+        };
+        let default = ModelDisplay::default();
+        ModelDisplay {
+            rotation: vector("rotation", default.rotation),
+            translation: vector("translation", default.translation),
+            scale: vector("scale", default.scale),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -936,6 +1059,7 @@ struct Model {
     faces: Vec<Face>,
     ambient_occlusion: bool,
     weight: f64,
+    particle: Option<render::Texture>,
 }
 
 #[derive(Clone, Debug)]
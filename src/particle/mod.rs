@@ -0,0 +1,402 @@
+//! Client side particles, spawned by the server or by events in the
+//! world such as blocks breaking.
+
+mod kind;
+pub mod simulation;
+
+pub use self::kind::{name_by_id, Kind};
+use self::simulation::{Particle, Sprite, System};
+use crate::console;
+use crate::model;
+use crate::render;
+use crate::render::model as render_model;
+use crate::settings;
+use crate::shared::Position;
+use crate::world;
+use crate::world::block;
+use cgmath::{InnerSpace, Vector3};
+use rand::Rng;
+use std::sync::{Arc, RwLock};
+
+/// Limits how far the simulation catches up after a long frame
+const MAX_STEPS_PER_FRAME: usize = 10;
+
+/// A request to spawn particles
+#[derive(Clone, Debug)]
+pub enum Event {
+    /// Particles sent by the server. `offset` is how far the particles
+    /// are spread from `position`, or their direction of travel when
+    /// `count` is zero.
+    Spawn {
+        kind: Kind,
+        position: Vector3<f64>,
+        offset: Vector3<f64>,
+        speed: f64,
+        count: i32,
+    },
+    /// Covers the block's position with pieces of it
+    BlockBreak {
+        position: Position,
+        block: block::Block,
+    },
+}
+
+pub struct Manager {
+    system: System,
+    /// Time since the last simulation step, in ticks
+    partial_tick: f64,
+    model: Option<render_model::ModelKey>,
+}
+
+impl Default for Manager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Manager {
+    pub fn new() -> Manager {
+        Manager {
+            system: System::new((settings::R_MAX_PARTICLES.default)() as usize),
+            partial_tick: 0.0,
+            model: None,
+        }
+    }
+
+    /// The number of live particles
+    pub fn len(&self) -> usize {
+        self.system.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.system.is_empty()
+    }
+
+    pub fn clear(&mut self, renderer: &mut render::Renderer) {
+        self.system.clear();
+        if let Some(model) = self.model.take() {
+            renderer.model.remove_model(model);
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        renderer: &mut render::Renderer,
+        world: &world::World,
+        models: &Arc<RwLock<model::Factory>>,
+        vars: &console::Vars,
+        events: Vec<Event>,
+        delta: f64,
+    ) {
+        self.system
+            .set_limit((*vars.get(settings::R_MAX_PARTICLES)).max(0) as usize);
+
+        let mut rng = rand::thread_rng();
+        for event in events {
+            self.handle_event(&mut rng, models, event);
+        }
+
+        // The game's delta is in 60ths of a second, the simulation
+        // runs at 20 ticks a second.
+        self.partial_tick += delta / 3.0;
+        let mut steps = 0;
+        while self.partial_tick >= 1.0 {
+            self.partial_tick -= 1.0;
+            if steps < MAX_STEPS_PER_FRAME {
+                self.system.step(&|point| is_solid(world, point));
+                steps += 1;
+            }
+        }
+
+        self.update_model(renderer, world);
+    }
+
+    fn handle_event<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        models: &Arc<RwLock<model::Factory>>,
+        event: Event,
+    ) {
+        match event {
+            Event::Spawn {
+                kind,
+                position,
+                offset,
+                speed,
+                count,
+            } => {
+                let block_texture = match kind {
+                    Kind::Block { block, .. } => {
+                        model::Factory::get_particle_texture(models, block)
+                    }
+                    _ => None,
+                };
+                if let Kind::ExplosionEmitter = kind {
+                    self.spawn_explosions(rng, position);
+                } else if count == 0 {
+                    // A single particle travelling along the offset
+                    let kind = offset_data(kind, offset);
+                    let particle =
+                        kind.create(rng, position, offset * speed, block_texture.as_ref());
+                    self.system.spawn(particle);
+                } else {
+                    for _ in 0..count.min(self.system.limit() as i32) {
+                        let spread = Vector3::new(
+                            gaussian(rng) * offset.x,
+                            gaussian(rng) * offset.y,
+                            gaussian(rng) * offset.z,
+                        );
+                        let velocity =
+                            Vector3::new(gaussian(rng), gaussian(rng), gaussian(rng)) * speed;
+                        let particle =
+                            kind.create(rng, position + spread, velocity, block_texture.as_ref());
+                        self.system.spawn(particle);
+                    }
+                }
+            }
+            Event::BlockBreak { position, block } => {
+                if block.get_material().renderable {
+                    self.spawn_block_break(rng, models, position, block);
+                }
+            }
+        }
+    }
+
+    /// Spreads explosions around the position over a few ticks
+    fn spawn_explosions<R: Rng>(&mut self, rng: &mut R, position: Vector3<f64>) {
+        const PER_TICK: i32 = 6;
+        const TICKS: i32 = 8;
+        for i in 0..PER_TICK * TICKS {
+            let offset = Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            ) * 4.0;
+            let mut particle =
+                Kind::Explosion.create(rng, position + offset, Vector3::new(0.0, 0.0, 0.0), None);
+            particle.age = -(i / PER_TICK);
+            self.system.spawn(particle);
+        }
+    }
+
+    /// Like vanilla, splits the block into a 4x4x4 grid of pieces
+    /// flying away from its centre.
+    fn spawn_block_break<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        models: &Arc<RwLock<model::Factory>>,
+        position: Position,
+        block: block::Block,
+    ) {
+        const PIECES: i32 = 4;
+        let texture = model::Factory::get_particle_texture(models, block);
+        let kind = Kind::Block {
+            block,
+            falling: false,
+        };
+        for x in 0..PIECES {
+            for y in 0..PIECES {
+                for z in 0..PIECES {
+                    let offset = Vector3::new(
+                        (f64::from(x) + 0.5) / f64::from(PIECES),
+                        (f64::from(y) + 0.5) / f64::from(PIECES),
+                        (f64::from(z) + 0.5) / f64::from(PIECES),
+                    );
+                    let origin = Vector3::new(
+                        f64::from(position.x),
+                        f64::from(position.y),
+                        f64::from(position.z),
+                    );
+                    let velocity = (offset - Vector3::new(0.5, 0.5, 0.5)) * 0.2;
+                    let particle = kind.create(rng, origin + offset, velocity, texture.as_ref());
+                    self.system.spawn(particle);
+                }
+            }
+        }
+    }
+
+    /// Rebuilds the model with a quad facing the camera for every visible
+    /// particle.
+    fn update_model(&mut self, renderer: &mut render::Renderer, world: &world::World) {
+        let view = renderer.view_vector.cast::<f64>().unwrap();
+        if self.system.is_empty() || view.magnitude2() < 0.0001 {
+            if let Some(model) = self.model.take() {
+                renderer.model.remove_model(model);
+            }
+            return;
+        }
+        let view = view.normalize();
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let left = if view.cross(up).magnitude2() < 0.0001 {
+            // Looking straight up or down
+            Vector3::new(1.0, 0.0, 0.0)
+        } else {
+            up.cross(view).normalize()
+        };
+        let up = view.cross(left);
+
+        let atlas =
+            render::Renderer::get_texture(renderer.get_textures_ref(), "particle/particles");
+        let mut verts = Vec::with_capacity(self.system.len() * 4);
+        for particle in self.system.iter() {
+            if !particle.is_visible() {
+                continue;
+            }
+            let texture = sprite_texture(renderer, &atlas, particle);
+            let size = f64::from(particle.current_size());
+            let (l, u) = (left * size, up * size);
+            let colour = particle.colour;
+            let (r, g, b, a) = (
+                (colour[0].clamp(0.0, 1.0) * 255.0) as u8,
+                (colour[1].clamp(0.0, 1.0) * 255.0) as u8,
+                (colour[2].clamp(0.0, 1.0) * 255.0) as u8,
+                (colour[3].clamp(0.0, 1.0) * 255.0) as u8,
+            );
+            // Same winding and texture layout as a block's north face
+            for &(corner, tx, ty) in &[
+                (-l - u, 1.0, 1.0),
+                (l - u, 0.0, 1.0),
+                (-l + u, 1.0, 0.0),
+                (l + u, 0.0, 0.0),
+            ] {
+                let pos = particle.position + corner;
+                verts.push(render_model::Vertex {
+                    x: pos.x as f32,
+                    y: pos.y as f32,
+                    z: pos.z as f32,
+                    texture: texture.clone(),
+                    texture_x: tx,
+                    texture_y: ty,
+                    r,
+                    g,
+                    b,
+                    a,
+                    id: 0,
+                });
+            }
+        }
+
+        let key = match self.model {
+            Some(key) => {
+                renderer.model.update_model(key, verts);
+                key
+            }
+            None => {
+                let key = renderer
+                    .model
+                    .create_model(render_model::DEFAULT, vec![verts]);
+                self.model = Some(key);
+                key
+            }
+        };
+        // Particles are lit like the area around the camera
+        let camera = renderer.camera.pos;
+        let camera = Position::new(
+            camera.x.floor() as i32,
+            camera.y.floor() as i32,
+            camera.z.floor() as i32,
+        );
+        let (block_light, sky_light) = (
+            f32::from(world.get_block_light(camera)),
+            f32::from(world.get_sky_light(camera)),
+        );
+        if let Some(model) = renderer.model.get_model(key) {
+            model.block_light = block_light;
+            model.sky_light = sky_light;
+        }
+    }
+}
+
+/// Moves data the server sends in the offset of single particles into
+/// the kind.
+fn offset_data(kind: Kind, offset: Vector3<f64>) -> Kind {
+    match kind {
+        Kind::EntitySpell { .. } => Kind::EntitySpell {
+            colour: Some((offset.x as f32, offset.y as f32, offset.z as f32)),
+        },
+        Kind::Note { .. } => Kind::Note {
+            note: offset.x as f32,
+        },
+        // Before 1.13 dust was coloured by the offset, with zero red
+        // meaning the default red.
+        Kind::Dust { scale, .. } => Kind::Dust {
+            red: if offset.x == 0.0 {
+                1.0
+            } else {
+                offset.x as f32
+            },
+            green: offset.y as f32,
+            blue: offset.z as f32,
+            scale,
+        },
+        kind => kind,
+    }
+}
+
+fn sprite_texture(
+    renderer: &render::Renderer,
+    atlas: &render::Texture,
+    particle: &Particle,
+) -> render::Texture {
+    // The particle atlas is a 16x16 grid
+    let cell = |index: u16| {
+        atlas.relative(
+            f32::from(index % 16) / 16.0,
+            f32::from(index / 16) / 16.0,
+            1.0 / 16.0,
+            1.0 / 16.0,
+        )
+    };
+    let frame =
+        |count: u16| ((particle.progress() * f32::from(count)) as u16).min(count.saturating_sub(1));
+    match particle.sprite {
+        Sprite::Cell(index) => cell(index),
+        Sprite::Animated {
+            first,
+            count,
+            reverse,
+        } => {
+            let frame = frame(count);
+            cell(first + if reverse { count - 1 - frame } else { frame })
+        }
+        Sprite::Sheet {
+            texture,
+            columns,
+            rows,
+        } => {
+            let frame = frame(columns * rows);
+            render::Renderer::get_texture(renderer.get_textures_ref(), texture).relative(
+                f32::from(frame % columns) / f32::from(columns),
+                f32::from(frame / columns) / f32::from(rows),
+                1.0 / f32::from(columns),
+                1.0 / f32::from(rows),
+            )
+        }
+        Sprite::Texture(ref texture) => renderer.check_texture(texture.clone()),
+    }
+}
+
+fn is_solid(world: &world::World, point: Vector3<f64>) -> bool {
+    let pos = Position::new(
+        point.x.floor() as i32,
+        point.y.floor() as i32,
+        point.z.floor() as i32,
+    );
+    let block = world.get_block(pos);
+    if !block.get_material().collidable {
+        return false;
+    }
+    let local = point - Vector3::new(f64::from(pos.x), f64::from(pos.y), f64::from(pos.z));
+    block.get_collision_boxes().iter().any(|bound| {
+        (bound.min.x..=bound.max.x).contains(&local.x)
+            && (bound.min.y..=bound.max.y).contains(&local.y)
+            && (bound.min.z..=bound.max.z).contains(&local.z)
+    })
+}
+
+/// A normally distributed random number
+fn gaussian<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::PI * 2.0 * u2).cos()
+}
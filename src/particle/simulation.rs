@@ -0,0 +1,330 @@
+use crate::render;
+use cgmath::Vector3;
+use std::collections::VecDeque;
+
+/// What a particle is drawn with
+#[derive(Clone)]
+pub enum Sprite {
+    /// A cell of the 16x16 grid in `particle/particles`
+    Cell(u16),
+    /// Steps through `count` cells starting at `first` over the
+    /// particle's lifetime, backwards if `reverse` is set.
+    Animated {
+        first: u16,
+        count: u16,
+        reverse: bool,
+    },
+    /// Steps through the frames of a texture split into a grid over the
+    /// particle's lifetime.
+    Sheet {
+        texture: &'static str,
+        columns: u16,
+        rows: u16,
+    },
+    /// Part of another texture, e.g. a block's particle texture
+    Texture(render::Texture),
+}
+
+/// A single CPU simulated particle.
+///
+/// Motion is simulated in game ticks (20 per second) to match the
+/// values vanilla uses.
+#[derive(Clone)]
+pub struct Particle {
+    pub position: Vector3<f64>,
+    /// In blocks per tick
+    pub velocity: Vector3<f64>,
+    /// In ticks, the particle isn't shown or moved while this is
+    /// negative.
+    pub age: i32,
+    pub lifetime: i32,
+    /// Acceleration downwards in blocks per tick squared, negative
+    /// values make the particle rise.
+    pub gravity: f64,
+    /// Multiplier applied to the velocity every tick
+    pub drag: f64,
+    /// Whether the particle stops at solid blocks
+    pub collides: bool,
+    pub on_ground: bool,
+    /// Half the width of the quad in blocks
+    pub size: f32,
+    /// Whether the quad shrinks to nothing over the lifetime
+    pub shrinks: bool,
+    pub colour: [f32; 4],
+    pub sprite: Sprite,
+}
+
+impl Particle {
+    pub fn new(position: Vector3<f64>, velocity: Vector3<f64>, sprite: Sprite) -> Particle {
+        Particle {
+            position,
+            velocity,
+            age: 0,
+            lifetime: 20,
+            gravity: 0.0,
+            drag: 0.98,
+            collides: true,
+            on_ground: false,
+            size: 0.1,
+            shrinks: false,
+            colour: [1.0, 1.0, 1.0, 1.0],
+            sprite,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.age < self.lifetime
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.age >= 0 && self.is_alive()
+    }
+
+    /// How far through its lifetime the particle is, from 0 to 1
+    pub fn progress(&self) -> f32 {
+        if self.lifetime <= 0 {
+            return 1.0;
+        }
+        (self.age.max(0) as f32 / self.lifetime as f32).clamp(0.0, 1.0)
+    }
+
+    /// The size the particle is currently drawn at
+    pub fn current_size(&self) -> f32 {
+        if self.shrinks {
+            self.size * (1.0 - self.progress())
+        } else {
+            self.size
+        }
+    }
+
+    /// Advances the particle by a single tick. `is_solid` reports
+    /// whether a point is inside a block the particle should collide
+    /// with.
+    pub fn step(&mut self, is_solid: &dyn Fn(Vector3<f64>) -> bool) {
+        self.age += 1;
+        if self.age <= 0 || !self.is_alive() {
+            return;
+        }
+        self.velocity.y -= self.gravity;
+
+        if self.collides {
+            // Each axis is moved separately so particles slide along
+            // the blocks they hit.
+            self.on_ground = false;
+            for axis in 0..3 {
+                let mut next = self.position;
+                next[axis] += self.velocity[axis];
+                if is_solid(next) {
+                    if axis == 1 && self.velocity.y < 0.0 {
+                        self.on_ground = true;
+                    }
+                    self.velocity[axis] = 0.0;
+                } else {
+                    self.position = next;
+                }
+            }
+        } else {
+            self.position += self.velocity;
+        }
+
+        self.velocity *= self.drag;
+        if self.on_ground {
+            self.velocity.x *= 0.7;
+            self.velocity.z *= 0.7;
+        }
+    }
+}
+
+/// Holds every live particle, dropping the oldest ones once there are
+/// more than the limit.
+pub struct System {
+    particles: VecDeque<Particle>,
+    limit: usize,
+}
+
+impl System {
+    pub fn new(limit: usize) -> System {
+        System {
+            particles: VecDeque::new(),
+            limit,
+        }
+    }
+
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        self.enforce_limit();
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn spawn(&mut self, particle: Particle) {
+        self.particles.push_back(particle);
+        self.enforce_limit();
+    }
+
+    fn enforce_limit(&mut self) {
+        while self.particles.len() > self.limit {
+            self.particles.pop_front();
+        }
+    }
+
+    /// Advances every particle by a tick, removing the ones that
+    /// expired.
+    pub fn step(&mut self, is_solid: &dyn Fn(Vector3<f64>) -> bool) {
+        for particle in &mut self.particles {
+            particle.step(is_solid);
+        }
+        self.particles.retain(Particle::is_alive);
+    }
+
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Particle> {
+        self.particles.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn particle(velocity: Vector3<f64>) -> Particle {
+        Particle::new(Vector3::new(0.5, 10.5, 0.5), velocity, Sprite::Cell(0))
+    }
+
+    fn air(_: Vector3<f64>) -> bool {
+        false
+    }
+
+    fn below_ten(pos: Vector3<f64>) -> bool {
+        pos.y < 10.0
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn gravity_and_drag() {
+        let mut p = particle(Vector3::new(0.5, 0.0, 0.0));
+        p.gravity = 0.04;
+        p.drag = 0.5;
+        p.step(&air);
+        assert_close(p.position.x, 1.0);
+        assert_close(p.position.y, 10.46);
+        assert_close(p.velocity.x, 0.25);
+        assert_close(p.velocity.y, -0.02);
+
+        p.step(&air);
+        assert_close(p.position.x, 1.25);
+        assert_close(p.position.y, 10.46 - 0.06);
+        assert_close(p.velocity.y, -0.03);
+    }
+
+    #[test]
+    fn collision_sets_on_ground() {
+        let mut p = particle(Vector3::new(0.2, -1.0, 0.0));
+        p.drag = 1.0;
+        p.step(&below_ten);
+        assert!(p.on_ground);
+        assert_close(p.position.y, 10.5);
+        assert_close(p.velocity.y, 0.0);
+        // Still slides along the ground, slowed by friction
+        assert_close(p.position.x, 0.7);
+        assert_close(p.velocity.x, 0.2 * 0.7);
+
+        // Moving upwards never counts as landing
+        let mut p = particle(Vector3::new(0.0, 1.0, 0.0));
+        p.step(&|pos: Vector3<f64>| pos.y > 11.0);
+        assert!(!p.on_ground);
+        assert_close(p.position.y, 10.5);
+
+        // Particles that don't collide pass straight through
+        let mut p = particle(Vector3::new(0.0, -1.0, 0.0));
+        p.collides = false;
+        p.step(&below_ten);
+        assert!(!p.on_ground);
+        assert_close(p.position.y, 9.5);
+    }
+
+    #[test]
+    fn expires_at_lifetime() {
+        let mut p = particle(Vector3::new(0.0, 0.0, 0.0));
+        p.lifetime = 3;
+        for _ in 0..2 {
+            p.step(&air);
+            assert!(p.is_alive());
+        }
+        p.step(&air);
+        assert!(!p.is_alive());
+        assert!(!p.is_visible());
+        assert_eq!(p.progress(), 1.0);
+    }
+
+    #[test]
+    fn delayed_particle() {
+        let mut p = particle(Vector3::new(1.0, 0.0, 0.0));
+        p.age = -2;
+        p.gravity = 0.1;
+        assert!(p.is_alive());
+        assert!(!p.is_visible());
+        assert_eq!(p.progress(), 0.0);
+
+        p.step(&air);
+        p.step(&air);
+        // Neither moved nor accelerated while waiting
+        assert_eq!(p.age, 0);
+        assert!(p.is_visible());
+        assert_close(p.position.x, 0.5);
+        assert_close(p.velocity.x, 1.0);
+        assert_close(p.velocity.y, 0.0);
+
+        p.step(&air);
+        assert_close(p.position.x, 1.5);
+    }
+
+    #[test]
+    fn system_drops_oldest() {
+        let mut system = System::new(3);
+        for i in 0..5 {
+            let mut p = particle(Vector3::new(0.0, 0.0, 0.0));
+            p.position.x = i as f64;
+            system.spawn(p);
+        }
+        assert_eq!(system.len(), 3);
+        let xs: Vec<f64> = system.iter().map(|p| p.position.x).collect();
+        assert_eq!(xs, vec![2.0, 3.0, 4.0]);
+
+        system.set_limit(1);
+        assert_eq!(system.len(), 1);
+        assert_eq!(system.iter().next().unwrap().position.x, 4.0);
+    }
+
+    #[test]
+    fn system_removes_expired() {
+        let mut system = System::new(10);
+        for lifetime in 1..=3 {
+            let mut p = particle(Vector3::new(0.0, 0.0, 0.0));
+            p.lifetime = lifetime;
+            system.spawn(p);
+        }
+        system.step(&air);
+        assert_eq!(system.len(), 2);
+        system.step(&air);
+        assert_eq!(system.len(), 1);
+        system.step(&air);
+        assert!(system.is_empty());
+    }
+}
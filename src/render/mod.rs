@@ -65,6 +65,9 @@ pub struct Renderer {
     element_buffer_type: gl::Type,
 
     pub camera: Camera,
+    /// Vertical field of view in degrees
+    fov: f32,
+    last_fov: f32,
     perspective_matrix: cgmath::Matrix4<f32>,
     camera_matrix: cgmath::Matrix4<f32>,
     pub frustum: collision::Frustum<f32>,
@@ -225,6 +228,8 @@ impl Renderer {
                 yaw: 0.0,
                 pitch: ::std::f64::consts::PI,
             },
+            fov: 90.0,
+            last_fov: 90.0,
             perspective_matrix: cgmath::Matrix4::identity(),
             camera_matrix: cgmath::Matrix4::identity(),
             frustum: collision::Frustum::from_matrix4(cgmath::Matrix4::identity()).unwrap(),
@@ -241,6 +246,10 @@ impl Renderer {
         }
     }
 
+    pub fn set_fov(&mut self, fov: f32) {
+        self.fov = fov;
+    }
+
     pub fn update_camera(&mut self, width: u32, height: u32) {
         use std::f64::consts::PI as PI64;
         // Not a sane place to put this but it works
@@ -259,12 +268,13 @@ impl Renderer {
             }
         }
 
-        if self.height != height || self.width != width {
+        if self.height != height || self.width != width || self.fov != self.last_fov {
             self.width = width;
             self.height = height;
+            self.last_fov = self.fov;
             gl::viewport(0, 0, width as i32, height as i32);
 
-            let fovy = cgmath::Rad::from(cgmath::Deg(90.0_f32));
+            let fovy = cgmath::Rad::from(cgmath::Deg(self.fov));
             let aspect = (width as f32 / height as f32).max(1.0);
 
             self.perspective_matrix = cgmath::Matrix4::from(cgmath::PerspectiveFov {
@@ -76,14 +76,8 @@ unsafe impl Sync for Manager {}
 
 impl Manager {
     pub fn new() -> (Manager, ManagerUI) {
-        let mut m = Manager {
-            packs: Vec::new(),
-            version: 0,
-            vanilla_chan: None,
-            vanilla_assets_chan: None,
-            vanilla_progress: Arc::new(Mutex::new(Progress { tasks: vec![] })),
-        };
-        m.add_pack(Box::new(InternalPack));
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut m = Manager::internal();
         #[cfg(not(target_arch = "wasm32"))]
         {
             m.download_vanilla();
@@ -98,6 +92,20 @@ impl Manager {
         )
     }
 
+    /// A manager with only the resources built into the client, which
+    /// doesn't download the vanilla ones.
+    pub fn internal() -> Manager {
+        let mut m = Manager {
+            packs: Vec::new(),
+            version: 0,
+            vanilla_chan: None,
+            vanilla_assets_chan: None,
+            vanilla_progress: Arc::new(Mutex::new(Progress { tasks: vec![] })),
+        };
+        m.add_pack(Box::new(InternalPack));
+        m
+    }
+
     /// Returns the 'version' of the manager. The version is
     /// increase everytime a pack is added or removed.
     pub fn version(&self) -> usize {
@@ -125,6 +133,16 @@ impl Manager {
         ret
     }
 
+    /// Opens the `pack.mcmeta` of every pack that has one, highest
+    /// priority first.
+    pub fn open_all_meta(&self) -> Vec<Box<dyn io::Read>> {
+        self.packs
+            .iter()
+            .rev()
+            .filter_map(|pack| pack.open("pack.mcmeta"))
+            .collect()
+    }
+
     pub fn tick(&mut self, mui: &mut ManagerUI, ui_container: &mut ui::Container, delta: f64) {
         let delta = delta.min(5.0);
         // Check to see if the download of vanilla has completed
@@ -530,10 +548,12 @@ impl ObjectPack {
 
 impl Pack for ObjectPack {
     fn open(&self, name: &str) -> Option<Box<dyn io::Read>> {
-        if !name.starts_with("assets/") {
-            return None;
-        }
-        let name = &name["assets/".len()..];
+        // The index's pack.mcmeta lists the downloadable languages
+        let name = if name == "pack.mcmeta" {
+            name
+        } else {
+            name.strip_prefix("assets/")?
+        };
         if let Some(hash) = self.objects.get(name) {
             let root_location = path::Path::new("./objects/");
             let hash_path = format!("{}/{}", &hash[..2], hash);
@@ -0,0 +1,66 @@
+use crate::render;
+use crate::ui;
+
+pub struct Chat {
+    elements: Option<UIElements>,
+    initial_input: String,
+}
+
+struct UIElements {
+    input: ui::TextBoxRef,
+}
+
+impl Chat {
+    pub fn new(initial_input: &str) -> Chat {
+        Chat {
+            elements: None,
+            initial_input: initial_input.to_owned(),
+        }
+    }
+}
+
+impl super::Screen for Chat {
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let input = ui::TextBoxBuilder::new()
+            .input(self.initial_input.clone())
+            .position(2.0, 2.0)
+            .size(850.0, 30.0)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Left)
+            .create(ui_container);
+        ui::TextBox::make_focusable(&input, ui_container);
+        input.borrow_mut().add_submit_func(|txt, game| {
+            let message = txt.input.trim().to_owned();
+            if !message.is_empty() && game.server.is_connected() {
+                game.server.send_chat_message(&message);
+            }
+            txt.input.clear();
+            game.screen_sys.pop_screen();
+            game.focused = true;
+        });
+
+        self.elements = Some(UIElements { input });
+    }
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        // Keep what was typed if the chat is only hidden
+        if let Some(elements) = self.elements.take() {
+            self.initial_input = elements.input.borrow().input.clone();
+        }
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        _renderer: &mut render::Renderer,
+        _ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        None
+    }
+
+    fn is_closable(&self) -> bool {
+        true
+    }
+
+    fn shows_chat(&self) -> bool {
+        true
+    }
+}
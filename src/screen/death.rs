@@ -0,0 +1,147 @@
+use crate::format;
+use crate::render;
+use crate::ui;
+
+/// Shown while the player is dead, until they choose to respawn
+pub struct Death {
+    elements: Option<UIElements>,
+    message: Option<format::Component>,
+    score: i32,
+}
+
+struct UIElements {
+    background: ui::ImageRef,
+    _title: ui::TextRef,
+    _message: Option<ui::FormattedRef>,
+    _score: ui::TextRef,
+    _buttons: Vec<ui::ButtonRef>,
+}
+
+impl Death {
+    pub fn new(message: Option<format::Component>, score: i32) -> Death {
+        Death {
+            elements: None,
+            message,
+            score,
+        }
+    }
+}
+
+impl super::Screen for Death {
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, 0.0)
+            .size(854.0, 480.0)
+            .colour((80, 0, 0, 120))
+            .create(ui_container);
+
+        let title = ui::TextBuilder::new()
+            .text("You died!")
+            .position(0.0, -120.0)
+            .scale_x(2.0)
+            .scale_y(2.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+
+        let message = self.message.as_ref().map(|message| {
+            ui::FormattedBuilder::new()
+                .text(message.clone())
+                .position(0.0, -70.0)
+                .max_width(600.0)
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(ui_container)
+        });
+
+        let score = ui::TextBuilder::new()
+            .text(format!("Score: {}", self.score))
+            .position(0.0, -40.0)
+            .colour((255, 255, 85, 255))
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+
+        let mut buttons = vec![];
+
+        let respawn = ui::ButtonBuilder::new()
+            .position(0.0, 10.0)
+            .size(400.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut respawn = respawn.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text("Respawn")
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *respawn);
+            respawn.add_text(txt);
+            respawn.add_click_func(|_, game| {
+                game.server.request_respawn();
+                game.screen_sys.pop_screen();
+                game.focused = true;
+                true
+            });
+        }
+        buttons.push(respawn);
+
+        let title_screen = ui::ButtonBuilder::new()
+            .position(0.0, 60.0)
+            .size(400.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut title_screen = title_screen.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text("Title screen")
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *title_screen);
+            title_screen.add_text(txt);
+            title_screen.add_click_func(|_, game| {
+                game.server.disconnect(None);
+                game.screen_sys
+                    .replace_screen(Box::new(super::ServerList::new(None)));
+                true
+            });
+        }
+        buttons.push(title_screen);
+
+        self.elements = Some(UIElements {
+            background,
+            _title: title,
+            _message: message,
+            _score: score,
+            _buttons: buttons,
+        });
+    }
+
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.elements = None;
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        let elements = self.elements.as_mut().unwrap();
+        let mode = ui_container.mode;
+        let mut background = elements.background.borrow_mut();
+        background.width = match mode {
+            ui::Mode::Unscaled(scale) => 854.0 / scale,
+            ui::Mode::Scaled => renderer.width as f64,
+        };
+        background.height = match mode {
+            ui::Mode::Unscaled(scale) => 480.0 / scale,
+            ui::Mode::Scaled => renderer.height as f64,
+        };
+        None
+    }
+
+    fn shows_chat(&self) -> bool {
+        true
+    }
+
+    fn is_death_screen(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,470 @@
+use crate::inventory::{self, Button, WindowKind};
+use crate::item;
+use crate::render;
+use crate::settings;
+use crate::ui;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+use winit::event::MouseButton;
+
+/// Vanilla's gui textures are drawn at twice their size
+const SCALE: f64 = 2.0;
+const SLOT_SIZE: f64 = 16.0;
+const TOOLTIP_WIDTH: f64 = 300.0;
+
+pub struct Inventory {
+    inventory: Arc<RwLock<inventory::Inventory>>,
+    models: ui::item::ItemModels,
+    elements: Option<UIElements>,
+
+    hovered_slot: Rc<Cell<Option<i16>>>,
+    over_window: Rc<Cell<bool>>,
+}
+
+struct UIElements {
+    window_id: u8,
+    kind: WindowKind,
+    slot_count: usize,
+    background: Vec<ui::ImageRef>,
+    _title: Option<ui::TextRef>,
+    slots: Vec<SlotElements>,
+    cursor: SlotElements,
+    furnace_progress: Option<(ui::ImageRef, ui::ImageRef)>,
+    tooltip: Option<SlotTooltip>,
+}
+
+struct SlotTooltip {
+    /// The slot and stack the tooltip is for
+    key: (i16, item::Stack),
+    _background: ui::ImageRef,
+    _text: ui::FormattedRef,
+}
+
+struct SlotElements {
+    highlight: ui::ImageRef,
+    icon: ui::item::Icon,
+}
+
+type Rect = (f64, f64, f64, f64);
+
+/// Where the parts of a window are drawn, in vanilla gui pixels
+struct Layout {
+    texture: &'static str,
+    width: f64,
+    height: f64,
+    /// Source and destination rectangles of the background
+    parts: Vec<(Rect, (f64, f64))>,
+    slots: Vec<(f64, f64)>,
+}
+
+impl Layout {
+    fn new(kind: WindowKind, size: usize) -> Layout {
+        let player_slots = |slots: &mut Vec<(f64, f64)>, y: f64| {
+            for i in 0..27 {
+                slots.push((8.0 + (i % 9) as f64 * 18.0, y + (i / 9) as f64 * 18.0));
+            }
+            for i in 0..9 {
+                slots.push((8.0 + i as f64 * 18.0, y + 58.0));
+            }
+        };
+        let mut slots = vec![];
+        match kind {
+            WindowKind::Player => {
+                slots.push((154.0, 28.0));
+                for i in 0..4 {
+                    slots.push((98.0 + (i % 2) as f64 * 18.0, 18.0 + (i / 2) as f64 * 18.0));
+                }
+                for i in 0..4 {
+                    slots.push((8.0, 8.0 + i as f64 * 18.0));
+                }
+                player_slots(&mut slots, 84.0);
+                slots.push((77.0, 62.0));
+                Layout {
+                    texture: "gui/container/inventory",
+                    width: 176.0,
+                    height: 166.0,
+                    parts: vec![((0.0, 0.0, 176.0, 166.0), (0.0, 0.0))],
+                    slots,
+                }
+            }
+            WindowKind::Furnace => {
+                slots.push((56.0, 17.0));
+                slots.push((56.0, 53.0));
+                slots.push((116.0, 35.0));
+                player_slots(&mut slots, 84.0);
+                Layout {
+                    texture: "gui/container/furnace",
+                    width: 176.0,
+                    height: 166.0,
+                    parts: vec![((0.0, 0.0, 176.0, 166.0), (0.0, 0.0))],
+                    slots,
+                }
+            }
+            WindowKind::CraftingTable => {
+                slots.push((124.0, 35.0));
+                for i in 0..9 {
+                    slots.push((30.0 + (i % 3) as f64 * 18.0, 17.0 + (i / 3) as f64 * 18.0));
+                }
+                player_slots(&mut slots, 84.0);
+                Layout {
+                    texture: "gui/container/crafting_table",
+                    width: 176.0,
+                    height: 166.0,
+                    parts: vec![((0.0, 0.0, 176.0, 166.0), (0.0, 0.0))],
+                    slots,
+                }
+            }
+            WindowKind::Chest { .. } | WindowKind::Generic { .. } => {
+                // Unknown windows are shown as a chest big enough for their slots
+                let rows = ((size + 8) / 9).clamp(1, 6);
+                for i in 0..size {
+                    slots.push((8.0 + (i % 9) as f64 * 18.0, 18.0 + (i / 9) as f64 * 18.0));
+                }
+                let top = 17.0 + rows as f64 * 18.0;
+                player_slots(&mut slots, top + 14.0);
+                Layout {
+                    texture: "gui/container/generic_54",
+                    width: 176.0,
+                    height: top + 96.0,
+                    parts: vec![
+                        ((0.0, 0.0, 176.0, top), (0.0, 0.0)),
+                        ((0.0, 126.0, 176.0, 96.0), (0.0, top)),
+                    ],
+                    slots,
+                }
+            }
+        }
+    }
+}
+
+impl Inventory {
+    pub fn new(
+        inventory: Arc<RwLock<inventory::Inventory>>,
+        models: ui::item::ItemModels,
+    ) -> Inventory {
+        Inventory {
+            inventory,
+            models,
+            elements: None,
+
+            hovered_slot: Rc::new(Cell::new(None)),
+            over_window: Rc::new(Cell::new(false)),
+        }
+    }
+
+    fn create_slot(parent: &mut ui::Image, x: f64, y: f64, draw_index: isize) -> SlotElements {
+        let highlight = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(x * SCALE, y * SCALE)
+            .size(SLOT_SIZE * SCALE, SLOT_SIZE * SCALE)
+            .colour((255, 255, 255, 0))
+            .draw_index(1)
+            .attach(parent);
+        let icon = ui::item::Icon::new(parent, x * SCALE, y * SCALE, SLOT_SIZE * SCALE, draw_index);
+        SlotElements { highlight, icon }
+    }
+
+    fn build(&mut self, inventory: &inventory::Inventory, ui_container: &mut ui::Container) {
+        let window = inventory.current();
+        let layout = Layout::new(window.kind, window.size());
+
+        let mut background = vec![];
+        for &((sx, sy, sw, sh), (dx, dy)) in &layout.parts {
+            background.push(
+                ui::ImageBuilder::new()
+                    .texture(layout.texture)
+                    .texture_coords((sx / 256.0, sy / 256.0, sw / 256.0, sh / 256.0))
+                    .position(
+                        dx * SCALE - layout.width * SCALE / 2.0,
+                        dy * SCALE - layout.height * SCALE / 2.0,
+                    )
+                    .size(sw * SCALE, sh * SCALE)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .create(ui_container),
+            );
+        }
+        // Slots are positioned relative to the top part of the window
+        let root = background[0].clone();
+        for part in &background {
+            let over_window = self.over_window.clone();
+            part.borrow_mut().add_hover_func(move |_, over, _| {
+                over_window.set(over);
+                false
+            });
+        }
+
+        let title = window.title.as_ref().map(|title| {
+            ui::TextBuilder::new()
+                .text(title.to_string())
+                .position(8.0 * SCALE, 6.0 * SCALE)
+                .colour((64, 64, 64, 255))
+                .attach(&mut *root.borrow_mut())
+        });
+
+        let mut slots = vec![];
+        for (index, &(x, y)) in layout.slots.iter().enumerate() {
+            let slot = Self::create_slot(&mut root.borrow_mut(), x, y, 0);
+            let hovered_slot = self.hovered_slot.clone();
+            let inventory = self.inventory.clone();
+            let index = index as i16;
+            slot.highlight
+                .borrow_mut()
+                .add_hover_func(move |_, over, _| {
+                    if over {
+                        hovered_slot.set(Some(index));
+                        inventory.write().unwrap().mouse_over(index);
+                    } else if hovered_slot.get() == Some(index) {
+                        hovered_slot.set(None);
+                    }
+                    false
+                });
+            slots.push(slot);
+        }
+        let cursor = Self::create_slot(&mut root.borrow_mut(), 0.0, 0.0, 2);
+
+        let furnace_progress = if window.kind == WindowKind::Furnace {
+            let flame = ui::ImageBuilder::new()
+                .texture(layout.texture)
+                .position(56.0 * SCALE, 36.0 * SCALE)
+                .size(14.0 * SCALE, 0.0)
+                .draw_index(1)
+                .attach(&mut *root.borrow_mut());
+            let arrow = ui::ImageBuilder::new()
+                .texture(layout.texture)
+                .position(79.0 * SCALE, 34.0 * SCALE)
+                .size(0.0, 16.0 * SCALE)
+                .draw_index(1)
+                .attach(&mut *root.borrow_mut());
+            Some((flame, arrow))
+        } else {
+            None
+        };
+
+        self.elements = Some(UIElements {
+            window_id: window.id,
+            kind: window.kind,
+            slot_count: layout.slots.len(),
+            background,
+            _title: title,
+            slots,
+            cursor,
+            furnace_progress,
+            tooltip: None,
+        });
+    }
+
+    fn update(&mut self, inventory: &inventory::Inventory) {
+        let elements = self.elements.as_mut().unwrap();
+        let hovered = self.hovered_slot.get();
+        let drag_slots = inventory.drag_slots();
+        for (index, slot) in elements.slots.iter_mut().enumerate() {
+            let index = index as i16;
+            slot.icon.set_stack(&self.models, inventory.slot(index));
+            slot.highlight.borrow_mut().colour.3 = if hovered == Some(index) {
+                128
+            } else if drag_slots.contains(&index) {
+                80
+            } else {
+                0
+            };
+        }
+
+        // There is no mouse cursor position, so the held item is drawn
+        // over the hovered slot instead.
+        elements
+            .cursor
+            .icon
+            .set_stack(&self.models, inventory.cursor());
+        if let Some(slot) = hovered.and_then(|slot| elements.slots.get(slot as usize)) {
+            let slot = slot.highlight.borrow();
+            elements
+                .cursor
+                .icon
+                .set_position(slot.x + 6.0, slot.y + 6.0);
+        }
+
+        if let Some((ref flame, ref arrow)) = elements.furnace_progress {
+            let properties = &inventory.current().properties;
+            let scaled = |value: i16, max: i16, size: f64| {
+                if max <= 0 {
+                    0.0
+                } else {
+                    (value as f64 / max as f64 * size).min(size).floor()
+                }
+            };
+            let burn = scaled(
+                *properties.get(&0).unwrap_or(&0),
+                *properties.get(&1).unwrap_or(&200),
+                13.0,
+            );
+            let cook = scaled(
+                *properties.get(&2).unwrap_or(&0),
+                *properties.get(&3).unwrap_or(&200),
+                24.0,
+            );
+            let mut flame = flame.borrow_mut();
+            flame.y = (36.0 + 12.0 - burn) * SCALE;
+            flame.height = if burn > 0.0 {
+                (burn + 1.0) * SCALE
+            } else {
+                0.0
+            };
+            flame.texture_coords = (
+                176.0 / 256.0,
+                (12.0 - burn) / 256.0,
+                14.0 / 256.0,
+                (burn + 1.0) / 256.0,
+            );
+            let mut arrow = arrow.borrow_mut();
+            arrow.width = if cook > 0.0 {
+                (cook + 1.0) * SCALE
+            } else {
+                0.0
+            };
+            arrow.texture_coords = (
+                176.0 / 256.0,
+                14.0 / 256.0,
+                (cook + 1.0) / 256.0,
+                16.0 / 256.0,
+            );
+        }
+    }
+
+    /// Describes the stack in the hovered slot next to it, unless an item
+    /// is being held
+    fn update_tooltip(
+        &mut self,
+        inventory: &inventory::Inventory,
+        renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) {
+        let elements = self.elements.as_mut().unwrap();
+        let hovered = self.hovered_slot.get().and_then(|index| {
+            let stack = inventory.slot(index)?;
+            let slot = elements.slots.get(index as usize)?;
+            Some((index, stack, slot))
+        });
+        let (index, stack, slot) = match hovered {
+            Some(hovered) if inventory.cursor().is_none() => hovered,
+            _ => {
+                elements.tooltip = None;
+                return;
+            }
+        };
+        if elements
+            .tooltip
+            .as_ref()
+            .map_or(false, |t| t.key.0 == index && &t.key.1 == stack)
+        {
+            return;
+        }
+
+        let text = self.models.tooltip(stack);
+        let (width, height) = ui::Formatted::compute_size(renderer, &text, TOOLTIP_WIDTH);
+        let (width, height) = (width + 8.0, height + 4.0);
+        // Placed to the right of the slot, in the same centered space as
+        // the window so it is drawn over every part of it
+        let (x, y) = {
+            let root = elements.background[0].borrow();
+            let slot = slot.highlight.borrow();
+            (
+                root.x - root.width / 2.0 + slot.x + (SLOT_SIZE + 4.0) * SCALE + width / 2.0,
+                root.y - root.height / 2.0 + slot.y + height / 2.0,
+            )
+        };
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(x, y)
+            .size(width, height)
+            .colour((16, 0, 16, 240))
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .draw_index(150)
+            .create(ui_container);
+        let text = ui::FormattedBuilder::new()
+            .text(text)
+            .position(4.0, 2.0)
+            .max_width(TOOLTIP_WIDTH)
+            .alignment(ui::VAttach::Top, ui::HAttach::Left)
+            .attach(&mut *background.borrow_mut());
+        elements.tooltip = Some(SlotTooltip {
+            key: (index, stack.clone()),
+            _background: background,
+            _text: text,
+        });
+    }
+}
+
+impl super::Screen for Inventory {
+    fn on_active(&mut self, renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let inventory = self.inventory.clone();
+        let inventory = inventory.read().unwrap();
+        self.build(&inventory, ui_container);
+        self.update(&inventory);
+        self.update_tooltip(&inventory, renderer, ui_container);
+    }
+
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.elements = None;
+        self.hovered_slot.set(None);
+        self.over_window.set(false);
+    }
+
+    fn deinit(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.inventory.write().unwrap().request_close();
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        let inventory = self.inventory.clone();
+        let inventory = inventory.read().unwrap();
+        let window = inventory.current();
+        let layout_changed = match self.elements {
+            Some(ref elements) => {
+                elements.window_id != window.id
+                    || elements.kind != window.kind
+                    || elements.slot_count != inventory.slot_count()
+            }
+            None => true,
+        };
+        if layout_changed {
+            self.hovered_slot.set(None);
+            self.build(&inventory, ui_container);
+        }
+        // Always update, the cursor follows the hovered slot
+        self.update(&inventory);
+        self.update_tooltip(&inventory, renderer, ui_container);
+        None
+    }
+
+    fn on_mouse_button(&mut self, button: MouseButton, down: bool, modifiers: settings::Modifiers) {
+        let button = match button {
+            MouseButton::Left => Button::Left,
+            MouseButton::Right => Button::Right,
+            _ => return,
+        };
+        let mut inventory = self.inventory.write().unwrap();
+        if down {
+            let slot = match self.hovered_slot.get() {
+                Some(slot) => Some(slot),
+                None if !self.over_window.get() => Some(inventory::OUTSIDE_SLOT),
+                None => None,
+            };
+            inventory.mouse_down(button, slot, modifiers.shift);
+        } else {
+            inventory.mouse_up(button);
+        }
+    }
+
+    fn is_closable(&self) -> bool {
+        true
+    }
+
+    fn is_inventory(&self) -> bool {
+        true
+    }
+}
@@ -17,15 +17,26 @@ pub use self::server_list::*;
 mod login;
 pub use self::login::*;
 
+mod chat;
+pub use self::chat::*;
+mod death;
+pub use self::death::*;
+mod inventory;
+pub use self::inventory::*;
+mod open_link;
+pub use self::open_link::*;
+
 pub mod connecting;
 pub mod delete_server;
 pub mod edit_server;
 
 pub mod settings_menu;
-pub use self::settings_menu::{AudioSettingsMenu, SettingsMenu, VideoSettingsMenu};
+pub use self::settings_menu::{AudioSettingsMenu, ControlsMenu, SettingsMenu, VideoSettingsMenu};
 
 use crate::render;
+use crate::settings;
 use crate::ui;
+use winit::event::{MouseButton, VirtualKeyCode};
 
 pub trait Screen {
     // Called once
@@ -46,10 +57,41 @@ pub trait Screen {
 
     // Events
     fn on_scroll(&mut self, _x: f64, _y: f64) {}
+    // Called for mouse presses and releases while the game isn't focused
+    fn on_mouse_button(
+        &mut self,
+        _button: MouseButton,
+        _down: bool,
+        _modifiers: settings::Modifiers,
+    ) {
+    }
+    // Called for key presses and releases while the game isn't focused,
+    // returns whether the screen used the key
+    fn on_key_press(
+        &mut self,
+        _key: VirtualKeyCode,
+        _down: bool,
+        _modifiers: settings::Modifiers,
+    ) -> bool {
+        false
+    }
 
     fn is_closable(&self) -> bool {
         false
     }
+
+    // Whether the full chat history should be shown behind this screen
+    fn shows_chat(&self) -> bool {
+        false
+    }
+
+    fn is_inventory(&self) -> bool {
+        false
+    }
+
+    fn is_death_screen(&self) -> bool {
+        false
+    }
 }
 
 struct ScreenInfo {
@@ -96,6 +138,30 @@ impl ScreenSystem {
         }
     }
 
+    pub fn is_current_showing_chat(&self) -> bool {
+        if let Some(last) = self.screens.last() {
+            last.screen.shows_chat()
+        } else {
+            false
+        }
+    }
+
+    pub fn is_current_inventory(&self) -> bool {
+        if let Some(last) = self.screens.last() {
+            last.screen.is_inventory()
+        } else {
+            false
+        }
+    }
+
+    pub fn is_current_death_screen(&self) -> bool {
+        if let Some(last) = self.screens.last() {
+            last.screen.is_death_screen()
+        } else {
+            false
+        }
+    }
+
     pub fn tick(
         &mut self,
         delta: f64,
@@ -147,4 +213,29 @@ impl ScreenSystem {
         let current = self.screens.last_mut().unwrap();
         current.screen.on_scroll(x, y);
     }
+
+    pub fn on_mouse_button(
+        &mut self,
+        button: MouseButton,
+        down: bool,
+        modifiers: settings::Modifiers,
+    ) {
+        if self.screens.is_empty() {
+            return;
+        }
+        let current = self.screens.last_mut().unwrap();
+        current.screen.on_mouse_button(button, down, modifiers);
+    }
+
+    pub fn on_key_press(
+        &mut self,
+        key: VirtualKeyCode,
+        down: bool,
+        modifiers: settings::Modifiers,
+    ) -> bool {
+        match self.screens.last_mut() {
+            Some(current) => current.screen.on_key_press(key, down, modifiers),
+            None => false,
+        }
+    }
 }
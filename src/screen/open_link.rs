@@ -0,0 +1,187 @@
+use crate::lang;
+use crate::render;
+use crate::ui;
+use log::warn;
+
+/// Asks before opening a link clicked in chat
+pub struct OpenLink {
+    elements: Option<UIElements>,
+    url: String,
+}
+
+struct UIElements {
+    background: ui::ImageRef,
+    _texts: Vec<ui::TextRef>,
+    _buttons: Vec<ui::ButtonRef>,
+}
+
+impl OpenLink {
+    pub fn new(url: &str) -> OpenLink {
+        OpenLink {
+            elements: None,
+            url: url.to_owned(),
+        }
+    }
+}
+
+/// Only web links are opened, other schemes could run programs
+pub fn is_web_link(url: &str) -> bool {
+    let url = url.to_lowercase();
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+fn open_in_browser(url: &str) {
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("rundll32")
+        .args(&["url.dll,FileProtocolHandler", url])
+        .spawn();
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(url).spawn();
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let result = std::process::Command::new("xdg-open").arg(url).spawn();
+    // TODO: wasm, window.open
+    #[cfg(target_arch = "wasm32")]
+    let result: std::io::Result<()> = Ok(());
+
+    if let Err(err) = result {
+        warn!("Failed to open {}: {}", url, err);
+    }
+}
+
+impl super::Screen for OpenLink {
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, 0.0)
+            .size(854.0, 480.0)
+            .colour((0, 0, 0, 200))
+            .create(ui_container);
+
+        let mut texts = vec![];
+        for (text, y, colour) in &[
+            (
+                lang::text(
+                    "chat.link.confirm",
+                    "Are you sure you want to open the following website?",
+                ),
+                -80.0,
+                (255, 255, 255, 255),
+            ),
+            (self.url.clone(), -50.0, (85, 255, 255, 255)),
+            (
+                lang::text(
+                    "chat.link.warning",
+                    "Never open links from people that you don't trust!",
+                ),
+                -20.0,
+                (255, 85, 85, 255),
+            ),
+        ] {
+            texts.push(
+                ui::TextBuilder::new()
+                    .text(text.clone())
+                    .position(0.0, *y)
+                    .colour(*colour)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .create(ui_container),
+            );
+        }
+
+        let mut buttons = vec![];
+
+        let open = ui::ButtonBuilder::new()
+            .position(-210.0, 50.0)
+            .size(200.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut open = open.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text(lang::text("gui.yes", "Yes"))
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *open);
+            open.add_text(txt);
+            let url = self.url.clone();
+            open.add_click_func(move |_, game| {
+                open_in_browser(&url);
+                game.screen_sys.pop_screen();
+                true
+            });
+        }
+        buttons.push(open);
+
+        let copy = ui::ButtonBuilder::new()
+            .position(0.0, 50.0)
+            .size(200.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut copy = copy.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text(lang::text("chat.copy", "Copy to Clipboard"))
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *copy);
+            copy.add_text(txt);
+            let url = self.url.clone();
+            copy.add_click_func(move |_, game| {
+                ui::copy_to_clipboard(&url);
+                game.screen_sys.pop_screen();
+                true
+            });
+        }
+        buttons.push(copy);
+
+        let cancel = ui::ButtonBuilder::new()
+            .position(210.0, 50.0)
+            .size(200.0, 40.0)
+            .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut cancel = cancel.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text(lang::text("gui.no", "No"))
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *cancel);
+            cancel.add_text(txt);
+            cancel.add_click_func(|_, game| {
+                game.screen_sys.pop_screen();
+                true
+            });
+        }
+        buttons.push(cancel);
+
+        self.elements = Some(UIElements {
+            background,
+            _texts: texts,
+            _buttons: buttons,
+        });
+    }
+
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.elements = None;
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        let elements = self.elements.as_mut().unwrap();
+        let mode = ui_container.mode;
+        let mut background = elements.background.borrow_mut();
+        background.width = match mode {
+            ui::Mode::Unscaled(scale) => 854.0 / scale,
+            ui::Mode::Scaled => renderer.width as f64,
+        };
+        background.height = match mode {
+            ui::Mode::Unscaled(scale) => 480.0 / scale,
+            ui::Mode::Scaled => renderer.height as f64,
+        };
+        None
+    }
+
+    fn is_closable(&self) -> bool {
+        true
+    }
+}
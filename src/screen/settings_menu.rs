@@ -1,9 +1,12 @@
 use crate::console;
+use crate::lang;
 use crate::render;
 use crate::settings;
 use crate::ui;
 
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use winit::event::{MouseButton, VirtualKeyCode};
 
 pub struct UIElements {
     background: ui::ImageRef,
@@ -46,7 +49,7 @@ impl super::Screen for SettingsMenu {
         {
             let mut audio_settings = audio_settings.borrow_mut();
             let txt = ui::TextBuilder::new()
-                .text("Audio settings...")
+                .text(lang::text("options.sounds", "Audio settings..."))
                 .alignment(ui::VAttach::Middle, ui::HAttach::Center)
                 .attach(&mut *audio_settings);
             audio_settings.add_text(txt);
@@ -66,7 +69,7 @@ impl super::Screen for SettingsMenu {
         {
             let mut video_settings = video_settings.borrow_mut();
             let txt = ui::TextBuilder::new()
-                .text("Video settings...")
+                .text(lang::text("options.video", "Video settings..."))
                 .alignment(ui::VAttach::Middle, ui::HAttach::Center)
                 .attach(&mut *video_settings);
             video_settings.add_text(txt);
@@ -86,10 +89,15 @@ impl super::Screen for SettingsMenu {
         {
             let mut controls_settings = controls_settings.borrow_mut();
             let txt = ui::TextBuilder::new()
-                .text("Controls...")
+                .text(lang::text("options.controls", "Controls..."))
                 .alignment(ui::VAttach::Middle, ui::HAttach::Center)
                 .attach(&mut *controls_settings);
             controls_settings.add_text(txt);
+            controls_settings.add_click_func(|_, game| {
+                game.screen_sys
+                    .add_screen(Box::new(ControlsMenu::new(game.vars.clone())));
+                true
+            });
         }
         buttons.push(controls_settings);
 
@@ -101,10 +109,19 @@ impl super::Screen for SettingsMenu {
         {
             let mut lang_settings = lang_settings.borrow_mut();
             let txt = ui::TextBuilder::new()
-                .text("Language...")
+                .text(lang::text("options.language", "Language..."))
                 .alignment(ui::VAttach::Middle, ui::HAttach::Center)
                 .attach(&mut *lang_settings);
             lang_settings.add_text(txt);
+            lang_settings.add_click_func(|_, game| {
+                let languages = lang::languages(&game.resource_manager.read().unwrap());
+                game.screen_sys
+                    .add_screen(Box::new(LanguageSettingsMenu::new(
+                        game.vars.clone(),
+                        languages,
+                    )));
+                true
+            });
         }
         buttons.push(lang_settings);
 
@@ -117,7 +134,7 @@ impl super::Screen for SettingsMenu {
         {
             let mut done_button = done_button.borrow_mut();
             let txt = ui::TextBuilder::new()
-                .text("Done")
+                .text(lang::text("gui.done", "Done"))
                 .alignment(ui::VAttach::Middle, ui::HAttach::Center)
                 .attach(&mut *done_button);
             done_button.add_text(txt);
@@ -138,7 +155,7 @@ impl super::Screen for SettingsMenu {
             {
                 let mut disconnect_button = disconnect_button.borrow_mut();
                 let txt = ui::TextBuilder::new()
-                    .text("Disconnect")
+                    .text(lang::text("menu.disconnect", "Disconnect"))
                     .alignment(ui::VAttach::Middle, ui::HAttach::Center)
                     .attach(&mut *disconnect_button);
                 disconnect_button.add_text(txt);
@@ -439,3 +456,566 @@ impl super::Screen for AudioSettingsMenu {
         true
     }
 }
+
+/// The number of languages shown at once, in two columns
+const LANGUAGES_PER_PAGE: usize = 10;
+
+struct LanguageElements {
+    background: ui::ImageRef,
+    _buttons: Vec<ui::ButtonRef>,
+    /// The buttons of the languages on the current page
+    _language_buttons: Vec<ui::ButtonRef>,
+    language_texts: Vec<(String, ui::TextRef)>,
+    page: usize,
+    selected: String,
+}
+
+pub struct LanguageSettingsMenu {
+    vars: Rc<console::Vars>,
+    languages: Rc<Vec<lang::Language>>,
+    page: Rc<Cell<usize>>,
+    elements: Option<LanguageElements>,
+}
+
+impl LanguageSettingsMenu {
+    pub fn new(vars: Rc<console::Vars>, languages: Vec<lang::Language>) -> LanguageSettingsMenu {
+        // Start on the page with the selected language
+        let selected = vars.get(settings::CL_LANGUAGE).to_lowercase();
+        let page = languages
+            .iter()
+            .position(|language| language.code == selected)
+            .unwrap_or(0)
+            / LANGUAGES_PER_PAGE;
+        LanguageSettingsMenu {
+            vars,
+            languages: Rc::new(languages),
+            page: Rc::new(Cell::new(page)),
+            elements: None,
+        }
+    }
+
+    fn pages(&self) -> usize {
+        ((self.languages.len() + LANGUAGES_PER_PAGE - 1) / LANGUAGES_PER_PAGE).max(1)
+    }
+
+    fn create_page(&mut self, ui_container: &mut ui::Container) {
+        let elements = self.elements.as_mut().unwrap();
+        let page = self.page.get();
+        let selected = self.vars.get(settings::CL_LANGUAGE).to_lowercase();
+        let mut buttons = vec![];
+        let mut texts = vec![];
+        for (i, language) in self
+            .languages
+            .iter()
+            .skip(page * LANGUAGES_PER_PAGE)
+            .take(LANGUAGES_PER_PAGE)
+            .enumerate()
+        {
+            let button = ui::ButtonBuilder::new()
+                .position(
+                    if i % 2 == 0 { -160.0 } else { 160.0 },
+                    -150.0 + (i / 2) as f64 * 50.0,
+                )
+                .size(300.0, 40.0)
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(ui_container);
+            {
+                let mut button = button.borrow_mut();
+                let txt = ui::TextBuilder::new()
+                    .text(language.display_name())
+                    .colour(language_colour(language.code == selected))
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .attach(&mut *button);
+                texts.push((language.code.clone(), txt.clone()));
+                button.add_text(txt);
+                let code = language.code.clone();
+                button.add_click_func(move |_, game| {
+                    game.vars.set(settings::CL_LANGUAGE, code.clone());
+                    true
+                });
+            }
+            buttons.push(button);
+        }
+        elements._language_buttons = buttons;
+        elements.language_texts = texts;
+        elements.page = page;
+        elements.selected = selected;
+    }
+
+    fn change_page(&self, diff: isize) {
+        change_page(&self.page, self.pages(), diff);
+    }
+}
+
+fn change_page(page: &Cell<usize>, pages: usize, diff: isize) {
+    let next = page.get() as isize + diff;
+    page.set(next.clamp(0, pages as isize - 1) as usize);
+}
+
+fn language_colour(selected: bool) -> (u8, u8, u8, u8) {
+    if selected {
+        (255, 255, 85, 255)
+    } else {
+        (255, 255, 255, 255)
+    }
+}
+
+impl super::Screen for LanguageSettingsMenu {
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, 0.0)
+            .size(854.0, 480.0)
+            .colour((0, 0, 0, 100))
+            .create(ui_container);
+
+        let mut buttons = vec![];
+
+        let pages = self.pages();
+        for &(label, x, diff) in &[("<", -160.0, -1), (">", 160.0, 1)] {
+            let page_button = ui::ButtonBuilder::new()
+                .position(x, 100.0)
+                .size(300.0, 40.0)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                .create(ui_container);
+            {
+                let mut page_button = page_button.borrow_mut();
+                let txt = ui::TextBuilder::new()
+                    .text(label)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .attach(&mut *page_button);
+                page_button.add_text(txt);
+                let page = self.page.clone();
+                page_button.add_click_func(move |_, _| {
+                    change_page(&page, pages, diff);
+                    true
+                });
+            }
+            buttons.push(page_button);
+        }
+
+        let done_button = ui::ButtonBuilder::new()
+            .position(0.0, 50.0)
+            .size(300.0, 40.0)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut done_button = done_button.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text(lang::text("gui.done", "Done"))
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *done_button);
+            done_button.add_text(txt);
+            done_button.add_click_func(|_, game| {
+                game.screen_sys.pop_screen();
+                true
+            });
+        }
+        buttons.push(done_button);
+
+        self.elements = Some(LanguageElements {
+            background,
+            _buttons: buttons,
+            _language_buttons: vec![],
+            language_texts: vec![],
+            page: 0,
+            selected: String::new(),
+        });
+        self.create_page(ui_container);
+    }
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.elements = None;
+    }
+
+    // Called every frame the screen is active
+    fn tick(
+        &mut self,
+        _delta: f64,
+        renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        if self.elements.as_ref().unwrap().page != self.page.get() {
+            self.create_page(ui_container);
+        }
+        let selected = self.vars.get(settings::CL_LANGUAGE).to_lowercase();
+        let elements = self.elements.as_mut().unwrap();
+        if elements.selected != selected {
+            for (code, txt) in &elements.language_texts {
+                txt.borrow_mut().colour = language_colour(*code == selected);
+            }
+            elements.selected = selected;
+        }
+        {
+            let mode = ui_container.mode;
+            let mut background = elements.background.borrow_mut();
+            background.width = match mode {
+                ui::Mode::Unscaled(scale) => 854.0 / scale,
+                ui::Mode::Scaled => renderer.width as f64,
+            };
+            background.height = match mode {
+                ui::Mode::Unscaled(scale) => 480.0 / scale,
+                ui::Mode::Scaled => renderer.height as f64,
+            };
+        }
+        None
+    }
+
+    // Events
+    fn on_scroll(&mut self, _x: f64, y: f64) {
+        if y > 0.0 {
+            self.change_page(-1);
+        } else if y < 0.0 {
+            self.change_page(1);
+        }
+    }
+
+    fn is_closable(&self) -> bool {
+        true
+    }
+}
+
+/// The number of keybindings shown at once
+const BINDINGS_PER_PAGE: usize = 6;
+
+fn action_label(action: &settings::Stevenkey) -> String {
+    use settings::Stevenkey::*;
+    let (key, fallback) = match action {
+        Forward => ("key.forward", "Walk Forwards"),
+        Backward => ("key.back", "Walk Backwards"),
+        Left => ("key.left", "Strafe Left"),
+        Right => ("key.right", "Strafe Right"),
+        OpenInv => ("key.inventory", "Open/Close Inventory"),
+        Sneak => ("key.sneak", "Sneak"),
+        Sprint => ("key.sprint", "Sprint"),
+        Jump => ("key.jump", "Jump"),
+        Chat => ("key.chat", "Open Chat"),
+        Command => ("key.command", "Open Command"),
+        Hotbar(slot) => {
+            return lang::text(
+                &format!("key.hotbar.{}", slot + 1),
+                &format!("Hotbar Slot {}", slot + 1),
+            )
+        }
+    };
+    lang::text(key, fallback)
+}
+
+/// The state of picking a new binding, shared with the click functions
+#[derive(Default)]
+struct Rebinding {
+    /// The index of the action waiting for a new binding
+    action: Option<usize>,
+    /// A modifier pressed on its own, bound if it is released before
+    /// another key is pressed
+    modifier: Option<VirtualKeyCode>,
+    /// A key used for a binding whose release shouldn't reach the game
+    swallow_key: Option<VirtualKeyCode>,
+    /// A mouse button used for a binding that is still held
+    bound_button: Option<MouseButton>,
+    /// Set when a bound mouse button is released so that the click
+    /// doesn't start another rebind
+    skip_click: bool,
+    dirty: bool,
+}
+
+struct ControlsElements {
+    background: ui::ImageRef,
+    _buttons: Vec<ui::ButtonRef>,
+    /// The labels and buttons of the actions on the current page
+    _labels: Vec<ui::TextRef>,
+    _bind_buttons: Vec<ui::ButtonRef>,
+    bind_texts: Vec<(usize, ui::TextRef)>,
+    page: usize,
+}
+
+/// Lists every keybinding, clicking one waits for the key or mouse
+/// button to bind to it.
+pub struct ControlsMenu {
+    vars: Rc<console::Vars>,
+    page: Rc<Cell<usize>>,
+    rebinding: Rc<RefCell<Rebinding>>,
+    elements: Option<ControlsElements>,
+}
+
+impl ControlsMenu {
+    pub fn new(vars: Rc<console::Vars>) -> ControlsMenu {
+        ControlsMenu {
+            vars,
+            page: Rc::new(Cell::new(0)),
+            rebinding: Rc::new(RefCell::new(Rebinding::default())),
+            elements: None,
+        }
+    }
+
+    fn pages(&self) -> usize {
+        (settings::Stevenkey::values().len() + BINDINGS_PER_PAGE - 1) / BINDINGS_PER_PAGE
+    }
+
+    fn create_page(&mut self, ui_container: &mut ui::Container) {
+        let elements = self.elements.as_mut().unwrap();
+        let page = self.page.get();
+        let mut labels = vec![];
+        let mut buttons = vec![];
+        let mut texts = vec![];
+        let actions = settings::Stevenkey::values();
+        for (i, (index, action)) in actions
+            .iter()
+            .enumerate()
+            .skip(page * BINDINGS_PER_PAGE)
+            .take(BINDINGS_PER_PAGE)
+            .enumerate()
+        {
+            let y = -160.0 + i as f64 * 40.0;
+            labels.push(
+                ui::TextBuilder::new()
+                    .text(action_label(action))
+                    .position(-110.0, y)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .create(ui_container),
+            );
+            let button = ui::ButtonBuilder::new()
+                .position(110.0, y)
+                .size(200.0, 34.0)
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .create(ui_container);
+            {
+                let mut button = button.borrow_mut();
+                let txt = ui::TextBuilder::new()
+                    .text("")
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .attach(&mut *button);
+                texts.push((index, txt.clone()));
+                button.add_text(txt);
+                let rebinding = self.rebinding.clone();
+                button.add_click_func(move |_, _| {
+                    let mut rebinding = rebinding.borrow_mut();
+                    if rebinding.skip_click {
+                        rebinding.skip_click = false;
+                    } else {
+                        rebinding.action = Some(index);
+                        rebinding.modifier = None;
+                        rebinding.dirty = true;
+                    }
+                    true
+                });
+            }
+            buttons.push(button);
+        }
+        elements._labels = labels;
+        elements._bind_buttons = buttons;
+        elements.bind_texts = texts;
+        elements.page = page;
+        self.update_texts();
+    }
+
+    /// Shows the current bindings, in red when more than one action
+    /// uses the same binding.
+    fn update_texts(&self) {
+        let elements = self.elements.as_ref().unwrap();
+        let binds = settings::Stevenkey::values()
+            .iter()
+            .map(|action| *self.vars.get(action.get_cvar()))
+            .collect::<Vec<_>>();
+        let waiting = self.rebinding.borrow().action;
+        for (index, txt) in &elements.bind_texts {
+            let mut txt = txt.borrow_mut();
+            let bind = binds[*index];
+            if waiting == Some(*index) {
+                txt.text = format!("> {} <", bind);
+                txt.colour = (255, 255, 85, 255);
+            } else {
+                txt.text = bind.to_string();
+                let conflicts = binds.iter().filter(|other| **other == bind).count() > 1;
+                txt.colour = if conflicts {
+                    (255, 85, 85, 255)
+                } else {
+                    (255, 255, 255, 255)
+                };
+            }
+        }
+    }
+
+    fn bind(&self, bind: settings::Keybind) {
+        let mut rebinding = self.rebinding.borrow_mut();
+        if let Some(index) = rebinding.action.take() {
+            let action = &settings::Stevenkey::values()[index];
+            self.vars.set(action.get_cvar(), bind);
+        }
+        rebinding.modifier = None;
+        rebinding.dirty = true;
+    }
+
+    fn change_page(&self, diff: isize) {
+        change_page(&self.page, self.pages(), diff);
+    }
+}
+
+impl super::Screen for ControlsMenu {
+    fn on_active(&mut self, _renderer: &mut render::Renderer, ui_container: &mut ui::Container) {
+        let background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(0.0, 0.0)
+            .size(854.0, 480.0)
+            .colour((0, 0, 0, 100))
+            .create(ui_container);
+
+        let mut buttons = vec![];
+
+        let pages = self.pages();
+        for &(label, x, diff) in &[("<", -160.0, -1), (">", 160.0, 1)] {
+            let page_button = ui::ButtonBuilder::new()
+                .position(x, 100.0)
+                .size(300.0, 40.0)
+                .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+                .create(ui_container);
+            {
+                let mut page_button = page_button.borrow_mut();
+                let txt = ui::TextBuilder::new()
+                    .text(label)
+                    .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                    .attach(&mut *page_button);
+                page_button.add_text(txt);
+                let page = self.page.clone();
+                page_button.add_click_func(move |_, _| {
+                    change_page(&page, pages, diff);
+                    true
+                });
+            }
+            buttons.push(page_button);
+        }
+
+        let done_button = ui::ButtonBuilder::new()
+            .position(0.0, 50.0)
+            .size(300.0, 40.0)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Center)
+            .create(ui_container);
+        {
+            let mut done_button = done_button.borrow_mut();
+            let txt = ui::TextBuilder::new()
+                .text(lang::text("gui.done", "Done"))
+                .alignment(ui::VAttach::Middle, ui::HAttach::Center)
+                .attach(&mut *done_button);
+            done_button.add_text(txt);
+            done_button.add_click_func(|_, game| {
+                game.screen_sys.pop_screen();
+                true
+            });
+        }
+        buttons.push(done_button);
+
+        self.elements = Some(ControlsElements {
+            background,
+            _buttons: buttons,
+            _labels: vec![],
+            _bind_buttons: vec![],
+            bind_texts: vec![],
+            page: 0,
+        });
+        self.create_page(ui_container);
+    }
+
+    fn on_deactive(&mut self, _renderer: &mut render::Renderer, _ui_container: &mut ui::Container) {
+        self.elements = None;
+        *self.rebinding.borrow_mut() = Rebinding::default();
+    }
+
+    fn tick(
+        &mut self,
+        _delta: f64,
+        renderer: &mut render::Renderer,
+        ui_container: &mut ui::Container,
+    ) -> Option<Box<dyn super::Screen>> {
+        if self.elements.as_ref().unwrap().page != self.page.get() {
+            self.create_page(ui_container);
+        }
+        let dirty = {
+            let mut rebinding = self.rebinding.borrow_mut();
+            // Only needed for the click straight after the release
+            rebinding.skip_click = false;
+            std::mem::replace(&mut rebinding.dirty, false)
+        };
+        if dirty {
+            self.update_texts();
+        }
+        let elements = self.elements.as_mut().unwrap();
+        {
+            let mode = ui_container.mode;
+            let mut background = elements.background.borrow_mut();
+            background.width = match mode {
+                ui::Mode::Unscaled(scale) => 854.0 / scale,
+                ui::Mode::Scaled => renderer.width as f64,
+            };
+            background.height = match mode {
+                ui::Mode::Unscaled(scale) => 480.0 / scale,
+                ui::Mode::Scaled => renderer.height as f64,
+            };
+        }
+        None
+    }
+
+    fn on_mouse_button(&mut self, button: MouseButton, down: bool, modifiers: settings::Modifiers) {
+        if down {
+            if self.rebinding.borrow().action.is_some() {
+                self.bind(settings::Keybind {
+                    input: settings::Input::Mouse(button),
+                    modifiers,
+                });
+                self.rebinding.borrow_mut().bound_button = Some(button);
+            }
+        } else {
+            let mut rebinding = self.rebinding.borrow_mut();
+            if rebinding.bound_button == Some(button) {
+                rebinding.bound_button = None;
+                rebinding.skip_click = true;
+            }
+        }
+    }
+
+    fn on_key_press(
+        &mut self,
+        key: VirtualKeyCode,
+        down: bool,
+        modifiers: settings::Modifiers,
+    ) -> bool {
+        let mut rebinding = self.rebinding.borrow_mut();
+        if !down && rebinding.swallow_key == Some(key) {
+            rebinding.swallow_key = None;
+            return true;
+        }
+        if rebinding.action.is_none() {
+            return false;
+        }
+        if down {
+            rebinding.swallow_key = Some(key);
+            if key == VirtualKeyCode::Escape {
+                rebinding.action = None;
+                rebinding.modifier = None;
+                rebinding.dirty = true;
+            } else if settings::is_modifier_key(key) {
+                rebinding.modifier = Some(key);
+            } else {
+                drop(rebinding);
+                self.bind(settings::Keybind {
+                    input: settings::Input::Key(key),
+                    modifiers,
+                });
+            }
+        } else if rebinding.modifier == Some(key) {
+            drop(rebinding);
+            self.bind(settings::Keybind::key(key));
+        }
+        true
+    }
+
+    fn on_scroll(&mut self, _x: f64, y: f64) {
+        if y > 0.0 {
+            self.change_page(-1);
+        } else if y < 0.0 {
+            self.change_page(1);
+        }
+    }
+
+    fn is_closable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,90 @@
+use crate::format;
+use instant::Instant;
+
+/// Maximum number of messages kept in the chat history
+const MAX_HISTORY: usize = 100;
+
+/// Where a received message should be displayed, from the `position`
+/// field of the server message packets.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MessageKind {
+    Chat,
+    System,
+    ActionBar,
+}
+
+impl MessageKind {
+    pub fn from_position(position: Option<u8>) -> MessageKind {
+        match position {
+            Some(1) => MessageKind::System,
+            Some(2) => MessageKind::ActionBar,
+            // 1.7 has no position field, everything is chat
+            _ => MessageKind::Chat,
+        }
+    }
+}
+
+pub struct ChatLine {
+    pub text: format::Component,
+    pub received: Instant,
+}
+
+pub struct Chat {
+    lines: Vec<ChatLine>,
+    action_bar: Option<ChatLine>,
+    dirty: bool,
+}
+
+impl Default for Chat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Chat {
+    pub fn new() -> Chat {
+        Chat {
+            lines: vec![],
+            action_bar: None,
+            // Force displays to refresh when a new server replaces the old one
+            dirty: true,
+        }
+    }
+
+    pub fn add_message(&mut self, text: format::Component, kind: MessageKind) {
+        let line = ChatLine {
+            text,
+            received: Instant::now(),
+        };
+        if kind == MessageKind::ActionBar {
+            self.action_bar = Some(line);
+        } else {
+            if self.lines.len() >= MAX_HISTORY {
+                self.lines.remove(0);
+            }
+            self.lines.push(line);
+        }
+        self.dirty = true;
+    }
+
+    /// Received messages, oldest first
+    pub fn lines(&self) -> &[ChatLine] {
+        &self.lines
+    }
+
+    pub fn action_bar(&self) -> Option<&ChatLine> {
+        self.action_bar.as_ref()
+    }
+
+    pub fn clear_action_bar(&mut self) {
+        self.action_bar = None;
+        self.dirty = true;
+    }
+
+    /// Returns whether messages were added since the last call
+    pub fn take_dirty(&mut self) -> bool {
+        let dirty = self.dirty;
+        self.dirty = false;
+        dirty
+    }
+}
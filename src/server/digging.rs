@@ -0,0 +1,444 @@
+use super::target;
+use crate::render;
+use crate::render::model;
+use crate::shared::{Direction, Position};
+use crate::world;
+use crate::world::block;
+use crate::world::block::item::{Tool, ToolKind};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Minecraft ticks to wait after breaking a block before
+/// the next one can be started.
+pub const BREAK_DELAY: u32 = 5;
+
+/// The `status` field of the PlayerDigging packet
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Status {
+    Start = 0,
+    Cancel = 1,
+    Finish = 2,
+}
+
+/// A block currently being broken by the local player
+pub struct Digging {
+    pub position: Position,
+    pub face: Direction,
+    /// Fraction of the block broken so far, between 0.0 and 1.0
+    progress: f64,
+    /// Progress made per minecraft tick
+    speed: f64,
+}
+
+impl Digging {
+    /// Starts breaking the block with the held tool, if any, and its level
+    /// of efficiency.
+    pub fn new(
+        position: Position,
+        face: Direction,
+        bl: block::Block,
+        tool: Option<Tool>,
+        efficiency: i16,
+    ) -> Digging {
+        // Same as vanilla, except that effects and being in water or the
+        // air aren't taken into account yet.
+        let hardness = bl.get_hardness();
+        let speed = if hardness > 0.0 {
+            let mut speed = tool.map_or(1.0, |tool| tool_speed(bl, tool));
+            if speed > 1.0 && efficiency > 0 {
+                speed += f64::from(efficiency * efficiency + 1);
+            }
+            // Blocks that won't drop anything take longer to break
+            let ticks = if can_harvest(bl, tool) { 30.0 } else { 100.0 };
+            speed / (hardness * ticks)
+        } else if hardness == 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+        Digging {
+            position,
+            face,
+            progress: 0.0,
+            speed,
+        }
+    }
+
+    /// Advances the progress by a minecraft tick, returning whether
+    /// the block is now broken.
+    pub fn tick(&mut self) -> bool {
+        self.progress = (self.progress + self.speed).min(1.0);
+        self.progress >= 1.0
+    }
+
+    pub fn stage(&self) -> Option<u8> {
+        if self.progress <= 0.0 {
+            None
+        } else {
+            Some(((self.progress * 10.0) as u8).min(9))
+        }
+    }
+}
+
+/// How many times faster than by hand the tool breaks the block
+fn tool_speed(bl: block::Block, tool: Tool) -> f64 {
+    match (tool.kind, bl) {
+        (ToolKind::Sword, block::Web { .. })
+        | (ToolKind::Shears, block::Web { .. })
+        | (ToolKind::Shears, block::Leaves { .. })
+        | (ToolKind::Shears, block::Leaves2 { .. }) => 15.0,
+        (ToolKind::Shears, block::Wool { .. }) => 5.0,
+        (kind, bl) if effective_tool(bl).map(|(effective, _)| effective) == Some(kind) => {
+            tool.speed
+        }
+        _ => 1.0,
+    }
+}
+
+/// Whether the block drops anything when broken with the tool
+fn can_harvest(bl: block::Block, tool: Option<Tool>) -> bool {
+    if let block::Web { .. } = bl {
+        return tool.map_or(false, |tool| {
+            tool.kind == ToolKind::Sword || tool.kind == ToolKind::Shears
+        });
+    }
+    match effective_tool(bl) {
+        Some((kind, Some(level))) => {
+            tool.map_or(false, |tool| tool.kind == kind && tool.level >= level)
+        }
+        _ => true,
+    }
+}
+
+/// Returns the kind of tool that breaks the block faster, with the level
+/// the tool needs for blocks that drop nothing otherwise.
+fn effective_tool(bl: block::Block) -> Option<(ToolKind, Option<u8>)> {
+    use crate::world::block::*;
+    Some(match bl {
+        Obsidian { .. } => (ToolKind::Pickaxe, Some(3)),
+        GoldOre { .. }
+        | GoldBlock { .. }
+        | DiamondOre { .. }
+        | DiamondBlock { .. }
+        | EmeraldOre { .. }
+        | EmeraldBlock { .. }
+        | RedstoneOre { .. }
+        | RedstoneOreLit { .. } => (ToolKind::Pickaxe, Some(2)),
+        IronOre { .. } | IronBlock { .. } | LapisOre { .. } | LapisBlock { .. } => {
+            (ToolKind::Pickaxe, Some(1))
+        }
+        Stone { .. }
+        | Cobblestone { .. }
+        | MossyCobblestone { .. }
+        | CobblestoneWall { .. }
+        | StoneStairs { .. }
+        | Sandstone { .. }
+        | SandstoneStairs { .. }
+        | RedSandstone { .. }
+        | RedSandstoneStairs { .. }
+        | SmoothStone { .. }
+        | StoneBrick { .. }
+        | StoneBrickStairs { .. }
+        | BrickBlock { .. }
+        | BrickStairs { .. }
+        | StoneSlab { .. }
+        | DoubleStoneSlab { .. }
+        | StoneSlab2 { .. }
+        | DoubleStoneSlab2 { .. }
+        | StoneSlabFlat { .. }
+        | CoalOre { .. }
+        | CoalBlock { .. }
+        | NetherGoldOre { .. }
+        | QuartzOre { .. }
+        | QuartzBlock { .. }
+        | QuartzStairs { .. }
+        | RedstoneBlock { .. }
+        | Netherrack { .. }
+        | NetherBrick { .. }
+        | NetherBrickFence { .. }
+        | NetherBrickStairs { .. }
+        | RedNetherBrick { .. }
+        | MagmaBlock { .. }
+        | BoneBlock { .. }
+        | Basalt { .. }
+        | PolishedBasalt { .. }
+        | EndStone { .. }
+        | EndBricks { .. }
+        | PurpurBlock { .. }
+        | PurpurPillar { .. }
+        | PurpurStairs { .. }
+        | PurpurSlab { .. }
+        | PurpurDoubleSlab { .. }
+        | Prismarine { .. }
+        | PrismarineStairs { .. }
+        | PrismarineSlab { .. }
+        | HardenedClay { .. }
+        | StainedHardenedClay { .. }
+        | WhiteGlazedTerracotta { .. }
+        | OrangeGlazedTerracotta { .. }
+        | MagentaGlazedTerracotta { .. }
+        | LightBlueGlazedTerracotta { .. }
+        | YellowGlazedTerracotta { .. }
+        | LimeGlazedTerracotta { .. }
+        | PinkGlazedTerracotta { .. }
+        | GrayGlazedTerracotta { .. }
+        | LightGrayGlazedTerracotta { .. }
+        | CyanGlazedTerracotta { .. }
+        | PurpleGlazedTerracotta { .. }
+        | BlueGlazedTerracotta { .. }
+        | BrownGlazedTerracotta { .. }
+        | GreenGlazedTerracotta { .. }
+        | RedGlazedTerracotta { .. }
+        | BlackGlazedTerracotta { .. }
+        | Concrete { .. }
+        | CoralBlock { .. }
+        | Furnace { .. }
+        | FurnaceLit { .. }
+        | Dispenser { .. }
+        | Dropper { .. }
+        | Observer { .. }
+        | MobSpawner { .. }
+        | EnchantingTable { .. }
+        | EnderChest { .. }
+        | StonePressurePlate { .. }
+        | LightWeightedPressurePlate { .. }
+        | HeavyWeightedPressurePlate { .. }
+        | IronBars { .. }
+        | IronDoor { .. }
+        | IronTrapDoor { .. }
+        | Chain { .. }
+        | Hopper { .. }
+        | Cauldron { .. }
+        | BrewingStand { .. }
+        | Anvil { .. } => (ToolKind::Pickaxe, Some(0)),
+        Rail { .. }
+        | GoldenRail { .. }
+        | DetectorRail { .. }
+        | ActivatorRail { .. }
+        | StoneButton { .. }
+        | Ice { .. }
+        | PackedIce { .. }
+        | BlueIce { .. } => (ToolKind::Pickaxe, None),
+        Snow { .. } | SnowLayer { .. } => (ToolKind::Shovel, Some(0)),
+        Grass { .. }
+        | Dirt { .. }
+        | Mycelium { .. }
+        | GrassPath { .. }
+        | Farmland { .. }
+        | Sand { .. }
+        | Gravel { .. }
+        | Clay { .. }
+        | SoulSand { .. }
+        | SoulSoil { .. }
+        | ConcretePowder { .. } => (ToolKind::Shovel, None),
+        Planks { .. }
+        | Log { .. }
+        | Log2 { .. }
+        | Wood { .. }
+        | WoodenSlab { .. }
+        | DoubleWoodenSlab { .. }
+        | WoodenSlabFlat { .. }
+        | OakStairs { .. }
+        | SpruceStairs { .. }
+        | BirchStairs { .. }
+        | JungleStairs { .. }
+        | AcaciaStairs { .. }
+        | DarkOakStairs { .. }
+        | Fence { .. }
+        | SpruceFence { .. }
+        | BirchFence { .. }
+        | JungleFence { .. }
+        | DarkOakFence { .. }
+        | AcaciaFence { .. }
+        | FenceGate { .. }
+        | SpruceFenceGate { .. }
+        | BirchFenceGate { .. }
+        | JungleFenceGate { .. }
+        | DarkOakFenceGate { .. }
+        | AcaciaFenceGate { .. }
+        | WoodenDoor { .. }
+        | SpruceDoor { .. }
+        | BirchDoor { .. }
+        | JungleDoor { .. }
+        | AcaciaDoor { .. }
+        | DarkOakDoor { .. }
+        | TrapDoor { .. }
+        | Chest { .. }
+        | TrappedChest { .. }
+        | CraftingTable { .. }
+        | BookShelf { .. }
+        | Jukebox { .. }
+        | NoteBlock { .. }
+        | StandingSign { .. }
+        | WallSign { .. }
+        | StandingBanner { .. }
+        | WallBanner { .. }
+        | Ladder { .. }
+        | WoodenPressurePlate { .. }
+        | WoodenButton { .. }
+        | DaylightDetector { .. }
+        | DaylightDetectorInverted { .. }
+        | Pumpkin { .. }
+        | PumpkinFace { .. }
+        | PumpkinCarved { .. }
+        | PumpkinLit { .. }
+        | MelonBlock { .. }
+        | Cocoa { .. }
+        | BrownMushroomBlock { .. }
+        | RedMushroomBlock { .. }
+        | MushroomStem { .. } => (ToolKind::Axe, None),
+        _ => return None,
+    })
+}
+
+struct BreakAnimation {
+    position: Position,
+    stage: u8,
+    model: Option<model::ModelKey>,
+}
+
+/// Cracks on blocks being broken by other players
+#[derive(Default)]
+pub struct BreakAnimations {
+    animations: HashMap<i32, BreakAnimation>,
+    removed: Vec<model::ModelKey>,
+}
+
+impl BreakAnimations {
+    pub fn new() -> BreakAnimations {
+        Default::default()
+    }
+
+    /// Updates the block being broken by an entity. Stages outside
+    /// of 0-9 remove the crack.
+    pub fn set(&mut self, entity_id: i32, position: Position, stage: i8) {
+        if !(0..=9).contains(&stage) {
+            self.remove(entity_id);
+            return;
+        }
+        if let Some(anim) = self.animations.get(&entity_id) {
+            if anim.position == position && anim.stage == stage as u8 {
+                return;
+            }
+        }
+        self.remove(entity_id);
+        self.animations.insert(
+            entity_id,
+            BreakAnimation {
+                position,
+                stage: stage as u8,
+                model: None,
+            },
+        );
+    }
+
+    fn remove(&mut self, entity_id: i32) {
+        if let Some(model) = self
+            .animations
+            .remove(&entity_id)
+            .and_then(|anim| anim.model)
+        {
+            self.removed.push(model);
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        world: &world::World,
+        renderer: &mut render::Renderer,
+        models: Option<&Arc<RwLock<crate::model::Factory>>>,
+    ) {
+        // Blocks can be broken or replaced without a final animation packet
+        let removed = &mut self.removed;
+        self.animations.retain(|_, anim| {
+            if matches!(world.get_block(anim.position), block::Air {}) {
+                removed.extend(anim.model.take());
+                false
+            } else {
+                true
+            }
+        });
+
+        for model in self.removed.drain(..) {
+            renderer.model.remove_model(model);
+        }
+        for anim in self.animations.values_mut() {
+            if anim.model.is_none() {
+                anim.model = Some(target::create_break_model(
+                    renderer,
+                    models,
+                    anim.position,
+                    world.get_block(anim.position),
+                    anim.stage,
+                ));
+            }
+        }
+    }
+
+    pub fn clear(&mut self, renderer: &mut render::Renderer) {
+        for (_, anim) in self.animations.drain() {
+            self.removed.extend(anim.model);
+        }
+        for model in self.removed.drain(..) {
+            renderer.model.remove_model(model);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STONE: block::Block = block::Stone {
+        variant: block::StoneVariant::Normal,
+    };
+
+    fn tool(kind: ToolKind, level: u8, speed: f64) -> Option<Tool> {
+        Some(Tool { kind, level, speed })
+    }
+
+    /// Asserts how many minecraft ticks the block takes to break
+    fn assert_ticks(bl: block::Block, tool: Option<Tool>, efficiency: i16, ticks: f64) {
+        let digging = Digging::new(Position::new(0, 0, 0), Direction::Up, bl, tool, efficiency);
+        assert!(
+            (1.0 / digging.speed - ticks).abs() < 1e-9,
+            "{:?} took {} ticks",
+            bl,
+            1.0 / digging.speed
+        );
+    }
+
+    #[test]
+    fn hand() {
+        // Stone only drops when mined with a pickaxe
+        assert_ticks(STONE, None, 0, 150.0);
+        let dirt = block::Dirt {
+            snowy: false,
+            variant: block::DirtVariant::Normal,
+        };
+        assert_ticks(dirt, None, 0, 15.0);
+        // Tools that don't suit the block are as slow as a hand
+        assert_ticks(STONE, tool(ToolKind::Axe, 3, 8.0), 0, 150.0);
+    }
+
+    #[test]
+    fn tools() {
+        assert_ticks(STONE, tool(ToolKind::Pickaxe, 0, 2.0), 0, 22.5);
+        assert_ticks(STONE, tool(ToolKind::Pickaxe, 3, 8.0), 0, 5.625);
+        // Efficiency V adds 26 to the speed
+        assert_ticks(STONE, tool(ToolKind::Pickaxe, 3, 8.0), 5, 45.0 / 34.0);
+        assert_ticks(block::Web {}, tool(ToolKind::Sword, 3, 8.0), 0, 8.0);
+        assert_ticks(block::Web {}, None, 0, 400.0);
+    }
+
+    #[test]
+    fn harvest_level() {
+        let diamond_ore = block::DiamondOre {};
+        assert!(!can_harvest(diamond_ore, tool(ToolKind::Pickaxe, 1, 4.0)));
+        assert!(can_harvest(diamond_ore, tool(ToolKind::Pickaxe, 2, 6.0)));
+        // A stone pickaxe is faster than a hand, but not as fast as one
+        // that can harvest the ore
+        assert_ticks(diamond_ore, tool(ToolKind::Pickaxe, 1, 4.0), 0, 75.0);
+        assert_ticks(diamond_ore, tool(ToolKind::Pickaxe, 2, 6.0), 0, 15.0);
+    }
+}
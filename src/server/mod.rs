@@ -12,20 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::audio;
 use crate::ecs;
 use crate::entity;
+use crate::entity::equipment::EquipmentSlot;
+use crate::entity::types::EntityType;
 use crate::format;
+use crate::inventory;
+use crate::item;
+use crate::model;
+use crate::particle;
 use crate::protocol::{self, forge, mojang, packet};
 use crate::render;
 use crate::resources;
 use crate::settings::Stevenkey;
-use crate::shared::{Axis, Position};
+use crate::shared::{Axis, Direction, Position};
 use crate::types::hash::FNVHash;
 use crate::types::Gamemode;
 use crate::world;
 use crate::world::block;
 use cgmath::prelude::*;
-use instant::Instant;
 use log::{debug, error, info, warn};
 use rand::{self, Rng};
 use std::collections::HashMap;
@@ -35,7 +41,11 @@ use std::sync::mpsc;
 use std::sync::{Arc, RwLock};
 use std::thread;
 
+pub mod chat;
+mod digging;
 pub mod plugin_messages;
+mod spawn;
+pub mod status;
 mod sun;
 pub mod target;
 
@@ -70,15 +80,67 @@ pub struct Server {
     target_rotation: ecs::Key<entity::TargetRotation>,
     //
     pub player: Option<ecs::Entity>,
+    /// The id the server gave the player's entity when joining
+    player_entity_id: Option<i32>,
     entity_map: HashMap<i32, ecs::Entity, BuildHasherDefault<FNVHash>>,
     players: HashMap<protocol::UUID, PlayerInfo, BuildHasherDefault<FNVHash>>,
 
     tick_timer: f64,
     entity_tick_timer: f64,
-    pub received_chat_at: Option<Instant>,
+    pub chat: chat::Chat,
 
     sun_model: Option<sun::SunModel>,
     target_info: target::Info,
+
+    pub inventory: Arc<RwLock<inventory::Inventory>>,
+    pub status: status::Status,
+    /// Sounds to start or stop, played by the game every frame
+    pub sounds: Vec<audio::Command>,
+    /// Particles to spawn, handled by the game every frame
+    pub particles: Vec<particle::Event>,
+
+    dig_pressed: bool,
+    dig_delay: u32,
+    digging: Option<digging::Digging>,
+    break_animations: digging::BreakAnimations,
+    /// The entity the player is looking at, with where it is looked at
+    /// relative to its position
+    target_entity: Option<(i32, cgmath::Vector3<f64>)>,
+
+    client_settings: ClientSettings,
+    /// Sent by 1.14+ servers, `None` when unknown
+    server_view_distance: Option<i32>,
+    /// The chunk and distance chunks were last unloaded around
+    retained_chunks: Option<(i32, i32, i32)>,
+}
+
+/// The client's options that the server is told about
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClientSettings {
+    /// The selected language, e.g. `en_us`
+    pub locale: String,
+    /// The render distance in chunks
+    pub view_distance: u8,
+    /// 0 for all chat, 1 for only command feedback and 2 for none
+    pub chat_mode: u8,
+    pub chat_colors: bool,
+    /// Bitmask of the skin layers other players see
+    pub displayed_skin_parts: u8,
+    /// 0 for left and 1 for right
+    pub main_hand: u8,
+}
+
+impl Default for ClientSettings {
+    fn default() -> Self {
+        ClientSettings {
+            locale: "en_us".to_owned(),
+            view_distance: 12,
+            chat_mode: 0,
+            chat_colors: true,
+            displayed_skin_parts: 0x7f,
+            main_hand: 1,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -98,13 +160,25 @@ macro_rules! handle_packet {
     }) => (
         match $pck {
         $(
-            protocol::packet::Packet::$packet(val) => $s.$func(val),
+            protocol::packet::Packet::$packet(val) => $s.$func(*val),
         )*
             _ => {},
         }
     )
 }
 
+/// The reason shown when the connection fails with an error
+pub fn error_reason(err: protocol::Error) -> format::Component {
+    match err {
+        protocol::Error::Disconnect(val) => *val,
+        err => {
+            let mut msg = format::TextComponent::new(&format!("{}", err));
+            msg.modifier.color = Some(format::Color::Red);
+            format::Component::Text(msg)
+        }
+    }
+}
+
 impl Server {
     pub fn connect(
         resources: Arc<RwLock<resources::Manager>>,
@@ -114,222 +188,15 @@ impl Server {
         forge_mods: Vec<forge::ForgeMod>,
         fml_network_version: Option<i64>,
     ) -> Result<Server, protocol::Error> {
-        let mut conn = protocol::Conn::new(address, protocol_version)?;
-
-        let tag = match fml_network_version {
-            Some(1) => "\0FML\0",
-            Some(2) => "\0FML2\0",
-            None => "",
-            _ => panic!("unsupported FML network version: {:?}", fml_network_version),
-        };
-
-        let host = conn.host.clone() + tag;
-        let port = conn.port;
-        conn.write_packet(protocol::packet::handshake::serverbound::Handshake {
-            protocol_version: protocol::VarInt(protocol_version),
-            host,
-            port,
-            next: protocol::VarInt(2),
-        })?;
-        conn.state = protocol::State::Login;
-        conn.write_packet(protocol::packet::login::serverbound::LoginStart {
-            username: profile.username.clone(),
-        })?;
-
-        use std::rc::Rc;
-        let (server_id, public_key, verify_token);
-        loop {
-            match conn.read_packet()? {
-                protocol::packet::Packet::SetInitialCompression(val) => {
-                    conn.set_compresssion(val.threshold.0);
-                }
-                protocol::packet::Packet::EncryptionRequest(val) => {
-                    server_id = Rc::new(val.server_id);
-                    public_key = Rc::new(val.public_key.data);
-                    verify_token = Rc::new(val.verify_token.data);
-                    break;
-                }
-                protocol::packet::Packet::EncryptionRequest_i16(val) => {
-                    server_id = Rc::new(val.server_id);
-                    public_key = Rc::new(val.public_key.data);
-                    verify_token = Rc::new(val.verify_token.data);
-                    break;
-                }
-                protocol::packet::Packet::LoginSuccess_String(val) => {
-                    warn!("Server is running in offline mode");
-                    debug!("Login: {} {}", val.username, val.uuid);
-                    let mut read = conn.clone();
-                    let mut write = conn;
-                    read.state = protocol::State::Play;
-                    write.state = protocol::State::Play;
-                    let rx = Self::spawn_reader(read);
-                    return Ok(Server::new(
-                        protocol_version,
-                        forge_mods,
-                        protocol::UUID::from_str(&val.uuid).unwrap(),
-                        resources,
-                        Some(write),
-                        Some(rx),
-                    ));
-                }
-                protocol::packet::Packet::LoginSuccess_UUID(val) => {
-                    warn!("Server is running in offline mode");
-                    debug!("Login: {} {:?}", val.username, val.uuid);
-                    let mut read = conn.clone();
-                    let mut write = conn;
-                    read.state = protocol::State::Play;
-                    write.state = protocol::State::Play;
-                    let rx = Self::spawn_reader(read);
-                    return Ok(Server::new(
-                        protocol_version,
-                        forge_mods,
-                        val.uuid,
-                        resources,
-                        Some(write),
-                        Some(rx),
-                    ));
-                }
-                protocol::packet::Packet::LoginDisconnect(val) => {
-                    return Err(protocol::Error::Disconnect(val.reason))
-                }
-                val => return Err(protocol::Error::Err(format!("Wrong packet 1: {:?}", val))),
-            };
-        }
-
-        let mut shared = [0; 16];
-        rand::thread_rng().fill(&mut shared);
-
-        let shared_e = rsa_public_encrypt_pkcs1::encrypt(&public_key, &shared).unwrap();
-        let token_e = rsa_public_encrypt_pkcs1::encrypt(&public_key, &verify_token).unwrap();
-
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            profile.join_server(&server_id, &shared, &public_key)?;
-        }
-
-        if protocol_version >= 47 {
-            conn.write_packet(protocol::packet::login::serverbound::EncryptionResponse {
-                shared_secret: protocol::LenPrefixedBytes::new(shared_e),
-                verify_token: protocol::LenPrefixedBytes::new(token_e),
-            })?;
-        } else {
-            conn.write_packet(
-                protocol::packet::login::serverbound::EncryptionResponse_i16 {
-                    shared_secret: protocol::LenPrefixedBytes::new(shared_e),
-                    verify_token: protocol::LenPrefixedBytes::new(token_e),
-                },
-            )?;
-        }
-
-        let mut read = conn.clone();
-        let mut write = conn;
-
-        read.enable_encyption(&shared, true);
-        write.enable_encyption(&shared, false);
-
-        let uuid;
-        let compression_threshold = read.compression_threshold;
-        loop {
-            match read.read_packet()? {
-                protocol::packet::Packet::SetInitialCompression(val) => {
-                    read.set_compresssion(val.threshold.0);
-                    write.set_compresssion(val.threshold.0);
-                }
-                protocol::packet::Packet::LoginSuccess_String(val) => {
-                    debug!("Login: {} {}", val.username, val.uuid);
-                    uuid = protocol::UUID::from_str(&val.uuid).unwrap();
-                    read.state = protocol::State::Play;
-                    write.state = protocol::State::Play;
-                    break;
-                }
-                protocol::packet::Packet::LoginSuccess_UUID(val) => {
-                    debug!("Login: {} {:?}", val.username, val.uuid);
-                    uuid = val.uuid;
-                    read.state = protocol::State::Play;
-                    write.state = protocol::State::Play;
-                    break;
-                }
-                protocol::packet::Packet::LoginDisconnect(val) => {
-                    return Err(protocol::Error::Disconnect(val.reason))
-                }
-                protocol::packet::Packet::LoginPluginRequest(req) => {
-                    match req.channel.as_ref() {
-                        "fml:loginwrapper" => {
-                            let mut cursor = std::io::Cursor::new(req.data);
-                            let channel: String = protocol::Serializable::read_from(&mut cursor)?;
-
-                            let (id, mut data) = protocol::Conn::read_raw_packet_from(
-                                &mut cursor,
-                                compression_threshold,
-                            )?;
-
-                            match channel.as_ref() {
-                                "fml:handshake" => {
-                                    let packet =
-                                        forge::fml2::FmlHandshake::packet_by_id(id, &mut data)?;
-                                    use forge::fml2::FmlHandshake::*;
-                                    match packet {
-                                        ModList {
-                                            mod_names,
-                                            channels,
-                                            registries,
-                                        } => {
-                                            info!("ModList mod_names={:?} channels={:?} registries={:?}", mod_names, channels, registries);
-                                            write.write_fml2_handshake_plugin_message(
-                                                req.message_id,
-                                                Some(&ModListReply {
-                                                    mod_names,
-                                                    channels,
-                                                    registries,
-                                                }),
-                                            )?;
-                                        }
-                                        ServerRegistry {
-                                            name,
-                                            snapshot_present: _,
-                                            snapshot: _,
-                                        } => {
-                                            info!("ServerRegistry {:?}", name);
-                                            write.write_fml2_handshake_plugin_message(
-                                                req.message_id,
-                                                Some(&Acknowledgement),
-                                            )?;
-                                        }
-                                        ConfigurationData { filename, contents } => {
-                                            info!(
-                                                "ConfigurationData filename={:?} contents={}",
-                                                filename,
-                                                String::from_utf8_lossy(&contents)
-                                            );
-                                            write.write_fml2_handshake_plugin_message(
-                                                req.message_id,
-                                                Some(&Acknowledgement),
-                                            )?;
-                                        }
-                                        _ => unimplemented!(),
-                                    }
-                                }
-                                _ => panic!(
-                                    "unknown LoginPluginRequest fml:loginwrapper channel: {:?}",
-                                    channel
-                                ),
-                            }
-                        }
-                        _ => panic!("unsupported LoginPluginRequest channel: {:?}", req.channel),
-                    }
-                }
-                val => return Err(protocol::Error::Err(format!("Wrong packet 2: {:?}", val))),
-            }
-        }
-
-        let rx = Self::spawn_reader(read);
-
+        let login =
+            protocol::login::login(&profile, address, protocol_version, fml_network_version)?;
+        let rx = Self::spawn_reader(login.read);
         Ok(Server::new(
             protocol_version,
             forge_mods,
-            uuid,
+            login.uuid,
             resources,
-            Some(write),
+            Some(login.write),
             Some(rx),
         ))
     }
@@ -340,7 +207,10 @@ impl Server {
         let (tx, rx) = mpsc::channel();
         thread::spawn(move || loop {
             let pck = read.read_packet();
-            let was_error = pck.is_err();
+            // Malformed packets were still read completely so reading can
+            // carry on
+            let was_error =
+                matches!(pck, Err(ref err) if !matches!(err, protocol::Error::Malformed(_)));
             if tx.send(pck).is_err() {
                 return;
             }
@@ -456,6 +326,8 @@ impl Server {
         entities.add_component(world_entity, game_info, entity::GameInfo::new());
 
         let version = resources.read().unwrap().version();
+        let world = world::World::new(protocol_version);
+        let inventory = inventory::Inventory::new(world.item_map.clone(), protocol_version);
         Server {
             uuid,
             conn,
@@ -465,7 +337,7 @@ impl Server {
             disconnect_reason: None,
             just_disconnected: false,
 
-            world: world::World::new(protocol_version),
+            world,
             world_age: 0,
             world_time: 0.0,
             world_time_target: 0.0,
@@ -487,37 +359,165 @@ impl Server {
             //
             entities,
             player: None,
+            player_entity_id: None,
             entity_map: HashMap::with_hasher(BuildHasherDefault::default()),
             players: HashMap::with_hasher(BuildHasherDefault::default()),
 
             tick_timer: 0.0,
             entity_tick_timer: 0.0,
-            received_chat_at: None,
+            chat: chat::Chat::new(),
             sun_model: None,
 
             target_info: target::Info::new(),
+
+            inventory: Arc::new(RwLock::new(inventory)),
+            status: status::Status::new(),
+            sounds: vec![],
+            particles: vec![],
+
+            dig_pressed: false,
+            dig_delay: 0,
+            digging: None,
+            break_animations: digging::BreakAnimations::new(),
+            target_entity: None,
+
+            client_settings: Default::default(),
+            server_view_distance: None,
+            retained_chunks: None,
         }
     }
 
     pub fn disconnect(&mut self, reason: Option<format::Component>) {
         self.conn = None;
         self.disconnect_reason = reason;
+        self.player_entity_id = None;
         if let Some(player) = self.player.take() {
             self.entities.remove_entity(player);
         }
         self.just_disconnected = true;
     }
 
+    /// Updates the client's options, telling the server if they changed
+    /// while playing.
+    pub fn update_client_settings(&mut self, settings: ClientSettings) {
+        if settings != self.client_settings {
+            self.client_settings = settings;
+            if self.player.is_some() {
+                self.send_client_settings();
+            }
+        }
+    }
+
+    fn send_client_settings(&mut self) {
+        let ClientSettings {
+            view_distance,
+            chat_mode,
+            chat_colors,
+            displayed_skin_parts,
+            main_hand,
+            ..
+        } = self.client_settings;
+        let locale = if self.protocol_version >= 315 {
+            self.client_settings.locale.clone()
+        } else {
+            // Older versions capitalize the region, e.g. en_US
+            match self.client_settings.locale.split_once('_') {
+                Some((lang, region)) => format!("{}_{}", lang, region.to_uppercase()),
+                None => self.client_settings.locale.clone(),
+            }
+        };
+        if self.protocol_version >= 755 {
+            self.write_packet(packet::play::serverbound::ClientSettings_Filtering {
+                locale,
+                view_distance,
+                chat_mode: protocol::VarInt(i32::from(chat_mode)),
+                chat_colors,
+                displayed_skin_parts,
+                main_hand: protocol::VarInt(i32::from(main_hand)),
+                text_filtering_enabled: false,
+            });
+        } else if self.protocol_version >= 107 {
+            self.write_packet(packet::play::serverbound::ClientSettings {
+                locale,
+                view_distance,
+                chat_mode: protocol::VarInt(i32::from(chat_mode)),
+                chat_colors,
+                displayed_skin_parts,
+                main_hand: protocol::VarInt(i32::from(main_hand)),
+            });
+        } else if self.protocol_version >= 74 {
+            self.write_packet(packet::play::serverbound::ClientSettings_u8 {
+                locale,
+                view_distance,
+                chat_mode,
+                chat_colors,
+                displayed_skin_parts,
+                main_hand: protocol::VarInt(i32::from(main_hand)),
+            });
+        } else if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::ClientSettings_u8_Handsfree {
+                locale,
+                view_distance,
+                chat_mode,
+                chat_colors,
+                displayed_skin_parts,
+            });
+        } else {
+            self.write_packet(
+                packet::play::serverbound::ClientSettings_u8_Handsfree_Difficulty {
+                    locale,
+                    view_distance,
+                    chat_mode,
+                    chat_colors,
+                    // Normal, only used in singleplayer
+                    difficulty: 2,
+                    displayed_skin_parts,
+                },
+            );
+        }
+    }
+
     pub fn is_connected(&self) -> bool {
         self.conn.is_some()
     }
 
-    pub fn tick(&mut self, renderer: &mut render::Renderer, delta: f64) {
+    /// Handles packets and simulates the world. Without a renderer the
+    /// server runs headless, skipping everything that is only drawn, and
+    /// there are no models to draw items with.
+    pub fn tick(
+        &mut self,
+        mut renderer: Option<&mut render::Renderer>,
+        models: Option<&Arc<RwLock<model::Factory>>>,
+        delta: f64,
+    ) {
         let version = self.resources.read().unwrap().version();
         if version != self.version {
             self.version = version;
             self.world.flag_dirty_all();
         }
+        if let Some(renderer) = renderer.as_deref_mut() {
+            self.update_camera(renderer);
+        }
+        self.entity_tick(renderer.as_deref_mut(), models, delta);
+        self.unload_distant_chunks();
+        self.send_inventory_actions();
+
+        self.tick_timer += delta;
+        while self.tick_timer >= 3.0 && self.is_connected() {
+            self.minecraft_tick();
+            self.tick_timer -= 3.0;
+        }
+
+        self.update_time(delta);
+
+        self.world.tick(&mut self.entities);
+
+        if let Some(renderer) = renderer {
+            self.render_tick(renderer, models);
+        }
+    }
+
+    fn update_camera(&mut self, renderer: &mut render::Renderer) {
         // TODO: Check if the world type actually needs a sun
         if self.sun_model.is_none() {
             self.sun_model = Some(sun::SunModel::new(renderer));
@@ -532,50 +532,92 @@ impl Server {
             renderer.camera.yaw = rotation.yaw;
             renderer.camera.pitch = rotation.pitch;
         }
-        self.entity_tick(renderer, delta);
-
-        self.tick_timer += delta;
-        while self.tick_timer >= 3.0 && self.is_connected() {
-            self.minecraft_tick();
-            self.tick_timer -= 3.0;
-        }
-
-        self.update_time(renderer, delta);
+    }
 
+    /// Updates the sky and the block the player is looking at
+    fn render_tick(
+        &mut self,
+        renderer: &mut render::Renderer,
+        models: Option<&Arc<RwLock<model::Factory>>>,
+    ) {
+        renderer.sky_offset = self.calculate_sky_offset();
         if let Some(sun_model) = self.sun_model.as_mut() {
             sun_model.tick(renderer, self.world_time, self.world_age);
         }
 
-        self.world.tick(&mut self.entities);
-
-        if self.player.is_some() {
-            if let Some((pos, bl, _, _)) = target::trace_ray(
-                &self.world,
-                4.0,
-                renderer.camera.pos.to_vec(),
-                renderer.view_vector.cast().unwrap(),
-                target::test_block,
-            ) {
-                self.target_info.update(renderer, pos, bl);
-            } else {
-                self.target_info.clear(renderer);
+        let (target, target_entity) = match self.player {
+            Some(player) => {
+                let start = renderer.camera.pos.to_vec();
+                let dir = renderer.view_vector.cast().unwrap();
+                let target = target::trace_ray(&self.world, 4.0, start, dir, target::test_block);
+                let target_entity = target::trace_entities(
+                    &self.entities,
+                    self.entity_map
+                        .iter()
+                        .map(|(&id, &e)| (id, e))
+                        .filter(|&(_, e)| e != player),
+                    target::ENTITY_REACH,
+                    start,
+                    dir,
+                );
+                // Whichever is closer hides the other
+                match (target, target_entity) {
+                    (Some(target), Some(entity)) => {
+                        let (pos, _, _, at) = target;
+                        let hit =
+                            cgmath::Vector3::new(pos.x as f64, pos.y as f64, pos.z as f64) + at;
+                        if (hit - start).magnitude() < entity.1 {
+                            (Some(target), None)
+                        } else {
+                            (None, Some(entity))
+                        }
+                    }
+                    (target, None) => (target, None),
+                    (None, entity) => (None, entity),
+                }
             }
+            None => (None, None),
+        };
+        self.target_entity = target_entity.map(|(id, _, at)| (id, at));
+        if let Some((pos, bl, face, _)) = target {
+            self.target_info.update(renderer, pos, bl);
+            self.update_digging(Some((pos, bl, face)));
         } else {
             self.target_info.clear(renderer);
+            self.update_digging(None);
         }
+        let break_stage = self.digging.as_ref().and_then(|d| d.stage());
+        self.target_info
+            .set_break_stage(renderer, models, break_stage);
+        self.break_animations.tick(&self.world, renderer, models);
     }
 
-    fn entity_tick(&mut self, renderer: &mut render::Renderer, delta: f64) {
+    fn entity_tick(
+        &mut self,
+        mut renderer: Option<&mut render::Renderer>,
+        models: Option<&Arc<RwLock<model::Factory>>>,
+        delta: f64,
+    ) {
         let world_entity = self.entities.get_world();
         // Update the game's state for entities to read
-        self.entities
+        let game_info = self
+            .entities
             .get_component_mut(world_entity, self.game_info)
-            .unwrap()
-            .delta = delta;
+            .unwrap();
+        game_info.delta = delta;
+        game_info.models = models.cloned();
 
         // Packets modify entities so need to handled here
         if let Some(rx) = self.read_queue.take() {
-            while let Ok(pck) = rx.try_recv() {
+            loop {
+                let pck = match rx.try_recv() {
+                    Ok(pck) => pck,
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        self.network_error(protocol::Error::Err("Connection closed".to_owned()));
+                        break;
+                    }
+                };
                 match pck {
                     Ok(pck) => handle_packet! {
                         self pck {
@@ -595,6 +637,7 @@ impl Server {
                             KeepAliveClientbound_i64 => on_keep_alive_i64,
                             KeepAliveClientbound_VarInt => on_keep_alive_varint,
                             KeepAliveClientbound_i32 => on_keep_alive_i32,
+                            ChunkData_Biomes3D_BitMask => on_chunk_data_biomes3d_bitmask,
                             ChunkData_Biomes3D_VarInt => on_chunk_data_biomes3d_varint,
                             ChunkData_Biomes3D_bool => on_chunk_data_biomes3d_bool,
                             ChunkData => on_chunk_data,
@@ -606,11 +649,28 @@ impl Server {
                             ChunkDataBulk => on_chunk_data_bulk,
                             ChunkDataBulk_17 => on_chunk_data_bulk_17,
                             ChunkUnload => on_chunk_unload,
+                            UpdateViewDistance => on_update_view_distance,
                             BlockChange_VarInt => on_block_change_varint,
                             BlockChange_u8 => on_block_change_u8,
                             MultiBlockChange_Packed => on_multi_block_change_packed,
                             MultiBlockChange_VarInt => on_multi_block_change_varint,
                             MultiBlockChange_u16 => on_multi_block_change_u16,
+                            BlockBreakAnimation => on_block_break_animation,
+                            BlockBreakAnimation_i32 => on_block_break_animation_i32,
+                            AcknowledgePlayerDigging => on_acknowledge_player_digging,
+                            // Inventories
+                            WindowOpen => on_window_open,
+                            WindowOpen_u8 => on_window_open_u8,
+                            WindowOpen_VarInt => on_window_open_varint,
+                            WindowOpenHorse => on_window_open_horse,
+                            WindowClose => on_window_close,
+                            WindowItems => on_window_items,
+                            WindowItems_State => on_window_items_state,
+                            WindowSetSlot => on_window_set_slot,
+                            WindowSetSlot_State => on_window_set_slot_state,
+                            WindowProperty => on_window_property,
+                            ConfirmTransaction => on_confirm_transaction,
+                            SetCurrentHotbarSlot => on_set_current_hotbar_slot,
                             TeleportPlayer_WithConfirm => on_teleport_player_withconfirm,
                             TeleportPlayer_NoConfirm => on_teleport_player_noconfirm,
                             TeleportPlayer_OnGround => on_teleport_player_onground,
@@ -625,15 +685,63 @@ impl Server {
                             ServerMessage_NoPosition => on_servermessage_noposition,
                             ServerMessage_Position => on_servermessage_position,
                             ServerMessage_Sender => on_servermessage_sender,
+                            ActionBar => on_action_bar,
                             Disconnect => on_disconnect,
                             // Entities
                             EntityDestroy => on_entity_destroy,
                             EntityDestroy_u8 => on_entity_destroy_u8,
+                            SpawnObject => on_entity_spawn,
+                            SpawnObject_i32 => on_entity_spawn,
+                            SpawnObject_i32_NoUUID => on_entity_spawn,
+                            SpawnObject_VarInt => on_entity_spawn,
+                            SpawnExperienceOrb => on_entity_spawn,
+                            SpawnExperienceOrb_i32 => on_entity_spawn,
+                            SpawnGlobalEntity => on_entity_spawn,
+                            SpawnGlobalEntity_i32 => on_entity_spawn,
+                            SpawnMob_NoMeta => on_entity_spawn,
+                            SpawnMob_WithMeta => on_entity_spawn,
+                            SpawnMob_u8 => on_entity_spawn,
+                            SpawnMob_u8_i32 => on_entity_spawn,
+                            SpawnMob_u8_i32_NoUUID => on_entity_spawn,
+                            SpawnPainting_VarInt => on_entity_spawn,
+                            SpawnPainting_String => on_entity_spawn,
+                            SpawnPainting_NoUUID => on_entity_spawn,
+                            SpawnPainting_NoUUID_i32 => on_entity_spawn,
                             SpawnPlayer_f64_NoMeta => on_player_spawn_f64_nometa,
                             SpawnPlayer_f64 => on_player_spawn_f64,
                             SpawnPlayer_i32 => on_player_spawn_i32,
                             SpawnPlayer_i32_HeldItem => on_player_spawn_i32_helditem,
                             SpawnPlayer_i32_HeldItem_String => on_player_spawn_i32_helditem_string,
+                            EntityMetadata => on_entity_metadata,
+                            EntityVelocity => on_entity_velocity,
+                            EntityVelocity_i32 => on_entity_velocity_i32,
+                            Explosion => on_explosion,
+                            UpdateHealth => on_update_health,
+                            NamedSoundEffect => on_named_sound_effect,
+                            NamedSoundEffect_u8 => on_named_sound_effect_u8,
+                            NamedSoundEffect_u8_NoCategory => on_named_sound_effect_u8_nocategory,
+                            SoundEffect => on_sound_effect,
+                            SoundEffect_u8 => on_sound_effect_u8,
+                            EntitySoundEffect => on_entity_sound_effect,
+                            StopSound => on_stop_sound,
+                            Particle_f64 => on_particle_f64,
+                            Particle_Data => on_particle_data,
+                            Particle_Data13 => on_particle_data13,
+                            Particle_VarIntArray => on_particle_varintarray,
+                            Particle_Named => on_particle_named,
+                            Effect => on_effect,
+                            Effect_u8y => on_effect_u8y,
+                            UpdateHealth_u16 => on_update_health_u16,
+                            SetExperience => on_set_experience,
+                            SetExperience_i16 => on_set_experience_i16,
+                            CombatEvent => on_combat_event,
+                            DeathCombatEvent => on_death_combat_event,
+                            EntityMetadata_i32 => on_entity_metadata_i32,
+                            Animation => on_animation,
+                            EntityEquipment_Array => on_entity_equipment_array,
+                            EntityEquipment_VarInt => on_entity_equipment_varint,
+                            EntityEquipment_u16 => on_entity_equipment_u16,
+                            EntityEquipment_u16_i32 => on_entity_equipment_u16_i32,
                             EntityTeleport_f64 => on_entity_teleport_f64,
                             EntityTeleport_i32 => on_entity_teleport_i32,
                             EntityTeleport_i32_i32_NoGround => on_entity_teleport_i32_i32_noground,
@@ -647,7 +755,10 @@ impl Server {
                             EntityLookAndMove_i8_i32_NoGround => on_entity_look_and_move_i8_i32_noground,
                         }
                     },
-                    Err(err) => panic!("Err: {:?}", err),
+                    Err(protocol::Error::Malformed(err)) => {
+                        warn!("Ignoring malformed packet: {}", err);
+                    }
+                    Err(err) => self.network_error(err),
                 }
                 // Disconnected
                 if self.conn.is_none() {
@@ -665,7 +776,7 @@ impl Server {
             self.just_disconnected = false;
             self.entity_tick_timer += delta;
             while self.entity_tick_timer >= 3.0 {
-                self.entities.tick(&mut self.world, renderer);
+                self.entities.tick(&mut self.world, renderer.as_deref_mut());
                 self.entity_tick_timer -= 3.0;
             }
 
@@ -674,14 +785,16 @@ impl Server {
     }
 
     pub fn remove(&mut self, renderer: &mut render::Renderer) {
-        self.entities.remove_all_entities(&mut self.world, renderer);
+        self.entities
+            .remove_all_entities(&mut self.world, Some(&mut *renderer));
         if let Some(mut sun_model) = self.sun_model.take() {
             sun_model.remove(renderer);
         }
         self.target_info.clear(renderer);
+        self.break_animations.clear(renderer);
     }
 
-    fn update_time(&mut self, renderer: &mut render::Renderer, delta: f64) {
+    fn update_time(&mut self, delta: f64) {
         if self.tick_time {
             self.world_time_target += delta / 3.0;
             self.world_time_target = (24000.0 + self.world_time_target) % 24000.0;
@@ -696,7 +809,6 @@ impl Server {
         } else {
             self.world_time = self.world_time_target;
         }
-        renderer.sky_offset = self.calculate_sky_offset();
     }
 
     fn calculate_sky_offset(&self) -> f32 {
@@ -774,6 +886,17 @@ impl Server {
                 self.write_packet(packet);
             }
         }
+
+        if self.dig_delay > 0 {
+            self.dig_delay -= 1;
+        }
+        if let Some(digging) = self.digging.as_mut() {
+            if digging.tick() {
+                let digging = self.digging.take().unwrap();
+                self.write_digging_packet(digging::Status::Finish, digging.position, digging.face);
+                self.break_block(digging.position);
+            }
+        }
     }
 
     pub fn key_press(&mut self, down: bool, key: Stevenkey) {
@@ -787,8 +910,295 @@ impl Server {
         }
     }
 
+    pub fn on_left_click(&mut self, down: bool) {
+        self.dig_pressed = down;
+        if !down || self.player.is_none() {
+            return;
+        }
+        if let Some((id, _)) = self.target_entity {
+            self.write_use_entity(id, UseEntityType::Attack);
+        }
+        self.swing_arm();
+    }
+
+    /// Swings the player's main arm and lets the server know, which shows
+    /// it to other players
+    fn swing_arm(&mut self) {
+        if let Some(model) = self.player.and_then(|player| {
+            self.entities
+                .get_component_mut_direct::<entity::player::PlayerModel>(player)
+        }) {
+            model.swing_arm(false);
+        }
+        if self.protocol_version >= 74 {
+            self.write_packet(packet::play::serverbound::ArmSwing {
+                hand: protocol::VarInt(0),
+            });
+        } else if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::ArmSwing_Handsfree { empty: () });
+        } else if let Some(entity_id) = self.player_entity_id {
+            self.write_packet(packet::play::serverbound::ArmSwing_Handsfree_ID {
+                entity_id,
+                // The swing arm animation
+                animation: 1,
+            });
+        }
+    }
+
+    fn write_use_entity(&mut self, target_id: i32, ty: UseEntityType) {
+        let (target_x, target_y, target_z) = match ty {
+            UseEntityType::InteractAt(at) => (at.x as f32, at.y as f32, at.z as f32),
+            _ => (0.0, 0.0, 0.0),
+        };
+        if self.protocol_version >= 735 {
+            let sneaking = self
+                .player
+                .and_then(|player| self.entities.get_component(player, self.player_movement))
+                .and_then(|movement| movement.pressed_keys.get(&Stevenkey::Sneak).copied())
+                .unwrap_or(false);
+            self.write_packet(packet::play::serverbound::UseEntity_Sneakflag {
+                target_id: protocol::VarInt(target_id),
+                ty: protocol::VarInt(ty.id()),
+                target_x,
+                target_y,
+                target_z,
+                hand: protocol::VarInt(0),
+                sneaking,
+            });
+        } else if self.protocol_version >= 74 {
+            self.write_packet(packet::play::serverbound::UseEntity_Hand {
+                target_id: protocol::VarInt(target_id),
+                ty: protocol::VarInt(ty.id()),
+                target_x,
+                target_y,
+                target_z,
+                hand: protocol::VarInt(0),
+            });
+        } else if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::UseEntity_Handsfree {
+                target_id: protocol::VarInt(target_id),
+                ty: protocol::VarInt(ty.id()),
+                target_x,
+                target_y,
+                target_z,
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::UseEntity_Handsfree_i32 {
+                target_id,
+                ty: ty.id() as u8,
+            });
+        }
+    }
+
+    /// Starts, continues or cancels digging based on the block the
+    /// player is looking at.
+    fn update_digging(&mut self, target: Option<(Position, block::Block, Direction)>) {
+        if let Some(digging) = self.digging.as_ref() {
+            let same_target = target.map(|(pos, _, _)| pos) == Some(digging.position);
+            if self.dig_pressed && same_target {
+                return;
+            }
+            let digging = self.digging.take().unwrap();
+            self.write_digging_packet(digging::Status::Cancel, digging.position, digging.face);
+        }
+
+        if !self.dig_pressed || self.dig_delay > 0 {
+            return;
+        }
+        let (pos, bl, face) = match target {
+            Some(target) => target,
+            None => return,
+        };
+        let gamemode = match self.player {
+            Some(player) => *self.entities.get_component(player, self.gamemode).unwrap(),
+            None => return,
+        };
+        if !gamemode.can_dig() {
+            return;
+        }
+
+        self.write_digging_packet(digging::Status::Start, pos, face);
+        // Like vanilla the server doesn't expect a finish packet for these
+        if gamemode.instant_break() || bl.get_hardness() == 0.0 {
+            self.break_block(pos);
+        } else {
+            let (tool, efficiency) = {
+                let inventory = self.inventory.read().unwrap();
+                match inventory.held_item() {
+                    Some(stack) => (
+                        self.world
+                            .item_map
+                            .by_vanilla_id(stack.id, stack.damage)
+                            .and_then(|item| item.tool()),
+                        stack.enchantment_level(32, "efficiency"),
+                    ),
+                    None => (None, 0),
+                }
+            };
+            self.digging = Some(digging::Digging::new(pos, face, bl, tool, efficiency));
+        }
+    }
+
+    /// Removes the block locally, the server will send it back if
+    /// the break wasn't allowed.
+    fn break_block(&mut self, pos: Position) {
+        self.particles.push(particle::Event::BlockBreak {
+            position: pos,
+            block: self.world.get_block(pos),
+        });
+        self.world.set_block(pos, block::Air {});
+        self.dig_delay = digging::BREAK_DELAY;
+    }
+
+    fn write_digging_packet(
+        &mut self,
+        status: digging::Status,
+        location: Position,
+        face: Direction,
+    ) {
+        let face = face.index() as u8;
+        if self.protocol_version >= 107 {
+            self.write_packet(packet::play::serverbound::PlayerDigging {
+                status: protocol::VarInt(status as i32),
+                location,
+                face,
+            });
+        } else if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::PlayerDigging_u8 {
+                status: status as u8,
+                location,
+                face,
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::PlayerDigging_u8_u8y {
+                status: status as u8,
+                x: location.x,
+                y: location.y as u8,
+                z: location.z,
+                face,
+            });
+        }
+    }
+
+    fn send_inventory_actions(&mut self) {
+        if !self.is_connected() {
+            return;
+        }
+        let actions = self.inventory.write().unwrap().take_actions();
+        for action in actions {
+            match action {
+                inventory::Action::Click {
+                    window_id,
+                    slot,
+                    button,
+                    mode,
+                    action_number,
+                    clicked_item,
+                    changed_slots,
+                    carried_item,
+                } => {
+                    let changed_slots = protocol::LenPrefixed::new(
+                        changed_slots
+                            .into_iter()
+                            .map(|(slot, item)| packet::ChangedSlot { slot, item })
+                            .collect(),
+                    );
+                    if self.protocol_version >= 756 {
+                        let state_id = self.inventory.read().unwrap().state_id();
+                        self.write_packet(packet::play::serverbound::ClickWindow_State {
+                            id: window_id,
+                            state_id: protocol::VarInt(state_id),
+                            slot,
+                            button,
+                            mode: protocol::VarInt(mode as i32),
+                            changed_slots,
+                            carried_item,
+                        });
+                    } else if self.protocol_version >= 755 {
+                        self.write_packet(packet::play::serverbound::ClickWindow_Slots {
+                            id: window_id,
+                            slot,
+                            button,
+                            mode: protocol::VarInt(mode as i32),
+                            changed_slots,
+                            carried_item,
+                        });
+                    } else if self.protocol_version >= 107 {
+                        self.write_packet(packet::play::serverbound::ClickWindow {
+                            id: window_id,
+                            slot,
+                            button,
+                            action_number,
+                            mode: protocol::VarInt(mode as i32),
+                            clicked_item,
+                        });
+                    } else {
+                        self.write_packet(packet::play::serverbound::ClickWindow_u8 {
+                            id: window_id,
+                            slot,
+                            button,
+                            action_number,
+                            mode,
+                            clicked_item,
+                        });
+                    }
+                }
+                inventory::Action::Rollback {
+                    window_id,
+                    action_number,
+                } => {
+                    self.write_packet(packet::play::serverbound::ConfirmTransactionServerbound {
+                        id: window_id,
+                        action_number: action_number as i16,
+                        accepted: false,
+                    });
+                }
+                inventory::Action::Close { window_id } => {
+                    self.write_packet(packet::play::serverbound::CloseWindow { id: window_id });
+                }
+            }
+        }
+    }
+
+    /// Changes the selected hotbar slot and tells the server about it
+    pub fn select_hotbar_slot(&mut self, slot: u8) {
+        if !self.is_connected() || slot >= inventory::HOTBAR_SIZE {
+            return;
+        }
+        {
+            let mut inventory = self.inventory.write().unwrap();
+            if inventory.held_slot() == slot {
+                return;
+            }
+            inventory.set_held_slot(slot);
+        }
+        self.write_packet(packet::play::serverbound::HeldItemChange { slot: slot as i16 });
+    }
+
+    /// Moves the hotbar selection by one slot in the direction of the
+    /// scroll, wrapping around at either end.
+    pub fn scroll_hotbar(&mut self, y: f64) {
+        let offset = if y > 0.0 {
+            inventory::HOTBAR_SIZE - 1
+        } else if y < 0.0 {
+            1
+        } else {
+            return;
+        };
+        let held_slot = self.inventory.read().unwrap().held_slot();
+        self.select_hotbar_slot((held_slot + offset) % inventory::HOTBAR_SIZE);
+    }
+
     pub fn on_right_click(&mut self, renderer: &mut render::Renderer) {
-        use crate::shared::Direction;
+        if let (Some(_), Some((id, at))) = (self.player, self.target_entity) {
+            // Like vanilla, the exact spot is sent first for entities
+            // like armor stands
+            if self.protocol_version >= 47 {
+                self.write_use_entity(id, UseEntityType::InteractAt(at));
+            }
+            self.write_use_entity(id, UseEntityType::Interact);
+            return;
+        }
         if self.player.is_some() {
             if let Some((pos, _, face, at)) = target::trace_ray(
                 &self.world,
@@ -797,6 +1207,8 @@ impl Server {
                 renderer.view_vector.cast().unwrap(),
                 target::test_block,
             ) {
+                // Before 1.9 the held item is sent along with the placement
+                let held_item = self.inventory.read().unwrap().held_item().cloned();
                 if self.protocol_version >= 477 {
                     self.write_packet(
                         packet::play::serverbound::PlayerBlockPlacement_insideblock {
@@ -863,7 +1275,7 @@ impl Server {
                             Direction::East => 5,
                             _ => unreachable!(),
                         },
-                        hand: None,
+                        hand: held_item,
                         cursor_x: (at.x * 16.0) as u8,
                         cursor_y: (at.y * 16.0) as u8,
                         cursor_z: (at.z * 16.0) as u8,
@@ -873,7 +1285,7 @@ impl Server {
                         packet::play::serverbound::PlayerBlockPlacement_u8_Item_u8y {
                             x: pos.x,
                             y: pos.y as u8,
-                            z: pos.x,
+                            z: pos.z,
                             face: match face {
                                 Direction::Down => 0,
                                 Direction::Up => 1,
@@ -883,7 +1295,7 @@ impl Server {
                                 Direction::East => 5,
                                 _ => unreachable!(),
                             },
-                            hand: None,
+                            hand: held_item,
                             cursor_x: (at.x * 16.0) as u8,
                             cursor_y: (at.y * 16.0) as u8,
                             cursor_z: (at.z * 16.0) as u8,
@@ -895,7 +1307,22 @@ impl Server {
     }
 
     pub fn write_packet<T: protocol::PacketType>(&mut self, p: T) {
-        let _ = self.conn.as_mut().unwrap().write_packet(p); // TODO handle errors
+        let result = match self.conn.as_mut() {
+            Some(conn) => conn.write_packet(p),
+            None => return,
+        };
+        if let Err(err) = result {
+            self.network_error(err);
+        }
+    }
+
+    /// Disconnects after the connection failed, showing the error
+    fn network_error(&mut self, err: protocol::Error) {
+        if self.conn.is_none() {
+            return;
+        }
+        error!("Disconnected by a network error: {}", err);
+        self.disconnect(Some(error_reason(err)));
     }
 
     fn on_keep_alive_i64(
@@ -951,13 +1378,19 @@ impl Server {
             "REGISTER" => {}   // TODO
             "UNREGISTER" => {} // TODO
             "FML|HS" => {
-                let msg = crate::protocol::Serializable::read_from(&mut std::io::Cursor::new(data))
-                    .unwrap();
+                let msg =
+                    match crate::protocol::Serializable::read_from(&mut std::io::Cursor::new(data))
+                    {
+                        Ok(msg) => msg,
+                        Err(err) => {
+                            warn!("Failed to read FML|HS plugin message: {}", err);
+                            return;
+                        }
+                    };
                 //debug!("FML|HS msg={:?}", msg);
 
                 use forge::FmlHs::*;
-                use forge::Phase::*;
-                match msg {
+                match &msg {
                     ServerHello {
                         fml_protocol_version,
                         override_dimension,
@@ -966,32 +1399,13 @@ impl Server {
                             "Received FML|HS ServerHello {} {:?}",
                             fml_protocol_version, override_dimension
                         );
-
-                        self.write_plugin_message("REGISTER", b"FML|HS\0FML\0FML|MP\0FML\0FORGE");
-                        self.write_fmlhs_plugin_message(&ClientHello {
-                            fml_protocol_version,
-                        });
-                        // Send stashed mods list received from ping packet, client matching server
-                        let mods = crate::protocol::LenPrefixed::<
-                            crate::protocol::VarInt,
-                            forge::ForgeMod,
-                        >::new(self.forge_mods.clone());
-                        self.write_fmlhs_plugin_message(&ModList { mods });
                     }
                     ModList { mods } => {
                         debug!("Received FML|HS ModList: {:?}", mods);
-
-                        self.write_fmlhs_plugin_message(&HandshakeAck {
-                            phase: WaitingServerData,
-                        });
                     }
-                    ModIdData {
-                        mappings,
-                        block_substitutions: _,
-                        item_substitutions: _,
-                    } => {
+                    ModIdData { mappings, .. } => {
                         debug!("Received FML|HS ModIdData");
-                        for m in mappings.data {
+                        for m in &mappings.data {
                             let (namespace, name) = m.name.split_at(1);
                             if namespace == protocol::forge::BLOCK_NAMESPACE {
                                 self.world
@@ -999,68 +1413,42 @@ impl Server {
                                     .insert(m.id.0 as usize, name.to_string());
                             }
                         }
-                        self.write_fmlhs_plugin_message(&HandshakeAck {
-                            phase: WaitingServerComplete,
-                        });
                     }
-                    RegistryData {
-                        has_more,
-                        name,
-                        ids,
-                        substitutions: _,
-                        dummies: _,
-                    } => {
+                    RegistryData { name, ids, .. } => {
                         debug!("Received FML|HS RegistryData for {}", name);
                         if name == "minecraft:blocks" {
-                            for m in ids.data {
-                                self.world.modded_block_ids.insert(m.id.0 as usize, m.name);
+                            for m in &ids.data {
+                                self.world
+                                    .modded_block_ids
+                                    .insert(m.id.0 as usize, m.name.clone());
                             }
                         }
-                        if !has_more {
-                            self.write_fmlhs_plugin_message(&HandshakeAck {
-                                phase: WaitingServerComplete,
-                            });
-                        }
                     }
-                    HandshakeAck { phase } => match phase {
-                        WaitingCAck => {
-                            self.write_fmlhs_plugin_message(&HandshakeAck {
-                                phase: PendingComplete,
-                            });
-                        }
-                        Complete => {
-                            debug!("FML|HS handshake complete!");
-                        }
-                        _ => unimplemented!(),
-                    },
                     _ => (),
                 }
+
+                let result = match self.conn.as_mut() {
+                    Some(conn) => conn.answer_fmlhs(&msg, &self.forge_mods),
+                    None => return,
+                };
+                if let Err(err) = result {
+                    self.network_error(err);
+                }
             }
             _ => (),
         }
     }
 
-    // TODO: remove wrappers and directly call on Conn
-    fn write_fmlhs_plugin_message(&mut self, msg: &forge::FmlHs) {
-        let _ = self.conn.as_mut().unwrap().write_fmlhs_plugin_message(msg); // TODO handle errors
-    }
-
-    fn write_plugin_message(&mut self, channel: &str, data: &[u8]) {
-        let _ = self
-            .conn
-            .as_mut()
-            .unwrap()
-            .write_plugin_message(channel, data); // TODO handle errors
-    }
-
     fn on_game_join_worldnames_ishard(
         &mut self,
         join: packet::play::clientbound::JoinGame_WorldNames_IsHard,
     ) {
+        self.server_view_distance = Some(join.view_distance.0);
         self.on_game_join(join.gamemode, join.entity_id)
     }
 
     fn on_game_join_worldnames(&mut self, join: packet::play::clientbound::JoinGame_WorldNames) {
+        self.server_view_distance = Some(join.view_distance.0);
         self.on_game_join(join.gamemode, join.entity_id)
     }
 
@@ -1068,6 +1456,7 @@ impl Server {
         &mut self,
         join: packet::play::clientbound::JoinGame_HashedSeed_Respawn,
     ) {
+        self.server_view_distance = Some(join.view_distance.0);
         self.on_game_join(join.gamemode, join.entity_id)
     }
 
@@ -1075,6 +1464,7 @@ impl Server {
         &mut self,
         join: packet::play::clientbound::JoinGame_i32_ViewDistance,
     ) {
+        self.server_view_distance = Some(join.view_distance.0);
         self.on_game_join(join.gamemode, join.entity_id)
     }
 
@@ -1112,6 +1502,7 @@ impl Server {
 
         self.entity_map.insert(entity_id, player);
         self.player = Some(player);
+        self.player_entity_id = Some(entity_id);
 
         // Let the server know who we are
         let brand = plugin_messages::Brand {
@@ -1123,38 +1514,431 @@ impl Server {
         } else {
             self.write_packet(brand.into_message17());
         }
+        self.send_client_settings();
     }
 
     fn on_respawn_hashedseed(&mut self, respawn: packet::play::clientbound::Respawn_HashedSeed) {
         self.respawn(respawn.gamemode)
     }
 
-    fn on_respawn_gamemode(&mut self, respawn: packet::play::clientbound::Respawn_Gamemode) {
-        self.respawn(respawn.gamemode)
+    fn on_respawn_gamemode(&mut self, respawn: packet::play::clientbound::Respawn_Gamemode) {
+        self.respawn(respawn.gamemode)
+    }
+
+    fn on_respawn_worldname(&mut self, respawn: packet::play::clientbound::Respawn_WorldName) {
+        self.respawn(respawn.gamemode)
+    }
+
+    fn on_respawn_nbt(&mut self, respawn: packet::play::clientbound::Respawn_NBT) {
+        self.respawn(respawn.gamemode)
+    }
+
+    fn respawn(&mut self, gamemode_u8: u8) {
+        self.world = world::World::new(self.protocol_version);
+        self.digging = None;
+        self.status.respawned();
+        let gamemode = Gamemode::from_int((gamemode_u8 & 0x7) as i32);
+
+        if let Some(player) = self.player {
+            *self
+                .entities
+                .get_component_mut(player, self.gamemode)
+                .unwrap() = gamemode;
+            // TODO: Temp
+            self.entities
+                .get_component_mut(player, self.player_movement)
+                .unwrap()
+                .flying = gamemode.can_fly();
+        }
+    }
+
+    fn on_named_sound_effect(&mut self, sound: packet::play::clientbound::NamedSoundEffect) {
+        self.on_sound(
+            sound.name,
+            sound.category.0,
+            (sound.x, sound.y, sound.z),
+            sound.volume,
+            sound.pitch,
+        )
+    }
+
+    fn on_named_sound_effect_u8(&mut self, sound: packet::play::clientbound::NamedSoundEffect_u8) {
+        self.on_sound(
+            sound.name,
+            sound.category.0,
+            (sound.x, sound.y, sound.z),
+            sound.volume,
+            f32::from(sound.pitch) / 63.0,
+        )
+    }
+
+    fn on_named_sound_effect_u8_nocategory(
+        &mut self,
+        sound: packet::play::clientbound::NamedSoundEffect_u8_NoCategory,
+    ) {
+        self.on_sound(
+            sound.name,
+            0,
+            (sound.x, sound.y, sound.z),
+            sound.volume,
+            f32::from(sound.pitch) / 63.0,
+        )
+    }
+
+    fn on_sound_effect(&mut self, sound: packet::play::clientbound::SoundEffect) {
+        let name = match self.sound_name(sound.name.0) {
+            Some(name) => name,
+            None => return,
+        };
+        self.on_sound(
+            name,
+            sound.category.0,
+            (sound.x, sound.y, sound.z),
+            sound.volume,
+            sound.pitch,
+        )
+    }
+
+    fn on_sound_effect_u8(&mut self, sound: packet::play::clientbound::SoundEffect_u8) {
+        let name = match self.sound_name(sound.name.0) {
+            Some(name) => name,
+            None => return,
+        };
+        self.on_sound(
+            name,
+            sound.category.0,
+            (sound.x, sound.y, sound.z),
+            sound.volume,
+            f32::from(sound.pitch) / 63.0,
+        )
+    }
+
+    /// Looks up a sound event by its id in the sound event registry
+    fn sound_name(&self, id: i32) -> Option<String> {
+        match audio::name_by_id(id, self.protocol_version) {
+            Some(name) => Some(name.to_owned()),
+            None => {
+                debug!("Unknown sound id {}", id);
+                None
+            }
+        }
+    }
+
+    /// Queues a sound at a position sent as fixed point, 1/8 of a block
+    fn on_sound(
+        &mut self,
+        name: String,
+        category: i32,
+        (x, y, z): (i32, i32, i32),
+        volume: f32,
+        pitch: f32,
+    ) {
+        self.sounds.push(audio::Command::Play {
+            name,
+            category: audio::Category::from_id(category),
+            position: Some(cgmath::Vector3::new(
+                f64::from(x) / 8.0,
+                f64::from(y) / 8.0,
+                f64::from(z) / 8.0,
+            )),
+            volume,
+            pitch,
+        });
+    }
+
+    fn on_entity_sound_effect(&mut self, sound: packet::play::clientbound::EntitySoundEffect) {
+        let position = match self.entity_map.get(&sound.entity_id.0) {
+            Some(entity) => match self.entities.get_component(*entity, self.position) {
+                Some(position) => position.position,
+                None => return,
+            },
+            None => return,
+        };
+        let name = match self.sound_name(sound.sound_id.0) {
+            Some(name) => name,
+            None => return,
+        };
+        self.sounds.push(audio::Command::Play {
+            name,
+            category: audio::Category::from_id(sound.sound_category.0),
+            position: Some(position),
+            volume: sound.volume,
+            pitch: sound.pitch,
+        });
+    }
+
+    fn on_stop_sound(&mut self, stop: packet::play::clientbound::StopSound) {
+        self.sounds.push(audio::Command::Stop {
+            category: stop.source.map(|v| audio::Category::from_id(v.0)),
+            name: stop.sound,
+        });
+    }
+
+    fn on_particle_f64(&mut self, p: packet::play::clientbound::Particle_f64) {
+        let kind = self.particle_kind(
+            p.particle_id,
+            Some(p.block_state.0),
+            Some([p.red, p.green, p.blue, p.scale]),
+        );
+        self.on_particle(
+            kind,
+            cgmath::Vector3::new(p.x, p.y, p.z),
+            (p.offset_x, p.offset_y, p.offset_z),
+            p.speed,
+            p.count,
+        );
+    }
+
+    fn on_particle_data(&mut self, p: packet::play::clientbound::Particle_Data) {
+        let kind = self.particle_kind(
+            p.particle_id,
+            Some(p.block_state.0),
+            Some([p.red, p.green, p.blue, p.scale]),
+        );
+        self.on_particle(
+            kind,
+            cgmath::Vector3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z)),
+            (p.offset_x, p.offset_y, p.offset_z),
+            p.speed,
+            p.count,
+        );
+    }
+
+    fn on_particle_data13(&mut self, p: packet::play::clientbound::Particle_Data13) {
+        let kind = self.particle_kind(
+            p.particle_id,
+            Some(p.block_state.0),
+            Some([p.red, p.green, p.blue, p.scale]),
+        );
+        self.on_particle(
+            kind,
+            cgmath::Vector3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z)),
+            (p.offset_x, p.offset_y, p.offset_z),
+            p.speed,
+            p.count,
+        );
+    }
+
+    fn on_particle_varintarray(&mut self, p: packet::play::clientbound::Particle_VarIntArray) {
+        // Block particles store the block as `id | meta << 12`
+        let block_state = ((p.data1.0 & 0xFFF) << 4) | ((p.data1.0 >> 12) & 0xF);
+        let kind = self.particle_kind(p.particle_id, Some(block_state), None);
+        self.on_particle(
+            kind,
+            cgmath::Vector3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z)),
+            (p.offset_x, p.offset_y, p.offset_z),
+            p.speed,
+            p.count,
+        );
+    }
+
+    fn on_particle_named(&mut self, p: packet::play::clientbound::Particle_Named) {
+        // Block particles include the block in the name, e.g.
+        // `blockcrack_1_0` for stone.
+        let mut parts = p.particle_id.split('_');
+        let name = parts.next().unwrap_or("");
+        let id = parts.next().and_then(|v| v.parse::<i32>().ok());
+        let meta = parts
+            .next()
+            .and_then(|v| v.parse::<i32>().ok())
+            .unwrap_or(0);
+        let block = id.map(|id| self.block_from_state((id << 4) | (meta & 0xF)));
+        let kind = particle::Kind::from_name(name, block);
+        self.on_particle(
+            kind,
+            cgmath::Vector3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z)),
+            (p.offset_x, p.offset_y, p.offset_z),
+            p.speed,
+            p.count,
+        );
+    }
+
+    /// Works out the kind of a particle from its id in the particle
+    /// registry and the extra data some particles have.
+    fn particle_kind(
+        &self,
+        id: i32,
+        block_state: Option<i32>,
+        dust: Option<[f32; 4]>,
+    ) -> Option<particle::Kind> {
+        let name = match particle::name_by_id(id, self.protocol_version) {
+            Some(name) => name,
+            None => {
+                debug!("Unknown particle id {}", id);
+                return None;
+            }
+        };
+        let block = block_state.map(|state| self.block_from_state(state));
+        match (particle::Kind::from_name(name, block), dust) {
+            // A scale of zero means the packet didn't include the colour
+            (Some(particle::Kind::Dust { .. }), Some([red, green, blue, scale])) if scale > 0.0 => {
+                Some(particle::Kind::Dust {
+                    red,
+                    green,
+                    blue,
+                    scale,
+                })
+            }
+            (kind, _) => kind,
+        }
+    }
+
+    fn on_particle(
+        &mut self,
+        kind: Option<particle::Kind>,
+        position: cgmath::Vector3<f64>,
+        (offset_x, offset_y, offset_z): (f32, f32, f32),
+        speed: f32,
+        count: i32,
+    ) {
+        if let Some(kind) = kind {
+            self.particles.push(particle::Event::Spawn {
+                kind,
+                position,
+                offset: cgmath::Vector3::new(
+                    f64::from(offset_x),
+                    f64::from(offset_y),
+                    f64::from(offset_z),
+                ),
+                speed: f64::from(speed),
+                count,
+            });
+        }
+    }
+
+    fn block_from_state(&self, state: i32) -> block::Block {
+        self.world
+            .id_map
+            .by_vanilla_id(state as usize, &self.world.modded_block_ids)
+    }
+
+    fn on_effect(&mut self, effect: packet::play::clientbound::Effect) {
+        self.on_world_event(effect.effect_id, effect.location, effect.data);
+    }
+
+    fn on_effect_u8y(&mut self, effect: packet::play::clientbound::Effect_u8y) {
+        self.on_world_event(
+            effect.effect_id,
+            Position::new(effect.x, i32::from(effect.y), effect.z),
+            effect.data,
+        );
+    }
+
+    /// Spawns the particles for world events. The sounds some events
+    /// play aren't handled.
+    fn on_world_event(&mut self, id: i32, location: Position, data: i32) {
+        let centre = cgmath::Vector3::new(
+            f64::from(location.x) + 0.5,
+            f64::from(location.y) + 0.5,
+            f64::from(location.z) + 0.5,
+        );
+        let spawn = |kind, offset: f64, count| particle::Event::Spawn {
+            kind,
+            position: centre,
+            offset: cgmath::Vector3::new(offset, offset, offset),
+            speed: 0.0,
+            count,
+        };
+        match id {
+            // Smoke from dispensers
+            2000 => self.particles.push(spawn(particle::Kind::Smoke, 0.2, 10)),
+            // Block broken
+            2001 => {
+                // Before 1.13 blocks are sent as `id | meta << 12`
+                let state = if self.protocol_version >= 404 {
+                    data
+                } else {
+                    ((data & 0xFFF) << 4) | ((data >> 12) & 0xF)
+                };
+                self.particles.push(particle::Event::BlockBreak {
+                    position: location,
+                    block: self.block_from_state(state),
+                });
+            }
+            // Splash potions, coloured by the potion since 1.9
+            2002 | 2007 => {
+                let colour = if self.protocol_version >= 107 {
+                    Some((
+                        ((data >> 16) & 0xFF) as f32 / 255.0,
+                        ((data >> 8) & 0xFF) as f32 / 255.0,
+                        (data & 0xFF) as f32 / 255.0,
+                    ))
+                } else {
+                    None
+                };
+                self.particles
+                    .push(spawn(particle::Kind::EntitySpell { colour }, 0.5, 100));
+            }
+            // Mob spawner spawning a mob
+            2004 => {
+                self.particles.push(spawn(particle::Kind::Smoke, 0.4, 20));
+                self.particles.push(spawn(particle::Kind::Flame, 0.4, 20));
+            }
+            // Bone meal, `data` is the number of particles
+            2005 => self.particles.push(spawn(
+                particle::Kind::HappyVillager,
+                0.5,
+                if data == 0 { 15 } else { data },
+            )),
+            _ => {}
+        }
+    }
+
+    fn on_update_health(&mut self, health: packet::play::clientbound::UpdateHealth) {
+        self.status
+            .set_health(health.health, health.food.0, health.food_saturation);
+    }
+
+    fn on_update_health_u16(&mut self, health: packet::play::clientbound::UpdateHealth_u16) {
+        self.status
+            .set_health(health.health, health.food as i32, health.food_saturation);
+    }
+
+    fn on_set_experience(&mut self, experience: packet::play::clientbound::SetExperience) {
+        self.status.set_experience(
+            experience.experience_bar,
+            experience.level.0,
+            experience.total_experience.0,
+        );
+    }
+
+    fn on_set_experience_i16(&mut self, experience: packet::play::clientbound::SetExperience_i16) {
+        self.status.set_experience(
+            experience.experience_bar,
+            experience.level as i32,
+            experience.total_experience as i32,
+        );
     }
 
-    fn on_respawn_worldname(&mut self, respawn: packet::play::clientbound::Respawn_WorldName) {
-        self.respawn(respawn.gamemode)
+    fn on_combat_event(&mut self, combat: packet::play::clientbound::CombatEvent) {
+        // Only the entity dead event (2) is of interest
+        if let (Some(player_id), Some(message)) = (combat.player_id, combat.message) {
+            self.on_player_death(player_id.0, message);
+        }
     }
 
-    fn on_respawn_nbt(&mut self, respawn: packet::play::clientbound::Respawn_NBT) {
-        self.respawn(respawn.gamemode)
+    fn on_death_combat_event(&mut self, death: packet::play::clientbound::DeathCombatEvent) {
+        self.on_player_death(death.player_id.0, death.message);
     }
 
-    fn respawn(&mut self, gamemode_u8: u8) {
-        self.world = world::World::new(self.protocol_version);
-        let gamemode = Gamemode::from_int((gamemode_u8 & 0x7) as i32);
+    fn on_player_death(&mut self, player_id: i32, message: format::Component) {
+        if self.player.is_some() && self.entity_map.get(&player_id).copied() == self.player {
+            self.status.died(message);
+        }
+    }
 
-        if let Some(player) = self.player {
-            *self
-                .entities
-                .get_component_mut(player, self.gamemode)
-                .unwrap() = gamemode;
-            // TODO: Temp
-            self.entities
-                .get_component_mut(player, self.player_movement)
-                .unwrap()
-                .flying = gamemode.can_fly();
+    /// Asks the server to respawn the player after dying
+    pub fn request_respawn(&mut self) {
+        if !self.is_connected() {
+            return;
+        }
+        // Action 0 is perform respawn in every version
+        if self.protocol_version >= 47 {
+            self.write_packet(packet::play::serverbound::ClientStatus {
+                action_id: protocol::VarInt(0),
+            });
+        } else {
+            self.write_packet(packet::play::serverbound::ClientStatus_u8 { action_id: 0 });
         }
     }
 
@@ -1325,6 +2109,67 @@ impl Server {
         }
     }
 
+    fn on_entity_velocity(&mut self, velocity: packet::play::clientbound::EntityVelocity) {
+        self.on_velocity(
+            velocity.entity_id.0,
+            velocity.velocity_x,
+            velocity.velocity_y,
+            velocity.velocity_z,
+        )
+    }
+
+    fn on_entity_velocity_i32(&mut self, velocity: packet::play::clientbound::EntityVelocity_i32) {
+        self.on_velocity(
+            velocity.entity_id,
+            velocity.velocity_x,
+            velocity.velocity_y,
+            velocity.velocity_z,
+        )
+    }
+
+    fn on_velocity(&mut self, entity_id: i32, x: i16, y: i16, z: i16) {
+        if let Some(entity) = self.entity_map.get(&entity_id) {
+            if let Some(velocity) = self.entities.get_component_mut(*entity, self.velocity) {
+                // Sent in 1/8000 of a block per tick
+                velocity.velocity = cgmath::Vector3::new(
+                    f64::from(x) / 8000.0,
+                    f64::from(y) / 8000.0,
+                    f64::from(z) / 8000.0,
+                );
+            }
+        }
+    }
+
+    fn on_explosion(&mut self, explosion: packet::play::clientbound::Explosion) {
+        self.particles.push(particle::Event::Spawn {
+            kind: if explosion.radius >= 2.0 {
+                particle::Kind::ExplosionEmitter
+            } else {
+                particle::Kind::Explosion
+            },
+            position: cgmath::Vector3::new(
+                f64::from(explosion.x),
+                f64::from(explosion.y),
+                f64::from(explosion.z),
+            ),
+            offset: cgmath::Vector3::new(0.0, 0.0, 0.0),
+            speed: 0.0,
+            count: 1,
+        });
+        for pos in explosion_blocks(&explosion) {
+            self.world.set_block(pos, block::Air {});
+        }
+        if let Some(player) = self.player {
+            if let Some(velocity) = self.entities.get_component_mut(player, self.velocity) {
+                velocity.velocity += cgmath::Vector3::new(
+                    f64::from(explosion.velocity_x),
+                    f64::from(explosion.velocity_y),
+                    f64::from(explosion.velocity_z),
+                );
+            }
+        }
+    }
+
     fn on_entity_look(&mut self, entity_id: i32, yaw: f64, pitch: f64) {
         use std::f64::consts::PI;
         if let Some(entity) = self.entity_map.get(&entity_id) {
@@ -1417,6 +2262,55 @@ impl Server {
         }
     }
 
+    /// Spawns the entity from any of the packets for objects, mobs,
+    /// experience orbs, lightning and paintings
+    fn on_entity_spawn<S: Into<spawn::EntitySpawn>>(&mut self, spawn: S) {
+        use std::f64::consts::PI;
+        let spawn::EntitySpawn {
+            entity_id,
+            kind,
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+            metadata,
+        } = spawn.into();
+        let ty = kind.entity_type(self.protocol_version);
+        if let Some(entity) = self.entity_map.remove(&entity_id) {
+            self.entities.remove_entity(entity);
+        }
+        let entity = entity::generic::create(&mut self.entities, ty);
+        let position = self
+            .entities
+            .get_component_mut(entity, self.position)
+            .unwrap();
+        position.position = cgmath::Vector3::new(x, y, z);
+        position.last_position = position.position;
+        let target_position = self
+            .entities
+            .get_component_mut(entity, self.target_position)
+            .unwrap();
+        target_position.position = cgmath::Vector3::new(x, y, z);
+        let rotation = self
+            .entities
+            .get_component_mut(entity, self.rotation)
+            .unwrap();
+        rotation.yaw = -(yaw / 256.0) * PI * 2.0;
+        rotation.pitch = -(pitch / 256.0) * PI * 2.0;
+        let (yaw, pitch) = (rotation.yaw, rotation.pitch);
+        let target_rotation = self
+            .entities
+            .get_component_mut(entity, self.target_rotation)
+            .unwrap();
+        target_rotation.yaw = yaw;
+        target_rotation.pitch = pitch;
+        self.entity_map.insert(entity_id, entity);
+        if let Some(metadata) = metadata {
+            self.apply_entity_metadata(entity_id, &metadata);
+        }
+    }
+
     fn on_player_spawn_f64_nometa(
         &mut self,
         spawn: packet::play::clientbound::SpawnPlayer_f64_NoMeta,
@@ -1441,7 +2335,8 @@ impl Server {
             spawn.z,
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.apply_entity_metadata(spawn.entity_id.0, &spawn.metadata);
     }
 
     fn on_player_spawn_i32(&mut self, spawn: packet::play::clientbound::SpawnPlayer_i32) {
@@ -1453,7 +2348,8 @@ impl Server {
             f64::from(spawn.z),
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.apply_entity_metadata(spawn.entity_id.0, &spawn.metadata);
     }
 
     fn on_player_spawn_i32_helditem(
@@ -1468,7 +2364,9 @@ impl Server {
             f64::from(spawn.z),
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.apply_entity_metadata(spawn.entity_id.0, &spawn.metadata);
+        self.set_held_item(spawn.entity_id.0, spawn.current_item);
     }
 
     fn on_player_spawn_i32_helditem_string(
@@ -1476,10 +2374,16 @@ impl Server {
         spawn: packet::play::clientbound::SpawnPlayer_i32_HeldItem_String,
     ) {
         // 1.7.10: populate the player list here, since we only now know the UUID
-        let uuid = protocol::UUID::from_str(&spawn.uuid).unwrap();
+        let uuid = match protocol::UUID::from_str(&spawn.uuid) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                warn!("Ignoring player spawn with invalid uuid {:?}", spawn.uuid);
+                return;
+            }
+        };
         self.players.entry(uuid.clone()).or_insert(PlayerInfo {
             name: spawn.name.clone(),
-            uuid,
+            uuid: uuid.clone(),
             skin_url: None,
 
             display_name: None,
@@ -1489,13 +2393,31 @@ impl Server {
 
         self.on_player_spawn(
             spawn.entity_id.0,
-            protocol::UUID::from_str(&spawn.uuid).unwrap(),
+            uuid,
             f64::from(spawn.x),
             f64::from(spawn.y),
             f64::from(spawn.z),
             spawn.yaw as f64,
             spawn.pitch as f64,
-        )
+        );
+        self.apply_entity_metadata(spawn.entity_id.0, &spawn.metadata);
+        self.set_held_item(spawn.entity_id.0, spawn.current_item);
+    }
+
+    /// Shows the item a player spawned before 1.9 is holding, these only
+    /// have an id
+    fn set_held_item(&mut self, entity_id: i32, current_item: u16) {
+        let stack = if current_item == 0 {
+            None
+        } else {
+            Some(item::Stack {
+                id: current_item as isize,
+                count: 1,
+                damage: Some(0),
+                tag: None,
+            })
+        };
+        self.set_entity_equipment(entity_id, Some(EquipmentSlot::MainHand), stack);
     }
 
     fn on_player_spawn(
@@ -1552,6 +2474,118 @@ impl Server {
         self.entity_map.insert(entity_id, entity);
     }
 
+    fn on_entity_metadata(&mut self, entity_metadata: packet::play::clientbound::EntityMetadata) {
+        self.apply_entity_metadata(entity_metadata.entity_id.0, &entity_metadata.metadata);
+    }
+
+    fn on_entity_metadata_i32(
+        &mut self,
+        entity_metadata: packet::play::clientbound::EntityMetadata_i32,
+    ) {
+        self.apply_entity_metadata(entity_metadata.entity_id, &entity_metadata.metadata);
+    }
+
+    fn on_animation(&mut self, animation: packet::play::clientbound::Animation) {
+        let off_hand = match animation.animation_id {
+            0 => false,
+            3 => true,
+            // Hurting, leaving a bed and critical hits aren't shown
+            _ => return,
+        };
+        let entity = match self.entity_map.get(&animation.entity_id.0) {
+            Some(entity) => *entity,
+            None => return,
+        };
+        if let Some(model) = self
+            .entities
+            .get_component_mut_direct::<entity::player::PlayerModel>(entity)
+        {
+            model.swing_arm(off_hand);
+        }
+    }
+
+    fn on_entity_equipment_array(
+        &mut self,
+        entity_equipment: packet::play::clientbound::EntityEquipment_Array,
+    ) {
+        for equipment in entity_equipment.equipments.equipments {
+            self.set_entity_equipment(
+                entity_equipment.entity_id.0,
+                EquipmentSlot::from_id(i32::from(equipment.slot)),
+                equipment.item,
+            );
+        }
+    }
+
+    fn on_entity_equipment_varint(
+        &mut self,
+        entity_equipment: packet::play::clientbound::EntityEquipment_VarInt,
+    ) {
+        self.set_entity_equipment(
+            entity_equipment.entity_id.0,
+            EquipmentSlot::from_id(entity_equipment.slot.0),
+            entity_equipment.item,
+        );
+    }
+
+    fn on_entity_equipment_u16(
+        &mut self,
+        entity_equipment: packet::play::clientbound::EntityEquipment_u16,
+    ) {
+        self.set_entity_equipment(
+            entity_equipment.entity_id.0,
+            EquipmentSlot::from_legacy_id(i32::from(entity_equipment.slot)),
+            entity_equipment.item,
+        );
+    }
+
+    fn on_entity_equipment_u16_i32(
+        &mut self,
+        entity_equipment: packet::play::clientbound::EntityEquipment_u16_i32,
+    ) {
+        self.set_entity_equipment(
+            entity_equipment.entity_id,
+            EquipmentSlot::from_legacy_id(i32::from(entity_equipment.slot)),
+            entity_equipment.item,
+        );
+    }
+
+    fn set_entity_equipment(
+        &mut self,
+        entity_id: i32,
+        slot: Option<EquipmentSlot>,
+        stack: Option<item::Stack>,
+    ) {
+        let (entity, slot) = match (self.entity_map.get(&entity_id), slot) {
+            (Some(entity), Some(slot)) => (*entity, slot),
+            _ => return,
+        };
+        if let Some(equipment) = self
+            .entities
+            .get_component_mut_direct::<entity::equipment::Equipment>(entity)
+        {
+            equipment.set(slot, stack);
+        }
+    }
+
+    fn apply_entity_metadata(&mut self, entity_id: i32, metadata: &crate::types::Metadata) {
+        let entity = match self.entity_map.get(&entity_id) {
+            Some(entity) => *entity,
+            None => return,
+        };
+        let ty = self
+            .entities
+            .get_component_direct::<EntityType>(entity)
+            .copied()
+            .unwrap_or(EntityType::Player);
+        if let Some(entity_metadata) = self
+            .entities
+            .get_component_mut_direct::<entity::metadata::EntityMetadata>(entity)
+        {
+            entity_metadata.apply(metadata, ty, self.protocol_version);
+        }
+    }
+
     fn on_teleport_player_withconfirm(
         &mut self,
         teleport: packet::play::clientbound::TeleportPlayer_WithConfirm,
@@ -1685,18 +2719,25 @@ impl Server {
                     //8 => // Gateway
                     9 => {
                         // Sign
-                        let line1 = format::Component::from_string(
-                            nbt.1.get("Text1").unwrap().as_str().unwrap(),
-                        );
-                        let line2 = format::Component::from_string(
-                            nbt.1.get("Text2").unwrap().as_str().unwrap(),
-                        );
-                        let line3 = format::Component::from_string(
-                            nbt.1.get("Text3").unwrap().as_str().unwrap(),
-                        );
-                        let line4 = format::Component::from_string(
-                            nbt.1.get("Text4").unwrap().as_str().unwrap(),
-                        );
+                        let tag = nbt.1.as_compound();
+                        let line = |name: &str| {
+                            tag.and_then(|tag| tag.get(name))
+                                .and_then(|line| line.as_str())
+                                .map(format::Component::from_string)
+                        };
+                        let (line1, line2, line3, line4) =
+                            match (line("Text1"), line("Text2"), line("Text3"), line("Text4")) {
+                                (Some(line1), Some(line2), Some(line3), Some(line4)) => {
+                                    (line1, line2, line3, line4)
+                                }
+                                _ => {
+                                    warn!(
+                                        "Ignoring sign at {:?} without text: {:?}",
+                                        block_update.location, nbt
+                                    );
+                                    return;
+                                }
+                            };
                         self.world.add_block_entity_action(
                             world::BlockEntityAction::UpdateSignText(Box::new((
                                 block_update.location,
@@ -1888,26 +2929,58 @@ impl Server {
     }
 
     fn on_servermessage_sender(&mut self, m: packet::play::clientbound::ServerMessage_Sender) {
-        self.on_servermessage(&m.message, Some(m.position), Some(m.sender));
+        self.on_servermessage(
+            &format::Component::from_value(&m.message),
+            Some(m.position),
+            Some(m.sender),
+        );
+    }
+
+    fn on_action_bar(&mut self, m: packet::play::clientbound::ActionBar) {
+        self.chat
+            .add_message(m.message, chat::MessageKind::ActionBar);
     }
 
     fn on_servermessage(
         &mut self,
         message: &format::Component,
-        _position: Option<u8>,
+        position: Option<u8>,
         _sender: Option<protocol::UUID>,
     ) {
         info!("Received chat message: {}", message);
-        self.received_chat_at = Some(Instant::now());
+        self.chat
+            .add_message(message.clone(), chat::MessageKind::from_position(position));
+    }
+
+    pub fn send_chat_message(&mut self, message: &str) {
+        // Servers before 1.11 kick clients sending messages over 100 characters
+        let max_length = if self.protocol_version >= 315 {
+            256
+        } else {
+            100
+        };
+        self.write_packet(packet::play::serverbound::ChatMessage {
+            message: message.chars().take(max_length).collect(),
+        });
     }
 
     fn load_block_entities(&mut self, block_entities: Vec<Option<crate::nbt::NamedTag>>) {
         for block_entity in block_entities.into_iter().flatten() {
-            let x = block_entity.1.get("x").unwrap().as_int().unwrap();
-            let y = block_entity.1.get("y").unwrap().as_int().unwrap();
-            let z = block_entity.1.get("z").unwrap().as_int().unwrap();
-            if let Some(tile_id) = block_entity.1.get("id") {
-                let tile_id = tile_id.as_str().unwrap();
+            let tag = block_entity.1.as_compound();
+            let get = |name: &str| tag.and_then(|tag| tag.get(name));
+            let int = |name: &str| get(name).and_then(|v| v.as_int());
+            let (x, y, z) = match (int("x"), int("y"), int("z")) {
+                (Some(x), Some(y), Some(z)) => (x, y, z),
+                _ => {
+                    warn!(
+                        "Ignoring block entity without a position: {:?}",
+                        block_entity
+                    );
+                    continue;
+                }
+            };
+            if let Some(tile_id) = get("id") {
+                let tile_id = tile_id.as_str().unwrap_or_default();
                 let action;
                 match tile_id {
                     // Fake a sign update
@@ -1929,6 +3002,30 @@ impl Server {
         }
     }
 
+    fn on_chunk_data_biomes3d_bitmask(
+        &mut self,
+        chunk_data: packet::play::clientbound::ChunkData_Biomes3D_BitMask,
+    ) {
+        // Chunks are always sent whole since 1.17. Only the sections of
+        // the first 256 blocks fit the world.
+        let new = true;
+        let bitmask = chunk_data
+            .bitmask
+            .data
+            .first()
+            .map_or(0, |mask| mask.0 as u16);
+        self.world
+            .load_chunk115(
+                chunk_data.chunk_x,
+                chunk_data.chunk_z,
+                new,
+                bitmask,
+                chunk_data.data.data,
+            )
+            .unwrap();
+        self.load_block_entities(chunk_data.block_entities.data);
+    }
+
     fn on_chunk_data_biomes3d_varint(
         &mut self,
         chunk_data: packet::play::clientbound::ChunkData_Biomes3D_VarInt,
@@ -2072,11 +3169,137 @@ impl Server {
             .unwrap();
     }
 
+    fn on_window_open(&mut self, window_open: packet::play::clientbound::WindowOpen) {
+        let kind = inventory::WindowKind::from_name(&window_open.ty, window_open.slot_count);
+        self.open_window(window_open.id, kind, Some(window_open.title));
+    }
+
+    fn on_window_open_u8(&mut self, window_open: packet::play::clientbound::WindowOpen_u8) {
+        let kind = inventory::WindowKind::from_legacy_id(window_open.ty, window_open.slot_count);
+        self.open_window(window_open.id, kind, Some(window_open.title));
+    }
+
+    fn on_window_open_varint(&mut self, window_open: packet::play::clientbound::WindowOpen_VarInt) {
+        let kind = inventory::WindowKind::from_registry_id(window_open.ty.0, self.protocol_version);
+        self.open_window(window_open.id.0 as u8, kind, Some(window_open.title));
+    }
+
+    fn on_window_open_horse(&mut self, window_open: packet::play::clientbound::WindowOpenHorse) {
+        let kind = inventory::WindowKind::Generic {
+            size: window_open.number_of_slots.0 as usize,
+        };
+        self.open_window(window_open.window_id, kind, None);
+    }
+
+    fn open_window(
+        &mut self,
+        id: u8,
+        kind: inventory::WindowKind,
+        title: Option<format::Component>,
+    ) {
+        self.inventory
+            .write()
+            .unwrap()
+            .open_window(inventory::Window::new(id, kind, title));
+    }
+
+    fn on_window_close(&mut self, _window_close: packet::play::clientbound::WindowClose) {
+        self.inventory.write().unwrap().close_window();
+    }
+
+    fn on_window_items(&mut self, window_items: packet::play::clientbound::WindowItems) {
+        self.inventory
+            .write()
+            .unwrap()
+            .set_items(window_items.id, window_items.items.data);
+    }
+
+    fn on_window_set_slot(&mut self, set_slot: packet::play::clientbound::WindowSetSlot) {
+        self.inventory
+            .write()
+            .unwrap()
+            .set_slot(set_slot.id, set_slot.property, set_slot.item);
+    }
+
+    fn on_window_items_state(
+        &mut self,
+        window_items: packet::play::clientbound::WindowItems_State,
+    ) {
+        let mut inventory = self.inventory.write().unwrap();
+        inventory.set_state_id(window_items.state_id.0);
+        inventory.set_items(window_items.id, window_items.items.data);
+        inventory.set_cursor(window_items.carried_item);
+    }
+
+    fn on_window_set_slot_state(
+        &mut self,
+        set_slot: packet::play::clientbound::WindowSetSlot_State,
+    ) {
+        let mut inventory = self.inventory.write().unwrap();
+        inventory.set_state_id(set_slot.state_id.0);
+        inventory.set_slot(set_slot.id, set_slot.property, set_slot.item);
+    }
+
+    fn on_window_property(&mut self, property: packet::play::clientbound::WindowProperty) {
+        self.inventory.write().unwrap().set_property(
+            property.id,
+            property.property,
+            property.value,
+        );
+    }
+
+    fn on_confirm_transaction(&mut self, confirm: packet::play::clientbound::ConfirmTransaction) {
+        self.inventory.write().unwrap().confirm_transaction(
+            confirm.id,
+            confirm.action_number as u16,
+            confirm.accepted,
+        );
+    }
+
+    fn on_set_current_hotbar_slot(
+        &mut self,
+        slot: packet::play::clientbound::SetCurrentHotbarSlot,
+    ) {
+        self.inventory.write().unwrap().set_held_slot(slot.slot);
+    }
+
     fn on_chunk_unload(&mut self, chunk_unload: packet::play::clientbound::ChunkUnload) {
         self.world
             .unload_chunk(chunk_unload.x, chunk_unload.z, &mut self.entities);
     }
 
+    fn on_update_view_distance(&mut self, update: packet::play::clientbound::UpdateViewDistance) {
+        self.server_view_distance = Some(update.view_distance.0);
+    }
+
+    /// Drops chunks too far away to be drawn. The server only keeps
+    /// track of chunks within its own view distance so that is kept as
+    /// well, for older servers that don't say what it is chunks are
+    /// only dropped when the server unloads them.
+    fn unload_distant_chunks(&mut self) {
+        let (player, server_view_distance) = match (self.player, self.server_view_distance) {
+            (Some(player), Some(distance)) => (player, distance),
+            _ => return,
+        };
+        let position = self
+            .entities
+            .get_component(player, self.position)
+            .unwrap()
+            .position;
+        // Like vanilla keep a few chunks past the edge
+        let distance = i32::from(self.client_settings.view_distance).max(server_view_distance) + 3;
+        let center = (
+            (position.x.floor() as i32) >> 4,
+            (position.z.floor() as i32) >> 4,
+            distance,
+        );
+        if self.retained_chunks != Some(center) {
+            self.retained_chunks = Some(center);
+            self.world
+                .unload_distant_chunks(center.0, center.1, distance, &mut self.entities);
+        }
+    }
+
     fn on_block_change(&mut self, location: Position, id: i32) {
         self.world.set_block(
             location,
@@ -2100,6 +3323,39 @@ impl Server {
         );
     }
 
+    fn on_block_break_animation(
+        &mut self,
+        animation: packet::play::clientbound::BlockBreakAnimation,
+    ) {
+        self.break_animations
+            .set(animation.entity_id.0, animation.location, animation.stage);
+    }
+
+    fn on_block_break_animation_i32(
+        &mut self,
+        animation: packet::play::clientbound::BlockBreakAnimation_i32,
+    ) {
+        self.break_animations.set(
+            animation.entity_id.0,
+            Position::new(animation.x, animation.y, animation.z),
+            animation.stage,
+        );
+    }
+
+    fn on_acknowledge_player_digging(
+        &mut self,
+        ack: packet::play::clientbound::AcknowledgePlayerDigging,
+    ) {
+        if !ack.successful {
+            // Undo the block broken locally
+            self.on_block_change(ack.location, ack.block.0);
+            if self.digging.as_ref().map(|d| d.position) == Some(ack.location) {
+                self.digging = None;
+                self.dig_delay = digging::BREAK_DELAY;
+            }
+        }
+    }
+
     fn on_multi_block_change_packed(
         &mut self,
         block_change: packet::play::clientbound::MultiBlockChange_Packed,
@@ -2189,3 +3445,92 @@ fn calculate_relative_teleport(flag: TeleportFlag, flags: u8, base: f64, val: f6
         base + val
     }
 }
+
+/// What the player does to an entity with `UseEntity`
+#[derive(Clone, Copy)]
+enum UseEntityType {
+    Interact,
+    Attack,
+    /// Interacts with the spot looked at, relative to the entity's position
+    InteractAt(cgmath::Vector3<f64>),
+}
+
+impl UseEntityType {
+    fn id(self) -> i32 {
+        match self {
+            UseEntityType::Interact => 0,
+            UseEntityType::Attack => 1,
+            UseEntityType::InteractAt(_) => 2,
+        }
+    }
+}
+
+/// The blocks destroyed by an explosion. Records are offsets from the block
+/// containing the centre.
+fn explosion_blocks(
+    explosion: &packet::play::clientbound::Explosion,
+) -> impl Iterator<Item = Position> + '_ {
+    let (x, y, z) = (
+        explosion.x.floor() as i32,
+        explosion.y.floor() as i32,
+        explosion.z.floor() as i32,
+    );
+    explosion.records.data.iter().map(move |record| {
+        Position::new(
+            x + i32::from(record.x),
+            y + i32::from(record.y),
+            z + i32::from(record.z),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::packet::ExplosionRecord;
+
+    fn explosion(x: f32, y: f32, z: f32, records: Vec<ExplosionRecord>) -> Vec<Position> {
+        let explosion = packet::play::clientbound::Explosion {
+            x,
+            y,
+            z,
+            radius: 4.0,
+            records: protocol::LenPrefixed::new(records),
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            velocity_z: 0.0,
+        };
+        explosion_blocks(&explosion).collect()
+    }
+
+    #[test]
+    fn explosion_records() {
+        let records = vec![
+            ExplosionRecord { x: 0, y: 0, z: 0 },
+            ExplosionRecord { x: 1, y: -1, z: 2 },
+            ExplosionRecord { x: -3, y: 2, z: -1 },
+        ];
+        assert_eq!(
+            explosion(10.7, 64.2, 5.5, records),
+            vec![
+                Position::new(10, 64, 5),
+                Position::new(11, 63, 7),
+                Position::new(7, 66, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn explosion_records_negative_centre() {
+        // The centre is floored, not truncated towards zero
+        let records = vec![
+            ExplosionRecord { x: 0, y: 0, z: 0 },
+            ExplosionRecord { x: 1, y: 0, z: -1 },
+        ];
+        assert_eq!(
+            explosion(-0.5, 12.0, -10.25, records),
+            vec![Position::new(-1, 12, -11), Position::new(0, 12, -12)]
+        );
+        assert!(explosion(-0.5, 12.0, -10.25, vec![]).is_empty());
+    }
+}
@@ -0,0 +1,364 @@
+use crate::entity::types::EntityType;
+use crate::protocol::packet::play::clientbound::*;
+use crate::shared::Position;
+use crate::types::Metadata;
+
+/// How a spawn packet gives the type of the entity
+pub enum SpawnKind {
+    /// An object's id, with data that picks the kind of some objects in
+    /// older versions
+    Object {
+        id: i32,
+        data: i32,
+    },
+    Mob(i32),
+    Fixed(EntityType),
+}
+
+impl SpawnKind {
+    pub fn entity_type(&self, protocol_version: i32) -> EntityType {
+        match *self {
+            SpawnKind::Object { id, data } => EntityType::by_object_id(id, data, protocol_version),
+            SpawnKind::Mob(id) => EntityType::by_mob_id(id, protocol_version),
+            SpawnKind::Fixed(ty) => ty,
+        }
+    }
+}
+
+/// An entity spawned by any of the packets for objects, mobs, experience
+/// orbs, lightning and paintings. The angles are in 256ths of a turn, as
+/// they are sent.
+pub struct EntitySpawn {
+    pub entity_id: i32,
+    pub kind: SpawnKind,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f64,
+    pub pitch: f64,
+    pub metadata: Option<Metadata>,
+}
+
+impl EntitySpawn {
+    /// An entity facing south without metadata
+    fn at(entity_id: i32, kind: SpawnKind, x: f64, y: f64, z: f64) -> EntitySpawn {
+        EntitySpawn {
+            entity_id,
+            kind,
+            x,
+            y,
+            z,
+            yaw: 0.0,
+            pitch: 0.0,
+            metadata: None,
+        }
+    }
+
+    /// Paintings are placed in the middle of the block and face one of
+    /// the four horizontal directions, starting from south and going
+    /// clockwise.
+    fn painting(entity_id: i32, location: Position, direction: i32) -> EntitySpawn {
+        EntitySpawn {
+            yaw: (direction * 64) as f64,
+            ..EntitySpawn::at(
+                entity_id,
+                SpawnKind::Fixed(EntityType::Painting),
+                location.x as f64 + 0.5,
+                location.y as f64,
+                location.z as f64 + 0.5,
+            )
+        }
+    }
+}
+
+impl From<SpawnObject> for EntitySpawn {
+    fn from(spawn: SpawnObject) -> Self {
+        let kind = SpawnKind::Object {
+            id: spawn.ty as i32,
+            data: spawn.data,
+        };
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            ..EntitySpawn::at(spawn.entity_id.0, kind, spawn.x, spawn.y, spawn.z)
+        }
+    }
+}
+
+impl From<SpawnObject_i32> for EntitySpawn {
+    fn from(spawn: SpawnObject_i32) -> Self {
+        let kind = SpawnKind::Object {
+            id: spawn.ty as i32,
+            data: spawn.data,
+        };
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                kind,
+                f64::from(spawn.x),
+                f64::from(spawn.y),
+                f64::from(spawn.z),
+            )
+        }
+    }
+}
+
+impl From<SpawnObject_i32_NoUUID> for EntitySpawn {
+    fn from(spawn: SpawnObject_i32_NoUUID) -> Self {
+        let kind = SpawnKind::Object {
+            id: spawn.ty as i32,
+            data: spawn.data,
+        };
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                kind,
+                f64::from(spawn.x),
+                f64::from(spawn.y),
+                f64::from(spawn.z),
+            )
+        }
+    }
+}
+
+impl From<SpawnObject_VarInt> for EntitySpawn {
+    fn from(spawn: SpawnObject_VarInt) -> Self {
+        let kind = SpawnKind::Object {
+            id: spawn.ty.0,
+            data: spawn.data,
+        };
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            ..EntitySpawn::at(spawn.entity_id.0, kind, spawn.x, spawn.y, spawn.z)
+        }
+    }
+}
+
+impl From<SpawnExperienceOrb> for EntitySpawn {
+    fn from(spawn: SpawnExperienceOrb) -> Self {
+        EntitySpawn::at(
+            spawn.entity_id.0,
+            SpawnKind::Fixed(EntityType::ExperienceOrb),
+            spawn.x,
+            spawn.y,
+            spawn.z,
+        )
+    }
+}
+
+impl From<SpawnExperienceOrb_i32> for EntitySpawn {
+    fn from(spawn: SpawnExperienceOrb_i32) -> Self {
+        EntitySpawn::at(
+            spawn.entity_id.0,
+            SpawnKind::Fixed(EntityType::ExperienceOrb),
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+        )
+    }
+}
+
+// Lightning is the only global entity
+impl From<SpawnGlobalEntity> for EntitySpawn {
+    fn from(spawn: SpawnGlobalEntity) -> Self {
+        EntitySpawn::at(
+            spawn.entity_id.0,
+            SpawnKind::Fixed(EntityType::LightningBolt),
+            spawn.x,
+            spawn.y,
+            spawn.z,
+        )
+    }
+}
+
+impl From<SpawnGlobalEntity_i32> for EntitySpawn {
+    fn from(spawn: SpawnGlobalEntity_i32) -> Self {
+        EntitySpawn::at(
+            spawn.entity_id.0,
+            SpawnKind::Fixed(EntityType::LightningBolt),
+            f64::from(spawn.x),
+            f64::from(spawn.y),
+            f64::from(spawn.z),
+        )
+    }
+}
+
+impl From<SpawnMob_NoMeta> for EntitySpawn {
+    fn from(spawn: SpawnMob_NoMeta) -> Self {
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                SpawnKind::Mob(spawn.ty.0),
+                spawn.x,
+                spawn.y,
+                spawn.z,
+            )
+        }
+    }
+}
+
+impl From<SpawnMob_WithMeta> for EntitySpawn {
+    fn from(spawn: SpawnMob_WithMeta) -> Self {
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            metadata: Some(spawn.metadata),
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                SpawnKind::Mob(spawn.ty.0),
+                spawn.x,
+                spawn.y,
+                spawn.z,
+            )
+        }
+    }
+}
+
+impl From<SpawnMob_u8> for EntitySpawn {
+    fn from(spawn: SpawnMob_u8) -> Self {
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            metadata: Some(spawn.metadata),
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                SpawnKind::Mob(spawn.ty as i32),
+                spawn.x,
+                spawn.y,
+                spawn.z,
+            )
+        }
+    }
+}
+
+impl From<SpawnMob_u8_i32> for EntitySpawn {
+    fn from(spawn: SpawnMob_u8_i32) -> Self {
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            metadata: Some(spawn.metadata),
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                SpawnKind::Mob(spawn.ty as i32),
+                f64::from(spawn.x),
+                f64::from(spawn.y),
+                f64::from(spawn.z),
+            )
+        }
+    }
+}
+
+impl From<SpawnMob_u8_i32_NoUUID> for EntitySpawn {
+    fn from(spawn: SpawnMob_u8_i32_NoUUID) -> Self {
+        EntitySpawn {
+            yaw: spawn.yaw as f64,
+            pitch: spawn.pitch as f64,
+            metadata: Some(spawn.metadata),
+            ..EntitySpawn::at(
+                spawn.entity_id.0,
+                SpawnKind::Mob(spawn.ty as i32),
+                f64::from(spawn.x),
+                f64::from(spawn.y),
+                f64::from(spawn.z),
+            )
+        }
+    }
+}
+
+impl From<SpawnPainting_VarInt> for EntitySpawn {
+    fn from(spawn: SpawnPainting_VarInt) -> Self {
+        EntitySpawn::painting(spawn.entity_id.0, spawn.location, spawn.direction as i32)
+    }
+}
+
+impl From<SpawnPainting_String> for EntitySpawn {
+    fn from(spawn: SpawnPainting_String) -> Self {
+        EntitySpawn::painting(spawn.entity_id.0, spawn.location, spawn.direction as i32)
+    }
+}
+
+impl From<SpawnPainting_NoUUID> for EntitySpawn {
+    fn from(spawn: SpawnPainting_NoUUID) -> Self {
+        EntitySpawn::painting(spawn.entity_id.0, spawn.location, spawn.direction as i32)
+    }
+}
+
+impl From<SpawnPainting_NoUUID_i32> for EntitySpawn {
+    fn from(spawn: SpawnPainting_NoUUID_i32) -> Self {
+        EntitySpawn::painting(
+            spawn.entity_id.0,
+            Position::new(spawn.x, spawn.y, spawn.z),
+            spawn.direction,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::VarInt;
+
+    #[test]
+    fn mob_spawn() {
+        let spawn = EntitySpawn::from(SpawnMob_u8_i32_NoUUID {
+            entity_id: VarInt(7),
+            ty: 54,
+            x: 1.5.into(),
+            y: (-2.0).into(),
+            z: 3.25.into(),
+            yaw: 64,
+            pitch: -32,
+            head_pitch: 0,
+            velocity_x: 0,
+            velocity_y: 0,
+            velocity_z: 0,
+            metadata: Metadata::new(),
+        });
+        assert_eq!(spawn.entity_id, 7);
+        assert_eq!((spawn.x, spawn.y, spawn.z), (1.5, -2.0, 3.25));
+        assert_eq!((spawn.yaw, spawn.pitch), (64.0, -32.0));
+        assert!(spawn.metadata.is_some());
+        assert_eq!(spawn.kind.entity_type(47), EntityType::Zombie);
+    }
+
+    #[test]
+    fn object_spawn() {
+        let spawn = EntitySpawn::from(SpawnObject_VarInt {
+            entity_id: VarInt(3),
+            ty: VarInt(2),
+            x: 0.5,
+            ..Default::default()
+        });
+        assert!(spawn.metadata.is_none());
+        assert_eq!(spawn.x, 0.5);
+        match spawn.kind {
+            SpawnKind::Object { id: 2, data: 0 } => {}
+            _ => panic!("expected an object"),
+        }
+        let orb = EntitySpawn::from(SpawnExperienceOrb::default());
+        assert_eq!(orb.kind.entity_type(340), EntityType::ExperienceOrb);
+    }
+
+    #[test]
+    fn painting_spawn() {
+        let spawn = EntitySpawn::from(SpawnPainting_NoUUID_i32 {
+            entity_id: VarInt(1),
+            title: "Kebab".to_owned(),
+            x: 10,
+            y: 64,
+            z: -3,
+            direction: 3,
+        });
+        // In the middle of the block, facing east
+        assert_eq!((spawn.x, spawn.y, spawn.z), (10.5, 64.0, -2.5));
+        assert_eq!(spawn.yaw, 192.0);
+        assert_eq!(spawn.kind.entity_type(5), EntityType::Painting);
+    }
+}
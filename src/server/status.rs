@@ -0,0 +1,104 @@
+use crate::format;
+
+/// Changes to the player's life that the game needs to react to
+pub enum Event {
+    /// The player died, with the death message if the server sent one
+    Died(Option<format::Component>),
+    Respawned,
+}
+
+/// The local player's health, hunger and experience as sent by the
+/// server.
+pub struct Status {
+    pub health: f32,
+    pub food: i32,
+    pub food_saturation: f32,
+    /// Progress towards the next level, between 0 and 1
+    pub experience_bar: f32,
+    pub level: i32,
+    pub total_experience: i32,
+
+    dead: bool,
+    event: Option<Event>,
+    revision: u64,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Status {
+    pub fn new() -> Status {
+        Status {
+            health: 20.0,
+            food: 20,
+            food_saturation: 5.0,
+            experience_bar: 0.0,
+            level: 0,
+            total_experience: 0,
+
+            dead: false,
+            event: None,
+            // Force displays to refresh when a new server replaces the old one
+            revision: 1,
+        }
+    }
+
+    pub fn set_health(&mut self, health: f32, food: i32, food_saturation: f32) {
+        self.health = health;
+        self.food = food;
+        self.food_saturation = food_saturation;
+        if health <= 0.0 {
+            // Older servers only tell us about deaths through the health
+            // update, the message (if any) follows in a combat event.
+            if !self.dead {
+                self.dead = true;
+                self.event = Some(Event::Died(None));
+            }
+        } else if self.dead {
+            self.dead = false;
+            self.event = Some(Event::Respawned);
+        }
+        self.revision += 1;
+    }
+
+    pub fn set_experience(&mut self, experience_bar: f32, level: i32, total_experience: i32) {
+        self.experience_bar = experience_bar;
+        self.level = level;
+        self.total_experience = total_experience;
+        self.revision += 1;
+    }
+
+    /// Called when the server reports the player's death along with
+    /// the message to show on the death screen.
+    pub fn died(&mut self, message: format::Component) {
+        self.dead = true;
+        self.event = Some(Event::Died(Some(message)));
+        self.revision += 1;
+    }
+
+    /// Called when the player changes world, which always leaves them
+    /// alive.
+    pub fn respawned(&mut self) {
+        if self.dead {
+            self.dead = false;
+            self.event = Some(Event::Respawned);
+            self.revision += 1;
+        }
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.dead
+    }
+
+    pub fn take_event(&mut self) -> Option<Event> {
+        self.event.take()
+    }
+
+    /// Incremented every time a value changes
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+}
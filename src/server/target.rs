@@ -1,14 +1,24 @@
+use crate::ecs;
+use crate::entity;
 use crate::render;
 use crate::render::model;
 use crate::shared::{Direction, Position};
 use crate::world;
 use crate::world::block;
+use cgmath::InnerSpace;
 use collision::{self, Aabb};
+use std::sync::{Arc, RwLock};
+
+/// How far away entities can be attacked or used, like vanilla in survival
+pub const ENTITY_REACH: f64 = 3.0;
 
 pub struct Info {
     model: Option<model::ModelKey>,
     last_block: block::Block,
     last_pos: Position,
+
+    break_model: Option<model::ModelKey>,
+    last_break_stage: Option<u8>,
 }
 
 impl Default for Info {
@@ -23,6 +33,9 @@ impl Info {
             model: None,
             last_block: block::Air {},
             last_pos: Position::new(0, 0, 0),
+
+            break_model: None,
+            last_break_stage: None,
         }
     }
 
@@ -31,6 +44,33 @@ impl Info {
         if let Some(model) = self.model.take() {
             renderer.model.remove_model(model);
         }
+        self.set_break_stage(renderer, None, None);
+    }
+
+    /// Draws the destroy stage (0-9) of the block currently being
+    /// broken over the targeted block.
+    pub fn set_break_stage(
+        &mut self,
+        renderer: &mut render::Renderer,
+        models: Option<&Arc<RwLock<crate::model::Factory>>>,
+        stage: Option<u8>,
+    ) {
+        if self.last_break_stage == stage {
+            return;
+        }
+        self.last_break_stage = stage;
+        if let Some(model) = self.break_model.take() {
+            renderer.model.remove_model(model);
+        }
+        if let Some(stage) = stage {
+            self.break_model = Some(create_break_model(
+                renderer,
+                models,
+                self.last_pos,
+                self.last_block,
+                stage,
+            ));
+        }
     }
 
     pub fn update(&mut self, renderer: &mut render::Renderer, pos: Position, bl: block::Block) {
@@ -42,6 +82,11 @@ impl Info {
         if let Some(model) = self.model.take() {
             renderer.model.remove_model(model);
         }
+        // The crack belongs to the previous block
+        if let Some(model) = self.break_model.take() {
+            renderer.model.remove_model(model);
+        }
+        self.last_break_stage = None;
         let mut parts = vec![];
 
         const LINE_SIZE: f64 = 1.0 / 128.0;
@@ -135,6 +180,57 @@ impl Info {
     }
 }
 
+/// Creates a model covering the block with the given destroy stage
+/// texture. The crack is drawn around the block's model, falling back to
+/// its collision boxes when the model isn't known.
+pub fn create_break_model(
+    renderer: &mut render::Renderer,
+    models: Option<&Arc<RwLock<crate::model::Factory>>>,
+    pos: Position,
+    bl: block::Block,
+    stage: u8,
+) -> model::ModelKey {
+    // Slightly larger than the block to prevent z-fighting
+    const OFFSET: f64 = 1.0 / 512.0;
+    let tex = render::Renderer::get_texture(
+        renderer.get_textures_ref(),
+        &format!("minecraft:blocks/destroy_stage_{}", stage.min(9)),
+    );
+
+    let bounds = match models.and_then(|models| crate::model::Factory::get_block_bounds(models, bl))
+    {
+        Some(bounds) => vec![bounds],
+        None => bl.get_collision_boxes(),
+    };
+    let mut parts = vec![];
+    for bound in bounds {
+        let bound = bound.add_v(cgmath::Vector3::new(
+            pos.x as f64,
+            pos.y as f64,
+            pos.z as f64,
+        ));
+        model::append_box(
+            &mut parts,
+            (bound.min.x - OFFSET) as f32,
+            (bound.min.y - OFFSET) as f32,
+            (bound.min.z - OFFSET) as f32,
+            ((bound.max.x - bound.min.x) + OFFSET * 2.0) as f32,
+            ((bound.max.y - bound.min.y) + OFFSET * 2.0) as f32,
+            ((bound.max.z - bound.min.z) + OFFSET * 2.0) as f32,
+            [
+                Some(tex.clone()),
+                Some(tex.clone()),
+                Some(tex.clone()),
+                Some(tex.clone()),
+                Some(tex.clone()),
+                Some(tex.clone()),
+            ],
+        );
+    }
+
+    renderer.model.create_model(model::DEFAULT, vec![parts])
+}
+
 #[allow(clippy::type_complexity)]
 pub fn test_block(
     world: &world::World,
@@ -158,6 +254,40 @@ pub fn test_block(
     (false, None)
 }
 
+/// Finds the closest entity hit by the ray within `max` blocks, returning
+/// its id, how far away it was hit and where relative to its position
+pub fn trace_entities<I>(
+    entities: &ecs::Manager,
+    candidates: I,
+    max: f64,
+    s: cgmath::Vector3<f64>,
+    d: cgmath::Vector3<f64>,
+) -> Option<(i32, f64, cgmath::Vector3<f64>)>
+where
+    I: IntoIterator<Item = (i32, ecs::Entity)>,
+{
+    let mut closest: Option<(i32, f64, cgmath::Vector3<f64>)> = None;
+    for (id, e) in candidates {
+        let position = match entities.get_component_direct::<entity::Position>(e) {
+            Some(position) => position.position,
+            None => continue,
+        };
+        let bounds = match entities.get_component_direct::<entity::Bounds>(e) {
+            Some(bounds) => bounds.bounds.add_v(position),
+            None => continue,
+        };
+        let hit = match intersects_line(bounds, s, d) {
+            Some(hit) => hit,
+            None => continue,
+        };
+        let distance = (hit - s).magnitude();
+        if distance <= max && closest.map_or(true, |(_, closest, _)| distance < closest) {
+            closest = Some((id, distance, hit - position));
+        }
+    }
+    closest
+}
+
 fn find_face(bound: collision::Aabb3<f64>, hit: cgmath::Vector3<f64>) -> Direction {
     if (bound.min.x - hit.x).abs() < 0.01 {
         Direction::West
@@ -318,3 +448,89 @@ where
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cgmath::{Point3, Vector3};
+
+    /// Adds an entity standing at `x`, `z`, as big as a player when
+    /// `sized`
+    fn spawn(entities: &mut ecs::Manager, x: f64, z: f64, sized: bool) -> ecs::Entity {
+        let e = entities.create_entity();
+        entities.add_component_direct(e, entity::Position::new(x, 0.0, z));
+        if sized {
+            entities.add_component_direct(
+                e,
+                entity::Bounds::new(collision::Aabb3::new(
+                    Point3::new(-0.3, 0.0, -0.3),
+                    Point3::new(0.3, 1.8, 0.3),
+                )),
+            );
+        }
+        e
+    }
+
+    fn trace(
+        entities: &ecs::Manager,
+        candidates: &[(i32, ecs::Entity)],
+        max: f64,
+    ) -> Option<(i32, f64, Vector3<f64>)> {
+        trace_entities(
+            entities,
+            candidates.iter().copied(),
+            max,
+            Vector3::new(0.0, 1.6, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+        )
+    }
+
+    fn assert_hit(hit: Option<(i32, f64, Vector3<f64>)>, id: i32, distance: f64) {
+        let (hit_id, hit_distance, _) = hit.unwrap();
+        assert_eq!(hit_id, id);
+        assert!((hit_distance - distance).abs() < 1e-9, "{}", hit_distance);
+    }
+
+    #[test]
+    fn closest_entity() {
+        let mut entities = ecs::Manager::new();
+        let far = spawn(&mut entities, 2.0, 0.0, true);
+        let near = spawn(&mut entities, 1.0, 0.0, true);
+        let aside = spawn(&mut entities, 1.0, 2.0, true);
+        let mut candidates = vec![(1, far), (2, near), (3, aside)];
+        let hit = trace(&entities, &candidates, ENTITY_REACH);
+        assert_hit(hit, 2, 0.7);
+        // Where it was hit is relative to its feet
+        let at = hit.unwrap().2;
+        assert!(
+            (at - Vector3::new(-0.3, 1.6, 0.0)).magnitude() < 1e-9,
+            "{:?}",
+            at
+        );
+
+        candidates.reverse();
+        assert_hit(trace(&entities, &candidates, ENTITY_REACH), 2, 0.7);
+        assert!(trace(&entities, &[(3, aside)], ENTITY_REACH).is_none());
+    }
+
+    #[test]
+    fn entity_out_of_reach() {
+        let mut entities = ecs::Manager::new();
+        let far = spawn(&mut entities, 4.0, 0.0, true);
+        assert!(trace(&entities, &[(1, far)], ENTITY_REACH).is_none());
+        assert_hit(trace(&entities, &[(1, far)], 5.0), 1, 3.7);
+    }
+
+    #[test]
+    fn entity_without_bounds() {
+        let mut entities = ecs::Manager::new();
+        let unsized_entity = spawn(&mut entities, 0.5, 0.0, false);
+        let sized = spawn(&mut entities, 2.0, 0.0, true);
+        assert!(trace(&entities, &[(1, unsized_entity)], ENTITY_REACH).is_none());
+        assert_hit(
+            trace(&entities, &[(1, unsized_entity), (2, sized)], ENTITY_REACH),
+            2,
+            1.7,
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use crate::console;
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
 use std::marker::PhantomData;
-use winit::event::VirtualKeyCode;
+use winit::event::{MouseButton, VirtualKeyCode};
 // Might just rename this to settings.rs
 
 pub const R_MAX_FPS: console::CVar<i64> = console::CVar {
@@ -9,6 +12,7 @@ pub const R_MAX_FPS: console::CVar<i64> = console::CVar {
     description: "fps_max caps the maximum FPS for the rendering engine",
     mutable: true,
     serializable: true,
+    range: Some((0, 1000)),
     default: &|| 60,
 };
 
@@ -18,6 +22,7 @@ pub const R_FOV: console::CVar<i64> = console::CVar {
     description: "Setting for controlling the client field of view",
     mutable: true,
     serializable: true,
+    range: Some((30, 110)),
     default: &|| 90,
 };
 
@@ -27,18 +32,219 @@ pub const R_VSYNC: console::CVar<bool> = console::CVar {
     description: "Toggle to enable/disable vsync",
     mutable: true,
     serializable: true,
+    range: None,
     default: &|| false,
 };
 
+pub const R_RENDER_DISTANCE: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "r_render_distance",
+    description: "How far away chunks are drawn and kept, in chunks (2-32)",
+    mutable: true,
+    serializable: true,
+    range: Some((2, 32)),
+    default: &|| 12,
+};
+
+pub const R_MAX_PARTICLES: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "r_max_particles",
+    description: "The maximum number of particles shown at once, older particles are removed first",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100_000)),
+    default: &|| 4000,
+};
+
 pub const CL_MASTER_VOLUME: console::CVar<i64> = console::CVar {
     ty: PhantomData,
     name: "cl_master_volume",
     description: "Main volume control",
     mutable: true,
     serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_MUSIC_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_music_volume",
+    description: "Music volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_RECORD_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_record_volume",
+    description: "Jukebox and note block volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_WEATHER_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_weather_volume",
+    description: "Weather volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_BLOCK_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_block_volume",
+    description: "Block volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_HOSTILE_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_hostile_volume",
+    description: "Hostile creature volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_NEUTRAL_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_neutral_volume",
+    description: "Friendly creature volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
     default: &|| 100,
 };
 
+pub const CL_PLAYER_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_player_volume",
+    description: "Player volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_AMBIENT_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_ambient_volume",
+    description: "Ambient and environment volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_VOICE_VOLUME: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_voice_volume",
+    description: "Voice and speech volume",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 100)),
+    default: &|| 100,
+};
+
+pub const CL_LANGUAGE: console::CVar<String> = console::CVar {
+    ty: PhantomData,
+    name: "cl_language",
+    description: "The locale used for translated text, e.g. en_us",
+    mutable: true,
+    serializable: true,
+    range: None,
+    default: &|| "en_us".to_owned(),
+};
+
+/// Which chat messages the server sends
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChatVisibility {
+    Full,
+    /// Only the results of commands
+    System,
+    Hidden,
+}
+
+impl console::CVarEnum for ChatVisibility {
+    const VALUES: &'static [(Self, &'static str)] = &[
+        (ChatVisibility::Full, "full"),
+        (ChatVisibility::System, "system"),
+        (ChatVisibility::Hidden, "hidden"),
+    ];
+}
+
+pub const CL_CHAT_VISIBILITY: console::CVar<ChatVisibility> = console::CVar {
+    ty: PhantomData,
+    name: "cl_chat_visibility",
+    description: "Which chat messages the server sends: full, system (only commands) or hidden",
+    mutable: true,
+    serializable: true,
+    range: None,
+    default: &|| ChatVisibility::Full,
+};
+
+pub const CL_CHAT_COLORS: console::CVar<bool> = console::CVar {
+    ty: PhantomData,
+    name: "cl_chat_colors",
+    description: "Whether the server may send coloured chat",
+    mutable: true,
+    serializable: true,
+    range: None,
+    default: &|| true,
+};
+
+pub const CL_SKIN_PARTS: console::CVar<i64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_skin_parts",
+    description: "The skin layers shown to other players as a bitmask: 1 cape, 2 jacket, \
+                  4 left sleeve, 8 right sleeve, 16 left trouser leg, 32 right trouser leg, 64 hat",
+    mutable: true,
+    serializable: true,
+    range: Some((0, 0x7f)),
+    default: &|| 0x7f,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MainHand {
+    Left,
+    Right,
+}
+
+impl console::CVarEnum for MainHand {
+    const VALUES: &'static [(Self, &'static str)] =
+        &[(MainHand::Left, "left"), (MainHand::Right, "right")];
+}
+
+pub const CL_MAIN_HAND: console::CVar<MainHand> = console::CVar {
+    ty: PhantomData,
+    name: "cl_main_hand",
+    description: "The hand used for attacking and using items: left or right",
+    mutable: true,
+    serializable: true,
+    range: None,
+    default: &|| MainHand::Right,
+};
+
+pub const CL_MOUSE_SENSITIVITY: console::CVar<f64> = console::CVar {
+    ty: PhantomData,
+    name: "cl_mouse_sensitivity",
+    description: "Multiplier for how far the camera turns when the mouse moves",
+    mutable: true,
+    serializable: true,
+    range: Some((0.1, 10.0)),
+    default: &|| 1.0,
+};
+
 macro_rules! create_keybind {
     ($keycode:ident, $name:expr, $description:expr) => {
         console::CVar {
@@ -47,30 +253,84 @@ macro_rules! create_keybind {
             description: $description,
             mutable: true,
             serializable: true,
-            default: &|| VirtualKeyCode::$keycode as i64,
+            range: None,
+            default: &|| Keybind::key(VirtualKeyCode::$keycode),
         }
     };
 }
 
-pub const CL_KEYBIND_FORWARD: console::CVar<i64> =
+pub const CL_KEYBIND_FORWARD: console::CVar<Keybind> =
     create_keybind!(W, "cl_keybind_forward", "Keybinding for moving forward");
-pub const CL_KEYBIND_BACKWARD: console::CVar<i64> =
+pub const CL_KEYBIND_BACKWARD: console::CVar<Keybind> =
     create_keybind!(S, "cl_keybind_backward", "Keybinding for moving backward");
-pub const CL_KEYBIND_LEFT: console::CVar<i64> =
+pub const CL_KEYBIND_LEFT: console::CVar<Keybind> =
     create_keybind!(A, "cl_keybind_left", "Keybinding for moving the left");
-pub const CL_KEYBIND_RIGHT: console::CVar<i64> =
+pub const CL_KEYBIND_RIGHT: console::CVar<Keybind> =
     create_keybind!(D, "cl_keybind_right", "Keybinding for moving to the right");
-pub const CL_KEYBIND_OPEN_INV: console::CVar<i64> = create_keybind!(
+pub const CL_KEYBIND_OPEN_INV: console::CVar<Keybind> = create_keybind!(
     E,
     "cl_keybind_open_inv",
     "Keybinding for opening the inventory"
 );
-pub const CL_KEYBIND_SNEAK: console::CVar<i64> =
+pub const CL_KEYBIND_SNEAK: console::CVar<Keybind> =
     create_keybind!(LShift, "cl_keybind_sneak", "Keybinding for sneaking");
-pub const CL_KEYBIND_SPRINT: console::CVar<i64> =
+pub const CL_KEYBIND_SPRINT: console::CVar<Keybind> =
     create_keybind!(LControl, "cl_keybind_sprint", "Keybinding for sprinting");
-pub const CL_KEYBIND_JUMP: console::CVar<i64> =
+pub const CL_KEYBIND_JUMP: console::CVar<Keybind> =
     create_keybind!(Space, "cl_keybind_jump", "Keybinding for jumping");
+pub const CL_KEYBIND_CHAT: console::CVar<Keybind> =
+    create_keybind!(T, "cl_keybind_chat", "Keybinding for opening the chat");
+pub const CL_KEYBIND_COMMAND: console::CVar<Keybind> = create_keybind!(
+    Slash,
+    "cl_keybind_command",
+    "Keybinding for opening the chat with a command"
+);
+
+pub const CL_KEYBIND_HOTBAR_1: console::CVar<Keybind> = create_keybind!(
+    Key1,
+    "cl_keybind_hotbar_1",
+    "Keybinding for selecting hotbar slot 1"
+);
+pub const CL_KEYBIND_HOTBAR_2: console::CVar<Keybind> = create_keybind!(
+    Key2,
+    "cl_keybind_hotbar_2",
+    "Keybinding for selecting hotbar slot 2"
+);
+pub const CL_KEYBIND_HOTBAR_3: console::CVar<Keybind> = create_keybind!(
+    Key3,
+    "cl_keybind_hotbar_3",
+    "Keybinding for selecting hotbar slot 3"
+);
+pub const CL_KEYBIND_HOTBAR_4: console::CVar<Keybind> = create_keybind!(
+    Key4,
+    "cl_keybind_hotbar_4",
+    "Keybinding for selecting hotbar slot 4"
+);
+pub const CL_KEYBIND_HOTBAR_5: console::CVar<Keybind> = create_keybind!(
+    Key5,
+    "cl_keybind_hotbar_5",
+    "Keybinding for selecting hotbar slot 5"
+);
+pub const CL_KEYBIND_HOTBAR_6: console::CVar<Keybind> = create_keybind!(
+    Key6,
+    "cl_keybind_hotbar_6",
+    "Keybinding for selecting hotbar slot 6"
+);
+pub const CL_KEYBIND_HOTBAR_7: console::CVar<Keybind> = create_keybind!(
+    Key7,
+    "cl_keybind_hotbar_7",
+    "Keybinding for selecting hotbar slot 7"
+);
+pub const CL_KEYBIND_HOTBAR_8: console::CVar<Keybind> = create_keybind!(
+    Key8,
+    "cl_keybind_hotbar_8",
+    "Keybinding for selecting hotbar slot 8"
+);
+pub const CL_KEYBIND_HOTBAR_9: console::CVar<Keybind> = create_keybind!(
+    Key9,
+    "cl_keybind_hotbar_9",
+    "Keybinding for selecting hotbar slot 9"
+);
 
 pub const DOUBLE_JUMP_MS: u32 = 100;
 
@@ -78,7 +338,24 @@ pub fn register_vars(vars: &mut console::Vars) {
     vars.register(R_MAX_FPS);
     vars.register(R_FOV);
     vars.register(R_VSYNC);
+    vars.register(R_RENDER_DISTANCE);
+    vars.register(R_MAX_PARTICLES);
     vars.register(CL_MASTER_VOLUME);
+    vars.register(CL_MUSIC_VOLUME);
+    vars.register(CL_RECORD_VOLUME);
+    vars.register(CL_WEATHER_VOLUME);
+    vars.register(CL_BLOCK_VOLUME);
+    vars.register(CL_HOSTILE_VOLUME);
+    vars.register(CL_NEUTRAL_VOLUME);
+    vars.register(CL_PLAYER_VOLUME);
+    vars.register(CL_AMBIENT_VOLUME);
+    vars.register(CL_VOICE_VOLUME);
+    vars.register(CL_LANGUAGE);
+    vars.register(CL_CHAT_VISIBILITY);
+    vars.register(CL_CHAT_COLORS);
+    vars.register(CL_SKIN_PARTS);
+    vars.register(CL_MAIN_HAND);
+    vars.register(CL_MOUSE_SENSITIVITY);
     vars.register(CL_KEYBIND_FORWARD);
     vars.register(CL_KEYBIND_BACKWARD);
     vars.register(CL_KEYBIND_LEFT);
@@ -87,6 +364,17 @@ pub fn register_vars(vars: &mut console::Vars) {
     vars.register(CL_KEYBIND_SNEAK);
     vars.register(CL_KEYBIND_SPRINT);
     vars.register(CL_KEYBIND_JUMP);
+    vars.register(CL_KEYBIND_CHAT);
+    vars.register(CL_KEYBIND_COMMAND);
+    vars.register(CL_KEYBIND_HOTBAR_1);
+    vars.register(CL_KEYBIND_HOTBAR_2);
+    vars.register(CL_KEYBIND_HOTBAR_3);
+    vars.register(CL_KEYBIND_HOTBAR_4);
+    vars.register(CL_KEYBIND_HOTBAR_5);
+    vars.register(CL_KEYBIND_HOTBAR_6);
+    vars.register(CL_KEYBIND_HOTBAR_7);
+    vars.register(CL_KEYBIND_HOTBAR_8);
+    vars.register(CL_KEYBIND_HOTBAR_9);
 }
 
 #[derive(Hash, PartialEq, Eq, Debug)]
@@ -99,11 +387,15 @@ pub enum Stevenkey {
     Sneak,
     Sprint,
     Jump,
+    Chat,
+    Command,
+    /// Selects the hotbar slot, starting from 0
+    Hotbar(u8),
 }
 
 impl Stevenkey {
     pub fn values() -> Vec<Stevenkey> {
-        vec![
+        let mut values = vec![
             Stevenkey::Forward,
             Stevenkey::Backward,
             Stevenkey::Left,
@@ -112,19 +404,38 @@ impl Stevenkey {
             Stevenkey::Sneak,
             Stevenkey::Sprint,
             Stevenkey::Jump,
-        ]
+            Stevenkey::Chat,
+            Stevenkey::Command,
+        ];
+        values.extend((0..9).map(Stevenkey::Hotbar));
+        values
     }
 
-    pub fn get_by_keycode(keycode: VirtualKeyCode, vars: &console::Vars) -> Option<Stevenkey> {
-        for steven_key in Stevenkey::values() {
-            if keycode as i64 == *vars.get(steven_key.get_cvar()) {
-                return Some(steven_key);
-            }
-        }
-        None
+    /// The action bound to the input. Bindings only match when their
+    /// modifiers are held, the one needing the most modifiers wins.
+    pub fn get_by_input(
+        input: Input,
+        modifiers: Modifiers,
+        vars: &console::Vars,
+    ) -> Option<Stevenkey> {
+        Stevenkey::values()
+            .into_iter()
+            .map(|steven_key| (*vars.get(steven_key.get_cvar()), steven_key))
+            .filter(|(bind, _)| bind.input == input && modifiers.contains(bind.modifiers))
+            .max_by_key(|(bind, _)| bind.modifiers.count())
+            .map(|(_, steven_key)| steven_key)
+    }
+
+    /// Every action bound to the input whatever the modifiers, used to
+    /// release actions when their input is released.
+    pub fn all_by_input(input: Input, vars: &console::Vars) -> Vec<Stevenkey> {
+        Stevenkey::values()
+            .into_iter()
+            .filter(|steven_key| vars.get(steven_key.get_cvar()).input == input)
+            .collect()
     }
 
-    pub fn get_cvar(&self) -> console::CVar<i64> {
+    pub fn get_cvar(&self) -> console::CVar<Keybind> {
         match *self {
             Stevenkey::Forward => CL_KEYBIND_FORWARD,
             Stevenkey::Backward => CL_KEYBIND_BACKWARD,
@@ -134,6 +445,460 @@ impl Stevenkey {
             Stevenkey::Sneak => CL_KEYBIND_SNEAK,
             Stevenkey::Sprint => CL_KEYBIND_SPRINT,
             Stevenkey::Jump => CL_KEYBIND_JUMP,
+            Stevenkey::Chat => CL_KEYBIND_CHAT,
+            Stevenkey::Command => CL_KEYBIND_COMMAND,
+            Stevenkey::Hotbar(0) => CL_KEYBIND_HOTBAR_1,
+            Stevenkey::Hotbar(1) => CL_KEYBIND_HOTBAR_2,
+            Stevenkey::Hotbar(2) => CL_KEYBIND_HOTBAR_3,
+            Stevenkey::Hotbar(3) => CL_KEYBIND_HOTBAR_4,
+            Stevenkey::Hotbar(4) => CL_KEYBIND_HOTBAR_5,
+            Stevenkey::Hotbar(5) => CL_KEYBIND_HOTBAR_6,
+            Stevenkey::Hotbar(6) => CL_KEYBIND_HOTBAR_7,
+            Stevenkey::Hotbar(7) => CL_KEYBIND_HOTBAR_8,
+            Stevenkey::Hotbar(8) => CL_KEYBIND_HOTBAR_9,
+            Stevenkey::Hotbar(_) => unreachable!(),
+        }
+    }
+
+    /// The name used for the action by the `bind` command, e.g. `forward`
+    pub fn name(&self) -> &'static str {
+        let name = self.get_cvar().name;
+        name.strip_prefix("cl_keybind_").unwrap_or(name)
+    }
+
+    pub fn by_name(name: &str) -> Option<Stevenkey> {
+        Stevenkey::values()
+            .into_iter()
+            .find(|key| key.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Shift, Ctrl and Alt, ignoring which side of the keyboard they are on
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+impl Modifiers {
+    /// Whether every modifier held in `other` is held in this
+    pub fn contains(self, other: Modifiers) -> bool {
+        (self.shift || !other.shift) && (self.ctrl || !other.ctrl) && (self.alt || !other.alt)
+    }
+
+    fn count(self) -> usize {
+        self.shift as usize + self.ctrl as usize + self.alt as usize
+    }
+}
+
+/// Whether the key is one of the modifiers, which can't be combined
+/// with themselves.
+pub fn is_modifier_key(key: VirtualKeyCode) -> bool {
+    matches!(
+        key,
+        VirtualKeyCode::LShift
+            | VirtualKeyCode::RShift
+            | VirtualKeyCode::LControl
+            | VirtualKeyCode::RControl
+            | VirtualKeyCode::LAlt
+            | VirtualKeyCode::RAlt
+    )
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Input {
+    Key(VirtualKeyCode),
+    Mouse(MouseButton),
+}
+
+/// What an action is bound to: a key or mouse button, optionally
+/// combined with modifiers.
+///
+/// Stored in `conf.cfg` by name, e.g. `W`, `Ctrl+Shift+S` or `MouseMiddle`.
+/// The key codes older configs stored are still read.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Keybind {
+    pub input: Input,
+    pub modifiers: Modifiers,
+}
+
+impl Keybind {
+    pub fn key(key: VirtualKeyCode) -> Keybind {
+        Keybind {
+            input: Input::Key(key),
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Option<Keybind> {
+        if let Ok(code) = input.parse::<i64>() {
+            return key_by_code(code).map(Keybind::key);
+        }
+        let mut modifiers = Modifiers::default();
+        let mut parts = input.split('+').map(str::trim).collect::<Vec<_>>();
+        let name = parts.pop()?;
+        for part in parts {
+            match part.to_lowercase().as_str() {
+                "shift" => modifiers.shift = true,
+                "ctrl" => modifiers.ctrl = true,
+                "alt" => modifiers.alt = true,
+                _ => return None,
+            }
+        }
+        let lower = name.to_lowercase();
+        let input = match lower.strip_prefix("mouse") {
+            Some("left") => Input::Mouse(MouseButton::Left),
+            Some("right") => Input::Mouse(MouseButton::Right),
+            Some("middle") => Input::Mouse(MouseButton::Middle),
+            Some(other) if !other.is_empty() => {
+                Input::Mouse(MouseButton::Other(other.parse().ok()?))
+            }
+            _ => Input::Key(key_by_name(name)?),
+        };
+        Some(Keybind { input, modifiers })
+    }
+}
+
+impl fmt::Display for Keybind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.modifiers.alt {
+            write!(f, "Alt+")?;
+        }
+        match self.input {
+            Input::Key(key) => write!(f, "{:?}", key),
+            Input::Mouse(MouseButton::Left) => write!(f, "MouseLeft"),
+            Input::Mouse(MouseButton::Right) => write!(f, "MouseRight"),
+            Input::Mouse(MouseButton::Middle) => write!(f, "MouseMiddle"),
+            Input::Mouse(MouseButton::Other(button)) => write!(f, "Mouse{}", button),
+        }
+    }
+}
+
+impl console::Var for console::CVar<Keybind> {
+    fn serialize(&self, val: &Box<dyn Any>) -> String {
+        val.downcast_ref::<Keybind>().unwrap().to_string()
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        match Keybind::parse(input) {
+            Some(bind) => Ok(Box::new(bind)),
+            None => Err(format!("{} is not a key or mouse button", input)),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn can_serialize(&self) -> bool {
+        self.serializable
+    }
+
+    fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
+/// Every key in the order winit declares them, so a key's index is the
+/// value older configs stored in the keybinding CVars.
+const KEYS: &[VirtualKeyCode] = &[
+    VirtualKeyCode::Key1,
+    VirtualKeyCode::Key2,
+    VirtualKeyCode::Key3,
+    VirtualKeyCode::Key4,
+    VirtualKeyCode::Key5,
+    VirtualKeyCode::Key6,
+    VirtualKeyCode::Key7,
+    VirtualKeyCode::Key8,
+    VirtualKeyCode::Key9,
+    VirtualKeyCode::Key0,
+    VirtualKeyCode::A,
+    VirtualKeyCode::B,
+    VirtualKeyCode::C,
+    VirtualKeyCode::D,
+    VirtualKeyCode::E,
+    VirtualKeyCode::F,
+    VirtualKeyCode::G,
+    VirtualKeyCode::H,
+    VirtualKeyCode::I,
+    VirtualKeyCode::J,
+    VirtualKeyCode::K,
+    VirtualKeyCode::L,
+    VirtualKeyCode::M,
+    VirtualKeyCode::N,
+    VirtualKeyCode::O,
+    VirtualKeyCode::P,
+    VirtualKeyCode::Q,
+    VirtualKeyCode::R,
+    VirtualKeyCode::S,
+    VirtualKeyCode::T,
+    VirtualKeyCode::U,
+    VirtualKeyCode::V,
+    VirtualKeyCode::W,
+    VirtualKeyCode::X,
+    VirtualKeyCode::Y,
+    VirtualKeyCode::Z,
+    VirtualKeyCode::Escape,
+    VirtualKeyCode::F1,
+    VirtualKeyCode::F2,
+    VirtualKeyCode::F3,
+    VirtualKeyCode::F4,
+    VirtualKeyCode::F5,
+    VirtualKeyCode::F6,
+    VirtualKeyCode::F7,
+    VirtualKeyCode::F8,
+    VirtualKeyCode::F9,
+    VirtualKeyCode::F10,
+    VirtualKeyCode::F11,
+    VirtualKeyCode::F12,
+    VirtualKeyCode::F13,
+    VirtualKeyCode::F14,
+    VirtualKeyCode::F15,
+    VirtualKeyCode::F16,
+    VirtualKeyCode::F17,
+    VirtualKeyCode::F18,
+    VirtualKeyCode::F19,
+    VirtualKeyCode::F20,
+    VirtualKeyCode::F21,
+    VirtualKeyCode::F22,
+    VirtualKeyCode::F23,
+    VirtualKeyCode::F24,
+    VirtualKeyCode::Snapshot,
+    VirtualKeyCode::Scroll,
+    VirtualKeyCode::Pause,
+    VirtualKeyCode::Insert,
+    VirtualKeyCode::Home,
+    VirtualKeyCode::Delete,
+    VirtualKeyCode::End,
+    VirtualKeyCode::PageDown,
+    VirtualKeyCode::PageUp,
+    VirtualKeyCode::Left,
+    VirtualKeyCode::Up,
+    VirtualKeyCode::Right,
+    VirtualKeyCode::Down,
+    VirtualKeyCode::Back,
+    VirtualKeyCode::Return,
+    VirtualKeyCode::Space,
+    VirtualKeyCode::Compose,
+    VirtualKeyCode::Caret,
+    VirtualKeyCode::Numlock,
+    VirtualKeyCode::Numpad0,
+    VirtualKeyCode::Numpad1,
+    VirtualKeyCode::Numpad2,
+    VirtualKeyCode::Numpad3,
+    VirtualKeyCode::Numpad4,
+    VirtualKeyCode::Numpad5,
+    VirtualKeyCode::Numpad6,
+    VirtualKeyCode::Numpad7,
+    VirtualKeyCode::Numpad8,
+    VirtualKeyCode::Numpad9,
+    VirtualKeyCode::NumpadAdd,
+    VirtualKeyCode::NumpadDivide,
+    VirtualKeyCode::NumpadDecimal,
+    VirtualKeyCode::NumpadComma,
+    VirtualKeyCode::NumpadEnter,
+    VirtualKeyCode::NumpadEquals,
+    VirtualKeyCode::NumpadMultiply,
+    VirtualKeyCode::NumpadSubtract,
+    VirtualKeyCode::AbntC1,
+    VirtualKeyCode::AbntC2,
+    VirtualKeyCode::Apostrophe,
+    VirtualKeyCode::Apps,
+    VirtualKeyCode::Asterisk,
+    VirtualKeyCode::At,
+    VirtualKeyCode::Ax,
+    VirtualKeyCode::Backslash,
+    VirtualKeyCode::Calculator,
+    VirtualKeyCode::Capital,
+    VirtualKeyCode::Colon,
+    VirtualKeyCode::Comma,
+    VirtualKeyCode::Convert,
+    VirtualKeyCode::Equals,
+    VirtualKeyCode::Grave,
+    VirtualKeyCode::Kana,
+    VirtualKeyCode::Kanji,
+    VirtualKeyCode::LAlt,
+    VirtualKeyCode::LBracket,
+    VirtualKeyCode::LControl,
+    VirtualKeyCode::LShift,
+    VirtualKeyCode::LWin,
+    VirtualKeyCode::Mail,
+    VirtualKeyCode::MediaSelect,
+    VirtualKeyCode::MediaStop,
+    VirtualKeyCode::Minus,
+    VirtualKeyCode::Mute,
+    VirtualKeyCode::MyComputer,
+    VirtualKeyCode::NavigateForward,
+    VirtualKeyCode::NavigateBackward,
+    VirtualKeyCode::NextTrack,
+    VirtualKeyCode::NoConvert,
+    VirtualKeyCode::OEM102,
+    VirtualKeyCode::Period,
+    VirtualKeyCode::PlayPause,
+    VirtualKeyCode::Plus,
+    VirtualKeyCode::Power,
+    VirtualKeyCode::PrevTrack,
+    VirtualKeyCode::RAlt,
+    VirtualKeyCode::RBracket,
+    VirtualKeyCode::RControl,
+    VirtualKeyCode::RShift,
+    VirtualKeyCode::RWin,
+    VirtualKeyCode::Semicolon,
+    VirtualKeyCode::Slash,
+    VirtualKeyCode::Sleep,
+    VirtualKeyCode::Stop,
+    VirtualKeyCode::Sysrq,
+    VirtualKeyCode::Tab,
+    VirtualKeyCode::Underline,
+    VirtualKeyCode::Unlabeled,
+    VirtualKeyCode::VolumeDown,
+    VirtualKeyCode::VolumeUp,
+    VirtualKeyCode::Wake,
+    VirtualKeyCode::WebBack,
+    VirtualKeyCode::WebFavorites,
+    VirtualKeyCode::WebForward,
+    VirtualKeyCode::WebHome,
+    VirtualKeyCode::WebRefresh,
+    VirtualKeyCode::WebSearch,
+    VirtualKeyCode::WebStop,
+    VirtualKeyCode::Yen,
+    VirtualKeyCode::Copy,
+    VirtualKeyCode::Paste,
+    VirtualKeyCode::Cut,
+];
+
+/// Looks up a key by the value older configs stored in a keybinding CVar
+fn key_by_code(code: i64) -> Option<VirtualKeyCode> {
+    usize::try_from(code)
+        .ok()
+        .and_then(|code| KEYS.get(code))
+        .copied()
+}
+
+/// Looks up a key by its name, e.g. `W` or `LShift`, ignoring case
+fn key_by_name(name: &str) -> Option<VirtualKeyCode> {
+    KEYS.iter()
+        .find(|key| format!("{:?}", key).eq_ignore_ascii_case(name))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::Var;
+
+    fn parse(input: &str) -> Option<String> {
+        Keybind::parse(input).map(|bind| bind.to_string())
+    }
+
+    #[test]
+    fn keybind_round_trip() {
+        for name in &[
+            "W",
+            "LShift",
+            "Space",
+            "Key1",
+            "F12",
+            "MouseLeft",
+            "MouseRight",
+            "MouseMiddle",
+            "Mouse4",
+            "Ctrl+S",
+            "Ctrl+Shift+Alt+Delete",
+            "Alt+Mouse5",
+        ] {
+            assert_eq!(parse(name).as_deref(), Some(*name));
+        }
+        assert_eq!(parse("lshift").as_deref(), Some("LShift"));
+        assert_eq!(parse("mouseleft").as_deref(), Some("MouseLeft"));
+        assert_eq!(parse("NotAKey"), None);
+        assert_eq!(parse("MouseBack"), None);
+        assert_eq!(parse(""), None);
+    }
+
+    #[test]
+    fn keybind_modifiers() {
+        let bind = Keybind::parse("shift + CTRL + s").unwrap();
+        assert_eq!(bind.input, Input::Key(VirtualKeyCode::S));
+        assert_eq!(
+            bind.modifiers,
+            Modifiers {
+                shift: true,
+                ctrl: true,
+                alt: false,
+            }
+        );
+        assert_eq!(bind.to_string(), "Ctrl+Shift+S");
+        assert_eq!(
+            parse("Alt+Shift+Ctrl+X").as_deref(),
+            Some("Ctrl+Shift+Alt+X")
+        );
+        assert_eq!(parse("Super+S"), None);
+        assert_eq!(parse("Ctrl+"), None);
+
+        let held = Modifiers {
+            shift: true,
+            ctrl: true,
+            alt: false,
+        };
+        assert!(held.contains(bind.modifiers));
+        assert!(held.contains(Modifiers::default()));
+        assert!(!Modifiers::default().contains(held));
+        assert_eq!(held.count(), 2);
+    }
+
+    #[test]
+    fn keybind_legacy_codes() {
+        // Older configs stored `VirtualKeyCode as i64`, so every entry has
+        // to sit at its winit discriminant.
+        for (code, key) in KEYS.iter().enumerate() {
+            assert_eq!(*key as usize, code, "{:?} is out of order", key);
+        }
+        assert_eq!(
+            Keybind::parse("0"),
+            Some(Keybind::key(VirtualKeyCode::Key1))
+        );
+        assert_eq!(Keybind::parse("10"), Some(Keybind::key(VirtualKeyCode::A)));
+        assert_eq!(
+            Keybind::parse("36"),
+            Some(Keybind::key(VirtualKeyCode::Escape))
+        );
+        assert_eq!(
+            Keybind::parse(&(VirtualKeyCode::LShift as i64).to_string()),
+            Some(Keybind::key(VirtualKeyCode::LShift))
+        );
+        assert_eq!(Keybind::parse("-1"), None);
+        assert_eq!(Keybind::parse(&KEYS.len().to_string()), None);
+    }
+
+    #[test]
+    fn keybind_cvar() {
+        let val = CL_KEYBIND_SNEAK.deserialize("Ctrl+Q").unwrap();
+        assert_eq!(CL_KEYBIND_SNEAK.serialize(&val), "Ctrl+Q");
+        let val = CL_KEYBIND_SNEAK.deserialize("32").unwrap();
+        assert_eq!(CL_KEYBIND_SNEAK.serialize(&val), "W");
+        assert_eq!(
+            CL_KEYBIND_SNEAK.deserialize("Hyper+Q").err().as_deref(),
+            Some("Hyper+Q is not a key or mouse button")
+        );
+    }
+
+    #[test]
+    fn stevenkey_names() {
+        assert_eq!(Stevenkey::Forward.name(), "forward");
+        assert_eq!(Stevenkey::Hotbar(0).name(), "hotbar_1");
+        assert_eq!(Stevenkey::by_name("OPEN_INV"), Some(Stevenkey::OpenInv));
+        assert_eq!(Stevenkey::by_name("hotbar_9"), Some(Stevenkey::Hotbar(8)));
+        assert_eq!(Stevenkey::by_name("fly"), None);
+        for key in Stevenkey::values() {
+            assert_eq!(Stevenkey::by_name(key.name()), Some(key));
         }
     }
 }
@@ -0,0 +1,694 @@
+//! Draws item stacks in slots: the item's model with its count and how
+//! damaged it is, and the tooltip describing the stack.
+
+use crate::format::{self, Color, Component, TextComponent};
+use crate::item;
+use crate::lang;
+use crate::model;
+use crate::nbt;
+use crate::ui;
+use crate::world::{self, block};
+use std::cmp::Ordering;
+use std::sync::{Arc, RwLock};
+
+/// Light from the front, above and to the right. Faces pointing at the
+/// viewer, like flat items, are fully lit.
+const LIGHT: [f64; 3] = [0.14, 0.29, 0.51];
+const AMBIENT_LIGHT: f64 = 0.49;
+
+/// Bits of the `HideFlags` tag
+const HIDE_ENCHANTMENTS: i32 = 1;
+const HIDE_UNBREAKABLE: i32 = 4;
+/// Hides the enchantments stored in enchanted books, among other details
+const HIDE_OTHER: i32 = 32;
+
+/// Enchantments by their numeric id before 1.13, with the name used since
+/// and their translation key in the 1.12 resources
+const ENCHANTMENTS: &[(i16, &str, &str)] = &[
+    (0, "protection", "enchantment.protect.all"),
+    (1, "fire_protection", "enchantment.protect.fire"),
+    (2, "feather_falling", "enchantment.protect.fall"),
+    (3, "blast_protection", "enchantment.protect.explosion"),
+    (4, "projectile_protection", "enchantment.protect.projectile"),
+    (5, "respiration", "enchantment.oxygen"),
+    (6, "aqua_affinity", "enchantment.waterWorker"),
+    (7, "thorns", "enchantment.thorns"),
+    (8, "depth_strider", "enchantment.waterWalker"),
+    (9, "frost_walker", "enchantment.frostWalker"),
+    (10, "binding_curse", "enchantment.binding_curse"),
+    (16, "sharpness", "enchantment.damage.all"),
+    (17, "smite", "enchantment.damage.undead"),
+    (18, "bane_of_arthropods", "enchantment.damage.arthropods"),
+    (19, "knockback", "enchantment.knockback"),
+    (20, "fire_aspect", "enchantment.fire"),
+    (21, "looting", "enchantment.lootBonus"),
+    (22, "sweeping", "enchantment.sweeping"),
+    (32, "efficiency", "enchantment.digging"),
+    (33, "silk_touch", "enchantment.untouching"),
+    (34, "unbreaking", "enchantment.durability"),
+    (35, "fortune", "enchantment.lootBonusDigger"),
+    (48, "power", "enchantment.arrowDamage"),
+    (49, "punch", "enchantment.arrowKnockback"),
+    (50, "flame", "enchantment.arrowFire"),
+    (51, "infinity", "enchantment.arrowInfinite"),
+    (61, "luck_of_the_sea", "enchantment.lootBonusFishing"),
+    (62, "lure", "enchantment.fishingSpeed"),
+    (70, "mending", "enchantment.mending"),
+    (71, "vanishing_curse", "enchantment.vanishing_curse"),
+];
+
+/// Enchantments with a single level, which isn't shown
+const SINGLE_LEVEL_ENCHANTMENTS: &[&str] = &[
+    "aqua_affinity",
+    "binding_curse",
+    "channeling",
+    "flame",
+    "infinity",
+    "mending",
+    "multishot",
+    "silk_touch",
+    "vanishing_curse",
+];
+
+const ROMAN_NUMERALS: &[&str] = &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"];
+
+/// Looks up the items in stacks to draw and describe them
+#[derive(Clone)]
+pub struct ItemModels {
+    models: Arc<RwLock<model::Factory>>,
+    items: Arc<block::item::VanillaIDMap>,
+    blocks: Arc<block::VanillaIDMap>,
+}
+
+impl ItemModels {
+    pub fn new(models: &Arc<RwLock<model::Factory>>, world: &world::World) -> ItemModels {
+        ItemModels {
+            models: models.clone(),
+            items: world.item_map.clone(),
+            blocks: world.id_map.clone(),
+        }
+    }
+
+    /// Returns the quads drawing the stack's item, in a square from 0 to 1
+    fn quads(&self, stack: &item::Stack) -> Vec<ui::Quad> {
+        let item = match self.items.by_vanilla_id(stack.id, stack.damage) {
+            Some(item) => item,
+            None => return vec![],
+        };
+        let block = item.block(&self.blocks);
+        let model = match model::Factory::get_item_model(&self.models, item, block) {
+            Some(model) => model,
+            None => return vec![],
+        };
+        let tint = self
+            .models
+            .read()
+            .unwrap()
+            .get_item_tint(item, block, stack);
+        project(&model, tint)
+    }
+
+    /// Returns how much durability the stack has left from 0 to 1, if it
+    /// has been damaged
+    fn durability(&self, stack: &item::Stack) -> Option<f64> {
+        let max_damage = self
+            .items
+            .by_vanilla_id(stack.id, stack.damage)?
+            .max_damage()?;
+        let damage = stack.durability_damage();
+        if damage <= 0 || stack.is_unbreakable() {
+            return None;
+        }
+        Some((1.0 - damage as f64 / max_damage as f64).max(0.0))
+    }
+
+    /// Builds the tooltip describing the stack
+    pub fn tooltip(&self, stack: &item::Stack) -> Component {
+        tooltip(self.items.by_vanilla_id(stack.id, stack.damage), stack)
+    }
+}
+
+/// Builds the tooltip describing the stack of the item: its name,
+/// enchantments, lore, whether it is unbreakable and its durability.
+fn tooltip(item: Option<block::item::Item>, stack: &item::Stack) -> Component {
+    let tag = stack.tag.as_ref().and_then(|tag| tag.1.as_compound());
+    let get = |name: &str| tag.and_then(|tag| tag.get(name));
+    let display = get("display").and_then(|display| display.as_compound());
+    let hide_flags = get("HideFlags")
+        .and_then(as_number)
+        .map_or(0, |flags| flags as i32);
+
+    let mut enchantments = vec![];
+    if hide_flags & HIDE_ENCHANTMENTS == 0 {
+        enchantments.extend(enchantment_lines(get("Enchantments")));
+        enchantments.extend(enchantment_lines(get("ench")));
+    }
+    if hide_flags & HIDE_OTHER == 0 {
+        enchantments.extend(enchantment_lines(get("StoredEnchantments")));
+    }
+
+    let name = display
+        .and_then(|display| display.get("Name"))
+        .and_then(|name| name.as_str())
+        .map(Component::from_string)
+        .unwrap_or_else(|| {
+            let name = item.map_or_else(|| format!("#{}", stack.id), |item| item_name(&item));
+            Component::Text(TextComponent::new(&name))
+        });
+    // Enchanted items stand out like in vanilla
+    let mut lines = vec![coloured(
+        name,
+        if enchantments.is_empty() {
+            Color::White
+        } else {
+            Color::Aqua
+        },
+    )];
+    lines.extend(enchantments);
+    let lore = display
+        .and_then(|display| display.get("Lore"))
+        .and_then(|lore| lore.as_list())
+        .unwrap_or(&[]);
+    for line in lore.iter().filter_map(|line| line.as_str()) {
+        lines.push(coloured(Component::from_string(line), Color::DarkPurple));
+    }
+    if stack.is_unbreakable() {
+        if hide_flags & HIDE_UNBREAKABLE == 0 {
+            lines.push(text_line(
+                &lang::text("item.unbreakable", "Unbreakable"),
+                Color::Blue,
+            ));
+        }
+    } else if let Some(max_damage) = item.and_then(|item| item.max_damage()) {
+        let damage = stack.durability_damage();
+        if damage > 0 {
+            let durability = lang::text("item.durability", "Durability: %s / %s")
+                .replacen("%s", &(max_damage as isize - damage).to_string(), 1)
+                .replacen("%s", &max_damage.to_string(), 1);
+            lines.push(text_line(&durability, Color::White));
+        }
+    }
+
+    let mut extra = vec![];
+    for (i, line) in lines.into_iter().enumerate() {
+        if i != 0 {
+            extra.push(Component::Text(TextComponent::new("\n")));
+        }
+        extra.push(line);
+    }
+    let mut text = TextComponent::new("");
+    text.modifier.extra = Some(extra);
+    Component::Text(text)
+}
+
+/// Reads a number stored as any integer type, as the type used for the
+/// same tag varies between versions
+fn as_number(tag: &nbt::Tag) -> Option<i64> {
+    match *tag {
+        nbt::Tag::Byte(val) => Some(val.into()),
+        nbt::Tag::Short(val) => Some(val.into()),
+        nbt::Tag::Int(val) => Some(val.into()),
+        nbt::Tag::Long(val) => Some(val),
+        _ => None,
+    }
+}
+
+/// Shows the component in the colour, unless it has its own
+fn coloured(component: Component, colour: Color) -> Component {
+    let mut text = TextComponent::new("");
+    text.modifier.color = Some(colour);
+    text.modifier.extra = Some(vec![component]);
+    Component::Text(text)
+}
+
+fn text_line(text: &str, colour: Color) -> Component {
+    coloured(Component::Text(TextComponent::new(text)), colour)
+}
+
+/// Turns a name like `diamond_sword` into `Diamond Sword`
+fn title_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the translated name of the item. The 1.12 resources name items
+/// differently, so their names are made from the item's own name instead.
+fn item_name(item: &block::item::Item) -> String {
+    format::translate(&format!("item.minecraft.{}", item.name))
+        .or_else(|| format::translate(&format!("block.minecraft.{}", item.name)))
+        .unwrap_or_else(|| title_case(item.name))
+}
+
+/// Describes each enchantment in a list of them, stored with numeric ids
+/// before 1.13 and named ones since.
+fn enchantment_lines(list: Option<&nbt::Tag>) -> Vec<Component> {
+    let list = list.and_then(|list| list.as_list()).unwrap_or(&[]);
+    let mut lines = vec![];
+    for enchantment in list.iter().filter_map(|e| e.as_compound()) {
+        let id = match enchantment.get("id") {
+            Some(id) => id,
+            None => continue,
+        };
+        let level = enchantment.get("lvl").and_then(as_number).unwrap_or(1);
+        let known = match id.as_str() {
+            Some(name) => {
+                let name = name.strip_prefix("minecraft:").unwrap_or(name);
+                ENCHANTMENTS
+                    .iter()
+                    .find(|e| e.1 == name)
+                    .copied()
+                    .ok_or(name)
+            }
+            None => {
+                let id = as_number(id);
+                match ENCHANTMENTS.iter().find(|e| Some(i64::from(e.0)) == id) {
+                    Some(&e) => Ok(e),
+                    None => continue,
+                }
+            }
+        };
+        let (name, text) = match known {
+            Ok((_, name, key)) => (name, format::translate(key)),
+            Err(name) => (name, None),
+        };
+        let mut text = text
+            .or_else(|| format::translate(&format!("enchantment.minecraft.{}", name)))
+            .unwrap_or_else(|| title_case(name));
+        if level != 1 || !SINGLE_LEVEL_ENCHANTMENTS.contains(&name) {
+            let numeral = ROMAN_NUMERALS
+                .get((level - 1) as usize)
+                .map_or_else(|| level.to_string(), |numeral| (*numeral).to_owned());
+            text.push(' ');
+            text.push_str(&lang::text(
+                &format!("enchantment.level.{}", level),
+                &numeral,
+            ));
+        }
+        let colour = if name.ends_with("_curse") {
+            Color::Red
+        } else {
+            Color::Gray
+        };
+        lines.push(text_line(&text, colour));
+    }
+    lines
+}
+
+/// Places the model's faces with its `gui` transform, viewed from the
+/// front. Faces are ordered back to front as the ui has no depth.
+fn project(model: &model::item::ItemModel, tint: (u8, u8, u8)) -> Vec<ui::Quad> {
+    let gui = &model.gui;
+    let [rx, ry, rz] = [
+        gui.rotation[0].to_radians(),
+        gui.rotation[1].to_radians(),
+        gui.rotation[2].to_radians(),
+    ];
+    let rotate = |[x, y, z]: [f64; 3]| {
+        let (x, y) = (x * rz.cos() - y * rz.sin(), x * rz.sin() + y * rz.cos());
+        let (x, z) = (x * ry.cos() + z * ry.sin(), z * ry.cos() - x * ry.sin());
+        let (y, z) = (y * rx.cos() - z * rx.sin(), y * rx.sin() + z * rx.cos());
+        [x, y, z]
+    };
+
+    let mut quads = vec![];
+    for face in &model.faces {
+        let (nx, ny, nz) = face.facing.get_offset();
+        let normal = rotate([nx as f64, ny as f64, nz as f64]);
+        if normal[2] <= 0.001 {
+            continue;
+        }
+        let mut corners = [(0.0, 0.0); 4];
+        let mut depth = 0.0;
+        for (corner, v) in corners.iter_mut().zip(&face.vertices) {
+            let p = rotate([
+                (v[0] - 0.5) * gui.scale[0],
+                (v[1] - 0.5) * gui.scale[1],
+                (v[2] - 0.5) * gui.scale[2],
+            ]);
+            *corner = (
+                0.5 + p[0] + gui.translation[0] / 16.0,
+                0.5 - p[1] - gui.translation[1] / 16.0,
+            );
+            depth += p[2];
+        }
+        // Only faces wound clockwise on screen are drawn
+        let mut texture_coords = face.texture_coords;
+        let (a, b, c) = (corners[0], corners[1], corners[2]);
+        if (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0) < 0.0 {
+            corners.swap(1, 2);
+            texture_coords.swap(1, 2);
+        }
+
+        let light =
+            (AMBIENT_LIGHT + normal[0] * LIGHT[0] + normal[1] * LIGHT[1] + normal[2] * LIGHT[2])
+                .clamp(0.0, 1.0);
+        let (r, g, b) = if face.tint_index == 0 {
+            tint
+        } else {
+            (255, 255, 255)
+        };
+        let shade = |c: u8| (c as f64 * light) as u8;
+        quads.push((
+            depth,
+            ui::Quad {
+                corners,
+                texture: face.texture.clone(),
+                texture_coords,
+                colour: (shade(r), shade(g), shade(b), 255),
+            },
+        ));
+    }
+    // Stable, so layers at the same depth keep their order
+    quads.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    quads.into_iter().map(|(_, quad)| quad).collect()
+}
+
+/// Returns the colour of a durability bar, from green when undamaged to
+/// red when about to break
+fn durability_colour(durability: f64) -> (u8, u8, u8, u8) {
+    let hue = durability * 2.0;
+    if hue < 1.0 {
+        (255, (hue * 255.0) as u8, 0, 255)
+    } else {
+        (((2.0 - hue) * 255.0) as u8, 255, 0, 255)
+    }
+}
+
+/// An item stack drawn in a slot, in vanilla's 16 pixel slot layout
+/// scaled to the icon's size
+pub struct Icon {
+    model: ui::ModelRef,
+    count: ui::TextRef,
+    durability_background: ui::ImageRef,
+    durability: ui::ImageRef,
+    scale: f64,
+    stack: Option<item::Stack>,
+}
+
+impl Icon {
+    /// Adds an empty icon to the parent
+    pub fn new<H: ui::ElementHolder>(
+        parent: &mut H,
+        x: f64,
+        y: f64,
+        size: f64,
+        draw_index: isize,
+    ) -> Icon {
+        let scale = size / 16.0;
+        let model = ui::ModelBuilder::new()
+            .position(x, y)
+            .size(size, size)
+            .draw_index(draw_index)
+            .attach(parent);
+        let durability_background = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(2.0 * scale, 13.0 * scale)
+            .size(13.0 * scale, 2.0 * scale)
+            .colour((0, 0, 0, 0))
+            .attach(&mut *model.borrow_mut());
+        let durability = ui::ImageBuilder::new()
+            .texture("steven:solid")
+            .position(2.0 * scale, 13.0 * scale)
+            .size(0.0, scale)
+            .colour((0, 0, 0, 0))
+            .draw_index(1)
+            .attach(&mut *model.borrow_mut());
+        let count = ui::TextBuilder::new()
+            .text("")
+            .position(0.0, 0.0)
+            .alignment(ui::VAttach::Bottom, ui::HAttach::Right)
+            .draw_index(2)
+            .attach(&mut *model.borrow_mut());
+        Icon {
+            model,
+            count,
+            durability_background,
+            durability,
+            scale,
+            stack: None,
+        }
+    }
+
+    /// Shows the stack, or nothing when the slot is empty
+    pub fn set_stack(&mut self, models: &ItemModels, stack: Option<&item::Stack>) {
+        if self.stack.as_ref() == stack {
+            return;
+        }
+        self.stack = stack.cloned();
+
+        self.model.borrow_mut().quads = stack.map_or_else(Vec::new, |stack| models.quads(stack));
+        self.count.borrow_mut().text = match stack {
+            Some(stack) if stack.count != 1 => stack.count.to_string(),
+            _ => String::new(),
+        };
+        let durability = stack.and_then(|stack| models.durability(stack));
+        let mut background = self.durability_background.borrow_mut();
+        let mut bar = self.durability.borrow_mut();
+        match durability {
+            Some(durability) => {
+                background.colour.3 = 255;
+                bar.width = (durability * 13.0).round() * self.scale;
+                bar.colour = durability_colour(durability);
+            }
+            None => {
+                background.colour.3 = 0;
+                bar.colour.3 = 0;
+            }
+        }
+    }
+
+    pub fn set_position(&self, x: f64, y: f64) {
+        let mut model = self.model.borrow_mut();
+        model.x = x;
+        model.y = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Finds the item's id in the version
+    fn item(version: i32, name: &str) -> (isize, block::item::Item) {
+        let items = block::item::VanillaIDMap::new(version);
+        (0..2000)
+            .find_map(|id| {
+                items
+                    .by_vanilla_id(id, None)
+                    .filter(|item| item.name == name)
+                    .map(|item| (id, item))
+            })
+            .unwrap()
+    }
+
+    fn compound(tags: Vec<(&str, nbt::Tag)>) -> nbt::Tag {
+        nbt::Tag::Compound(
+            tags.into_iter()
+                .map(|(name, tag)| (name.to_owned(), tag))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    fn string(val: &str) -> nbt::Tag {
+        nbt::Tag::String(val.to_owned())
+    }
+
+    fn enchantment(id: nbt::Tag, level: i16) -> nbt::Tag {
+        compound(vec![("id", id), ("lvl", nbt::Tag::Short(level))])
+    }
+
+    /// The tooltip of a diamond sword in the version
+    fn sword_tooltip(
+        version: i32,
+        damage: Option<isize>,
+        tags: Vec<(&str, nbt::Tag)>,
+    ) -> Component {
+        let (id, item) = item(version, "diamond_sword");
+        let stack = item::Stack {
+            id,
+            count: 1,
+            damage,
+            tag: Some(nbt::NamedTag(String::new(), compound(tags))),
+        };
+        tooltip(Some(item), &stack)
+    }
+
+    /// The lines of the tooltip of a diamond sword in the version
+    fn sword(version: i32, damage: Option<isize>, tags: Vec<(&str, nbt::Tag)>) -> Vec<String> {
+        sword_tooltip(version, damage, tags)
+            .to_string()
+            .split('\n')
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// The colour of the first line of a diamond sword's tooltip, its name
+    fn name_colour(version: i32, tags: Vec<(&str, nbt::Tag)>) -> String {
+        match sword_tooltip(version, None, tags) {
+            Component::Text(text) => match &text.modifier.extra.unwrap()[0] {
+                Component::Text(name) => format!("{:?}", name.modifier.color.unwrap()),
+                other => panic!("{:?}", other),
+            },
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(sword(340, None, vec![]), vec!["Diamond Sword"]);
+        let legacy = compound(vec![("Name", string("§6Golden §lBlade"))]);
+        assert_eq!(
+            sword(340, None, vec![("display", legacy)]),
+            vec!["Golden Blade"]
+        );
+        let json = compound(vec![("Name", string(r#"{"text":"Blade","color":"red"}"#))]);
+        assert_eq!(sword(404, None, vec![("display", json)]), vec!["Blade"]);
+
+        let unknown = item::Stack {
+            id: 9999,
+            count: 1,
+            damage: None,
+            tag: None,
+        };
+        assert_eq!(tooltip(None, &unknown).to_string(), "#9999");
+    }
+
+    #[test]
+    fn lore() {
+        let display = compound(vec![
+            ("Name", string("Blade")),
+            (
+                "Lore",
+                nbt::Tag::List(vec![string("First"), string("§cSecond")]),
+            ),
+        ]);
+        assert_eq!(
+            sword(340, None, vec![("display", display)]),
+            vec!["Blade", "First", "Second"]
+        );
+    }
+
+    #[test]
+    fn enchantments() {
+        let named = nbt::Tag::List(vec![
+            enchantment(string("minecraft:sharpness"), 5),
+            enchantment(string("mending"), 1),
+            enchantment(string("minecraft:binding_curse"), 1),
+            enchantment(string("minecraft:looting"), 11),
+            enchantment(string("minecraft:soul_speed"), 3),
+        ]);
+        assert_eq!(
+            sword(404, None, vec![("Enchantments", named.clone())]),
+            vec![
+                "Diamond Sword",
+                "Sharpness V",
+                "Mending",
+                "Binding Curse",
+                "Looting 11",
+                "Soul Speed III",
+            ]
+        );
+        // Enchanted items have an aqua name
+        assert_eq!(name_colour(404, vec![]), "White");
+        assert_eq!(name_colour(404, vec![("Enchantments", named)]), "Aqua");
+
+        // Numeric ids before 1.13, unknown ones are left out
+        let legacy = nbt::Tag::List(vec![
+            enchantment(nbt::Tag::Short(16), 2),
+            enchantment(nbt::Tag::Short(1000), 1),
+            enchantment(nbt::Tag::Short(70), 1),
+        ]);
+        assert_eq!(
+            sword(340, None, vec![("ench", legacy)]),
+            vec!["Diamond Sword", "Sharpness II", "Mending"]
+        );
+    }
+
+    #[test]
+    fn damage() {
+        assert_eq!(
+            sword(340, Some(61), vec![]),
+            vec!["Diamond Sword", "Durability: 1500 / 1561"]
+        );
+        // Kept in the NBT since 1.13
+        assert_eq!(
+            sword(404, None, vec![("Damage", nbt::Tag::Int(61))]),
+            vec!["Diamond Sword", "Durability: 1500 / 1561"]
+        );
+        assert_eq!(
+            sword(404, None, vec![("Damage", nbt::Tag::Int(0))]),
+            vec!["Diamond Sword"]
+        );
+    }
+
+    #[test]
+    fn unbreakable() {
+        assert_eq!(
+            sword(
+                404,
+                None,
+                vec![
+                    ("Damage", nbt::Tag::Int(61)),
+                    ("Unbreakable", nbt::Tag::Byte(1)),
+                ]
+            ),
+            vec!["Diamond Sword", "Unbreakable"]
+        );
+        assert_eq!(
+            sword(340, Some(61), vec![("Unbreakable", nbt::Tag::Byte(0))]),
+            vec!["Diamond Sword", "Durability: 1500 / 1561"]
+        );
+    }
+
+    #[test]
+    fn hide_flags() {
+        let tags = |flags: nbt::Tag| {
+            vec![
+                ("HideFlags", flags),
+                (
+                    "Enchantments",
+                    nbt::Tag::List(vec![enchantment(string("sharpness"), 1)]),
+                ),
+                (
+                    "StoredEnchantments",
+                    nbt::Tag::List(vec![enchantment(string("mending"), 1)]),
+                ),
+                ("Unbreakable", nbt::Tag::Byte(1)),
+            ]
+        };
+        assert_eq!(
+            sword(404, None, tags(nbt::Tag::Int(0))),
+            vec!["Diamond Sword", "Sharpness I", "Mending", "Unbreakable"]
+        );
+        assert_eq!(
+            sword(404, None, tags(nbt::Tag::Int(HIDE_ENCHANTMENTS))),
+            vec!["Diamond Sword", "Mending", "Unbreakable"]
+        );
+        assert_eq!(
+            sword(404, None, tags(nbt::Tag::Int(HIDE_UNBREAKABLE))),
+            vec!["Diamond Sword", "Sharpness I", "Mending"]
+        );
+        assert_eq!(
+            sword(404, None, tags(nbt::Tag::Int(HIDE_OTHER))),
+            vec!["Diamond Sword", "Sharpness I", "Unbreakable"]
+        );
+        // Older versions store the flags as other integer types
+        assert_eq!(
+            sword(340, None, tags(nbt::Tag::Byte(37))),
+            vec!["Diamond Sword"]
+        );
+        // Hidden enchantments don't make the name aqua
+        assert_eq!(
+            name_colour(404, tags(nbt::Tag::Int(HIDE_ENCHANTMENTS | HIDE_OTHER))),
+            "White"
+        );
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod item;
 pub mod logo;
 
 use crate::format;
@@ -252,6 +253,7 @@ macro_rules! define_elements {
 define_elements! {
     Image,
     Batch,
+    Model,
     Text,
     Formatted,
     Button,
@@ -487,6 +489,22 @@ impl Container {
     }
 }
 
+/// Copies the text to the system clipboard
+pub fn copy_to_clipboard(text: &str) {
+    // TODO: wasm clipboard, Clipboard API: https://www.w3.org/TR/clipboard-apis/
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let clipboard: Result<ClipboardContext, _> = ClipboardProvider::new();
+        if let Err(err) =
+            clipboard.and_then(|mut clipboard| clipboard.set_contents(text.to_owned()))
+        {
+            log::warn!("Failed to copy to the clipboard: {}", err);
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = text;
+}
+
 impl ElementHolder for Container {
     fn add(&mut self, el: Element, auto_free: bool) {
         if !auto_free {
@@ -520,6 +538,8 @@ trait UIElement {
     ) {
     }
     fn key_type(&mut self, _game: &mut crate::Game, _c: char) {}
+    /// Called with the mouse position before hover and click handlers
+    fn mouse_at(&mut self, _r: &Region, _mx: f64, _my: f64, _sw: f64, _sh: f64) {}
     fn tick(&mut self, renderer: &mut render::Renderer);
 }
 
@@ -633,6 +653,7 @@ macro_rules! element {
 
             fn hover_at(&mut self, super_region: &Region, game: &mut crate::Game, mx: f64, my: f64, sw: f64, sh: f64) -> bool {
                 use std::mem;
+                self.mouse_at(super_region, mx, my, sw, sh);
                 let mut handle_self = true;
                 for e in &self.elements {
                     let r = Container::compute_draw_region(&e.1, sw, sh, &super_region);
@@ -666,6 +687,7 @@ macro_rules! element {
 
             fn click_at(&mut self, super_region: &Region, game: &mut crate::Game, mx: f64, my: f64, sw: f64, sh: f64) -> bool {
                 use std::mem;
+                self.mouse_at(super_region, mx, my, sw, sh);
                 let mut handle_self = true;
                 for e in &self.elements {
                     let r = Container::compute_draw_region(&e.1, sw, sh, &super_region);
@@ -959,6 +981,95 @@ impl UIElement for Batch {
     }
 }
 
+/// A textured quad with any four corners, drawn by `Model`
+#[derive(Clone, PartialEq)]
+pub struct Quad {
+    /// The corners relative to the element from 0 to 1, ordered like a
+    /// rectangle's top left, top right, bottom left then bottom right
+    pub corners: [(f64, f64); 4],
+    pub texture: String,
+    /// The texture coordinates of each corner from 0 to 1
+    pub texture_coords: [(f64, f64); 4],
+    pub colour: (u8, u8, u8, u8),
+}
+
+element! {
+    ref ModelRef
+    pub struct Model {
+        pub width: f64,
+        pub height: f64,
+        pub quads: Vec<Quad>,
+        priv last_quads: Vec<Quad>,
+    }
+    builder ModelBuilder {
+        hardcode last_quads = vec![],
+        optional quads: Vec<Quad> = vec![],
+        noset width: f64 = |b| b.width.expect("Missing required field width"),
+        noset height: f64 = |b| b.height.expect("Missing required field height"),
+    }
+}
+
+impl ModelBuilder {
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+}
+
+impl UIElement for Model {
+    fn draw(
+        &mut self,
+        renderer: &mut render::Renderer,
+        r: &Region,
+        sw: f64,
+        sh: f64,
+        width: f64,
+        height: f64,
+        delta: f64,
+    ) -> &mut [u8] {
+        if self.check_rebuild() {
+            self.data.clear();
+            for quad in &self.quads {
+                let texture =
+                    render::Renderer::get_texture(renderer.get_textures_ref(), &quad.texture);
+                let mut element =
+                    render::ui::UIElement::new(&texture, r.x, r.y, r.w, r.h, 0.0, 0.0, 1.0, 1.0);
+                element.r = quad.colour.0;
+                element.g = quad.colour.1;
+                element.b = quad.colour.2;
+                element.a = quad.colour.3;
+                for (&(x, y), &(tx, ty)) in quad.corners.iter().zip(&quad.texture_coords) {
+                    element.append_vertex(
+                        &mut self.data,
+                        element.x + x * element.w,
+                        element.y + y * element.h,
+                        (tx * texture.get_width() as f64 * 16.0) as i16,
+                        (ty * texture.get_height() as f64 * 16.0) as i16,
+                        width,
+                        height,
+                    );
+                }
+            }
+            self.super_draw(renderer, r, sw, sh, width, height, delta);
+            self.last_quads = self.quads.clone();
+        }
+        &mut self.data
+    }
+
+    fn tick(&mut self, renderer: &mut render::Renderer) {
+        self.super_tick(renderer);
+    }
+
+    fn get_size(&self) -> (f64, f64) {
+        (self.width, self.height)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.last_quads != self.quads
+    }
+}
+
 element! {
     ref TextRef
     pub struct Text {
@@ -1081,13 +1192,17 @@ element! {
         pub scale_x: f64,
         pub scale_y: f64,
         pub max_width: f64,
+        pub alpha: u8,
         priv text: format::Component,
         priv text_elements: Vec<Element>,
         priv last_text: format::Component,
         priv last_scale_x: f64,
         priv last_scale_y: f64,
         priv last_max_width: f64,
+        priv last_alpha: u8,
         priv dirty: bool,
+        priv spans: Vec<FormatSpan>,
+        priv mouse: Option<(f64, f64)>,
     }
     builder FormattedBuilder {
         hardcode width = 0.0,
@@ -1097,11 +1212,15 @@ element! {
         hardcode last_scale_x = 0.0,
         hardcode last_scale_y = 0.0,
         hardcode last_max_width = -1.0,
+        hardcode last_alpha = 255,
         hardcode dirty = true,
+        hardcode spans = vec![],
+        hardcode mouse = None,
         simple text: format::Component,
         optional scale_x: f64 = 1.0,
         optional scale_y: f64 = 1.0,
         optional max_width: f64 = -1.0,
+        optional alpha: u8 = 255,
     }
 }
 
@@ -1127,10 +1246,15 @@ impl UIElement for Formatted {
                     offset: 0.0,
                     text: Vec::new(),
                     max_width: self.max_width,
+                    alpha: self.alpha,
                     renderer,
+                    spans: Vec::new(),
+                    click_event: None,
+                    hover_event: None,
                 };
                 state.build(&self.text, format::Color::White);
                 self.text_elements = state.text;
+                self.spans = state.spans;
             }
 
             for e in &self.text_elements {
@@ -1147,6 +1271,7 @@ impl UIElement for Formatted {
             self.last_scale_x = self.scale_x;
             self.last_scale_y = self.scale_y;
             self.last_max_width = self.max_width;
+            self.last_alpha = self.alpha;
             self.dirty = false;
         }
         &mut self.data
@@ -1168,11 +1293,20 @@ impl UIElement for Formatted {
         )
     }
 
+    fn mouse_at(&mut self, r: &Region, mx: f64, my: f64, sw: f64, sh: f64) {
+        self.mouse = if mx >= r.x && mx <= r.x + r.w && my >= r.y && my <= r.y + r.h {
+            Some(((mx - r.x) / sw, (my - r.y) / sh))
+        } else {
+            None
+        };
+    }
+
     fn is_dirty(&self) -> bool {
         self.dirty
             || self.last_scale_x != self.scale_x
             || self.last_scale_y != self.scale_y
             || self.last_max_width != self.max_width
+            || self.last_alpha != self.alpha
     }
 }
 
@@ -1193,20 +1327,50 @@ impl Formatted {
             offset: 0.0,
             text: Vec::new(),
             max_width,
+            alpha: 255,
             renderer,
+            spans: Vec::new(),
+            click_event: None,
+            hover_event: None,
         };
         state.build(text, format::Color::White);
         (state.width + 2.0, (state.lines + 1) as f64 * 18.0)
     }
+
+    /// The part of the text under the mouse that has a click or hover
+    /// event, with its index.
+    pub fn span_at_mouse(&self) -> Option<(usize, &FormatSpan)> {
+        let (mx, my) = self.mouse?;
+        self.spans.iter().enumerate().find(|(_, span)| {
+            mx >= span.x && mx < span.x + span.width && my >= span.y && my < span.y + span.height
+        })
+    }
+}
+
+/// Part of a formatted text with a click or hover event, positioned
+/// relative to the text.
+pub struct FormatSpan {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub click_event: Option<format::ClickEvent>,
+    pub hover_event: Option<format::HoverEvent>,
 }
 
 struct FormatState<'a> {
     max_width: f64,
+    alpha: u8,
     lines: usize,
     offset: f64,
     width: f64,
     text: Vec<Element>,
     renderer: &'a render::Renderer,
+    spans: Vec<FormatSpan>,
+    /// The events of the component being built, inherited from its
+    /// parents.
+    click_event: Option<format::ClickEvent>,
+    hover_event: Option<format::HoverEvent>,
 }
 
 impl<'a> ElementHolder for FormatState<'a> {
@@ -1220,17 +1384,42 @@ impl<'a> FormatState<'a> {
         match *c {
             format::Component::Text(ref txt) => {
                 let col = FormatState::get_color(&txt.modifier, color);
-                self.append_text(&txt.text, col);
                 let modi = &txt.modifier;
+                let parent_click = self.click_event.clone();
+                let parent_hover = self.hover_event.clone();
+                if let Some(ref click_event) = modi.click_event {
+                    self.click_event = Some((**click_event).clone());
+                }
+                if let Some(ref hover_event) = modi.hover_event {
+                    self.hover_event = Some((**hover_event).clone());
+                }
+                self.append_text(&txt.text, col);
                 if let Some(ref extra) = modi.extra {
                     for e in extra {
                         self.build(e, col);
                     }
                 }
+                self.click_event = parent_click;
+                self.hover_event = parent_hover;
             }
+            ref other => self.build(&format::Component::Text(other.resolve()), color),
         }
     }
 
+    fn add_span(&mut self, x: f64, width: f64) {
+        if self.click_event.is_none() && self.hover_event.is_none() {
+            return;
+        }
+        self.spans.push(FormatSpan {
+            x,
+            y: (self.lines * 18) as f64,
+            width,
+            height: 18.0,
+            click_event: self.click_event.clone(),
+            hover_event: self.hover_event.clone(),
+        });
+    }
+
     fn append_text(&mut self, txt: &str, color: format::Color) {
         let mut width = 0.0;
         let mut last = 0;
@@ -1241,8 +1430,9 @@ impl<'a> FormatState<'a> {
                 TextBuilder::new()
                     .text(&txt[last..i])
                     .position(self.offset, (self.lines * 18 + 1) as f64)
-                    .colour((rr, gg, bb, 255))
+                    .colour((rr, gg, bb, self.alpha))
                     .create(self);
+                self.add_span(self.offset, width);
                 last = i;
                 if c == '\n' {
                     last += 1;
@@ -1262,9 +1452,11 @@ impl<'a> FormatState<'a> {
             TextBuilder::new()
                 .text(&txt[last..])
                 .position(self.offset, (self.lines * 18 + 1) as f64)
-                .colour((rr, gg, bb, 255))
+                .colour((rr, gg, bb, self.alpha))
                 .create(self);
-            self.offset += self.renderer.ui.size_of_string(&txt[last..]) + 2.0;
+            let width = self.renderer.ui.size_of_string(&txt[last..]) + 2.0;
+            self.add_span(self.offset, width);
+            self.offset += width;
             if self.offset > self.width {
                 self.width = self.offset;
             }
@@ -1641,6 +1833,12 @@ impl TextBox {
         self.submit_funcs.push(Box::new(f));
     }
 
+    /// Shows the cursor for text boxes that receive key presses without
+    /// being focused in the container.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
     fn transform_input(&self) -> String {
         if self.password {
             ::std::iter::repeat('*').take(self.input.len()).collect()
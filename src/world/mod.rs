@@ -30,6 +30,7 @@ use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::hash::BuildHasherDefault;
 use std::io::Read;
+use std::sync::Arc;
 
 pub mod biome;
 mod storage;
@@ -46,7 +47,8 @@ pub struct World {
 
     protocol_version: i32,
     pub modded_block_ids: HashMap<usize, String>,
-    pub id_map: block::VanillaIDMap,
+    pub id_map: Arc<block::VanillaIDMap>,
+    pub item_map: Arc<block::item::VanillaIDMap>,
 }
 
 #[derive(Clone, Debug)]
@@ -92,10 +94,12 @@ struct LightUpdate {
 
 impl World {
     pub fn new(protocol_version: i32) -> World {
-        let id_map = block::VanillaIDMap::new(protocol_version);
+        let id_map = Arc::new(block::VanillaIDMap::new(protocol_version));
+        let item_map = Arc::new(block::item::VanillaIDMap::new(protocol_version));
         World {
             protocol_version,
             id_map,
+            item_map,
             ..Default::default()
         }
     }
@@ -337,7 +341,9 @@ impl World {
         dirty
     }
 
-    pub fn compute_render_list(&mut self, renderer: &mut render::Renderer) {
+    /// Finds the sections to draw this frame, up to `render_distance`
+    /// chunks away from the camera.
+    pub fn compute_render_list(&mut self, renderer: &mut render::Renderer, render_distance: i32) {
         self.render_list.clear();
 
         let mut valid_dirs = [false; 6];
@@ -392,6 +398,11 @@ impl World {
             for dir in Direction::all() {
                 let (ox, oy, oz) = dir.get_offset();
                 let opos = (pos.0 + ox, pos.1 + oy, pos.2 + oz);
+                if (opos.0 - start.0).abs() > render_distance
+                    || (opos.2 - start.2).abs() > render_distance
+                {
+                    continue;
+                }
                 if let Some((_, rendered_on)) = self.get_render_section_mut(opos.0, opos.1, opos.2)
                 {
                     if *rendered_on == renderer.frame_id {
@@ -601,6 +612,20 @@ impl World {
         }
     }
 
+    /// Unloads every chunk further than `distance` chunks away from the
+    /// chunk at `x`, `z` on either axis.
+    pub fn unload_distant_chunks(&mut self, x: i32, z: i32, distance: i32, m: &mut ecs::Manager) {
+        let distant: Vec<CPos> = self
+            .chunks
+            .keys()
+            .filter(|pos| (pos.0 - x).abs() > distance || (pos.1 - z).abs() > distance)
+            .copied()
+            .collect();
+        for pos in distant {
+            self.unload_chunk(pos.0, pos.1, m);
+        }
+    }
+
     pub fn load_chunks18(
         &mut self,
         new: bool,
@@ -0,0 +1,83 @@
+//! Connects the client's `Server` to a mock server for every supported
+//! protocol version, checking how it plays the start of a game.
+
+#[path = "../protocol/tests/mock_server/mod.rs"]
+mod mock_server;
+
+use mock_server::{MockServer, Step};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use steven_protocol::protocol::{State, SUPPORTED_PROTOCOLS};
+use steven_shared::Position;
+use stevenarella::resources;
+use stevenarella::server::Server;
+use stevenarella::world::block;
+
+/// How long the client has to load the chunk
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn connect_and_load_chunk() {
+    let _lock = mock_server::lock_version();
+    let resources = Arc::new(RwLock::new(resources::Manager::internal()));
+    for version in SUPPORTED_PROTOCOLS.iter().copied() {
+        let mut steps = mock_server::expect_login(version, "");
+        steps.extend(vec![
+            Step::Send(mock_server::login_success(version)),
+            Step::SetState(State::Play),
+            Step::Send(mock_server::join_game(version)),
+            // Before the chunk, so it has been answered once the chunk
+            // is loaded
+            Step::Send(mock_server::keep_alive(version)),
+            Step::Send(mock_server::chunk_data(version)),
+            Step::find("KeepAlive", mock_server::is_keep_alive_reply),
+        ]);
+        let mock = MockServer::start(version, steps);
+
+        let mut server = Server::connect(
+            resources.clone(),
+            mock_server::profile(),
+            &mock.address,
+            version,
+            vec![],
+            None,
+        )
+        .unwrap_or_else(|err| panic!("protocol {}: connecting failed: {}", version, err));
+        let start = Instant::now();
+        while !server.world.is_chunk_loaded(0, 0) {
+            assert!(
+                server.is_connected(),
+                "protocol {}: disconnected: {:?}",
+                version,
+                server.disconnect_reason
+            );
+            assert!(
+                start.elapsed() < TIMEOUT,
+                "protocol {}: the chunk wasn't loaded",
+                version
+            );
+            server.tick(None, None, 1.0);
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(server.player.is_some(), "protocol {}", version);
+        assert_eq!(
+            server.world.get_block(Position::new(3, 15, 7)),
+            block::Stone {
+                variant: block::StoneVariant::Normal,
+            },
+            "protocol {}",
+            version
+        );
+        assert_eq!(
+            server.world.get_block(Position::new(3, 16, 7)),
+            block::Air {},
+            "protocol {}",
+            version
+        );
+
+        if let Err(err) = mock.finish() {
+            panic!("protocol {}: {}", version, err);
+        }
+    }
+}